@@ -7,6 +7,7 @@ use crate::api_worker::api::middleware::websocket_authenticated;
 use crate::api_worker::api::routes::checkout::handle_create_checkout_session;
 use crate::api_worker::api::routes::checkout::handle_stripe_webhook;
 use crate::api_worker::api::routes::commands::handle_command;
+use crate::api_worker::api::routes::commands::handle_command_batch;
 use crate::api_worker::api::routes::labour::get_active_labour;
 use crate::api_worker::api::routes::labour::get_labour_history;
 use crate::api_worker::api::routes::labour::handle_plan_labour;
@@ -42,6 +43,10 @@ pub fn create_router(app_state: AppState) -> Router<'static, AppState> {
             authenticated(handle_command, req, ctx)
         })
         .options("/api/v1/command", create_options_handler)
+        .post_async("/api/v1/command/batch", |req, ctx| {
+            authenticated(handle_command_batch, req, ctx)
+        })
+        .options("/api/v1/command/batch", create_options_handler)
         .post_async("/api/v1/query", |req, ctx| {
             authenticated(handle_query, req, ctx)
         })
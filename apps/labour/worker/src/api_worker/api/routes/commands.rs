@@ -1,4 +1,4 @@
-use fern_labour_labour_shared::ApiCommand;
+use fern_labour_labour_shared::{ApiCommand, CommandBatch};
 use fern_labour_workers_shared::{CorsContext, clients::worker_clients::auth::User};
 use tracing::error;
 use worker::{Request, Response, RouteContext};
@@ -52,3 +52,38 @@ pub async fn handle_command(
 
     Ok(cors_context.add_to_response(new_response))
 }
+
+pub async fn handle_command_batch(
+    mut req: Request,
+    ctx: RouteContext<AppState>,
+    cors_context: CorsContext,
+    user: User,
+) -> worker::Result<Response> {
+    let batch: CommandBatch = match req.json().await {
+        Ok(batch) => batch,
+        Err(e) => {
+            error!(user_id = %user.user_id, error = ?e, "Failed to parse request body");
+            let response = Response::from(ApiError::ValidationError(
+                "Failed to parse request body".into(),
+            ));
+            return Ok(cors_context.add_to_response(response));
+        }
+    };
+
+    let mut do_response = ctx
+        .data
+        .do_client
+        .send_raw_command(batch.labour_id, &batch, &user, "/api/command/batch")
+        .await
+        .map_err(|e| format!("Failed to send command batch to labour_aggregate: {e}"))?;
+
+    let body = do_response.text().await?;
+    let status = do_response.status_code();
+
+    let mut new_response = Response::ok(body)?.with_status(status);
+    let _ = new_response
+        .headers_mut()
+        .set("Content-Type", "application/json");
+
+    Ok(cors_context.add_to_response(new_response))
+}
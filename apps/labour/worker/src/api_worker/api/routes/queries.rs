@@ -24,6 +24,7 @@ pub async fn handle_query(
     };
 
     let labour_id = query.labour_id();
+
     let query_payload = serde_json::to_value(&query)?;
 
     let mut do_response = ctx
@@ -14,9 +14,12 @@ pub enum QueryAction {
     GetLabour,
     GetContractions,
     GetLabourUpdates,
+    GetScheduledLabourUpdates,
+    GetMilestones,
     GetSubscriptionToken,
     GetLabourSubscriptions,
     GetUserSubscription,
     GetUser,
     GetUsers,
+    GetLabourHistory,
 }
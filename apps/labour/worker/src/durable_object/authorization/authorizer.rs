@@ -92,8 +92,7 @@ mod tests {
     };
 
     use super::*;
-    use chrono::Utc;
-    use fern_labour_event_sourcing_rs::Aggregate;
+    use fern_labour_event_sourcing_rs::{Aggregate, Clock, testing::FixedClock};
     use fern_labour_labour_shared::value_objects::{
         SubscriberAccessLevel, SubscriberRole, subscriber::status::SubscriberStatus,
     };
@@ -120,11 +119,11 @@ mod tests {
             mother_id: mother_id.to_string(),
             mother_name: "Test Mother".to_string(),
             first_labour: true,
-            due_date: Utc::now(),
+            due_date: FixedClock::default().now(),
             labour_name: Some("Test Labour".to_string()),
         });
 
-        let events = Labour::handle_command(None, command).unwrap();
+        let events = Labour::handle_command(None, command, &FixedClock::default()).unwrap();
         let labour = Labour::from_events(&events);
         labour.unwrap()
     }
@@ -145,7 +144,9 @@ mod tests {
             labour_id: Uuid::parse_str(&aggregate.aggregate_id()).unwrap(),
             token: token.clone(),
         });
-        let events = Labour::handle_command(Some(&aggregate), set_token_cmd).unwrap();
+        let events =
+            Labour::handle_command(Some(&aggregate), set_token_cmd, &FixedClock::default())
+                .unwrap();
         for event in events {
             aggregate.apply(&event);
         }
@@ -156,7 +157,8 @@ mod tests {
             subscriber_id: subscriber_id.to_string(),
             token,
         });
-        let events = Labour::handle_command(Some(&aggregate), request_cmd).unwrap();
+        let events =
+            Labour::handle_command(Some(&aggregate), request_cmd, &FixedClock::default()).unwrap();
         for event in events {
             aggregate.apply(&event);
         }
@@ -170,7 +172,9 @@ mod tests {
                 labour_id: Uuid::parse_str(&aggregate.aggregate_id()).unwrap(),
                 subscription_id,
             });
-            let events = Labour::handle_command(Some(&aggregate), approve_cmd).unwrap();
+            let events =
+                Labour::handle_command(Some(&aggregate), approve_cmd, &FixedClock::default())
+                    .unwrap();
             for event in events {
                 aggregate.apply(&event);
             }
@@ -182,7 +186,12 @@ mod tests {
                     subscription_id,
                     role,
                 });
-                let events = Labour::handle_command(Some(&aggregate), update_role_cmd).unwrap();
+                let events = Labour::handle_command(
+                    Some(&aggregate),
+                    update_role_cmd,
+                    &FixedClock::default(),
+                )
+                .unwrap();
                 for event in events {
                     aggregate.apply(&event);
                 }
@@ -223,7 +232,7 @@ mod tests {
         let action = Action::Command(LabourCommand::StartContraction(StartContraction {
             labour_id: Uuid::parse_str(&aggregate.aggregate_id()).unwrap(),
             contraction_id: Uuid::now_v7(),
-            start_time: Utc::now(),
+            start_time: FixedClock::default().now(),
         }));
 
         assert!(
@@ -244,6 +253,7 @@ mod tests {
             labour_update_type:
                 fern_labour_labour_shared::value_objects::LabourUpdateType::STATUS_UPDATE,
             message: "Test update".to_string(),
+            scheduled_update_id: None,
         }));
 
         assert!(
@@ -340,7 +350,7 @@ mod tests {
         let action = Action::Command(LabourCommand::StartContraction(StartContraction {
             labour_id: Uuid::parse_str(&aggregate.aggregate_id()).unwrap(),
             contraction_id: Uuid::now_v7(),
-            start_time: Utc::now(),
+            start_time: FixedClock::default().now(),
         }));
 
         assert!(
@@ -453,7 +463,7 @@ mod tests {
         let action = Action::Command(LabourCommand::StartContraction(StartContraction {
             labour_id: Uuid::parse_str(&aggregate.aggregate_id()).unwrap(),
             contraction_id: Uuid::now_v7(),
-            start_time: Utc::now(),
+            start_time: FixedClock::default().now(),
         }));
 
         let result = auth.authorize(&principal, &action, Some(&aggregate));
@@ -548,7 +558,7 @@ mod tests {
         let action = Action::Command(LabourCommand::StartContraction(StartContraction {
             labour_id: Uuid::parse_str(&aggregate.aggregate_id()).unwrap(),
             contraction_id: Uuid::now_v7(),
-            start_time: Utc::now(),
+            start_time: FixedClock::default().now(),
         }));
 
         let result = auth.authorize(&principal, &action, Some(&aggregate));
@@ -640,7 +650,7 @@ mod tests {
             mother_id: "new-mother".to_string(),
             mother_name: "New Mother".to_string(),
             first_labour: true,
-            due_date: Utc::now(),
+            due_date: FixedClock::default().now(),
             labour_name: Some("My Labour".to_string()),
         }));
 
@@ -671,6 +681,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scheduled_labour_updates_are_published_as_a_user_who_can_post_updates() {
+        let auth = Authorizer::new();
+        let aggregate = create_test_aggregate("mother-1");
+        let action = Action::Command(LabourCommand::PostLabourUpdate(PostLabourUpdate {
+            labour_id: Uuid::parse_str(&aggregate.aggregate_id()).unwrap(),
+            labour_update_type:
+                fern_labour_labour_shared::value_objects::LabourUpdateType::STATUS_UPDATE,
+            message: "Test update".to_string(),
+            scheduled_update_id: Some(Uuid::now_v7()),
+        }));
+
+        let mother = resolve_principal(&create_test_user("mother-1"), Some(&aggregate));
+        assert!(auth.authorize(&mother, &action, Some(&aggregate)).is_ok());
+
+        let internal = resolve_principal(
+            &create_test_user("fern-labour-internal-user-1"),
+            Some(&aggregate),
+        );
+        assert!(matches!(
+            auth.authorize(&internal, &action, Some(&aggregate)),
+            Err(DenyReason::MissingCapability(
+                Capability::ExecuteLabourCommand
+            ))
+        ));
+    }
+
+    #[test]
+    fn only_internal_user_can_read_labour_history() {
+        let auth = Authorizer::new();
+        let aggregate = create_test_aggregate("mother-1");
+        let action = Action::Query(QueryAction::GetLabourHistory);
+
+        let mother = resolve_principal(&create_test_user("mother-1"), Some(&aggregate));
+        assert!(matches!(
+            auth.authorize(&mother, &action, Some(&aggregate)),
+            Err(DenyReason::MissingCapability(Capability::ReadLabourHistory))
+        ));
+
+        let stranger = resolve_principal(&create_test_user("stranger"), Some(&aggregate));
+        assert!(
+            auth.authorize(&stranger, &action, Some(&aggregate))
+                .is_err()
+        );
+
+        let internal = resolve_principal(
+            &create_test_user("fern-labour-internal-user-1"),
+            Some(&aggregate),
+        );
+        assert!(auth.authorize(&internal, &action, Some(&aggregate)).is_ok());
+    }
+
     #[test]
     fn internal_user_can_set_subscription_token() {
         let auth = Authorizer::new();
@@ -723,7 +785,7 @@ mod tests {
         let action = Action::Command(LabourCommand::StartContraction(StartContraction {
             labour_id: Uuid::parse_str(&aggregate.aggregate_id()).unwrap(),
             contraction_id: Uuid::now_v7(),
-            start_time: Utc::now(),
+            start_time: FixedClock::default().now(),
         }));
         let result = auth.authorize(&principal, &action, Some(&aggregate));
         assert!(matches!(
@@ -13,6 +13,7 @@ use crate::durable_object::{
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Capability {
     AdvanceLabourPhase,
+    AbandonContraction,
     PostApplicationLabourUpdates,
     ManageLabour,
     ExecuteLabourCommand,
@@ -23,6 +24,7 @@ pub enum Capability {
     ManageSubscriptionToken,
     ReadSubscriptions,
     ReadOwnSubscription,
+    ReadLabourHistory,
 }
 
 pub fn capabilities_for(principal: &Principal) -> HashSet<Capability> {
@@ -58,9 +60,11 @@ pub fn capabilities_for(principal: &Principal) -> HashSet<Capability> {
 
         Principal::Internal => HashSet::from([
             Capability::AdvanceLabourPhase,
+            Capability::AbandonContraction,
             Capability::PostApplicationLabourUpdates,
             Capability::ManageSubscriptionToken,
             Capability::UpdateSubscriptionAccessLevel,
+            Capability::ReadLabourHistory,
         ]),
 
         Principal::Unassociated => HashSet::new(),
@@ -76,19 +80,32 @@ pub fn required_capability(action: &Action) -> Capability {
             | LabourCommand::CompleteLabour(..)
             | LabourCommand::DeleteLabour(..)
             | LabourCommand::SendLabourInvite(..)
+            | LabourCommand::UpdatePhaseProgressionPolicy(..)
+            | LabourCommand::RecordBirth(..)
+            | LabourCommand::AmendBirthRecord(..)
+            | LabourCommand::PublishBirthAnnouncement(..)
             | LabourCommand::InvalidateSubscriptionToken(..) => Capability::ManageLabour,
 
             LabourCommand::StartContraction(..)
             | LabourCommand::EndContraction(..)
+            | LabourCommand::RecordContraction(..)
             | LabourCommand::UpdateContraction(..)
             | LabourCommand::DeleteContraction(..)
             | LabourCommand::PostLabourUpdate(..)
             | LabourCommand::UpdateLabourUpdateMessage(..)
             | LabourCommand::UpdateLabourUpdateType(..)
-            | LabourCommand::DeleteLabourUpdate(..) => Capability::ExecuteLabourCommand,
+            | LabourCommand::DeleteLabourUpdate(..)
+            | LabourCommand::ScheduleLabourUpdate(..)
+            | LabourCommand::CancelScheduledLabourUpdate(..)
+            | LabourCommand::RecordMilestone(..)
+            | LabourCommand::UpdateMilestone(..)
+            | LabourCommand::DeleteMilestone(..)
+            | LabourCommand::UndoLastAction(..) => Capability::ExecuteLabourCommand,
 
             LabourCommand::AdvanceLabourPhase(..) => Capability::AdvanceLabourPhase,
 
+            LabourCommand::AbandonContraction(..) => Capability::AbandonContraction,
+
             LabourCommand::PostApplicationLabourUpdate(..) => {
                 Capability::PostApplicationLabourUpdates
             }
@@ -115,7 +132,10 @@ pub fn required_capability(action: &Action) -> Capability {
         Action::Query(q) => match q {
             QueryAction::GetLabour
             | QueryAction::GetContractions
-            | QueryAction::GetLabourUpdates => Capability::ReadLabour,
+            | QueryAction::GetLabourUpdates
+            | QueryAction::GetMilestones => Capability::ReadLabour,
+
+            QueryAction::GetScheduledLabourUpdates => Capability::ExecuteLabourCommand,
 
             QueryAction::GetUserSubscription => Capability::ReadOwnSubscription,
 
@@ -123,6 +143,8 @@ pub fn required_capability(action: &Action) -> Capability {
             | QueryAction::GetLabourSubscriptions
             | QueryAction::GetUser
             | QueryAction::GetUsers => Capability::ReadSubscriptions,
+
+            QueryAction::GetLabourHistory => Capability::ReadLabourHistory,
         },
     }
 }
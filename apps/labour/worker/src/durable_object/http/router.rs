@@ -4,9 +4,14 @@ use crate::durable_object::{
     http::{
         middleware::with_auth_context,
         routes::{
-            admin::handle_admin_command,
+            admin::{
+                handle_admin_command, handle_admin_query, handle_export_event_stream,
+                handle_import_event_stream, handle_list_dead_letters,
+                handle_projection_checkpoints, handle_retry_dead_letter, handle_skip_dead_letter,
+                handle_verify_event_stream,
+            },
             checkout::handle_create_checkout_session,
-            command::handle_command,
+            command::{handle_command, handle_command_batch},
             events::handle_events_query,
             labour::handle_labour_domain_command,
             query::{get_server_timestamp, handle_query},
@@ -32,11 +37,36 @@ pub async fn route_request(req: Request, services: &LabourRoomServices) -> Resul
 
     match (method, path.as_str()) {
         (Method::Post, "/api/command") => with_auth_context(handle_command, req, ctx).await,
+        (Method::Post, "/api/command/batch") => {
+            with_auth_context(handle_command_batch, req, ctx).await
+        }
         (Method::Post, "/api/query") => with_auth_context(handle_query, req, ctx).await,
         (Method::Post, "/api/checkout") => {
             with_auth_context(handle_create_checkout_session, req, ctx).await
         }
         (Method::Post, "/admin/command") => with_auth_context(handle_admin_command, req, ctx).await,
+        (Method::Post, "/admin/query") => with_auth_context(handle_admin_query, req, ctx).await,
+        (Method::Get, "/admin/export") => {
+            with_auth_context(handle_export_event_stream, req, ctx).await
+        }
+        (Method::Post, "/admin/import") => {
+            with_auth_context(handle_import_event_stream, req, ctx).await
+        }
+        (Method::Get, "/admin/verify") => {
+            with_auth_context(handle_verify_event_stream, req, ctx).await
+        }
+        (Method::Get, "/admin/projections") => {
+            with_auth_context(handle_projection_checkpoints, req, ctx).await
+        }
+        (Method::Get, "/admin/dead-letters") => {
+            with_auth_context(handle_list_dead_letters, req, ctx).await
+        }
+        (Method::Post, "/admin/dead-letters/retry") => {
+            with_auth_context(handle_retry_dead_letter, req, ctx).await
+        }
+        (Method::Post, "/admin/dead-letters/skip") => {
+            with_auth_context(handle_skip_dead_letter, req, ctx).await
+        }
         (Method::Get, "/labour/events") => with_auth_context(handle_events_query, req, ctx).await,
         (Method::Post, "/labour/domain") => {
             with_auth_context(handle_labour_domain_command, req, ctx).await
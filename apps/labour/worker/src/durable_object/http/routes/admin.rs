@@ -1,10 +1,16 @@
 use fern_labour_event_sourcing_rs::CommandEnvelope;
-use fern_labour_labour_shared::AdminCommand;
+use fern_labour_labour_shared::{AdminCommand, AdminQuery};
 use fern_labour_workers_shared::User;
+use futures::stream;
+use serde::Deserialize;
 use tracing::{error, info};
 use worker::{Request, Response};
 
-use crate::durable_object::{http::ApiResult, http::router::RequestContext};
+use crate::durable_object::{
+    authorization::{Action, Authorizer, QueryAction, resolve_principal},
+    http::ApiResult,
+    http::router::RequestContext,
+};
 
 pub async fn handle_admin_command(
     mut req: Request,
@@ -38,3 +44,227 @@ pub async fn handle_admin_command(
 
     Ok(ApiResult::from_unit_result(result).into_response())
 }
+
+pub async fn handle_admin_query(
+    mut req: Request,
+    ctx: RequestContext<'_>,
+    user: User,
+) -> worker::Result<Response> {
+    let Ok(query) = req.json::<AdminQuery>().await else {
+        return Response::error("Failed to parse request body", 400);
+    };
+
+    info!(
+        labour_id = %query.labour_id(),
+        auth_user_id = %user.user_id,
+        query = ?query,
+        "Processing admin query"
+    );
+
+    // Historical labours include subscriber contact details and unpublished
+    // birth records, so only internal users may read them.
+    let aggregate = match ctx.data.read_model().aggregate_repository.load() {
+        Ok(aggregate) => aggregate,
+        Err(err) => return Ok(ApiResult::from_unit_result(Err(err)).into_response()),
+    };
+    let principal = resolve_principal(&user, aggregate.as_ref());
+    if let Err(reason) = Authorizer::new().authorize(
+        &principal,
+        &Action::Query(QueryAction::GetLabourHistory),
+        aggregate.as_ref(),
+    ) {
+        error!(auth_user_id = %user.user_id, %reason, "Admin query denied");
+        return Response::error(format!("Authorization failed: {reason}"), 403);
+    }
+
+    let result = ctx.data.read_model().history_query.handle(query);
+
+    if let Err(ref err) = result {
+        error!("Admin query failed: {}", err);
+    } else {
+        info!("Admin query executed successfully");
+    }
+
+    Ok(ApiResult::from_json_result(result).into_response())
+}
+
+pub async fn handle_verify_event_stream(
+    _req: Request,
+    ctx: RequestContext<'_>,
+    user: User,
+) -> worker::Result<Response> {
+    info!(auth_user_id = %user.user_id, "Verifying event stream hash chain");
+
+    let result = ctx.data.read_model().event_query.verify_event_stream();
+
+    match &result {
+        Ok(verification) if !verification.is_intact() => {
+            error!(broken_link = ?verification.first_broken_link, "Event stream hash chain is broken");
+        }
+        Ok(_) => info!("Event stream hash chain verified"),
+        Err(err) => error!("Event stream verification failed: {}", err),
+    }
+
+    Ok(ApiResult::from_json_result(result).into_response())
+}
+
+pub async fn handle_projection_checkpoints(
+    _req: Request,
+    ctx: RequestContext<'_>,
+    user: User,
+) -> worker::Result<Response> {
+    info!(auth_user_id = %user.user_id, "Fetching projection checkpoints");
+
+    let result = ctx.data.read_model().projection_query.get_checkpoints();
+
+    if let Err(ref err) = result {
+        error!("Projection checkpoint query failed: {}", err);
+    }
+
+    Ok(ApiResult::from_json_result(result).into_response())
+}
+
+/// Identifies a quarantined event to retry or skip.
+#[derive(Deserialize)]
+pub struct DeadLetterRequest {
+    pub projector_name: String,
+    pub sequence: i64,
+}
+
+pub async fn handle_list_dead_letters(
+    _req: Request,
+    ctx: RequestContext<'_>,
+    user: User,
+) -> worker::Result<Response> {
+    info!(auth_user_id = %user.user_id, "Fetching dead letters");
+
+    let result = ctx.data.async_processors().dead_letter_queue.list();
+
+    if let Err(ref err) = result {
+        error!("Dead letter query failed: {}", err);
+    }
+
+    Ok(ApiResult::from_json_result(result).into_response())
+}
+
+pub async fn handle_retry_dead_letter(
+    mut req: Request,
+    ctx: RequestContext<'_>,
+    user: User,
+) -> worker::Result<Response> {
+    let Ok(request) = req.json::<DeadLetterRequest>().await else {
+        return Response::error("Failed to parse request body", 400);
+    };
+
+    info!(
+        auth_user_id = %user.user_id,
+        projector = %request.projector_name,
+        sequence = request.sequence,
+        "Retrying quarantined event"
+    );
+
+    let processors = ctx.data.async_processors();
+    let result = if processors
+        .sync_projection_processor
+        .has_projector(&request.projector_name)
+    {
+        processors
+            .sync_projection_processor
+            .retry_dead_letter(&request.projector_name, request.sequence)
+    } else {
+        processors
+            .async_projection_processor
+            .retry_dead_letter(&request.projector_name, request.sequence)
+            .await
+    };
+
+    if let Err(ref err) = result {
+        error!("Dead letter retry failed: {}", err);
+    }
+
+    Ok(ApiResult::from_unit_result(result).into_response())
+}
+
+pub async fn handle_skip_dead_letter(
+    mut req: Request,
+    ctx: RequestContext<'_>,
+    user: User,
+) -> worker::Result<Response> {
+    let Ok(request) = req.json::<DeadLetterRequest>().await else {
+        return Response::error("Failed to parse request body", 400);
+    };
+
+    info!(
+        auth_user_id = %user.user_id,
+        projector = %request.projector_name,
+        sequence = request.sequence,
+        "Skipping quarantined event"
+    );
+
+    let result = ctx
+        .data
+        .async_processors()
+        .dead_letter_queue
+        .resolve(&request.projector_name, request.sequence);
+
+    if let Err(ref err) = result {
+        error!("Dead letter skip failed: {}", err);
+    }
+
+    Ok(ApiResult::from_unit_result(result).into_response())
+}
+
+pub async fn handle_export_event_stream(
+    _req: Request,
+    ctx: RequestContext<'_>,
+    user: User,
+) -> worker::Result<Response> {
+    info!(auth_user_id = %user.user_id, "Exporting event stream");
+
+    match ctx.data.write_model().event_stream_transfer.export() {
+        Ok(export) => {
+            let chunks = export.map(|chunk| {
+                chunk.map_err(|err| {
+                    error!("Event stream export failed: {}", err);
+                    worker::Error::RustError(err.to_string())
+                })
+            });
+            let mut response = Response::from_stream(stream::iter(chunks))?;
+            response
+                .headers_mut()
+                .set("Content-Type", "application/x-ndjson")?;
+            Ok(response)
+        }
+        Err(err) => {
+            error!("Event stream export failed: {}", err);
+            Ok(ApiResult::from_unit_result(Err(err)).into_response())
+        }
+    }
+}
+
+pub async fn handle_import_event_stream(
+    mut req: Request,
+    ctx: RequestContext<'_>,
+    user: User,
+) -> worker::Result<Response> {
+    let Ok(ndjson) = req.text().await else {
+        return Response::error("Failed to read request body", 400);
+    };
+
+    info!(auth_user_id = %user.user_id, "Importing event stream");
+
+    let result = ctx
+        .data
+        .write_model()
+        .event_stream_transfer
+        .import(&ndjson)
+        .map(|_| ());
+
+    if let Err(ref err) = result {
+        error!("Event stream import failed: {}", err);
+    } else {
+        info!("Event stream imported successfully");
+    }
+
+    Ok(ApiResult::from_unit_result(result).into_response())
+}
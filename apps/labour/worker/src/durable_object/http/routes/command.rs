@@ -1,4 +1,5 @@
-use fern_labour_labour_shared::ApiCommand;
+use fern_labour_event_sourcing_rs::CausationContext;
+use fern_labour_labour_shared::{ApiCommand, CommandBatch, commands::batch::MAX_BATCH_COMMANDS};
 use fern_labour_workers_shared::User;
 use tracing::{error, info};
 use worker::{Request, Response};
@@ -23,7 +24,7 @@ pub async fn handle_command(
 
     info!(command = ?command, user_id = %user.user_id, "Processing command");
 
-    let domain_command = match CommandTranslator::translate(command, &user) {
+    let domain_command = match CommandTranslator::translate(command, &user, ctx.data.clock()) {
         Ok(cmd) => cmd,
         Err(e) => {
             error!(error = %e, "Command translation failed");
@@ -35,7 +36,7 @@ pub async fn handle_command(
         .data
         .write_model()
         .labour_command_processor
-        .handle_command(domain_command, user);
+        .handle_command(domain_command, user, CausationContext::new_request());
 
     if let Err(ref err) = result {
         error!(error = %err, "Command execution failed");
@@ -45,3 +46,39 @@ pub async fn handle_command(
 
     Ok(ApiResult::from_unit_result(result).into_response())
 }
+
+pub async fn handle_command_batch(
+    mut req: Request,
+    ctx: RequestContext<'_>,
+    user: User,
+) -> worker::Result<Response> {
+    let batch: CommandBatch = match req.json().await {
+        Ok(batch) => batch,
+        Err(e) => {
+            error!(error = ?e, "Failed to parse CommandBatch");
+            return Response::error("Failed to parse request body", 400);
+        }
+    };
+
+    if batch.commands.len() > MAX_BATCH_COMMANDS {
+        return Response::error(
+            format!("A batch can contain at most {MAX_BATCH_COMMANDS} commands"),
+            400,
+        );
+    }
+
+    info!(
+        labour_id = %batch.labour_id,
+        command_count = batch.commands.len(),
+        user_id = %user.user_id,
+        "Processing command batch"
+    );
+
+    let result = ctx
+        .data
+        .write_model()
+        .labour_command_processor
+        .handle_command_batch(batch, user);
+
+    Response::from_json(&result)
+}
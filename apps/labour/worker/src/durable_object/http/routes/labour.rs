@@ -1,4 +1,4 @@
-use fern_labour_event_sourcing_rs::CommandEnvelope;
+use fern_labour_event_sourcing_rs::{CausationContext, CommandEnvelope};
 use fern_labour_workers_shared::User;
 use tracing::{error, info};
 use worker::{Request, Response};
@@ -30,7 +30,12 @@ pub async fn handle_labour_domain_command(
         .data
         .write_model()
         .labour_command_processor
-        .handle_command(envelope.command, user);
+        .handle_idempotent_command(
+            envelope.command,
+            user,
+            CausationContext::from_command(&envelope.metadata),
+            &envelope.metadata.idempotency_key.to_string(),
+        );
 
     if let Err(ref err) = result {
         error!(error = %err, "Domain command execution failed");
@@ -39,7 +39,7 @@ pub async fn handle_query(
 
 pub async fn get_server_timestamp(
     _req: Request,
-    _ctx: RequestContext<'_>,
+    ctx: RequestContext<'_>,
     user: User,
 ) -> worker::Result<Response> {
     info!(user_id = %user.user_id, "Processing server timestamp query");
@@ -50,7 +50,7 @@ pub async fn get_server_timestamp(
     }
 
     let data = ServerTimestamp {
-        server_timestamp: Utc::now(),
+        server_timestamp: ctx.data.clock().now(),
     };
 
     Ok(ApiResult::from_json_result(Ok(data)).into_response())
@@ -6,7 +6,8 @@ pub mod setup;
 pub mod websocket;
 pub mod write_side;
 
-use chrono::Utc;
+use fern_labour_event_sourcing_rs::CausationContext;
+use fern_labour_labour_shared::commands::batch::MAX_BATCH_COMMANDS;
 use fern_labour_workers_shared::User;
 use serde_json::json;
 use tracing::{error, info};
@@ -59,9 +60,12 @@ impl DurableObject for LabourRoom {
             return upgrade_connection(req, &self.state).await;
         }
 
+        // Batches answer with per-command results rather than 204, but may
+        // still have appended events.
+        let is_command_batch = req.path() == "/api/command/batch";
         let result = route_request(req, &self.services).await?;
 
-        if result.status_code() == 204 {
+        if result.status_code() == 204 || (is_command_batch && result.status_code() == 200) {
             self.alarm_manager
                 .set_alarm(0)
                 .await
@@ -82,13 +86,26 @@ impl DurableObject for LabourRoom {
         info!(user_id = %user.user_id, "Processing message from WebSocket");
         let (success, data, error) = match msg.request {
             WebSocketRequest::Command { command } => {
-                match CommandTranslator::translate(command, &user) {
+                match CommandTranslator::translate(command, &user, self.services.clock()) {
                     Ok(domain_command) => {
-                        let result = self
-                            .services
-                            .write_model()
-                            .labour_command_processor
-                            .handle_command(domain_command, user);
+                        let processor = &self.services.write_model().labour_command_processor;
+                        let result = match &msg.correlation_id {
+                            Some(correlation_id) => {
+                                let idempotency_key =
+                                    format!("ws:{}:{}", user.user_id, correlation_id);
+                                processor.handle_idempotent_command(
+                                    domain_command,
+                                    user,
+                                    CausationContext::new_request(),
+                                    &idempotency_key,
+                                )
+                            }
+                            None => processor.handle_command(
+                                domain_command,
+                                user,
+                                CausationContext::new_request(),
+                            ),
+                        };
 
                         match result {
                             Ok(()) => {
@@ -101,6 +118,25 @@ impl DurableObject for LabourRoom {
                     Err(e) => (false, None, Some(e.to_string())),
                 }
             }
+            WebSocketRequest::CommandBatch { batch } => {
+                if batch.commands.len() > MAX_BATCH_COMMANDS {
+                    (
+                        false,
+                        None,
+                        Some(format!(
+                            "A batch can contain at most {MAX_BATCH_COMMANDS} commands"
+                        )),
+                    )
+                } else {
+                    let result = self
+                        .services
+                        .write_model()
+                        .labour_command_processor
+                        .handle_command_batch(batch, user);
+                    self.alarm_manager.set_alarm(0).await.ok();
+                    (true, serde_json::to_value(result).ok(), None)
+                }
+            }
             WebSocketRequest::Query { query } => {
                 let handler = QueryHandler::new(self.services.read_model());
                 match handler.handle(query, &user) {
@@ -108,9 +144,11 @@ impl DurableObject for LabourRoom {
                     Err(e) => (false, None, Some(e.to_string())),
                 }
             }
-            WebSocketRequest::ServerTimestamp => {
-                (true, Some(json!({"server_timestamp": Utc::now()})), None)
-            }
+            WebSocketRequest::ServerTimestamp => (
+                true,
+                Some(json!({"server_timestamp": self.services.clock().now()})),
+                None,
+            ),
         };
 
         let response = serde_json::json!({
@@ -172,27 +210,84 @@ impl DurableObject for LabourRoom {
             error!(error = %e, "Error in async processing");
         }
 
+        let sync_rebuild_result = alarm_services.sync_projection_processor.process_rebuilds();
+        let async_rebuild_result = alarm_services
+            .async_projection_processor
+            .process_rebuilds()
+            .await;
+        let rebuild_result = sync_rebuild_result.and(async_rebuild_result);
+
+        if let Err(ref e) = rebuild_result {
+            error!(error = %e, "Error in projection rebuilds");
+        }
+
         let process_mgmt = self.services.process_management();
         let process_manager_result = process_mgmt.process_manager.on_alarm().await;
         if let Err(ref e) = process_manager_result {
             error!(error = %e, "Error in process manager alarm handling");
         }
 
-        if sync_result.is_err() || async_result.is_err() || process_manager_result.is_err() {
-            return Err(worker::Error::RustError(
-                "Error in alarm handling".to_string(),
-            ));
+        // Any command resets the alarm to fire immediately, so every run
+        // re-arms the timed checks below.
+        let next_contraction_check = process_mgmt.abandoned_contraction_monitor.check();
+        if let Err(ref e) = next_contraction_check {
+            error!(error = %e, "Error checking for an abandoned contraction");
         }
 
-        if alarm_services
-            .sync_projection_processor
-            .has_unprocessed_events()
+        let next_scheduled_update_check = process_mgmt.scheduled_labour_update_publisher.check();
+        if let Err(ref e) = next_scheduled_update_check {
+            error!(error = %e, "Error publishing scheduled labour updates");
+        }
+
+        let failed = sync_result.is_err()
+            || async_result.is_err()
+            || rebuild_result.is_err()
+            || process_manager_result.is_err()
+            || next_contraction_check.is_err()
+            || next_scheduled_update_check.is_err();
+
+        // A failed run is retried by the runtime, so it skips the immediate
+        // follow-ups but still re-arms the timed checks before reporting the
+        // error; otherwise a failure in one step would leave them unscheduled.
+        if !failed
+            && alarm_services
+                .sync_projection_processor
+                .has_unprocessed_events()
         {
             info!("Scheduling follow-up alarm to process events generated by process manager");
             self.alarm_manager
                 .set_alarm(0)
                 .await
                 .map_err(|e| worker::Error::RustError(e.to_string()))?;
+        } else if !failed
+            && (alarm_services
+                .sync_projection_processor
+                .has_pending_rebuilds()
+                || alarm_services
+                    .async_projection_processor
+                    .has_pending_rebuilds())
+        {
+            info!("Scheduling follow-up alarm to continue projection rebuilds");
+            self.alarm_manager
+                .set_alarm(0)
+                .await
+                .map_err(|e| worker::Error::RustError(e.to_string()))?;
+        } else if let Some(check_at) = [next_contraction_check, next_scheduled_update_check]
+            .into_iter()
+            .filter_map(|check| check.ok().flatten())
+            .min()
+        {
+            info!(%check_at, "Scheduling alarm for the next timed check");
+            self.alarm_manager
+                .set_alarm_at(check_at)
+                .await
+                .map_err(|e| worker::Error::RustError(e.to_string()))?;
+        }
+
+        if failed {
+            return Err(worker::Error::RustError(
+                "Error in alarm handling".to_string(),
+            ));
         }
 
         Response::empty()
@@ -106,7 +106,7 @@ impl CheckpointRepository for SqlCheckpointRepository {
                     status,
                     error_message,
                     error_count
-                ) VALUES (?1, ?2, ?3, CURRENT_TIMESTAMP, ?4, ?5, ?6)",
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
                 Some(vec![
                     checkpoint.projector_name.clone().into(),
                     (checkpoint.last_processed_sequence as f64).into(),
@@ -115,6 +115,11 @@ impl CheckpointRepository for SqlCheckpointRepository {
                         .format("%Y-%m-%d %H:%M:%S")
                         .to_string()
                         .into(),
+                    checkpoint
+                        .updated_at
+                        .format("%Y-%m-%d %H:%M:%S")
+                        .to_string()
+                        .into(),
                     checkpoint.status.as_str().into(),
                     checkpoint.error_message.clone().into(),
                     (checkpoint.error_count as f64).into(),
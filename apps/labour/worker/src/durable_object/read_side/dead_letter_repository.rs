@@ -0,0 +1,146 @@
+use anyhow::{Context, Result};
+use chrono::NaiveDateTime;
+use serde::Deserialize;
+use worker::SqlStorage;
+
+use fern_labour_event_sourcing_rs::{DeadLetter, DeadLetterRepository};
+
+#[derive(Deserialize)]
+struct DeadLetterRow {
+    projector_name: String,
+    sequence: i64,
+    event_type: String,
+    error_message: String,
+    failure_count: i64,
+    quarantined_at: String,
+}
+
+impl DeadLetterRow {
+    fn take_as_dead_letter(self) -> Result<DeadLetter> {
+        let quarantined_at =
+            NaiveDateTime::parse_from_str(&self.quarantined_at, "%Y-%m-%d %H:%M:%S")
+                .context("Failed to parse quarantined_at timestamp")?
+                .and_utc();
+
+        Ok(DeadLetter {
+            projector_name: self.projector_name,
+            sequence: self.sequence,
+            event_type: self.event_type,
+            error_message: self.error_message,
+            failure_count: self.failure_count,
+            quarantined_at,
+        })
+    }
+}
+
+pub struct SqlDeadLetterRepository {
+    sql: SqlStorage,
+}
+
+impl SqlDeadLetterRepository {
+    pub fn create(sql: SqlStorage) -> Self {
+        Self { sql }
+    }
+
+    fn query(
+        &self,
+        query: &str,
+        bindings: Option<Vec<worker::SqlStorageValue>>,
+    ) -> Result<Vec<DeadLetter>> {
+        let rows: Vec<DeadLetterRow> = self
+            .sql
+            .exec(query, bindings)
+            .context("Failed in dead letter query")?
+            .to_array()
+            .context("Failed to deserialize dead letter rows")?;
+
+        rows.into_iter()
+            .map(|row| row.take_as_dead_letter())
+            .collect()
+    }
+}
+
+impl DeadLetterRepository for SqlDeadLetterRepository {
+    fn init_schema(&self) -> Result<()> {
+        self.sql
+            .exec(
+                "CREATE TABLE IF NOT EXISTS projection_dead_letters (
+                    projector_name TEXT NOT NULL,
+                    sequence INTEGER NOT NULL,
+                    event_type TEXT NOT NULL,
+                    error_message TEXT NOT NULL,
+                    failure_count INTEGER NOT NULL,
+                    quarantined_at DATETIME NOT NULL,
+                    PRIMARY KEY (projector_name, sequence)
+                )",
+                None,
+            )
+            .context("Failed to create projection_dead_letters table")?;
+
+        Ok(())
+    }
+
+    fn insert(&self, dead_letter: &DeadLetter) -> Result<()> {
+        self.sql
+            .exec(
+                "INSERT OR REPLACE INTO projection_dead_letters (
+                    projector_name,
+                    sequence,
+                    event_type,
+                    error_message,
+                    failure_count,
+                    quarantined_at
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                Some(vec![
+                    dead_letter.projector_name.clone().into(),
+                    (dead_letter.sequence as f64).into(),
+                    dead_letter.event_type.clone().into(),
+                    dead_letter.error_message.clone().into(),
+                    (dead_letter.failure_count as f64).into(),
+                    dead_letter
+                        .quarantined_at
+                        .format("%Y-%m-%d %H:%M:%S")
+                        .to_string()
+                        .into(),
+                ]),
+            )
+            .context("Failed to insert dead letter")?;
+
+        Ok(())
+    }
+
+    fn get(&self, projector_name: &str, sequence: i64) -> Result<Option<DeadLetter>> {
+        Ok(self
+            .query(
+                "SELECT * FROM projection_dead_letters WHERE projector_name = ?1 AND sequence = ?2",
+                Some(vec![projector_name.into(), (sequence as f64).into()]),
+            )?
+            .into_iter()
+            .next())
+    }
+
+    fn get_all(&self) -> Result<Vec<DeadLetter>> {
+        self.query(
+            "SELECT * FROM projection_dead_letters ORDER BY projector_name, sequence",
+            None,
+        )
+    }
+
+    fn get_for_projector(&self, projector_name: &str) -> Result<Vec<DeadLetter>> {
+        self.query(
+            "SELECT * FROM projection_dead_letters WHERE projector_name = ?1 ORDER BY sequence",
+            Some(vec![projector_name.into()]),
+        )
+    }
+
+    fn remove(&self, projector_name: &str, sequence: i64) -> Result<()> {
+        self.sql
+            .exec(
+                "DELETE FROM projection_dead_letters WHERE projector_name = ?1 AND sequence = ?2",
+                Some(vec![projector_name.into(), (sequence as f64).into()]),
+            )
+            .context("Failed to remove dead letter")?;
+
+        Ok(())
+    }
+}
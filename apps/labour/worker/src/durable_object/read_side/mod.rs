@@ -1,4 +1,5 @@
 pub mod checkpoint_repository;
+pub mod dead_letter_repository;
 pub mod projection_processors;
 pub mod query_handler;
 pub mod read_models;
@@ -1,36 +1,79 @@
-use std::rc::Rc;
+use std::{collections::HashMap, rc::Rc};
 
 use anyhow::{Context, Result, anyhow};
-use tracing::{debug, info, warn};
+use tracing::{debug, error, info, warn};
 
 use fern_labour_event_sourcing_rs::{
-    CacheTrait, EventEnvelope, EventEnvelopeAdapter, EventStoreTrait, IncrementalAsyncProjector,
+    CacheTrait, CheckpointRepository, CheckpointStatus, Clock, DeadLetterQueue, EventEnvelope,
+    EventEnvelopeAdapter, EventStoreTrait, IncrementalAsyncProjector, PoisonEventPolicy,
+    ProjectionCheckpoint, StoredEventRow, SystemClock, rebuild_checkpoint_name,
 };
 
-use crate::durable_object::write_side::domain::LabourEvent;
+use crate::durable_object::{
+    read_side::projection_processors::sync_processor::MAX_PROJECTOR_ERROR_COUNT,
+    write_side::domain::LabourEvent,
+};
 
 pub struct AsyncProjectionProcessor {
     event_store: Rc<dyn EventStoreTrait>,
     cache: Rc<dyn CacheTrait>,
+    checkpoint_repository: Box<dyn CheckpointRepository>,
+    dead_letter_queue: Rc<DeadLetterQueue>,
     projectors: Vec<Box<dyn IncrementalAsyncProjector<LabourEvent>>>,
+    policies: HashMap<String, PoisonEventPolicy>,
     default_batch_size: i64,
+    clock: Rc<dyn Clock>,
 }
 
 impl AsyncProjectionProcessor {
     pub fn create(
         event_store: Rc<dyn EventStoreTrait>,
         cache: Rc<dyn CacheTrait>,
+        checkpoint_repository: Box<dyn CheckpointRepository>,
+        dead_letter_queue: Rc<DeadLetterQueue>,
         projectors: Vec<Box<dyn IncrementalAsyncProjector<LabourEvent>>>,
         default_batch_size: i64,
     ) -> Self {
         Self {
             event_store,
             cache,
+            checkpoint_repository,
+            dead_letter_queue,
             projectors,
+            policies: HashMap::new(),
             default_batch_size,
+            clock: Rc::new(SystemClock),
         }
     }
 
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Overrides how `projector_name` handles an event that keeps failing.
+    pub fn with_poison_event_policy(
+        mut self,
+        projector_name: &str,
+        policy: PoisonEventPolicy,
+    ) -> Self {
+        self.policies.insert(projector_name.to_string(), policy);
+        self
+    }
+
+    fn policy(&self, projector_name: &str) -> PoisonEventPolicy {
+        self.policies
+            .get(projector_name)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn has_projector(&self, projector_name: &str) -> bool {
+        self.projectors
+            .iter()
+            .any(|projector| projector.name() == projector_name)
+    }
+
     fn get_min_cached_sequence(&self) -> i64 {
         self.projectors
             .iter()
@@ -58,31 +101,35 @@ impl AsyncProjectionProcessor {
             return Ok(());
         }
 
-        let stored_events = self
+        let event_types = self.event_types();
+        let pages = self
             .event_store
-            .events_since(min_cached_sequence, self.default_batch_size)
-            .context("Failed to fetch events since checkpoint")?;
-
-        if stored_events.is_empty() {
+            .pages_since(min_cached_sequence, self.default_batch_size);
+        let mut pages = match &event_types {
+            Some(event_types) => pages.only(event_types),
+            None => pages,
+        };
+        let Some(page) = pages
+            .next()
+            .transpose()
+            .context("Failed to fetch events since checkpoint")?
+        else {
             debug!("No new events to process");
             return Ok(());
-        }
-
-        let envelopes: Vec<EventEnvelope<LabourEvent>> = stored_events
-            .into_iter()
-            .map(|stored| stored.to_envelope())
-            .collect::<Result<Vec<_>>>()?;
+        };
+        let stored_events = page.rows;
 
         debug!(
-            event_count = envelopes.len(),
+            event_count = stored_events.len(),
             min_cached_sequence = min_cached_sequence,
             max_sequence = max_sequence,
             "Processing events through incremental projectors"
         );
 
+        let mut errors: Vec<String> = Vec::new();
         for projector in &self.projectors {
-            if let Err(e) = projector
-                .process(&self.cache, &envelopes, max_sequence)
+            if let Err(e) = self
+                .process_single_projector(projector.as_ref(), &stored_events, page.scanned_to)
                 .await
             {
                 warn!(
@@ -90,21 +137,327 @@ impl AsyncProjectionProcessor {
                     error = %e,
                     "Failed to process projector"
                 );
-                return Err(anyhow!(
-                    "Failed to process projector {}: {}",
-                    projector.name(),
-                    e
-                ));
+                errors.push(format!("{}: {}", projector.name(), e));
             }
         }
 
+        if !errors.is_empty() {
+            return Err(anyhow!(
+                "One or more projectors failed: {}",
+                errors.join("; ")
+            ));
+        }
+
         info!(
             projector_count = self.projectors.len(),
-            events_loaded = envelopes.len(),
+            events_loaded = stored_events.len(),
             max_sequence = max_sequence,
             "Async projection processing completed"
         );
 
         Ok(())
     }
+
+    /// The event types any projector handles, or `None` when one of them
+    /// needs every event.
+    fn event_types(&self) -> Option<Vec<&'static str>> {
+        let mut event_types = Vec::new();
+        for projector in &self.projectors {
+            for event_type in projector.event_types()? {
+                if !event_types.contains(event_type) {
+                    event_types.push(*event_type);
+                }
+            }
+        }
+        Some(event_types)
+    }
+
+    /// Folds the events `projector` has not seen yet into its model. The
+    /// cache holds the projector's position; its checkpoint mirrors it and
+    /// tracks failures so a poison event can be quarantined. Once every
+    /// relevant event is handled the projector moves on to `scanned_to`.
+    async fn process_single_projector(
+        &self,
+        projector: &dyn IncrementalAsyncProjector<LabourEvent>,
+        stored_events: &[StoredEventRow],
+        scanned_to: i64,
+    ) -> Result<()> {
+        let cached_sequence = projector.get_cached_sequence(&self.cache);
+        let event_types = projector.event_types();
+        let pending: Vec<&StoredEventRow> = stored_events
+            .iter()
+            .filter(|stored| stored.sequence > cached_sequence)
+            .filter(|stored| {
+                event_types.is_none_or(|types| types.contains(&stored.event_type.as_str()))
+            })
+            .collect();
+        if pending.is_empty() && cached_sequence >= scanned_to {
+            return Ok(());
+        }
+
+        let policy = self.policy(projector.name());
+        let checkpoint = self
+            .checkpoint_repository
+            .get_checkpoint(projector.name())?
+            .unwrap_or_else(|| {
+                ProjectionCheckpoint::initial(projector.name(), self.clock.as_ref())
+            });
+        if policy.is_halted(&checkpoint) {
+            warn!(
+                projector = %projector.name(),
+                error_count = checkpoint.error_count,
+                "Skipping faulted projector - exceeded max error count. Manual reset required."
+            );
+            return Ok(());
+        }
+
+        if pending.is_empty() {
+            projector.skip_to(&self.cache, scanned_to)?;
+            return self.advance_checkpoint(checkpoint, scanned_to);
+        }
+
+        if pending.len() > 1
+            && let Ok(envelopes) = pending
+                .iter()
+                .map(|stored| stored.to_envelope())
+                .collect::<Result<Vec<EventEnvelope<LabourEvent>>>>()
+            && projector
+                .process(&self.cache, &envelopes, scanned_to)
+                .await
+                .is_ok()
+        {
+            let last_envelope = envelopes.last().unwrap();
+            return self
+                .checkpoint_repository
+                .update_checkpoint(&checkpoint.advanced_to(
+                    scanned_to,
+                    last_envelope.metadata.timestamp,
+                    self.clock.as_ref(),
+                ))
+                .context("Failed to update checkpoint");
+        }
+
+        let mut checkpoint = checkpoint;
+        let mut outcome = Ok(());
+        for stored in pending {
+            let result = match stored.to_envelope() {
+                Ok(envelope) => projector
+                    .process(
+                        &self.cache,
+                        std::slice::from_ref(&envelope),
+                        stored.sequence,
+                    )
+                    .await
+                    .map(|_| envelope.metadata.timestamp),
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok(processed_at) => {
+                    checkpoint =
+                        checkpoint.advanced_to(stored.sequence, processed_at, self.clock.as_ref());
+                }
+                Err(err) => {
+                    let failed = checkpoint.failed(err.to_string(), self.clock.as_ref());
+                    if policy.should_quarantine(failed.error_count) {
+                        warn!(
+                            projector = %projector.name(),
+                            sequence = stored.sequence,
+                            event_type = %stored.event_type,
+                            error = %err,
+                            "Quarantining event after repeated failures"
+                        );
+                        checkpoint = self.dead_letter_queue.quarantine(failed, stored)?;
+                        projector.skip_to(&self.cache, stored.sequence)?;
+                        continue;
+                    }
+
+                    outcome = Err(anyhow!(
+                        "Projector {} failed to process event {} (attempt {}): {err}",
+                        projector.name(),
+                        stored.sequence,
+                        failed.error_count
+                    ));
+                    checkpoint = failed;
+                    break;
+                }
+            }
+        }
+
+        if outcome.is_ok() {
+            projector.skip_to(&self.cache, scanned_to)?;
+            return self.advance_checkpoint(checkpoint, scanned_to);
+        }
+
+        self.checkpoint_repository
+            .update_checkpoint(&checkpoint)
+            .context("Failed to update checkpoint")?;
+
+        outcome
+    }
+
+    fn advance_checkpoint(&self, checkpoint: ProjectionCheckpoint, sequence: i64) -> Result<()> {
+        let checkpoint = if checkpoint.last_processed_sequence < sequence {
+            let processed_at = checkpoint.last_processed_at;
+            checkpoint.advanced_to(sequence, processed_at, self.clock.as_ref())
+        } else {
+            checkpoint
+        };
+        self.checkpoint_repository
+            .update_checkpoint(&checkpoint)
+            .context("Failed to update checkpoint")
+    }
+
+    /// Folds a quarantined event into the projector's current model and, if
+    /// that succeeds, removes it from the dead-letter store.
+    pub async fn retry_dead_letter(&self, projector_name: &str, sequence: i64) -> Result<()> {
+        let dead_letter = self.dead_letter_queue.get(projector_name, sequence)?;
+        let projector = self
+            .projectors
+            .iter()
+            .find(|projector| projector.name() == projector_name)
+            .ok_or_else(|| anyhow!("Unknown projector {projector_name}"))?;
+
+        let stored = self
+            .event_store
+            .events_since(dead_letter.sequence - 1, 1)?
+            .into_iter()
+            .next()
+            .filter(|stored| stored.sequence == dead_letter.sequence)
+            .ok_or_else(|| anyhow!("Event {} no longer exists", dead_letter.sequence))?;
+        let envelope: EventEnvelope<LabourEvent> = stored.to_envelope()?;
+        projector
+            .process(
+                &self.cache,
+                std::slice::from_ref(&envelope),
+                projector.get_cached_sequence(&self.cache),
+            )
+            .await?;
+
+        info!(
+            projector = %projector_name,
+            sequence = sequence,
+            "Retried quarantined event"
+        );
+        self.dead_letter_queue.resolve(projector_name, sequence)
+    }
+
+    /// Advances each requested rebuild by one batch into the projector's
+    /// shadow cache entry. The backing D1 table is only written when the
+    /// rebuilt model is swapped in, so readers never see it emptied.
+    pub async fn process_rebuilds(&self) -> Result<()> {
+        let mut errors: Vec<String> = Vec::new();
+
+        for projector in &self.projectors {
+            let Some(checkpoint) = self.pending_rebuild(projector.name())? else {
+                continue;
+            };
+
+            if let Err(e) = self.advance_rebuild(projector.as_ref(), &checkpoint).await {
+                error!(projector = %projector.name(), error = %e, "Failed to rebuild projector");
+                if let Err(update_err) = self.checkpoint_repository.update_checkpoint(
+                    &checkpoint.rebuild_failed(e.to_string(), self.clock.as_ref()),
+                ) {
+                    error!(
+                        projector = %projector.name(),
+                        error = %update_err,
+                        "Failed to update rebuild checkpoint with error state"
+                    );
+                }
+                errors.push(format!("{}: {}", projector.name(), e));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "One or more rebuilds failed: {}",
+                errors.join("; ")
+            ))
+        }
+    }
+
+    fn pending_rebuild(&self, projector_name: &str) -> Result<Option<ProjectionCheckpoint>> {
+        Ok(self
+            .checkpoint_repository
+            .get_checkpoint(&rebuild_checkpoint_name(projector_name))?
+            .filter(|checkpoint| checkpoint.error_count < MAX_PROJECTOR_ERROR_COUNT))
+    }
+
+    pub fn has_pending_rebuilds(&self) -> bool {
+        self.projectors
+            .iter()
+            .any(|projector| matches!(self.pending_rebuild(projector.name()), Ok(Some(_))))
+    }
+
+    async fn advance_rebuild(
+        &self,
+        projector: &dyn IncrementalAsyncProjector<LabourEvent>,
+        checkpoint: &ProjectionCheckpoint,
+    ) -> Result<()> {
+        if checkpoint.last_processed_sequence == 0 {
+            projector.discard_shadow(&self.cache)?;
+        }
+
+        let pages = self
+            .event_store
+            .pages_since(checkpoint.last_processed_sequence, self.default_batch_size);
+        let mut pages = match projector.event_types() {
+            Some(event_types) => pages.only(event_types),
+            None => pages,
+        };
+        let page = pages
+            .next()
+            .transpose()
+            .context("Failed to fetch events for rebuild")?;
+
+        let mut progress = ProjectionCheckpoint {
+            updated_at: self.clock.now(),
+            status: CheckpointStatus::Rebuilding,
+            error_message: None,
+            error_count: 0,
+            ..checkpoint.clone()
+        };
+        if let Some(page) = page {
+            let envelopes: Vec<EventEnvelope<LabourEvent>> = page
+                .rows
+                .iter()
+                .map(|stored| stored.to_envelope())
+                .collect::<Result<Vec<_>>>()?;
+            if let Some(last_envelope) = envelopes.last() {
+                progress.last_processed_at = last_envelope.metadata.timestamp;
+            }
+            progress.last_processed_sequence = page.scanned_to;
+            projector
+                .process_shadow(&self.cache, &envelopes, progress.last_processed_sequence)
+                .await?;
+        }
+
+        let max_sequence = self.event_store.max_sequence()?.unwrap_or(0);
+        if progress.last_processed_sequence < max_sequence {
+            debug!(
+                projector = %projector.name(),
+                rebuilt_sequence = progress.last_processed_sequence,
+                max_sequence = max_sequence,
+                "Rebuild in progress"
+            );
+            return self
+                .checkpoint_repository
+                .update_checkpoint(&progress)
+                .context("Failed to update rebuild checkpoint");
+        }
+
+        projector.promote_shadow(&self.cache).await?;
+        self.checkpoint_repository
+            .reset_checkpoint(&progress.projector_name)
+            .context("Failed to clear rebuild checkpoint")?;
+
+        info!(
+            projector = %projector.name(),
+            sequence = progress.last_processed_sequence,
+            "Rebuilt read model swapped in"
+        );
+        Ok(())
+    }
 }
@@ -2,29 +2,33 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use anyhow::{Context, Result, anyhow};
-use chrono::Utc;
-use tracing::{debug, error, warn};
+use tracing::{debug, error, info, warn};
 
 use fern_labour_event_sourcing_rs::{
-    CheckpointRepository, CheckpointStatus, EventEnvelopeAdapter, EventStoreTrait,
-    ProjectionCheckpoint, SyncProjector,
+    CheckpointRepository, CheckpointStatus, Clock, DeadLetterQueue, EventEnvelope,
+    EventEnvelopeAdapter, EventPage, EventStoreTrait, PoisonEventPolicy, ProjectionCheckpoint,
+    StoredEventRow, SyncProjector, SystemClock, rebuild_checkpoint_name,
 };
 
 use crate::durable_object::write_side::domain::LabourEvent;
 
-const MAX_PROJECTOR_ERROR_COUNT: i64 = 5;
+pub const MAX_PROJECTOR_ERROR_COUNT: i64 = 5;
 
 pub struct SyncProjectionProcessor {
     event_store: Rc<dyn EventStoreTrait>,
     checkpoint_repository: Box<dyn CheckpointRepository>,
+    dead_letter_queue: Rc<DeadLetterQueue>,
     projectors: HashMap<String, Box<dyn SyncProjector<LabourEvent>>>,
+    policies: HashMap<String, PoisonEventPolicy>,
     batch_size: i64,
+    clock: Rc<dyn Clock>,
 }
 
 impl SyncProjectionProcessor {
     pub fn create(
         event_store: Rc<dyn EventStoreTrait>,
         checkpoint_repository: Box<dyn CheckpointRepository>,
+        dead_letter_queue: Rc<DeadLetterQueue>,
         projectors: Vec<Box<dyn SyncProjector<LabourEvent>>>,
         default_batch_size: i64,
     ) -> Self {
@@ -36,11 +40,42 @@ impl SyncProjectionProcessor {
         Self {
             event_store,
             checkpoint_repository,
+            dead_letter_queue,
             projectors: projector_map,
+            policies: HashMap::new(),
             batch_size: default_batch_size,
+            clock: Rc::new(SystemClock),
         }
     }
 
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Overrides how `projector_name` handles an event that keeps failing.
+    /// Projectors without an override quarantine it after
+    /// [`PoisonEventPolicy::default`]'s number of attempts.
+    pub fn with_poison_event_policy(
+        mut self,
+        projector_name: &str,
+        policy: PoisonEventPolicy,
+    ) -> Self {
+        self.policies.insert(projector_name.to_string(), policy);
+        self
+    }
+
+    fn policy(&self, projector_name: &str) -> PoisonEventPolicy {
+        self.policies
+            .get(projector_name)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn has_projector(&self, projector_name: &str) -> bool {
+        self.projectors.contains_key(projector_name)
+    }
+
     pub fn process_projections(&self) -> Result<()> {
         debug!("Starting checkpoint-based projection processing");
 
@@ -48,8 +83,7 @@ impl SyncProjectionProcessor {
 
         for (projector_name, projector) in &self.projectors {
             if let Ok(Some(checkpoint)) = self.checkpoint_repository.get_checkpoint(projector_name)
-                && checkpoint.status == CheckpointStatus::Error
-                && checkpoint.error_count >= MAX_PROJECTOR_ERROR_COUNT
+                && self.policy(projector_name).is_halted(&checkpoint)
             {
                 warn!(
                     projector = %projector_name,
@@ -83,7 +117,7 @@ impl SyncProjectionProcessor {
         let checkpoint = self
             .checkpoint_repository
             .get_checkpoint(projector_name)?
-            .unwrap_or_else(|| self.create_initial_checkpoint(projector_name));
+            .unwrap_or_else(|| ProjectionCheckpoint::initial(projector_name, self.clock.as_ref()));
 
         let last_sequence = checkpoint.last_processed_sequence;
 
@@ -93,95 +127,301 @@ impl SyncProjectionProcessor {
             "Processing projector from checkpoint"
         );
 
-        let stored_events = self
-            .event_store
-            .events_since(last_sequence, self.batch_size)
-            .context("Failed to fetch events since checkpoint")?;
-
-        if stored_events.is_empty() {
+        let Some(page) = self
+            .next_page(last_sequence, projector)
+            .context("Failed to fetch events since checkpoint")?
+        else {
             debug!(
                 projector = %projector_name,
                 "No new events to process"
             );
             return Ok(());
-        }
+        };
+        let stored_events = page.rows;
 
-        let envelopes = stored_events
-            .iter()
-            .map(|stored| stored.to_envelope())
-            .collect::<Result<Vec<_>>>()?;
+        if stored_events.is_empty() {
+            debug!(
+                projector = %projector_name,
+                scanned_to = page.scanned_to,
+                "No relevant events, advancing checkpoint"
+            );
+            let processed_at = checkpoint.last_processed_at;
+            return self
+                .checkpoint_repository
+                .update_checkpoint(&checkpoint.advanced_to(
+                    page.scanned_to,
+                    processed_at,
+                    self.clock.as_ref(),
+                ))
+                .context("Failed to update checkpoint");
+        }
 
-        let event_count = envelopes.len();
         debug!(
             projector = %projector_name,
-            event_count = event_count,
+            event_count = stored_events.len(),
             "Processing events"
         );
 
-        if let Err(err) = projector.project_batch(&envelopes) {
-            let new_error_count = checkpoint.error_count + 1;
-            let error_checkpoint = ProjectionCheckpoint {
-                projector_name: projector_name.to_string(),
-                last_processed_sequence: checkpoint.last_processed_sequence,
-                last_processed_at: checkpoint.last_processed_at,
-                updated_at: Utc::now(),
-                status: CheckpointStatus::Error,
-                error_message: Some(err.to_string()),
-                error_count: new_error_count,
-            };
+        if stored_events.len() > 1
+            && let Ok(envelopes) = stored_events
+                .iter()
+                .map(|stored| stored.to_envelope())
+                .collect::<Result<Vec<EventEnvelope<LabourEvent>>>>()
+            && projector.project_batch(&envelopes).is_ok()
+        {
+            let last_envelope = envelopes.last().unwrap();
+            let new_checkpoint = checkpoint.advanced_to(
+                page.scanned_to,
+                last_envelope.metadata.timestamp,
+                self.clock.as_ref(),
+            );
 
-            if let Err(update_err) = self
-                .checkpoint_repository
-                .update_checkpoint(&error_checkpoint)
-            {
-                error!(
-                    projector = %projector_name,
-                    error = %update_err,
-                    "Failed to update checkpoint with error state"
-                );
-            }
+            self.checkpoint_repository
+                .update_checkpoint(&new_checkpoint)
+                .context("Failed to update checkpoint")?;
 
-            return Err(anyhow!(
-                "Projector {projector_name} failed to process batch (attempt {}): {err}",
-                new_error_count
-            ));
+            debug!(
+                projector = %projector_name,
+                events_processed = envelopes.len(),
+                new_sequence = new_checkpoint.last_processed_sequence,
+                "Successfully processed and checkpointed events"
+            );
+            return Ok(());
         }
 
-        let last_envelope = envelopes.last().unwrap();
-        let new_checkpoint = ProjectionCheckpoint {
-            projector_name: projector_name.to_string(),
-            last_processed_sequence: last_envelope.metadata.sequence,
-            last_processed_at: last_envelope.metadata.timestamp,
-            updated_at: chrono::Utc::now(),
-            status: CheckpointStatus::Healthy,
-            error_message: None,
-            error_count: 0,
+        self.process_one_by_one(
+            projector_name,
+            projector,
+            checkpoint,
+            &stored_events,
+            page.scanned_to,
+        )
+    }
+
+    /// Reads the next page of events `projector` handles after `sequence`.
+    fn next_page(
+        &self,
+        sequence: i64,
+        projector: &dyn SyncProjector<LabourEvent>,
+    ) -> Result<Option<EventPage>> {
+        let pages = self.event_store.pages_since(sequence, self.batch_size);
+        let mut pages = match projector.event_types() {
+            Some(event_types) => pages.only(event_types),
+            None => pages,
         };
+        pages.next().transpose()
+    }
+
+    /// Projects `stored_events` one at a time after a batch has failed, so
+    /// the checkpoint advances past every good event and the one at fault is
+    /// retried or quarantined on its own.
+    fn process_one_by_one(
+        &self,
+        projector_name: &str,
+        projector: &dyn SyncProjector<LabourEvent>,
+        checkpoint: ProjectionCheckpoint,
+        stored_events: &[StoredEventRow],
+        scanned_to: i64,
+    ) -> Result<()> {
+        let policy = self.policy(projector_name);
+        let mut checkpoint = checkpoint;
+        let mut outcome = Ok(());
+
+        for stored in stored_events {
+            let result = stored
+                .to_envelope()
+                .and_then(|envelope: EventEnvelope<LabourEvent>| {
+                    projector.project_batch(std::slice::from_ref(&envelope))?;
+                    Ok(envelope.metadata.timestamp)
+                });
+
+            match result {
+                Ok(processed_at) => {
+                    checkpoint =
+                        checkpoint.advanced_to(stored.sequence, processed_at, self.clock.as_ref());
+                }
+                Err(err) => {
+                    let failed = checkpoint.failed(err.to_string(), self.clock.as_ref());
+                    if policy.should_quarantine(failed.error_count) {
+                        warn!(
+                            projector = %projector_name,
+                            sequence = stored.sequence,
+                            event_type = %stored.event_type,
+                            error = %err,
+                            "Quarantining event after repeated failures"
+                        );
+                        checkpoint = self.dead_letter_queue.quarantine(failed, stored)?;
+                        continue;
+                    }
+
+                    outcome = Err(anyhow!(
+                        "Projector {projector_name} failed to process event {} (attempt {}): {err}",
+                        stored.sequence,
+                        failed.error_count
+                    ));
+                    checkpoint = failed;
+                    break;
+                }
+            }
+        }
+
+        if outcome.is_ok() && checkpoint.last_processed_sequence < scanned_to {
+            let processed_at = checkpoint.last_processed_at;
+            checkpoint = checkpoint.advanced_to(scanned_to, processed_at, self.clock.as_ref());
+        }
 
         self.checkpoint_repository
-            .update_checkpoint(&new_checkpoint)
+            .update_checkpoint(&checkpoint)
             .context("Failed to update checkpoint")?;
 
-        debug!(
+        outcome
+    }
+
+    /// Projects a quarantined event again and, if it succeeds, removes it
+    /// from the dead-letter store.
+    pub fn retry_dead_letter(&self, projector_name: &str, sequence: i64) -> Result<()> {
+        let dead_letter = self.dead_letter_queue.get(projector_name, sequence)?;
+        let projector = self
+            .projectors
+            .get(projector_name)
+            .ok_or_else(|| anyhow!("Unknown projector {projector_name}"))?;
+
+        let stored = self
+            .event_store
+            .events_since(dead_letter.sequence - 1, 1)?
+            .into_iter()
+            .next()
+            .filter(|stored| stored.sequence == dead_letter.sequence)
+            .ok_or_else(|| anyhow!("Event {} no longer exists", dead_letter.sequence))?;
+        let envelope: EventEnvelope<LabourEvent> = stored.to_envelope()?;
+        projector.project_batch(std::slice::from_ref(&envelope))?;
+
+        info!(
             projector = %projector_name,
-            events_processed = event_count,
-            new_sequence = new_checkpoint.last_processed_sequence,
-            "Successfully processed and checkpointed events"
+            sequence = sequence,
+            "Retried quarantined event"
         );
+        self.dead_letter_queue.resolve(projector_name, sequence)
+    }
 
-        Ok(())
+    /// Advances each requested rebuild by one batch into its projector's
+    /// shadow storage. Once a rebuild has caught up with the event store the
+    /// shadow is swapped in and the projector's live checkpoint moves to the
+    /// rebuilt sequence.
+    pub fn process_rebuilds(&self) -> Result<()> {
+        let mut errors: Vec<String> = Vec::new();
+
+        for (projector_name, projector) in &self.projectors {
+            let Some(checkpoint) = self.pending_rebuild(projector_name)? else {
+                continue;
+            };
+
+            if let Err(e) = self.advance_rebuild(projector_name, projector.as_ref(), &checkpoint) {
+                error!(projector = %projector_name, error = %e, "Failed to rebuild projector");
+                if let Err(update_err) = self.checkpoint_repository.update_checkpoint(
+                    &checkpoint.rebuild_failed(e.to_string(), self.clock.as_ref()),
+                ) {
+                    error!(
+                        projector = %projector_name,
+                        error = %update_err,
+                        "Failed to update rebuild checkpoint with error state"
+                    );
+                }
+                errors.push(format!("{}: {}", projector_name, e));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "One or more rebuilds failed: {}",
+                errors.join("; ")
+            ))
+        }
+    }
+
+    fn pending_rebuild(&self, projector_name: &str) -> Result<Option<ProjectionCheckpoint>> {
+        Ok(self
+            .checkpoint_repository
+            .get_checkpoint(&rebuild_checkpoint_name(projector_name))?
+            .filter(|checkpoint| checkpoint.error_count < MAX_PROJECTOR_ERROR_COUNT))
     }
 
-    fn create_initial_checkpoint(&self, projector_name: &str) -> ProjectionCheckpoint {
-        ProjectionCheckpoint {
-            projector_name: projector_name.to_string(),
-            last_processed_sequence: 0,
-            last_processed_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-            status: CheckpointStatus::Healthy,
+    pub fn has_pending_rebuilds(&self) -> bool {
+        self.projectors
+            .keys()
+            .any(|projector_name| matches!(self.pending_rebuild(projector_name), Ok(Some(_))))
+    }
+
+    fn advance_rebuild(
+        &self,
+        projector_name: &str,
+        projector: &dyn SyncProjector<LabourEvent>,
+        checkpoint: &ProjectionCheckpoint,
+    ) -> Result<()> {
+        if checkpoint.last_processed_sequence == 0 {
+            projector.discard_shadow()?;
+        }
+        let shadow = projector.shadow()?;
+
+        let page = self
+            .next_page(checkpoint.last_processed_sequence, projector)
+            .context("Failed to fetch events for rebuild")?;
+        let envelopes = page
+            .as_ref()
+            .map_or(&[][..], |page| page.rows.as_slice())
+            .iter()
+            .map(|stored| stored.to_envelope())
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut progress = ProjectionCheckpoint {
+            updated_at: self.clock.now(),
+            status: CheckpointStatus::Rebuilding,
             error_message: None,
             error_count: 0,
+            ..checkpoint.clone()
+        };
+        if let Some(last_envelope) = envelopes.last() {
+            shadow.project_batch(&envelopes)?;
+            progress.last_processed_at = last_envelope.metadata.timestamp;
+        }
+        if let Some(page) = &page {
+            progress.last_processed_sequence = page.scanned_to;
         }
+
+        let max_sequence = self.event_store.max_sequence()?.unwrap_or(0);
+        if progress.last_processed_sequence < max_sequence {
+            debug!(
+                projector = %projector_name,
+                rebuilt_sequence = progress.last_processed_sequence,
+                max_sequence = max_sequence,
+                "Rebuild in progress"
+            );
+            return self
+                .checkpoint_repository
+                .update_checkpoint(&progress)
+                .context("Failed to update rebuild checkpoint");
+        }
+
+        projector.promote_shadow()?;
+        self.checkpoint_repository
+            .update_checkpoint(&ProjectionCheckpoint {
+                projector_name: projector_name.to_string(),
+                status: CheckpointStatus::Healthy,
+                ..progress.clone()
+            })
+            .context("Failed to update checkpoint after rebuild")?;
+        self.checkpoint_repository
+            .reset_checkpoint(&progress.projector_name)
+            .context("Failed to clear rebuild checkpoint")?;
+
+        info!(
+            projector = %projector_name,
+            sequence = progress.last_processed_sequence,
+            "Rebuilt read model swapped in"
+        );
+        Ok(())
     }
 
     pub fn get_last_processed_sequence(&self) -> i64 {
@@ -193,9 +433,7 @@ impl SyncProjectionProcessor {
                     .ok()
                     .flatten()
                     .and_then(|cp| {
-                        if cp.status == CheckpointStatus::Error
-                            && cp.error_count >= MAX_PROJECTOR_ERROR_COUNT
-                        {
+                        if self.policy(projector_name).is_halted(&cp) {
                             None
                         } else {
                             Some(cp.last_processed_sequence)
@@ -214,3 +452,300 @@ impl SyncProjectionProcessor {
             .unwrap_or(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use fern_labour_event_sourcing_rs::{
+        EventDescriptor, InMemorySyncRepository, SyncRepositoryTrait,
+        testing::{
+            AggregateTestHarness, FixedClock, InMemoryCheckpointRepository,
+            InMemoryDeadLetterRepository,
+        },
+    };
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::durable_object::{
+        read_side::read_models::labour::{LabourReadModel, LabourReadModelProjector},
+        write_side::domain::{
+            Labour,
+            events::{LabourPlanUpdated, LabourPlanned},
+        },
+    };
+
+    struct RecordingProjector {
+        name: &'static str,
+        fail: bool,
+        poison_sequence: Option<i64>,
+        event_types: Option<&'static [&'static str]>,
+        calls: Rc<Cell<usize>>,
+        sequences: Rc<RefCell<Vec<i64>>>,
+    }
+
+    impl SyncProjector<LabourEvent> for RecordingProjector {
+        fn project_batch(&self, events: &[EventEnvelope<LabourEvent>]) -> Result<()> {
+            self.calls.set(self.calls.get() + 1);
+            if self.fail
+                || events
+                    .iter()
+                    .any(|envelope| Some(envelope.metadata.sequence) == self.poison_sequence)
+            {
+                return Err(anyhow!("projection failed"));
+            }
+            self.sequences
+                .borrow_mut()
+                .extend(events.iter().map(|envelope| envelope.metadata.sequence));
+            Ok(())
+        }
+
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn event_types(&self) -> Option<&'static [&'static str]> {
+            self.event_types
+        }
+    }
+
+    fn recording_projector(name: &'static str, fail: bool) -> RecordingProjector {
+        RecordingProjector {
+            name,
+            fail,
+            poison_sequence: None,
+            event_types: None,
+            calls: Rc::new(Cell::new(0)),
+            sequences: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    fn dead_letter_queue() -> Rc<DeadLetterQueue> {
+        Rc::new(DeadLetterQueue::new(
+            Box::new(InMemoryDeadLetterRepository::new()),
+            Box::new(InMemoryCheckpointRepository::new()),
+        ))
+    }
+
+    fn labour_planned() -> LabourEvent {
+        LabourEvent::LabourPlanned(LabourPlanned {
+            labour_id: Uuid::now_v7(),
+            mother_id: "mother_123".to_string(),
+            mother_name: "Test Mother".to_string(),
+            first_labour: true,
+            due_date: FixedClock::default().now(),
+            labour_name: None,
+        })
+    }
+
+    #[test]
+    fn checkpoints_advance_past_projected_events() {
+        let harness = AggregateTestHarness::<Labour>::new().given([labour_planned()]);
+        let projector = recording_projector("Recording", false);
+        let sequences = projector.sequences.clone();
+        let processor = SyncProjectionProcessor::create(
+            harness.event_store(),
+            Box::new(InMemoryCheckpointRepository::new()),
+            dead_letter_queue(),
+            vec![Box::new(projector)],
+            100,
+        );
+
+        processor.process_projections().unwrap();
+        processor.process_projections().unwrap();
+
+        assert_eq!(*sequences.borrow(), vec![1]);
+        assert_eq!(processor.get_last_processed_sequence(), 1);
+        assert!(!processor.has_unprocessed_events());
+    }
+
+    #[test]
+    fn projectors_only_see_their_event_types_but_checkpoint_past_the_rest() {
+        let LabourEvent::LabourPlanned(planned) = labour_planned() else {
+            unreachable!()
+        };
+        let plan_updated = LabourEvent::LabourPlanUpdated(LabourPlanUpdated {
+            labour_id: planned.labour_id,
+            first_labour: false,
+            due_date: planned.due_date,
+            labour_name: None,
+        });
+        let harness = AggregateTestHarness::<Labour>::new().given([
+            LabourEvent::LabourPlanned(planned),
+            plan_updated.clone(),
+            plan_updated,
+        ]);
+        let mut updates = recording_projector("Updates", false);
+        updates.event_types = Some(&[LabourPlanUpdated::EVENT_TYPE]);
+        let update_sequences = updates.sequences.clone();
+        let mut unrelated = recording_projector("Unrelated", false);
+        unrelated.event_types = Some(&["ContractionStarted"]);
+        let unrelated_calls = unrelated.calls.clone();
+        let processor = SyncProjectionProcessor::create(
+            harness.event_store(),
+            Box::new(InMemoryCheckpointRepository::new()),
+            dead_letter_queue(),
+            vec![Box::new(updates), Box::new(unrelated)],
+            100,
+        );
+
+        processor.process_projections().unwrap();
+
+        assert_eq!(*update_sequences.borrow(), vec![2, 3]);
+        assert_eq!(unrelated_calls.get(), 0);
+        assert_eq!(processor.get_last_processed_sequence(), 3);
+        assert!(!processor.has_unprocessed_events());
+    }
+
+    #[test]
+    fn faulted_projectors_are_skipped_after_max_errors() {
+        let harness = AggregateTestHarness::<Labour>::new().given([labour_planned()]);
+        let projector = recording_projector("Faulty", true);
+        let calls = projector.calls.clone();
+        let processor = SyncProjectionProcessor::create(
+            harness.event_store(),
+            Box::new(InMemoryCheckpointRepository::new()),
+            dead_letter_queue(),
+            vec![Box::new(projector)],
+            100,
+        )
+        .with_poison_event_policy(
+            "Faulty",
+            PoisonEventPolicy::Halt {
+                max_failures: MAX_PROJECTOR_ERROR_COUNT,
+            },
+        );
+
+        for _ in 0..MAX_PROJECTOR_ERROR_COUNT {
+            assert!(processor.process_projections().is_err());
+        }
+
+        assert!(processor.process_projections().is_ok());
+        assert_eq!(calls.get(), MAX_PROJECTOR_ERROR_COUNT as usize);
+    }
+
+    #[test]
+    fn poison_events_are_quarantined_and_can_be_retried() {
+        let LabourEvent::LabourPlanned(planned) = labour_planned() else {
+            unreachable!()
+        };
+        let plan_updated = LabourEvent::LabourPlanUpdated(LabourPlanUpdated {
+            labour_id: planned.labour_id,
+            first_labour: false,
+            due_date: planned.due_date,
+            labour_name: None,
+        });
+        let harness = AggregateTestHarness::<Labour>::new().given([
+            LabourEvent::LabourPlanned(planned),
+            plan_updated.clone(),
+            plan_updated,
+        ]);
+        let mut projector = recording_projector("Flaky", false);
+        projector.poison_sequence = Some(2);
+        let sequences = projector.sequences.clone();
+        let checkpoint_repository = InMemoryCheckpointRepository::new();
+        let dead_letter_queue = dead_letter_queue();
+        let processor = SyncProjectionProcessor::create(
+            harness.event_store(),
+            Box::new(checkpoint_repository),
+            dead_letter_queue.clone(),
+            vec![Box::new(projector)],
+            100,
+        )
+        .with_poison_event_policy("Flaky", PoisonEventPolicy::Quarantine { max_failures: 2 });
+        let checkpoint = || {
+            processor
+                .checkpoint_repository
+                .get_checkpoint("Flaky")
+                .unwrap()
+                .unwrap()
+        };
+
+        assert!(processor.process_projections().is_err());
+        assert_eq!(checkpoint().last_processed_sequence, 1);
+        assert_eq!(checkpoint().status, CheckpointStatus::Error);
+
+        processor.process_projections().unwrap();
+        assert_eq!(checkpoint().last_processed_sequence, 3);
+        assert_eq!(checkpoint().status, CheckpointStatus::Stale);
+        assert_eq!(*sequences.borrow(), vec![1, 3]);
+        let dead_letters = dead_letter_queue.list().unwrap();
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].sequence, 2);
+        assert_eq!(dead_letters[0].failure_count, 2);
+
+        assert!(processor.retry_dead_letter("Flaky", 2).is_err());
+        assert_eq!(dead_letter_queue.list().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn rebuilds_into_a_shadow_and_swaps_it_in_once_caught_up() {
+        let LabourEvent::LabourPlanned(planned) = labour_planned() else {
+            unreachable!()
+        };
+        let harness = AggregateTestHarness::<Labour>::new().given([
+            LabourEvent::LabourPlanned(planned.clone()),
+            LabourEvent::LabourPlanUpdated(LabourPlanUpdated {
+                labour_id: planned.labour_id,
+                first_labour: false,
+                due_date: planned.due_date,
+                labour_name: Some("Rebuilt".to_string()),
+            }),
+        ]);
+
+        let repository = InMemorySyncRepository::<LabourReadModel>::new();
+        let stale = LabourReadModel::new(
+            Uuid::now_v7(),
+            "mother_123".to_string(),
+            "Test Mother".to_string(),
+            true,
+            FixedClock::default().now(),
+            Some("Stale".to_string()),
+            FixedClock::default().now(),
+        );
+        repository.upsert(&stale).unwrap();
+
+        let checkpoint_repository = InMemoryCheckpointRepository::new();
+        checkpoint_repository
+            .update_checkpoint(&ProjectionCheckpoint::rebuild_requested(
+                LabourReadModelProjector::NAME,
+                &FixedClock::default(),
+            ))
+            .unwrap();
+        let processor = SyncProjectionProcessor::create(
+            harness.event_store(),
+            Box::new(checkpoint_repository),
+            dead_letter_queue(),
+            vec![Box::new(LabourReadModelProjector::create(Box::new(
+                repository.clone(),
+            )))],
+            1,
+        );
+        let rebuild_checkpoint = || {
+            processor
+                .checkpoint_repository
+                .get_checkpoint(&rebuild_checkpoint_name(LabourReadModelProjector::NAME))
+                .unwrap()
+        };
+
+        processor.process_rebuilds().unwrap();
+
+        let live = repository.values();
+        assert_eq!(live.len(), 1);
+        assert_eq!(live[0].labour_id, stale.labour_id);
+        let progress = rebuild_checkpoint().unwrap();
+        assert_eq!(progress.status, CheckpointStatus::Rebuilding);
+        assert_eq!(progress.last_processed_sequence, 1);
+        assert!(processor.has_pending_rebuilds());
+
+        processor.process_rebuilds().unwrap();
+
+        let rebuilt = repository.values();
+        assert_eq!(rebuilt.len(), 1);
+        assert_eq!(rebuilt[0].labour_id, planned.labour_id);
+        assert_eq!(rebuilt[0].labour_name, Some("Rebuilt".to_string()));
+        assert!(rebuild_checkpoint().is_none());
+        assert!(!processor.has_pending_rebuilds());
+        assert_eq!(processor.get_last_processed_sequence(), 2);
+    }
+}
@@ -2,20 +2,30 @@ use anyhow::{Result, anyhow};
 use fern_labour_event_sourcing_rs::PaginatedResponse;
 use fern_labour_labour_shared::{
     ApiQuery, ContractionQuery, LabourQuery, LabourUpdateQuery,
-    queries::{subscription::SubscriptionQuery, user::UserQuery},
+    queries::{milestone::MilestoneQuery, subscription::SubscriptionQuery, user::UserQuery},
 };
 use fern_labour_workers_shared::User;
 use serde_json::Value;
 
 use super::read_models::{
     contractions::ContractionReadModelQueryHandler, labour::LabourReadModelQueryHandler,
-    labour_updates::LabourUpdateReadModelQueryHandler,
+    labour_updates::LabourUpdateReadModelQueryHandler, milestones::MilestoneReadModelQueryHandler,
+    scheduled_labour_updates::ScheduledLabourUpdateReadModelQueryHandler,
     subscription_token::SubscriptionTokenQueryHandler, subscriptions::SubscriptionQueryHandler,
 };
 use crate::durable_object::{
-    authorization::{Action, Authorizer, QueryAction, resolve_principal},
+    authorization::{Action, Authorizer, Principal, QueryAction, resolve_principal},
     http::utils::{build_paginated_response, decode_cursor},
     setup::state::ReadModel,
+    write_side::domain::{
+        Labour,
+        services::{
+            ContractionAnalytics,
+            contraction_analytics::{
+                DEFAULT_STATISTICS_WINDOW_MINUTES, MAX_STATISTICS_WINDOW_MINUTES,
+            },
+        },
+    },
 };
 
 pub struct QueryHandler<'a> {
@@ -37,7 +47,13 @@ impl<'a> QueryHandler<'a> {
         let action = match &query {
             ApiQuery::Labour(_) => Action::Query(QueryAction::GetLabour),
             ApiQuery::Contraction(_) => Action::Query(QueryAction::GetContractions),
-            ApiQuery::LabourUpdate(_) => Action::Query(QueryAction::GetLabourUpdates),
+            ApiQuery::LabourUpdate(luq) => match luq {
+                LabourUpdateQuery::GetScheduledLabourUpdates { .. } => {
+                    Action::Query(QueryAction::GetScheduledLabourUpdates)
+                }
+                _ => Action::Query(QueryAction::GetLabourUpdates),
+            },
+            ApiQuery::Milestone(_) => Action::Query(QueryAction::GetMilestones),
             ApiQuery::Subscription(sq) => match sq {
                 SubscriptionQuery::GetSubscriptionToken { .. } => {
                     Action::Query(QueryAction::GetSubscriptionToken)
@@ -61,24 +77,45 @@ impl<'a> QueryHandler<'a> {
             .map_err(|e| anyhow!("Authorization failed: {}", e))?;
 
         match query {
-            ApiQuery::Labour(q) => self.handle_labour(q),
-            ApiQuery::Contraction(q) => self.handle_contraction(q),
+            ApiQuery::Labour(q) => self.handle_labour(q, aggregate.as_ref(), &principal),
+            ApiQuery::Contraction(q) => self.handle_contraction(q, aggregate.as_ref()),
             ApiQuery::LabourUpdate(q) => self.handle_labour_update(q),
+            ApiQuery::Milestone(q) => self.handle_milestone(q),
             ApiQuery::Subscription(q) => self.handle_subscription(q, user),
             ApiQuery::User(q) => self.handle_user(q),
         }
     }
 
-    fn handle_labour(&self, query: LabourQuery) -> Result<Value> {
+    fn handle_labour(
+        &self,
+        query: LabourQuery,
+        aggregate: Option<&Labour>,
+        principal: &Principal,
+    ) -> Result<Value> {
         match query {
             LabourQuery::GetLabour { .. } => {
                 let labour = self.read_model.labour_query.get()?;
                 Ok(serde_json::to_value(labour)?)
             }
+            LabourQuery::GetPhaseProgressionPolicy { .. } => {
+                let labour = aggregate.ok_or_else(|| anyhow!("Labour not found"))?;
+                Ok(serde_json::to_value(labour.phase_progression_policy())?)
+            }
+            LabourQuery::GetBirthRecord { .. } => {
+                let labour = aggregate.ok_or_else(|| anyhow!("Labour not found"))?;
+                let birth_record = labour
+                    .birth_record()
+                    .filter(|record| record.is_published() || *principal == Principal::Mother);
+                Ok(serde_json::to_value(birth_record)?)
+            }
         }
     }
 
-    fn handle_contraction(&self, query: ContractionQuery) -> Result<Value> {
+    fn handle_contraction(
+        &self,
+        query: ContractionQuery,
+        aggregate: Option<&Labour>,
+    ) -> Result<Value> {
         match query {
             ContractionQuery::GetContractions { limit, cursor, .. } => {
                 let decoded = decode_cursor(cursor);
@@ -94,6 +131,25 @@ impl<'a> QueryHandler<'a> {
                     .get_by_id(contraction_id)?;
                 Ok(serde_json::to_value(item)?)
             }
+            ContractionQuery::GetContractionStatistics {
+                window_minutes,
+                rule,
+                ..
+            } => {
+                let labour = aggregate.ok_or_else(|| anyhow!("Labour not found"))?;
+                let window_minutes = window_minutes.unwrap_or(DEFAULT_STATISTICS_WINDOW_MINUTES);
+                if !(1..=MAX_STATISTICS_WINDOW_MINUTES).contains(&window_minutes) {
+                    return Err(anyhow!(
+                        "window_minutes must be between 1 and {MAX_STATISTICS_WINDOW_MINUTES}"
+                    ));
+                }
+                let statistics = ContractionAnalytics::statistics(
+                    labour.contractions(),
+                    window_minutes,
+                    rule.unwrap_or_default(),
+                );
+                Ok(serde_json::to_value(statistics)?)
+            }
         }
     }
 
@@ -123,6 +179,42 @@ impl<'a> QueryHandler<'a> {
                     })?;
                 Ok(serde_json::to_value(response)?)
             }
+            LabourUpdateQuery::GetScheduledLabourUpdates { limit, cursor, .. } => {
+                let decoded_cursor = decode_cursor(cursor);
+                let response = self
+                    .read_model
+                    .scheduled_labour_update_query
+                    .get(limit + 1, decoded_cursor)
+                    .map(|items| build_paginated_response(items, limit))?;
+                Ok(serde_json::to_value(response)?)
+            }
+        }
+    }
+
+    fn handle_milestone(&self, query: MilestoneQuery) -> Result<Value> {
+        match query {
+            MilestoneQuery::GetMilestones { limit, cursor, .. } => {
+                let decoded_cursor = decode_cursor(cursor);
+                let response = self
+                    .read_model
+                    .milestone_query
+                    .get(limit + 1, decoded_cursor)
+                    .map(|items| build_paginated_response(items, limit))?;
+                Ok(serde_json::to_value(response)?)
+            }
+            MilestoneQuery::GetMilestoneById { milestone_id, .. } => {
+                let response = self
+                    .read_model
+                    .milestone_query
+                    .get_by_id(milestone_id)
+                    .map(|m| vec![m])
+                    .map(|items| PaginatedResponse {
+                        data: items,
+                        next_cursor: None,
+                        has_more: false,
+                    })?;
+                Ok(serde_json::to_value(response)?)
+            }
         }
     }
 
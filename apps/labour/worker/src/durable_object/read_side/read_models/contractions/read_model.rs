@@ -13,6 +13,8 @@ pub struct ContractionReadModel {
     pub duration: Duration,
     pub duration_seconds: f64,
     pub intensity: Option<u8>,
+    /// Closed by the server rather than ended by the mother or a birth partner.
+    pub auto_closed: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -32,6 +34,7 @@ impl ContractionReadModel {
             duration,
             duration_seconds: *duration_seconds,
             intensity,
+            auto_closed: false,
             created_at,
             updated_at: created_at,
         }
@@ -57,6 +60,7 @@ pub struct ContractionRow {
     pub end_time: String,
     pub duration_seconds: String,
     pub intensity: Option<String>,
+    pub auto_closed: String,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -76,6 +80,7 @@ impl ContractionRow {
             duration,
             duration_seconds: parse_float(&self.duration_seconds),
             intensity: Self::parse_optional_intensity(self.intensity)?,
+            auto_closed: Self::parse_bool(&self.auto_closed)?,
             created_at: Self::parse_timestamp(&self.created_at)?,
             updated_at: Self::parse_timestamp(&self.updated_at)?,
         })
@@ -89,6 +94,7 @@ impl ContractionRow {
             end_time: model.duration.end_time().to_rfc3339(),
             duration_seconds: model.duration_seconds.to_string(),
             intensity: model.intensity.map(|i| i.to_string()),
+            auto_closed: Self::bool_to_string(model.auto_closed),
             created_at: model.created_at.to_rfc3339(),
             updated_at: model.updated_at.to_rfc3339(),
         })
@@ -112,4 +118,16 @@ impl ContractionRow {
             .with_timezone(&Utc);
         Ok(datetime)
     }
+
+    fn parse_bool(value: &str) -> Result<bool> {
+        match value {
+            "true" | "1" => Ok(true),
+            "false" | "0" => Ok(false),
+            _ => Err(anyhow!("Invalid boolean value: {}", value)),
+        }
+    }
+
+    fn bool_to_string(value: bool) -> String {
+        if value { "true" } else { "false" }.to_string()
+    }
 }
@@ -1,9 +1,15 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
 use fern_labour_labour_shared::value_objects::contraction::duration::Duration;
 
-use fern_labour_event_sourcing_rs::{EventEnvelope, SyncProjector, SyncRepositoryTrait};
+use fern_labour_event_sourcing_rs::{
+    EventDescriptor, EventEnvelope, SyncProjector, SyncRepositoryTrait,
+};
 
+use crate::durable_object::write_side::domain::events::{
+    ContractionAbandoned, ContractionDeleted, ContractionEnded, ContractionResumed,
+    ContractionStarted, ContractionUpdated,
+};
 use crate::durable_object::{
     read_side::read_models::contractions::ContractionReadModel, write_side::domain::LabourEvent,
 };
@@ -14,9 +20,11 @@ pub struct ContractionReadModelProjector {
 }
 
 impl ContractionReadModelProjector {
+    pub const NAME: &'static str = "ContractionReadModelProjector";
+
     pub fn create(repository: Box<dyn SyncRepositoryTrait<ContractionReadModel>>) -> Self {
         Self {
-            name: "ContractionReadModelProjector".to_string(),
+            name: Self::NAME.to_string(),
             repository,
         }
     }
@@ -51,6 +59,36 @@ impl ContractionReadModelProjector {
                 contraction.updated_at = timestamp;
                 self.repository.upsert(&contraction)
             }
+            LabourEvent::ContractionAbandoned(e) => {
+                let mut contraction =
+                    self.repository
+                        .get_by_id(e.contraction_id)
+                        .with_context(|| {
+                            format!("No contraction found with id: {}", e.contraction_id)
+                        })?;
+                let duration = Duration::create(*contraction.duration.start_time(), e.end_time)?;
+                contraction.duration_seconds = duration.duration_seconds();
+                contraction.duration = duration;
+                contraction.auto_closed = true;
+                contraction.updated_at = timestamp;
+                self.repository.upsert(&contraction)
+            }
+            LabourEvent::ContractionResumed(e) => {
+                let mut contraction =
+                    self.repository
+                        .get_by_id(e.contraction_id)
+                        .with_context(|| {
+                            format!("No contraction found with id: {}", e.contraction_id)
+                        })?;
+                let start_time = *contraction.duration.start_time();
+                let duration = Duration::create(start_time, start_time)?;
+                contraction.duration_seconds = duration.duration_seconds();
+                contraction.duration = duration;
+                contraction.intensity = None;
+                contraction.auto_closed = false;
+                contraction.updated_at = timestamp;
+                self.repository.upsert(&contraction)
+            }
             LabourEvent::ContractionDeleted(e) => self.repository.delete(e.contraction_id),
             LabourEvent::ContractionUpdated(e) => {
                 let mut contraction =
@@ -71,6 +109,9 @@ impl ContractionReadModelProjector {
                 contraction.duration_seconds = duration.duration_seconds();
                 contraction.duration = duration;
                 contraction.intensity = e.intensity;
+                if e.end_time.is_some() {
+                    contraction.auto_closed = false;
+                }
                 self.repository.upsert(&contraction)
             }
             _ => Ok(()),
@@ -84,6 +125,17 @@ impl SyncProjector<LabourEvent> for ContractionReadModelProjector {
         &self.name
     }
 
+    fn event_types(&self) -> Option<&'static [&'static str]> {
+        Some(&[
+            ContractionStarted::EVENT_TYPE,
+            ContractionEnded::EVENT_TYPE,
+            ContractionAbandoned::EVENT_TYPE,
+            ContractionResumed::EVENT_TYPE,
+            ContractionUpdated::EVENT_TYPE,
+            ContractionDeleted::EVENT_TYPE,
+        ])
+    }
+
     fn project_batch(&self, events: &[EventEnvelope<LabourEvent>]) -> Result<()> {
         if events.is_empty() {
             return Ok(());
@@ -93,4 +145,16 @@ impl SyncProjector<LabourEvent> for ContractionReadModelProjector {
             .iter()
             .try_for_each(|envelope| self.project_event(envelope))
     }
+
+    fn shadow(&self) -> Result<Box<dyn SyncProjector<LabourEvent>>> {
+        Ok(Box::new(Self::create(self.repository.shadow()?)))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        self.repository.promote_shadow()
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        self.repository.discard_shadow()
+    }
 }
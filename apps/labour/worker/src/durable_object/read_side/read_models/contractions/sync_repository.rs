@@ -1,5 +1,8 @@
 use anyhow::{Context, Result, anyhow};
 use fern_labour_event_sourcing_rs::{DecodedCursor, SyncRepositoryTrait};
+use fern_labour_workers_shared::sql::{
+    add_column_if_missing, drop_shadow_table, promote_shadow_table, shadow_table_name,
+};
 use uuid::Uuid;
 use worker::SqlStorage;
 
@@ -7,34 +10,53 @@ use super::read_model::{ContractionReadModel, ContractionRow};
 
 pub struct SqlContractionRepository {
     sql: SqlStorage,
+    table: String,
 }
 
 impl SqlContractionRepository {
+    const TABLE: &'static str = "contractions";
+
     pub fn create(sql: SqlStorage) -> Self {
-        Self { sql }
+        Self {
+            sql,
+            table: Self::TABLE.to_string(),
+        }
     }
 
     pub fn init_schema(&self) -> Result<()> {
         self.sql
             .exec(
-                "CREATE TABLE IF NOT EXISTS contractions (
-                    contraction_id TEXT PRIMARY KEY,
-                    labour_id TEXT NOT NULL,
-                    start_time TEXT NOT NULL,
-                    end_time TEXT NOT NULL,
-                    duration_seconds TEXT NOT NULL,
-                    intensity TEXT,
-                    created_at TEXT NOT NULL,
-                    updated_at TEXT NOT NULL
-                )",
+                &format!(
+                    "CREATE TABLE IF NOT EXISTS {table} (
+                        contraction_id TEXT PRIMARY KEY,
+                        labour_id TEXT NOT NULL,
+                        start_time TEXT NOT NULL,
+                        end_time TEXT NOT NULL,
+                        duration_seconds TEXT NOT NULL,
+                        intensity TEXT,
+                        auto_closed TEXT NOT NULL DEFAULT 'false',
+                        created_at TEXT NOT NULL,
+                        updated_at TEXT NOT NULL
+                    )",
+                    table = self.table
+                ),
                 None,
             )
             .map_err(|err| anyhow!("Failed to create contractions table: {err}"))?;
+        add_column_if_missing(
+            &self.sql,
+            &self.table,
+            "auto_closed",
+            "TEXT NOT NULL DEFAULT 'false'",
+        )?;
 
         self.sql
             .exec(
-                "CREATE INDEX IF NOT EXISTS idx_contractions_start_time
-                 ON contractions(start_time DESC)",
+                &format!(
+                    "CREATE INDEX IF NOT EXISTS idx_{table}_start_time
+                     ON {table}(start_time DESC)",
+                    table = self.table
+                ),
                 None,
             )
             .context("Failed to create start_time index")?;
@@ -45,7 +67,13 @@ impl SqlContractionRepository {
     pub fn get_all(&self) -> Result<Vec<ContractionReadModel>> {
         let rows: Vec<ContractionRow> = self
             .sql
-            .exec("SELECT * FROM contractions ORDER BY start_time ASC", None)
+            .exec(
+                &format!(
+                    "SELECT * FROM {table} ORDER BY start_time ASC",
+                    table = self.table
+                ),
+                None,
+            )
             .context("Failed to execute contractions query")?
             .to_array()
             .context("Failed to fetch contractions")?;
@@ -57,7 +85,10 @@ impl SqlContractionRepository {
         let rows: Vec<ContractionRow> = self
             .sql
             .exec(
-                "SELECT * FROM contractions WHERE labour_id = ?1 ORDER BY start_time ASC",
+                &format!(
+                    "SELECT * FROM {table} WHERE labour_id = ?1 ORDER BY start_time ASC",
+                    table = self.table
+                ),
                 Some(vec![labour_id.to_string().into()]),
             )
             .context("Failed to execute contractions query")?
@@ -73,7 +104,10 @@ impl SyncRepositoryTrait<ContractionReadModel> for SqlContractionRepository {
         let rows: Vec<ContractionRow> = self
             .sql
             .exec(
-                "SELECT * FROM contractions WHERE contraction_id = ?1",
+                &format!(
+                    "SELECT * FROM {table} WHERE contraction_id = ?1",
+                    table = self.table
+                ),
                 Some(vec![contraction_id.to_string().into()]),
             )
             .context("Failed to execute contraction query")?
@@ -91,7 +125,7 @@ impl SyncRepositoryTrait<ContractionReadModel> for SqlContractionRepository {
         limit: usize,
         cursor: Option<DecodedCursor>,
     ) -> Result<Vec<ContractionReadModel>> {
-        let mut query = "SELECT * FROM contractions".to_string();
+        let mut query = format!("SELECT * FROM {table}", table = self.table);
         let mut bindings = vec![];
 
         if let Some(cur) = cursor {
@@ -136,23 +170,28 @@ impl SyncRepositoryTrait<ContractionReadModel> for SqlContractionRepository {
             None => worker::SqlStorageValue::Null,
         });
 
+        bindings.push(row.auto_closed.into());
         bindings.push(row.created_at.into());
         bindings.push(row.updated_at.into());
 
         self.sql
             .exec(
-                "INSERT INTO contractions (
-                    contraction_id, labour_id, start_time, end_time, duration_seconds, intensity,
-                    created_at, updated_at
-                 )
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
-                 ON CONFLICT(contraction_id)
-                 DO UPDATE SET
-                    start_time = ?3,
-                    end_time = ?4,
-                    duration_seconds = ?5,
-                    intensity = ?6,
-                    updated_at = ?8",
+                &format!(
+                    "INSERT INTO {table} (
+                        contraction_id, labour_id, start_time, end_time, duration_seconds, intensity,
+                        auto_closed, created_at, updated_at
+                     )
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                     ON CONFLICT(contraction_id)
+                     DO UPDATE SET
+                        start_time = ?3,
+                        end_time = ?4,
+                        duration_seconds = ?5,
+                        intensity = ?6,
+                        auto_closed = ?7,
+                        updated_at = ?9",
+                    table = self.table
+                ),
                 Some(bindings),
             )
             .map_err(|err| anyhow!("Failed to upsert contraction: {err}"))?;
@@ -163,7 +202,10 @@ impl SyncRepositoryTrait<ContractionReadModel> for SqlContractionRepository {
     fn delete(&self, contraction_id: Uuid) -> Result<()> {
         self.sql
             .exec(
-                "DELETE FROM contractions WHERE contraction_id = ?1",
+                &format!(
+                    "DELETE FROM {table} WHERE contraction_id = ?1",
+                    table = self.table
+                ),
                 Some(vec![contraction_id.to_string().into()]),
             )
             .context("Failed to delete contraction")?;
@@ -188,20 +230,41 @@ impl SyncRepositoryTrait<ContractionReadModel> for SqlContractionRepository {
             None => worker::SqlStorageValue::Null,
         });
 
+        bindings.push(row.auto_closed.into());
         bindings.push(row.created_at.into());
         bindings.push(row.updated_at.into());
 
         self.sql
             .exec(
-                "INSERT OR REPLACE INTO contractions (
-                    contraction_id, labour_id, start_time, end_time, duration_seconds, intensity,
-                    created_at, updated_at
-                 )
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                &format!(
+                    "INSERT OR REPLACE INTO {table} (
+                        contraction_id, labour_id, start_time, end_time, duration_seconds, intensity,
+                        auto_closed, created_at, updated_at
+                     )
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                    table = self.table
+                ),
                 Some(bindings),
             )
             .context("Failed to overwrite contraction")?;
 
         Ok(())
     }
+
+    fn shadow(&self) -> Result<Box<dyn SyncRepositoryTrait<ContractionReadModel>>> {
+        let shadow = Self {
+            sql: self.sql.clone(),
+            table: shadow_table_name(&self.table),
+        };
+        shadow.init_schema()?;
+        Ok(Box::new(shadow))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        promote_shadow_table(&self.sql, &self.table)
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        drop_shadow_table(&self.sql, &self.table)
+    }
 }
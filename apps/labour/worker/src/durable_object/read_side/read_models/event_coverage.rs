@@ -0,0 +1,156 @@
+//! Checks that each projector's `event_types()` names every event its
+//! projection reacts to, since the processors only read the declared types.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use fern_labour_event_sourcing_rs::{
+    AsyncRepositoryTrait, Clock, Cursor, DecodedCursor, Event, EventEnvelope, EventMetadata,
+    InMemorySyncRepository, SyncProjector, testing::FixedClock,
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::durable_object::{
+    read_side::read_models::{
+        contractions::ContractionReadModelProjector, labour::LabourReadModelProjector,
+        labour_updates::LabourUpdateReadModelProjector, milestones::MilestoneReadModelProjector,
+        scheduled_labour_updates::ScheduledLabourUpdateReadModelProjector,
+        subscription_token::SubscriptionTokenProjector,
+        subscriptions::SubscriptionReadModelProjector,
+    },
+    write_side::domain::{
+        LabourEvent,
+        events::fixtures::{LABOUR_ID, one_of_each},
+    },
+};
+
+pub fn envelope(sequence: i64, event: LabourEvent) -> EventEnvelope<LabourEvent> {
+    EventEnvelope {
+        metadata: EventMetadata {
+            aggregate_id: LABOUR_ID,
+            sequence,
+            event_version: 1,
+            timestamp: FixedClock::default().now(),
+            user_id: "mother".to_string(),
+            correlation_id: None,
+            causation_id: None,
+        },
+        event,
+    }
+}
+
+/// Envelopes for [`one_of_each`], numbered from sequence 1.
+pub fn every_event() -> Vec<EventEnvelope<LabourEvent>> {
+    one_of_each()
+        .into_iter()
+        .zip(1..)
+        .map(|(event, sequence)| envelope(sequence, event))
+        .collect()
+}
+
+/// Stands in for D1 when only an async projector's pure fold is exercised.
+pub struct UnusedAsyncRepository;
+
+#[async_trait(?Send)]
+impl<T> AsyncRepositoryTrait<T> for UnusedAsyncRepository {
+    async fn get_by_id(&self, _id: Uuid) -> Result<T> {
+        unreachable!("the projection fold does not read the repository")
+    }
+
+    async fn get(&self, _limit: usize, _cursor: Option<DecodedCursor>) -> Result<Vec<T>> {
+        unreachable!("the projection fold does not read the repository")
+    }
+
+    async fn upsert(&self, _value: &T) -> Result<()> {
+        unreachable!("the projection fold does not write the repository")
+    }
+
+    async fn delete(&self, _id: Uuid) -> Result<()> {
+        unreachable!("the projection fold does not write the repository")
+    }
+
+    async fn overwrite(&self, _value: &T) -> Result<()> {
+        unreachable!("the projection fold does not write the repository")
+    }
+}
+
+/// Projects every event in turn, checking that the ones left out of
+/// `event_types()` succeed without changing the read models.
+fn assert_undeclared_events_are_ignored<T>(
+    projector: &dyn SyncProjector<LabourEvent>,
+    repository: &InMemorySyncRepository<T>,
+) where
+    T: Cursor + Clone + Serialize + 'static,
+{
+    let declared = projector.event_types().unwrap_or_default();
+    let snapshot = || serde_json::to_value(repository.values()).unwrap();
+
+    for envelope in every_event() {
+        let event_type = envelope.event.event_type().to_string();
+        let before = snapshot();
+        let result = projector.project_batch(std::slice::from_ref(&envelope));
+
+        if !declared.contains(&event_type.as_str()) {
+            assert!(
+                result.is_ok(),
+                "{} failed on undeclared {event_type}",
+                projector.name()
+            );
+            assert_eq!(
+                before,
+                snapshot(),
+                "{} projects {event_type} but does not declare it",
+                projector.name()
+            );
+        }
+    }
+}
+
+#[test]
+fn contraction_projector_declares_its_events() {
+    let repository = InMemorySyncRepository::new();
+    let projector = ContractionReadModelProjector::create(Box::new(repository.clone()));
+    assert_undeclared_events_are_ignored(&projector, &repository);
+}
+
+#[test]
+fn labour_projector_declares_its_events() {
+    let repository = InMemorySyncRepository::new();
+    let projector = LabourReadModelProjector::create(Box::new(repository.clone()));
+    assert_undeclared_events_are_ignored(&projector, &repository);
+}
+
+#[test]
+fn labour_update_projector_declares_its_events() {
+    let repository = InMemorySyncRepository::new();
+    let projector = LabourUpdateReadModelProjector::create(Box::new(repository.clone()));
+    assert_undeclared_events_are_ignored(&projector, &repository);
+}
+
+#[test]
+fn milestone_projector_declares_its_events() {
+    let repository = InMemorySyncRepository::new();
+    let projector = MilestoneReadModelProjector::create(Box::new(repository.clone()));
+    assert_undeclared_events_are_ignored(&projector, &repository);
+}
+
+#[test]
+fn scheduled_labour_update_projector_declares_its_events() {
+    let repository = InMemorySyncRepository::new();
+    let projector = ScheduledLabourUpdateReadModelProjector::create(Box::new(repository.clone()));
+    assert_undeclared_events_are_ignored(&projector, &repository);
+}
+
+#[test]
+fn subscription_token_projector_declares_its_events() {
+    let repository = InMemorySyncRepository::new();
+    let projector = SubscriptionTokenProjector::create(Box::new(repository.clone()));
+    assert_undeclared_events_are_ignored(&projector, &repository);
+}
+
+#[test]
+fn subscription_projector_declares_its_events() {
+    let repository = InMemorySyncRepository::new();
+    let projector = SubscriptionReadModelProjector::create(Box::new(repository.clone()));
+    assert_undeclared_events_are_ignored(&projector, &repository);
+}
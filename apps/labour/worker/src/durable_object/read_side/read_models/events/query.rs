@@ -1,17 +1,24 @@
 use std::rc::Rc;
 
 use anyhow::{Context, Result};
-use fern_labour_event_sourcing_rs::{AggregateRepositoryTrait, EventEnvelope};
+use fern_labour_event_sourcing_rs::{
+    AggregateRepositoryTrait, EventEnvelope, EventStoreTrait, StreamVerification,
+};
 
 use crate::durable_object::write_side::domain::{Labour, LabourEvent};
 
 pub struct EventQuery {
+    event_store: Rc<dyn EventStoreTrait>,
     aggregate_repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
 }
 
 impl EventQuery {
-    pub fn new(aggregate_repository: Rc<dyn AggregateRepositoryTrait<Labour>>) -> Self {
+    pub fn new(
+        event_store: Rc<dyn EventStoreTrait>,
+        aggregate_repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
+    ) -> Self {
         Self {
+            event_store,
             aggregate_repository,
         }
     }
@@ -24,4 +31,10 @@ impl EventQuery {
 
         Ok(events)
     }
+
+    pub fn verify_event_stream(&self) -> Result<StreamVerification> {
+        self.event_store
+            .verify_chain()
+            .context("Failed to verify event stream")
+    }
 }
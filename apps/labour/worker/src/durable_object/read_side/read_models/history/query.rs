@@ -0,0 +1,121 @@
+use std::rc::Rc;
+
+use anyhow::{Context, Result};
+use fern_labour_event_sourcing_rs::{
+    AggregateRepositoryTrait, EventEnvelope, EventEnvelopeAdapter, EventStoreTrait,
+    InMemorySyncRepository, SyncProjector,
+};
+use fern_labour_labour_shared::AdminQuery;
+use serde::Serialize;
+
+use crate::durable_object::{
+    read_side::read_models::{
+        contractions::{ContractionReadModel, ContractionReadModelProjector},
+        labour::{LabourReadModel, LabourReadModelProjector},
+        labour_updates::{LabourUpdateReadModel, LabourUpdateReadModelProjector},
+        milestones::{MilestoneReadModel, MilestoneReadModelProjector},
+        scheduled_labour_updates::{
+            ScheduledLabourUpdateReadModel, ScheduledLabourUpdateReadModelProjector,
+        },
+        subscriptions::{SubscriptionReadModel, SubscriptionReadModelProjector},
+    },
+    write_side::domain::{Labour, LabourEvent},
+};
+
+/// The labour aggregate and its read models as they stood at `sequence`.
+#[derive(Debug, Serialize)]
+pub struct LabourHistoryView {
+    pub sequence: i64,
+    pub labour: Option<Labour>,
+    pub labour_read_model: Option<LabourReadModel>,
+    pub contractions: Vec<ContractionReadModel>,
+    pub labour_updates: Vec<LabourUpdateReadModel>,
+    pub milestones: Vec<MilestoneReadModel>,
+    pub scheduled_labour_updates: Vec<ScheduledLabourUpdateReadModel>,
+    pub subscriptions: Vec<SubscriptionReadModel>,
+}
+
+/// Answers admin queries about past states of the labour by replaying the
+/// event stream up to a bound. Read models are rebuilt in memory through the
+/// same projectors that maintain the persisted ones, which are left untouched.
+pub struct HistoryQuery {
+    event_store: Rc<dyn EventStoreTrait>,
+    aggregate_repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
+}
+
+impl HistoryQuery {
+    pub fn new(
+        event_store: Rc<dyn EventStoreTrait>,
+        aggregate_repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
+    ) -> Self {
+        Self {
+            event_store,
+            aggregate_repository,
+        }
+    }
+
+    pub fn handle(&self, query: AdminQuery) -> Result<LabourHistoryView> {
+        let (labour, sequence) = match query {
+            AdminQuery::GetLabourAtSequence { sequence, .. } => {
+                self.aggregate_repository.load_at_sequence(sequence)
+            }
+            AdminQuery::GetLabourAtTime { timestamp, .. } => {
+                self.aggregate_repository.load_at_time(timestamp)
+            }
+        }
+        .context("Failed to rebuild historical labour")?;
+
+        let events: Vec<EventEnvelope<LabourEvent>> = self
+            .event_store
+            .events_until_sequence(sequence)
+            .context("Failed to load historical events")?
+            .iter()
+            .map(|row| row.to_envelope())
+            .collect::<Result<_>>()?;
+
+        let labour_repository = InMemorySyncRepository::new();
+        let contraction_repository = InMemorySyncRepository::new();
+        let labour_update_repository = InMemorySyncRepository::new();
+        let milestone_repository = InMemorySyncRepository::new();
+        let scheduled_labour_update_repository = InMemorySyncRepository::new();
+        let subscription_repository = InMemorySyncRepository::new();
+
+        let projectors: Vec<Box<dyn SyncProjector<LabourEvent>>> = vec![
+            Box::new(LabourReadModelProjector::create(Box::new(
+                labour_repository.clone(),
+            ))),
+            Box::new(ContractionReadModelProjector::create(Box::new(
+                contraction_repository.clone(),
+            ))),
+            Box::new(LabourUpdateReadModelProjector::create(Box::new(
+                labour_update_repository.clone(),
+            ))),
+            Box::new(MilestoneReadModelProjector::create(Box::new(
+                milestone_repository.clone(),
+            ))),
+            Box::new(ScheduledLabourUpdateReadModelProjector::create(Box::new(
+                scheduled_labour_update_repository.clone(),
+            ))),
+            Box::new(SubscriptionReadModelProjector::create(Box::new(
+                subscription_repository.clone(),
+            ))),
+        ];
+
+        for projector in &projectors {
+            projector
+                .project_batch(&events)
+                .with_context(|| format!("Failed to replay {}", projector.name()))?;
+        }
+
+        Ok(LabourHistoryView {
+            sequence,
+            labour,
+            labour_read_model: labour_repository.values().into_iter().next(),
+            contractions: contraction_repository.values(),
+            labour_updates: labour_update_repository.values(),
+            milestones: milestone_repository.values(),
+            scheduled_labour_updates: scheduled_labour_update_repository.values(),
+            subscriptions: subscription_repository.values(),
+        })
+    }
+}
@@ -1,9 +1,14 @@
 use anyhow::{Result, anyhow};
 use fern_labour_labour_shared::value_objects::LabourPhase;
 
-use fern_labour_event_sourcing_rs::{EventEnvelope, SyncProjector, SyncRepositoryTrait};
+use fern_labour_event_sourcing_rs::{
+    EventDescriptor, EventEnvelope, SyncProjector, SyncRepositoryTrait,
+};
 use uuid::Uuid;
 
+use crate::durable_object::write_side::domain::events::{
+    LabourBegun, LabourCompleted, LabourPlanUpdated, LabourPlanned,
+};
 use crate::durable_object::{
     read_side::read_models::labour::read_model::LabourReadModel, write_side::domain::LabourEvent,
 };
@@ -14,9 +19,11 @@ pub struct LabourReadModelProjector {
 }
 
 impl LabourReadModelProjector {
+    pub const NAME: &'static str = "LabourReadModelProjector";
+
     pub fn create(repository: Box<dyn SyncRepositoryTrait<LabourReadModel>>) -> Self {
         Self {
-            name: "LabourReadModelProjector".to_string(),
+            name: Self::NAME.to_string(),
             repository,
         }
     }
@@ -92,6 +99,15 @@ impl SyncProjector<LabourEvent> for LabourReadModelProjector {
         &self.name
     }
 
+    fn event_types(&self) -> Option<&'static [&'static str]> {
+        Some(&[
+            LabourPlanned::EVENT_TYPE,
+            LabourPlanUpdated::EVENT_TYPE,
+            LabourBegun::EVENT_TYPE,
+            LabourCompleted::EVENT_TYPE,
+        ])
+    }
+
     fn project_batch(&self, events: &[EventEnvelope<LabourEvent>]) -> Result<()> {
         if events.is_empty() {
             return Ok(());
@@ -109,4 +125,16 @@ impl SyncProjector<LabourEvent> for LabourReadModelProjector {
 
         Ok(())
     }
+
+    fn shadow(&self) -> Result<Box<dyn SyncProjector<LabourEvent>>> {
+        Ok(Box::new(Self::create(self.repository.shadow()?)))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        self.repository.promote_shadow()
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        self.repository.discard_shadow()
+    }
 }
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result, anyhow};
 use fern_labour_event_sourcing_rs::{DecodedCursor, SyncRepositoryTrait};
+use fern_labour_workers_shared::sql::{drop_shadow_table, promote_shadow_table, shadow_table_name};
 use uuid::Uuid;
 use worker::SqlStorage;
 
@@ -7,30 +8,39 @@ use super::read_model::{LabourReadModel, LabourRow};
 
 pub struct SqlLabourRepository {
     sql: SqlStorage,
+    table: String,
 }
 
 impl SqlLabourRepository {
+    const TABLE: &'static str = "labours";
+
     pub fn create(sql: SqlStorage) -> Self {
-        Self { sql }
+        Self {
+            sql,
+            table: Self::TABLE.to_string(),
+        }
     }
 
     pub fn init_schema(&self) -> Result<()> {
         self.sql
             .exec(
-                "CREATE TABLE IF NOT EXISTS labours (
-                    labour_id TEXT PRIMARY KEY,
-                    mother_id TEXT NOT NULL,
-                    mother_name TEXT NOT NULL,
-                    current_phase TEXT NOT NULL,
-                    first_labour TEXT NOT NULL,
-                    due_date TEXT NOT NULL,
-                    labour_name TEXT,
-                    start_time TEXT,
-                    end_time TEXT,
-                    notes TEXT,
-                    created_at TEXT NOT NULL,
-                    updated_at TEXT NOT NULL
-                )",
+                &format!(
+                    "CREATE TABLE IF NOT EXISTS {table} (
+                        labour_id TEXT PRIMARY KEY,
+                        mother_id TEXT NOT NULL,
+                        mother_name TEXT NOT NULL,
+                        current_phase TEXT NOT NULL,
+                        first_labour TEXT NOT NULL,
+                        due_date TEXT NOT NULL,
+                        labour_name TEXT,
+                        start_time TEXT,
+                        end_time TEXT,
+                        notes TEXT,
+                        created_at TEXT NOT NULL,
+                        updated_at TEXT NOT NULL
+                    )",
+                    table = self.table
+                ),
                 None,
             )
             .map_err(|err| anyhow!("Failed to create labours table: {err}"))?;
@@ -44,7 +54,7 @@ impl SyncRepositoryTrait<LabourReadModel> for SqlLabourRepository {
         let rows: Vec<LabourRow> = self
             .sql
             .exec(
-                "SELECT * FROM labours LIMIT ?1",
+                &format!("SELECT * FROM {table} LIMIT ?1", table = self.table),
                 Some(vec![(limit as i32).into()]),
             )
             .context("Failed to execute labours query")?
@@ -58,7 +68,10 @@ impl SyncRepositoryTrait<LabourReadModel> for SqlLabourRepository {
         let row: LabourRow = self
             .sql
             .exec(
-                "SELECT * FROM labours WHERE labour_id=?1",
+                &format!(
+                    "SELECT * FROM {table} WHERE labour_id=?1",
+                    table = self.table
+                ),
                 Some(vec![id.to_string().into()]),
             )
             .context("Failed to execute labour query")?
@@ -71,7 +84,7 @@ impl SyncRepositoryTrait<LabourReadModel> for SqlLabourRepository {
     fn delete(&self, id: Uuid) -> Result<()> {
         self.sql
             .exec(
-                "DELETE FROM labours WHERE labour_id=?1",
+                &format!("DELETE FROM {table} WHERE labour_id=?1", table = self.table),
                 Some(vec![id.to_string().into()]),
             )
             .context("Failed to delete labour")?;
@@ -116,22 +129,25 @@ impl SyncRepositoryTrait<LabourReadModel> for SqlLabourRepository {
 
         self.sql
             .exec(
-                "INSERT INTO labours (
-                    labour_id, mother_id, mother_name, current_phase, first_labour,
-                    due_date, labour_name, start_time, end_time, notes,
-                    created_at, updated_at
-                 )
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
-                 ON CONFLICT(labour_id)
-                 DO UPDATE SET
-                    current_phase = ?4,
-                    first_labour = ?5,
-                    due_date = ?6,
-                    labour_name = ?7,
-                    start_time = ?8,
-                    end_time = ?9,
-                    notes = ?10,
-                    updated_at = ?12",
+                &format!(
+                    "INSERT INTO {table} (
+                        labour_id, mother_id, mother_name, current_phase, first_labour,
+                        due_date, labour_name, start_time, end_time, notes,
+                        created_at, updated_at
+                     )
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+                     ON CONFLICT(labour_id)
+                     DO UPDATE SET
+                        current_phase = ?4,
+                        first_labour = ?5,
+                        due_date = ?6,
+                        labour_name = ?7,
+                        start_time = ?8,
+                        end_time = ?9,
+                        notes = ?10,
+                        updated_at = ?12",
+                    table = self.table
+                ),
                 Some(bindings),
             )
             .context("Failed to upsert labour")?;
@@ -176,16 +192,36 @@ impl SyncRepositoryTrait<LabourReadModel> for SqlLabourRepository {
 
         self.sql
             .exec(
-                "INSERT OR REPLACE INTO labours (
-                    labour_id, mother_id, mother_name, current_phase, first_labour,
-                    due_date, labour_name, start_time, end_time, notes,
-                    created_at, updated_at
-                 )
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                &format!(
+                    "INSERT OR REPLACE INTO {table} (
+                        labour_id, mother_id, mother_name, current_phase, first_labour,
+                        due_date, labour_name, start_time, end_time, notes,
+                        created_at, updated_at
+                     )
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                    table = self.table
+                ),
                 Some(bindings),
             )
             .context("Failed to overwrite labour")?;
 
         Ok(())
     }
+
+    fn shadow(&self) -> Result<Box<dyn SyncRepositoryTrait<LabourReadModel>>> {
+        let shadow = Self {
+            sql: self.sql.clone(),
+            table: shadow_table_name(&self.table),
+        };
+        shadow.init_schema()?;
+        Ok(Box::new(shadow))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        promote_shadow_table(&self.sql, &self.table)
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        drop_shadow_table(&self.sql, &self.table)
+    }
 }
@@ -5,10 +5,13 @@ use async_trait::async_trait;
 use tracing::{debug, info, warn};
 
 use fern_labour_event_sourcing_rs::{
-    AsyncRepositoryTrait, CacheExt, CacheTrait, CachedReadModelState, EventEnvelope,
-    IncrementalAsyncProjector,
+    AsyncRepositoryTrait, CacheExt, CacheTrait, CachedReadModelState, EventDescriptor,
+    EventEnvelope, IncrementalAsyncProjector, shadow_cache_key,
 };
 
+use crate::durable_object::write_side::domain::events::{
+    LabourDeleted, LabourPhaseChanged, LabourPlanUpdated, LabourPlanned,
+};
 use crate::durable_object::{
     read_side::read_models::labour_status::read_model::LabourStatusReadModel,
     write_side::domain::LabourEvent,
@@ -21,10 +24,12 @@ pub struct LabourStatusReadModelProjector {
 }
 
 impl LabourStatusReadModelProjector {
+    pub const NAME: &'static str = "LabourStatusReadModelProjector";
+
     pub fn create(repository: Box<dyn AsyncRepositoryTrait<LabourStatusReadModel>>) -> Self {
         Self {
-            name: "LabourStatusReadModelProjector".to_string(),
-            cache_key: "read_model_cache:LabourStatusReadModelProjector".to_string(),
+            name: Self::NAME.to_string(),
+            cache_key: format!("read_model_cache:{}", Self::NAME),
             repository,
         }
     }
@@ -64,6 +69,43 @@ impl LabourStatusReadModelProjector {
             _ => model,
         }
     }
+
+    fn load_state(
+        &self,
+        cache: &Rc<dyn CacheTrait>,
+        key: &str,
+    ) -> CachedReadModelState<LabourStatusReadModel> {
+        cache
+            .get(key.to_string())
+            .ok()
+            .flatten()
+            .unwrap_or_else(CachedReadModelState::empty)
+    }
+
+    async fn persist_change(
+        &self,
+        before: &Option<LabourStatusReadModel>,
+        after: &Option<LabourStatusReadModel>,
+    ) -> Result<()> {
+        match (before, after) {
+            (Some(old_model), None) => {
+                info!(projector = %self.name, "Model deleted, removing from D1");
+                self.repository
+                    .delete(old_model.labour_id)
+                    .await
+                    .map_err(|e| anyhow!("Failed to delete: {e}"))?;
+            }
+            (_, Some(new_model)) => {
+                info!(projector = %self.name, "Model changed, persisting to D1");
+                self.repository
+                    .overwrite(new_model)
+                    .await
+                    .map_err(|e| anyhow!("Failed to persist: {e}"))?;
+            }
+            (None, None) => {}
+        }
+        Ok(())
+    }
 }
 
 #[async_trait(?Send)]
@@ -72,13 +114,17 @@ impl IncrementalAsyncProjector<LabourEvent> for LabourStatusReadModelProjector {
         &self.name
     }
 
+    fn event_types(&self) -> Option<&'static [&'static str]> {
+        Some(&[
+            LabourPlanned::EVENT_TYPE,
+            LabourPlanUpdated::EVENT_TYPE,
+            LabourPhaseChanged::EVENT_TYPE,
+            LabourDeleted::EVENT_TYPE,
+        ])
+    }
+
     fn get_cached_sequence(&self, cache: &Rc<dyn CacheTrait>) -> i64 {
-        cache
-            .get::<CachedReadModelState<LabourStatusReadModel>>(self.cache_key.clone())
-            .ok()
-            .flatten()
-            .map(|s| s.sequence)
-            .unwrap_or(0)
+        self.load_state(cache, &self.cache_key).sequence
     }
 
     async fn process(
@@ -87,11 +133,7 @@ impl IncrementalAsyncProjector<LabourEvent> for LabourStatusReadModelProjector {
         events: &[EventEnvelope<LabourEvent>],
         max_sequence: i64,
     ) -> Result<()> {
-        let cached_state: CachedReadModelState<LabourStatusReadModel> = cache
-            .get(self.cache_key.clone())
-            .ok()
-            .flatten()
-            .unwrap_or_else(CachedReadModelState::empty);
+        let cached_state = self.load_state(cache, &self.cache_key);
 
         if events.is_empty() {
             debug!(projector = %self.name, "No new events to process");
@@ -106,23 +148,7 @@ impl IncrementalAsyncProjector<LabourEvent> for LabourStatusReadModelProjector {
         }
 
         if before != current_model {
-            match (&before, &current_model) {
-                (Some(old_model), None) => {
-                    info!(projector = %self.name, "Model deleted, removing from D1");
-                    self.repository
-                        .delete(old_model.labour_id)
-                        .await
-                        .map_err(|e| anyhow!("Failed to delete: {e}"))?;
-                }
-                (_, Some(new_model)) => {
-                    info!(projector = %self.name, "Model changed, persisting to D1");
-                    self.repository
-                        .overwrite(new_model)
-                        .await
-                        .map_err(|e| anyhow!("Failed to persist: {e}"))?;
-                }
-                (None, None) => {}
-            }
+            self.persist_change(&before, &current_model).await?;
         } else {
             debug!(projector = %self.name, "Model unchanged, skipping D1 write");
         }
@@ -134,4 +160,85 @@ impl IncrementalAsyncProjector<LabourEvent> for LabourStatusReadModelProjector {
 
         Ok(())
     }
+
+    fn skip_to(&self, cache: &Rc<dyn CacheTrait>, sequence: i64) -> Result<()> {
+        let state = self.load_state(cache, &self.cache_key);
+        if state.sequence >= sequence {
+            return Ok(());
+        }
+        cache
+            .set(
+                self.cache_key.clone(),
+                &CachedReadModelState::new(sequence, state.model),
+            )
+            .map_err(|e| anyhow!("Failed to update cache: {e}"))
+    }
+
+    async fn process_shadow(
+        &self,
+        cache: &Rc<dyn CacheTrait>,
+        events: &[EventEnvelope<LabourEvent>],
+        max_sequence: i64,
+    ) -> Result<()> {
+        let shadow_key = shadow_cache_key(&self.cache_key);
+        let mut model = self.load_state(cache, &shadow_key).model;
+
+        for envelope in events {
+            model = self.project_event(model, envelope);
+        }
+
+        cache
+            .set(shadow_key, &CachedReadModelState::new(max_sequence, model))
+            .map_err(|e| anyhow!("Failed to update shadow cache: {e}"))
+    }
+
+    async fn promote_shadow(&self, cache: &Rc<dyn CacheTrait>) -> Result<()> {
+        let shadow_key = shadow_cache_key(&self.cache_key);
+        let shadow = self.load_state(cache, &shadow_key);
+        let live = self.load_state(cache, &self.cache_key);
+
+        if live.model != shadow.model {
+            self.persist_change(&live.model, &shadow.model).await?;
+        }
+
+        cache
+            .set(self.cache_key.clone(), &shadow)
+            .map_err(|e| anyhow!("Failed to update cache: {e}"))?;
+        self.discard_shadow(cache)
+    }
+
+    fn discard_shadow(&self, cache: &Rc<dyn CacheTrait>) -> Result<()> {
+        cache
+            .clear(shadow_cache_key(&self.cache_key))
+            .map_err(|e| anyhow!("Failed to clear shadow cache: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fern_labour_event_sourcing_rs::Event;
+
+    use super::*;
+    use crate::durable_object::read_side::read_models::event_coverage::{
+        UnusedAsyncRepository, every_event,
+    };
+
+    #[test]
+    fn undeclared_events_leave_the_model_unchanged() {
+        let projector = LabourStatusReadModelProjector::create(Box::new(UnusedAsyncRepository));
+        let declared = projector.event_types().unwrap();
+        let mut model = None;
+
+        for envelope in every_event() {
+            let event_type = envelope.event.event_type();
+            let projected = projector.project_event(model.clone(), &envelope);
+            if !declared.contains(&event_type) {
+                assert_eq!(
+                    projected, model,
+                    "{event_type} is projected but not declared"
+                );
+            }
+            model = projected;
+        }
+    }
 }
@@ -1,8 +1,13 @@
 use anyhow::Result;
 use async_trait::async_trait;
 
-use fern_labour_event_sourcing_rs::{EventEnvelope, SyncProjector, SyncRepositoryTrait};
+use fern_labour_event_sourcing_rs::{
+    EventDescriptor, EventEnvelope, SyncProjector, SyncRepositoryTrait,
+};
 
+use crate::durable_object::write_side::domain::events::{
+    LabourUpdateDeleted, LabourUpdateMessageUpdated, LabourUpdatePosted, LabourUpdateTypeUpdated,
+};
 use crate::durable_object::{
     read_side::read_models::labour_updates::LabourUpdateReadModel, write_side::domain::LabourEvent,
 };
@@ -13,9 +18,11 @@ pub struct LabourUpdateReadModelProjector {
 }
 
 impl LabourUpdateReadModelProjector {
+    pub const NAME: &'static str = "LabourUpdateReadModelProjector";
+
     pub fn create(repository: Box<dyn SyncRepositoryTrait<LabourUpdateReadModel>>) -> Self {
         Self {
-            name: "LabourUpdateReadModelProjector".to_string(),
+            name: Self::NAME.to_string(),
             repository,
         }
     }
@@ -72,6 +79,15 @@ impl SyncProjector<LabourEvent> for LabourUpdateReadModelProjector {
         &self.name
     }
 
+    fn event_types(&self) -> Option<&'static [&'static str]> {
+        Some(&[
+            LabourUpdatePosted::EVENT_TYPE,
+            LabourUpdateMessageUpdated::EVENT_TYPE,
+            LabourUpdateTypeUpdated::EVENT_TYPE,
+            LabourUpdateDeleted::EVENT_TYPE,
+        ])
+    }
+
     fn project_batch(&self, events: &[EventEnvelope<LabourEvent>]) -> Result<()> {
         if events.is_empty() {
             return Ok(());
@@ -81,4 +97,16 @@ impl SyncProjector<LabourEvent> for LabourUpdateReadModelProjector {
             .iter()
             .try_for_each(|envelope| self.project_event(envelope))
     }
+
+    fn shadow(&self) -> Result<Box<dyn SyncProjector<LabourEvent>>> {
+        Ok(Box::new(Self::create(self.repository.shadow()?)))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        self.repository.promote_shadow()
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        self.repository.discard_shadow()
+    }
 }
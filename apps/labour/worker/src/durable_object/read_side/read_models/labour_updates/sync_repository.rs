@@ -1,5 +1,6 @@
 use anyhow::{Context, Result, anyhow};
 use fern_labour_event_sourcing_rs::{DecodedCursor, SyncRepositoryTrait};
+use fern_labour_workers_shared::sql::{drop_shadow_table, promote_shadow_table, shadow_table_name};
 use uuid::Uuid;
 use worker::SqlStorage;
 
@@ -7,34 +8,46 @@ use super::read_model::{LabourUpdateReadModel, LabourUpdateRow};
 
 pub struct SqlLabourUpdateRepository {
     sql: SqlStorage,
+    table: String,
 }
 
 impl SqlLabourUpdateRepository {
+    const TABLE: &'static str = "labour_updates";
+
     pub fn create(sql: SqlStorage) -> Self {
-        Self { sql }
+        Self {
+            sql,
+            table: Self::TABLE.to_string(),
+        }
     }
 
     pub fn init_schema(&self) -> Result<()> {
         self.sql
             .exec(
-                "CREATE TABLE IF NOT EXISTS labour_updates (
-                    labour_update_id TEXT PRIMARY KEY,
-                    labour_id TEXT NOT NULL,
-                    labour_update_type TEXT NOT NULL,
-                    message TEXT NOT NULL,
-                    edited TEXT NOT NULL,
-                    application_generated TEXT NOT NULL,
-                    created_at TEXT NOT NULL,
-                    updated_at TEXT NOT NULL
-                )",
+                &format!(
+                    "CREATE TABLE IF NOT EXISTS {table} (
+                        labour_update_id TEXT PRIMARY KEY,
+                        labour_id TEXT NOT NULL,
+                        labour_update_type TEXT NOT NULL,
+                        message TEXT NOT NULL,
+                        edited TEXT NOT NULL,
+                        application_generated TEXT NOT NULL,
+                        created_at TEXT NOT NULL,
+                        updated_at TEXT NOT NULL
+                    )",
+                    table = self.table
+                ),
                 None,
             )
             .map_err(|err| anyhow!("Failed to create labour_updates table: {err}"))?;
 
         self.sql
             .exec(
-                "CREATE INDEX IF NOT EXISTS idx_labour_updates_created_at
-                 ON labour_updates(created_at DESC)",
+                &format!(
+                    "CREATE INDEX IF NOT EXISTS idx_{table}_created_at
+                     ON {table}(created_at DESC)",
+                    table = self.table
+                ),
                 None,
             )
             .context("Failed to create created_at index")?;
@@ -45,7 +58,13 @@ impl SqlLabourUpdateRepository {
     pub fn get_all(&self) -> Result<Vec<LabourUpdateReadModel>> {
         let rows: Vec<LabourUpdateRow> = self
             .sql
-            .exec("SELECT * FROM labour_updates ORDER BY created_at ASC", None)
+            .exec(
+                &format!(
+                    "SELECT * FROM {table} ORDER BY created_at ASC",
+                    table = self.table
+                ),
+                None,
+            )
             .context("Failed to execute labour_updates query")?
             .to_array()
             .context("Failed to fetch labour_updates")?;
@@ -57,7 +76,10 @@ impl SqlLabourUpdateRepository {
         let rows: Vec<LabourUpdateRow> = self
             .sql
             .exec(
-                "SELECT * FROM labour_updates WHERE labour_id = ?1 ORDER BY created_at ASC",
+                &format!(
+                    "SELECT * FROM {table} WHERE labour_id = ?1 ORDER BY created_at ASC",
+                    table = self.table
+                ),
                 Some(vec![labour_id.to_string().into()]),
             )
             .context("Failed to execute labour_updates query")?
@@ -73,7 +95,10 @@ impl SyncRepositoryTrait<LabourUpdateReadModel> for SqlLabourUpdateRepository {
         let rows: Vec<LabourUpdateRow> = self
             .sql
             .exec(
-                "SELECT * FROM labour_updates WHERE labour_update_id = ?1",
+                &format!(
+                    "SELECT * FROM {table} WHERE labour_update_id = ?1",
+                    table = self.table
+                ),
                 Some(vec![labour_update_id.to_string().into()]),
             )
             .context("Failed to execute labour_update query")?
@@ -91,7 +116,7 @@ impl SyncRepositoryTrait<LabourUpdateReadModel> for SqlLabourUpdateRepository {
         limit: usize,
         cursor: Option<DecodedCursor>,
     ) -> Result<Vec<LabourUpdateReadModel>> {
-        let mut query = "SELECT * FROM labour_updates".to_string();
+        let mut query = format!("SELECT * FROM {table}", table = self.table);
         let mut bindings = vec![];
 
         if let Some(cur) = cursor {
@@ -136,18 +161,21 @@ impl SyncRepositoryTrait<LabourUpdateReadModel> for SqlLabourUpdateRepository {
 
         self.sql
             .exec(
-                "INSERT INTO labour_updates (
-                    labour_update_id, labour_id, labour_update_type, message,
-                    edited, application_generated, created_at, updated_at
-                 )
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
-                 ON CONFLICT(labour_update_id)
-                 DO UPDATE SET
-                    labour_update_type = ?3,
-                    message = ?4,
-                    edited = ?5,
-                    application_generated = ?6,
-                    updated_at = ?8",
+                &format!(
+                    "INSERT INTO {table} (
+                        labour_update_id, labour_id, labour_update_type, message,
+                        edited, application_generated, created_at, updated_at
+                     )
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                     ON CONFLICT(labour_update_id)
+                     DO UPDATE SET
+                        labour_update_type = ?3,
+                        message = ?4,
+                        edited = ?5,
+                        application_generated = ?6,
+                        updated_at = ?8",
+                    table = self.table
+                ),
                 Some(bindings),
             )
             .context("Failed to upsert labour_update")?;
@@ -158,7 +186,10 @@ impl SyncRepositoryTrait<LabourUpdateReadModel> for SqlLabourUpdateRepository {
     fn delete(&self, labour_update_id: Uuid) -> Result<()> {
         self.sql
             .exec(
-                "DELETE FROM labour_updates WHERE labour_update_id = ?1",
+                &format!(
+                    "DELETE FROM {table} WHERE labour_update_id = ?1",
+                    table = self.table
+                ),
                 Some(vec![labour_update_id.to_string().into()]),
             )
             .context("Failed to delete labour_update")?;
@@ -183,15 +214,35 @@ impl SyncRepositoryTrait<LabourUpdateReadModel> for SqlLabourUpdateRepository {
 
         self.sql
             .exec(
-                "INSERT OR REPLACE INTO labour_updates (
-                    labour_update_id, labour_id, labour_update_type, message,
-                    edited, application_generated, created_at, updated_at
-                 )
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                &format!(
+                    "INSERT OR REPLACE INTO {table} (
+                        labour_update_id, labour_id, labour_update_type, message,
+                        edited, application_generated, created_at, updated_at
+                     )
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                    table = self.table
+                ),
                 Some(bindings),
             )
             .context("Failed to overwrite labour_update")?;
 
         Ok(())
     }
+
+    fn shadow(&self) -> Result<Box<dyn SyncRepositoryTrait<LabourUpdateReadModel>>> {
+        let shadow = Self {
+            sql: self.sql.clone(),
+            table: shadow_table_name(&self.table),
+        };
+        shadow.init_schema()?;
+        Ok(Box::new(shadow))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        promote_shadow_table(&self.sql, &self.table)
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        drop_shadow_table(&self.sql, &self.table)
+    }
 }
@@ -0,0 +1,9 @@
+pub mod query;
+pub mod read_model;
+pub mod sync_projector;
+pub mod sync_repository;
+
+pub use query::{MilestoneReadModelQuery, MilestoneReadModelQueryHandler};
+pub use read_model::MilestoneReadModel;
+pub use sync_projector::MilestoneReadModelProjector;
+pub use sync_repository::SqlMilestoneRepository;
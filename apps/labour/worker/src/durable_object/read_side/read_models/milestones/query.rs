@@ -0,0 +1,34 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use fern_labour_event_sourcing_rs::{DecodedCursor, SyncRepositoryTrait};
+use uuid::Uuid;
+
+use crate::durable_object::read_side::read_models::milestones::MilestoneReadModel;
+
+#[async_trait(?Send)]
+pub trait MilestoneReadModelQueryHandler {
+    fn get(&self, limit: usize, cursor: Option<DecodedCursor>) -> Result<Vec<MilestoneReadModel>>;
+    fn get_by_id(&self, id: Uuid) -> Result<MilestoneReadModel>;
+}
+
+pub struct MilestoneReadModelQuery {
+    repository: Box<dyn SyncRepositoryTrait<MilestoneReadModel>>,
+}
+
+impl MilestoneReadModelQuery {
+    pub fn create(repository: Box<dyn SyncRepositoryTrait<MilestoneReadModel>>) -> Self {
+        Self { repository }
+    }
+}
+
+impl MilestoneReadModelQueryHandler for MilestoneReadModelQuery {
+    fn get(&self, limit: usize, cursor: Option<DecodedCursor>) -> Result<Vec<MilestoneReadModel>> {
+        let milestones = self.repository.get(limit, cursor)?;
+        Ok(milestones)
+    }
+
+    fn get_by_id(&self, id: Uuid) -> Result<MilestoneReadModel> {
+        let milestone = self.repository.get_by_id(id)?;
+        Ok(milestone)
+    }
+}
@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use fern_labour_event_sourcing_rs::Cursor;
+use fern_labour_labour_shared::value_objects::LabourMilestoneType;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MilestoneReadModel {
+    pub labour_id: Uuid,
+    pub milestone_id: Uuid,
+    pub milestone_type: LabourMilestoneType,
+    pub occurred_at: DateTime<Utc>,
+    pub notes: Option<String>,
+    pub edited: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MilestoneReadModel {
+    pub fn new(
+        labour_id: Uuid,
+        milestone_id: Uuid,
+        milestone_type: LabourMilestoneType,
+        occurred_at: DateTime<Utc>,
+        notes: Option<String>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            labour_id,
+            milestone_id,
+            milestone_type,
+            occurred_at,
+            notes,
+            edited: false,
+            created_at,
+            updated_at: created_at,
+        }
+    }
+}
+
+impl Cursor for MilestoneReadModel {
+    fn id(&self) -> Uuid {
+        self.milestone_id
+    }
+
+    #[allow(clippy::misnamed_getters)]
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MilestoneRow {
+    pub labour_id: String,
+    pub milestone_id: String,
+    pub milestone_type: String,
+    pub occurred_at: String,
+    pub notes: Option<String>,
+    pub edited: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl MilestoneRow {
+    pub fn into_read_model(self) -> Result<MilestoneReadModel> {
+        Ok(MilestoneReadModel {
+            labour_id: Uuid::parse_str(&self.labour_id)
+                .map_err(|e| anyhow!("Invalid labour_id UUID: {}", e))?,
+            milestone_id: Uuid::parse_str(&self.milestone_id)
+                .map_err(|e| anyhow!("Invalid milestone_id UUID: {}", e))?,
+            milestone_type: LabourMilestoneType::from_str(&self.milestone_type)
+                .map_err(|e| anyhow!("Invalid milestone_type: {}", e))?,
+            occurred_at: Self::parse_timestamp(&self.occurred_at)?,
+            notes: self.notes,
+            edited: Self::parse_bool(&self.edited)?,
+            created_at: Self::parse_timestamp(&self.created_at)?,
+            updated_at: Self::parse_timestamp(&self.updated_at)?,
+        })
+    }
+
+    pub fn from_read_model(model: &MilestoneReadModel) -> Result<Self> {
+        Ok(Self {
+            labour_id: model.labour_id.to_string(),
+            milestone_id: model.milestone_id.to_string(),
+            milestone_type: model.milestone_type.to_string(),
+            occurred_at: model.occurred_at.to_rfc3339(),
+            notes: model.notes.clone(),
+            edited: Self::bool_to_string(model.edited),
+            created_at: model.created_at.to_rfc3339(),
+            updated_at: model.updated_at.to_rfc3339(),
+        })
+    }
+
+    fn parse_timestamp(timestamp: &str) -> Result<DateTime<Utc>> {
+        let datetime = DateTime::parse_from_rfc3339(timestamp)
+            .map_err(|e| anyhow!("Invalid timestamp: {}", e))?
+            .with_timezone(&Utc);
+        Ok(datetime)
+    }
+
+    fn parse_bool(value: &str) -> Result<bool> {
+        match value {
+            "true" | "1" => Ok(true),
+            "false" | "0" => Ok(false),
+            _ => Err(anyhow!("Invalid boolean value: {}", value)),
+        }
+    }
+
+    fn bool_to_string(value: bool) -> String {
+        if value { "true" } else { "false" }.to_string()
+    }
+}
@@ -0,0 +1,176 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use fern_labour_event_sourcing_rs::{
+    EventDescriptor, EventEnvelope, SyncProjector, SyncRepositoryTrait,
+};
+
+use crate::durable_object::write_side::domain::events::{
+    MilestoneDeleted, MilestoneRecorded, MilestoneUpdated,
+};
+use crate::durable_object::{
+    read_side::read_models::milestones::MilestoneReadModel, write_side::domain::LabourEvent,
+};
+
+pub struct MilestoneReadModelProjector {
+    name: String,
+    repository: Box<dyn SyncRepositoryTrait<MilestoneReadModel>>,
+}
+
+impl MilestoneReadModelProjector {
+    pub const NAME: &'static str = "MilestoneReadModelProjector";
+
+    pub fn create(repository: Box<dyn SyncRepositoryTrait<MilestoneReadModel>>) -> Self {
+        Self {
+            name: Self::NAME.to_string(),
+            repository,
+        }
+    }
+
+    fn project_event(&self, envelope: &EventEnvelope<LabourEvent>) -> Result<()> {
+        let event = &envelope.event;
+        let timestamp = envelope.metadata.timestamp;
+
+        match event {
+            LabourEvent::MilestoneRecorded(e) => {
+                let milestone = MilestoneReadModel::new(
+                    e.labour_id,
+                    e.milestone_id,
+                    e.milestone_type.clone(),
+                    e.occurred_at,
+                    e.notes.clone(),
+                    timestamp,
+                );
+                self.repository.overwrite(&milestone)
+            }
+            LabourEvent::MilestoneUpdated(e) => {
+                let mut milestone = self.repository.get_by_id(e.milestone_id)?;
+                if let Some(occurred_at) = e.occurred_at {
+                    milestone.occurred_at = occurred_at;
+                }
+                if e.notes.is_some() {
+                    milestone.notes = e.notes.clone();
+                }
+                milestone.edited = true;
+                milestone.updated_at = timestamp;
+                self.repository.upsert(&milestone)
+            }
+            LabourEvent::MilestoneDeleted(e) => self.repository.delete(e.milestone_id),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl SyncProjector<LabourEvent> for MilestoneReadModelProjector {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn event_types(&self) -> Option<&'static [&'static str]> {
+        Some(&[
+            MilestoneRecorded::EVENT_TYPE,
+            MilestoneUpdated::EVENT_TYPE,
+            MilestoneDeleted::EVENT_TYPE,
+        ])
+    }
+
+    fn project_batch(&self, events: &[EventEnvelope<LabourEvent>]) -> Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        events
+            .iter()
+            .try_for_each(|envelope| self.project_event(envelope))
+    }
+
+    fn shadow(&self) -> Result<Box<dyn SyncProjector<LabourEvent>>> {
+        Ok(Box::new(Self::create(self.repository.shadow()?)))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        self.repository.promote_shadow()
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        self.repository.discard_shadow()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use fern_labour_event_sourcing_rs::{
+        Clock, EventMetadata, InMemorySyncRepository, testing::FixedClock,
+    };
+    use fern_labour_labour_shared::value_objects::LabourMilestoneType;
+    use uuid::Uuid;
+
+    fn envelope(sequence: i64, event: LabourEvent) -> EventEnvelope<LabourEvent> {
+        EventEnvelope {
+            metadata: EventMetadata {
+                aggregate_id: Uuid::now_v7(),
+                sequence,
+                event_version: 1,
+                timestamp: FixedClock::default().now(),
+                user_id: "mother".to_string(),
+                correlation_id: None,
+                causation_id: None,
+            },
+            event,
+        }
+    }
+
+    #[test]
+    fn milestones_are_recorded_updated_and_deleted() {
+        let repository = InMemorySyncRepository::new();
+        let projector = MilestoneReadModelProjector::create(Box::new(repository.clone()));
+        let labour_id = Uuid::now_v7();
+        let milestone_id = Uuid::now_v7();
+        let occurred_at = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        projector
+            .project_batch(&[
+                envelope(
+                    1,
+                    LabourEvent::MilestoneRecorded(MilestoneRecorded {
+                        labour_id,
+                        milestone_id,
+                        milestone_type: LabourMilestoneType::WATERS_BROKEN,
+                        occurred_at,
+                        notes: None,
+                        notify_subscribers: false,
+                    }),
+                ),
+                envelope(
+                    2,
+                    LabourEvent::MilestoneUpdated(MilestoneUpdated {
+                        labour_id,
+                        milestone_id,
+                        occurred_at: None,
+                        notes: Some("Clear".to_string()),
+                    }),
+                ),
+            ])
+            .unwrap();
+
+        let milestone = repository.get_by_id(milestone_id).unwrap();
+        assert_eq!(milestone.occurred_at, occurred_at);
+        assert_eq!(milestone.notes.as_deref(), Some("Clear"));
+        assert!(milestone.edited);
+
+        projector
+            .project_batch(&[envelope(
+                3,
+                LabourEvent::MilestoneDeleted(MilestoneDeleted {
+                    labour_id,
+                    milestone_id,
+                }),
+            )])
+            .unwrap();
+
+        assert!(repository.values().is_empty());
+    }
+}
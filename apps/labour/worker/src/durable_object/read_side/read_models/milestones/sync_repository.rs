@@ -0,0 +1,210 @@
+use anyhow::{Context, Result, anyhow};
+use fern_labour_event_sourcing_rs::{DecodedCursor, SyncRepositoryTrait};
+use fern_labour_workers_shared::sql::{drop_shadow_table, promote_shadow_table, shadow_table_name};
+use uuid::Uuid;
+use worker::SqlStorage;
+
+use super::read_model::{MilestoneReadModel, MilestoneRow};
+
+pub struct SqlMilestoneRepository {
+    sql: SqlStorage,
+    table: String,
+}
+
+impl SqlMilestoneRepository {
+    const TABLE: &'static str = "milestones";
+
+    pub fn create(sql: SqlStorage) -> Self {
+        Self {
+            sql,
+            table: Self::TABLE.to_string(),
+        }
+    }
+
+    pub fn init_schema(&self) -> Result<()> {
+        self.sql
+            .exec(
+                &format!(
+                    "CREATE TABLE IF NOT EXISTS {table} (
+                        milestone_id TEXT PRIMARY KEY,
+                        labour_id TEXT NOT NULL,
+                        milestone_type TEXT NOT NULL,
+                        occurred_at TEXT NOT NULL,
+                        notes TEXT,
+                        edited TEXT NOT NULL,
+                        created_at TEXT NOT NULL,
+                        updated_at TEXT NOT NULL
+                    )",
+                    table = self.table
+                ),
+                None,
+            )
+            .map_err(|err| anyhow!("Failed to create milestones table: {err}"))?;
+
+        self.sql
+            .exec(
+                &format!(
+                    "CREATE INDEX IF NOT EXISTS idx_{table}_occurred_at
+                     ON {table}(occurred_at DESC)",
+                    table = self.table
+                ),
+                None,
+            )
+            .context("Failed to create occurred_at index")?;
+
+        Ok(())
+    }
+}
+
+impl SyncRepositoryTrait<MilestoneReadModel> for SqlMilestoneRepository {
+    fn get_by_id(&self, milestone_id: Uuid) -> Result<MilestoneReadModel> {
+        let rows: Vec<MilestoneRow> = self
+            .sql
+            .exec(
+                &format!(
+                    "SELECT * FROM {table} WHERE milestone_id = ?1",
+                    table = self.table
+                ),
+                Some(vec![milestone_id.to_string().into()]),
+            )
+            .context("Failed to execute milestone query")?
+            .to_array()
+            .context("Failed to fetch milestone")?;
+
+        match rows.into_iter().next() {
+            Some(row) => row.into_read_model(),
+            None => Err(anyhow::anyhow!("Milestone not found")),
+        }
+    }
+
+    fn get(&self, limit: usize, cursor: Option<DecodedCursor>) -> Result<Vec<MilestoneReadModel>> {
+        let mut query = format!("SELECT * FROM {table}", table = self.table);
+        let mut bindings = vec![];
+
+        if let Some(cur) = cursor {
+            query.push_str(" WHERE occurred_at < ?1 OR (occurred_at = ?1 AND milestone_id < ?2)");
+            bindings.push(cur.last_updated_at.to_rfc3339().into());
+            bindings.push(cur.last_id.to_string().into());
+        }
+
+        let limit_param_index = bindings.len() + 1;
+        query.push_str(&format!(
+            " ORDER BY occurred_at DESC, milestone_id DESC LIMIT ?{}",
+            limit_param_index
+        ));
+
+        let plus_one_limit = limit + 1;
+        bindings.push((plus_one_limit as f64).into());
+
+        let rows: Vec<MilestoneRow> = self
+            .sql
+            .exec(&query, Some(bindings))
+            .context("Failed to execute milestones query")?
+            .to_array()
+            .context("Failed to fetch milestones")?;
+
+        rows.into_iter().map(|row| row.into_read_model()).collect()
+    }
+
+    fn upsert(&self, milestone: &MilestoneReadModel) -> Result<()> {
+        let row = MilestoneRow::from_read_model(milestone)
+            .context("Failed to convert milestone to row")?;
+
+        let bindings = vec![
+            row.milestone_id.into(),
+            row.labour_id.into(),
+            row.milestone_type.into(),
+            row.occurred_at.into(),
+            row.notes.into(),
+            row.edited.into(),
+            row.created_at.into(),
+            row.updated_at.into(),
+        ];
+
+        self.sql
+            .exec(
+                &format!(
+                    "INSERT INTO {table} (
+                        milestone_id, labour_id, milestone_type, occurred_at,
+                        notes, edited, created_at, updated_at
+                     )
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                     ON CONFLICT(milestone_id)
+                     DO UPDATE SET
+                        milestone_type = ?3,
+                        occurred_at = ?4,
+                        notes = ?5,
+                        edited = ?6,
+                        updated_at = ?8",
+                    table = self.table
+                ),
+                Some(bindings),
+            )
+            .context("Failed to upsert milestone")?;
+
+        Ok(())
+    }
+
+    fn delete(&self, milestone_id: Uuid) -> Result<()> {
+        self.sql
+            .exec(
+                &format!(
+                    "DELETE FROM {table} WHERE milestone_id = ?1",
+                    table = self.table
+                ),
+                Some(vec![milestone_id.to_string().into()]),
+            )
+            .context("Failed to delete milestone")?;
+
+        Ok(())
+    }
+
+    fn overwrite(&self, milestone: &MilestoneReadModel) -> Result<()> {
+        let row = MilestoneRow::from_read_model(milestone)
+            .context("Failed to convert milestone to row")?;
+
+        let bindings = vec![
+            row.milestone_id.into(),
+            row.labour_id.into(),
+            row.milestone_type.into(),
+            row.occurred_at.into(),
+            row.notes.into(),
+            row.edited.into(),
+            row.created_at.into(),
+            row.updated_at.into(),
+        ];
+
+        self.sql
+            .exec(
+                &format!(
+                    "INSERT OR REPLACE INTO {table} (
+                        milestone_id, labour_id, milestone_type, occurred_at,
+                        notes, edited, created_at, updated_at
+                     )
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                    table = self.table
+                ),
+                Some(bindings),
+            )
+            .context("Failed to overwrite milestone")?;
+
+        Ok(())
+    }
+
+    fn shadow(&self) -> Result<Box<dyn SyncRepositoryTrait<MilestoneReadModel>>> {
+        let shadow = Self {
+            sql: self.sql.clone(),
+            table: shadow_table_name(&self.table),
+        };
+        shadow.init_schema()?;
+        Ok(Box::new(shadow))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        promote_shadow_table(&self.sql, &self.table)
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        drop_shadow_table(&self.sql, &self.table)
+    }
+}
@@ -1,8 +1,14 @@
 pub mod contractions;
+#[cfg(test)]
+mod event_coverage;
 pub mod events;
+pub mod history;
 pub mod labour;
 pub mod labour_status;
 pub mod labour_updates;
+pub mod milestones;
+pub mod projections;
+pub mod scheduled_labour_updates;
 pub mod subscription_status;
 pub mod subscription_token;
 pub mod subscriptions;
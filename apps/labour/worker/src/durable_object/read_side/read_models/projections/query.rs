@@ -0,0 +1,21 @@
+use anyhow::{Context, Result};
+use fern_labour_event_sourcing_rs::{CheckpointRepository, ProjectionCheckpoint};
+
+/// Reports projector checkpoints, including the progress of any rebuilds.
+pub struct ProjectionQuery {
+    checkpoint_repository: Box<dyn CheckpointRepository>,
+}
+
+impl ProjectionQuery {
+    pub fn new(checkpoint_repository: Box<dyn CheckpointRepository>) -> Self {
+        Self {
+            checkpoint_repository,
+        }
+    }
+
+    pub fn get_checkpoints(&self) -> Result<Vec<ProjectionCheckpoint>> {
+        self.checkpoint_repository
+            .get_all_checkpoints()
+            .context("Failed to load projection checkpoints")
+    }
+}
@@ -0,0 +1,9 @@
+pub mod query;
+pub mod read_model;
+pub mod sync_projector;
+pub mod sync_repository;
+
+pub use query::{ScheduledLabourUpdateReadModelQuery, ScheduledLabourUpdateReadModelQueryHandler};
+pub use read_model::ScheduledLabourUpdateReadModel;
+pub use sync_projector::ScheduledLabourUpdateReadModelProjector;
+pub use sync_repository::SqlScheduledLabourUpdateRepository;
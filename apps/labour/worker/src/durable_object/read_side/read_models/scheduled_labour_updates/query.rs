@@ -0,0 +1,37 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use fern_labour_event_sourcing_rs::{DecodedCursor, SyncRepositoryTrait};
+
+use crate::durable_object::read_side::read_models::scheduled_labour_updates::ScheduledLabourUpdateReadModel;
+
+#[async_trait(?Send)]
+pub trait ScheduledLabourUpdateReadModelQueryHandler {
+    fn get(
+        &self,
+        limit: usize,
+        cursor: Option<DecodedCursor>,
+    ) -> Result<Vec<ScheduledLabourUpdateReadModel>>;
+}
+
+pub struct ScheduledLabourUpdateReadModelQuery {
+    repository: Box<dyn SyncRepositoryTrait<ScheduledLabourUpdateReadModel>>,
+}
+
+impl ScheduledLabourUpdateReadModelQuery {
+    pub fn create(
+        repository: Box<dyn SyncRepositoryTrait<ScheduledLabourUpdateReadModel>>,
+    ) -> Self {
+        Self { repository }
+    }
+}
+
+impl ScheduledLabourUpdateReadModelQueryHandler for ScheduledLabourUpdateReadModelQuery {
+    fn get(
+        &self,
+        limit: usize,
+        cursor: Option<DecodedCursor>,
+    ) -> Result<Vec<ScheduledLabourUpdateReadModel>> {
+        let scheduled_updates = self.repository.get(limit, cursor)?;
+        Ok(scheduled_updates)
+    }
+}
@@ -0,0 +1,110 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use fern_labour_event_sourcing_rs::Cursor;
+use fern_labour_labour_shared::value_objects::{
+    LabourPhase, LabourUpdateTrigger, LabourUpdateType,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledLabourUpdateReadModel {
+    pub labour_id: Uuid,
+    pub scheduled_update_id: Uuid,
+    pub labour_update_type: LabourUpdateType,
+    pub message: String,
+    pub trigger: LabourUpdateTrigger,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ScheduledLabourUpdateReadModel {
+    pub fn new(
+        labour_id: Uuid,
+        scheduled_update_id: Uuid,
+        labour_update_type: LabourUpdateType,
+        message: String,
+        trigger: LabourUpdateTrigger,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            labour_id,
+            scheduled_update_id,
+            labour_update_type,
+            message,
+            trigger,
+            created_at,
+        }
+    }
+}
+
+impl Cursor for ScheduledLabourUpdateReadModel {
+    fn id(&self) -> Uuid {
+        self.scheduled_update_id
+    }
+
+    #[allow(clippy::misnamed_getters)]
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+/// The trigger is flattened into `send_at` and `on_phase`, exactly one of
+/// which is set.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScheduledLabourUpdateRow {
+    pub labour_id: String,
+    pub scheduled_update_id: String,
+    pub labour_update_type: String,
+    pub message: String,
+    pub send_at: Option<String>,
+    pub on_phase: Option<String>,
+    pub created_at: String,
+}
+
+impl ScheduledLabourUpdateRow {
+    pub fn into_read_model(self) -> Result<ScheduledLabourUpdateReadModel> {
+        let trigger = match (self.send_at, self.on_phase) {
+            (Some(send_at), None) => LabourUpdateTrigger::AtTime {
+                send_at: Self::parse_timestamp(&send_at)?,
+            },
+            (None, Some(on_phase)) => LabourUpdateTrigger::OnPhase {
+                phase: LabourPhase::from_str(&on_phase)
+                    .map_err(|e| anyhow!("Invalid on_phase: {}", e))?,
+            },
+            _ => return Err(anyhow!("Scheduled labour update must have one trigger")),
+        };
+
+        Ok(ScheduledLabourUpdateReadModel {
+            labour_id: Uuid::parse_str(&self.labour_id)
+                .map_err(|e| anyhow!("Invalid labour_id UUID: {}", e))?,
+            scheduled_update_id: Uuid::parse_str(&self.scheduled_update_id)
+                .map_err(|e| anyhow!("Invalid scheduled_update_id UUID: {}", e))?,
+            labour_update_type: LabourUpdateType::from_str(&self.labour_update_type)
+                .map_err(|e| anyhow!("Invalid labour_update_type: {}", e))?,
+            message: self.message,
+            trigger,
+            created_at: Self::parse_timestamp(&self.created_at)?,
+        })
+    }
+
+    pub fn from_read_model(model: &ScheduledLabourUpdateReadModel) -> Result<Self> {
+        Ok(Self {
+            labour_id: model.labour_id.to_string(),
+            scheduled_update_id: model.scheduled_update_id.to_string(),
+            labour_update_type: model.labour_update_type.to_string(),
+            message: model.message.clone(),
+            send_at: model.trigger.send_at().map(|send_at| send_at.to_rfc3339()),
+            on_phase: model.trigger.phase().map(|phase| phase.to_string()),
+            created_at: model.created_at.to_rfc3339(),
+        })
+    }
+
+    fn parse_timestamp(timestamp: &str) -> Result<DateTime<Utc>> {
+        let datetime = DateTime::parse_from_rfc3339(timestamp)
+            .map_err(|e| anyhow!("Invalid timestamp: {}", e))?
+            .with_timezone(&Utc);
+        Ok(datetime)
+    }
+}
@@ -0,0 +1,174 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use fern_labour_event_sourcing_rs::{
+    EventDescriptor, EventEnvelope, SyncProjector, SyncRepositoryTrait,
+};
+
+use crate::durable_object::write_side::domain::events::{
+    LabourUpdateScheduled, ScheduledLabourUpdateCancelled, ScheduledLabourUpdatePosted,
+};
+use crate::durable_object::{
+    read_side::read_models::scheduled_labour_updates::ScheduledLabourUpdateReadModel,
+    write_side::domain::LabourEvent,
+};
+
+pub struct ScheduledLabourUpdateReadModelProjector {
+    name: String,
+    repository: Box<dyn SyncRepositoryTrait<ScheduledLabourUpdateReadModel>>,
+}
+
+impl ScheduledLabourUpdateReadModelProjector {
+    pub const NAME: &'static str = "ScheduledLabourUpdateReadModelProjector";
+
+    pub fn create(
+        repository: Box<dyn SyncRepositoryTrait<ScheduledLabourUpdateReadModel>>,
+    ) -> Self {
+        Self {
+            name: Self::NAME.to_string(),
+            repository,
+        }
+    }
+
+    fn project_event(&self, envelope: &EventEnvelope<LabourEvent>) -> Result<()> {
+        let event = &envelope.event;
+        let timestamp = envelope.metadata.timestamp;
+
+        match event {
+            LabourEvent::LabourUpdateScheduled(e) => {
+                let scheduled_update = ScheduledLabourUpdateReadModel::new(
+                    e.labour_id,
+                    e.scheduled_update_id,
+                    e.labour_update_type.clone(),
+                    e.message.clone(),
+                    e.trigger.clone(),
+                    timestamp,
+                );
+                self.repository.overwrite(&scheduled_update)
+            }
+            LabourEvent::ScheduledLabourUpdateCancelled(e) => {
+                self.repository.delete(e.scheduled_update_id)
+            }
+            LabourEvent::ScheduledLabourUpdatePosted(e) => {
+                self.repository.delete(e.scheduled_update_id)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl SyncProjector<LabourEvent> for ScheduledLabourUpdateReadModelProjector {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn event_types(&self) -> Option<&'static [&'static str]> {
+        Some(&[
+            LabourUpdateScheduled::EVENT_TYPE,
+            ScheduledLabourUpdateCancelled::EVENT_TYPE,
+            ScheduledLabourUpdatePosted::EVENT_TYPE,
+        ])
+    }
+
+    fn project_batch(&self, events: &[EventEnvelope<LabourEvent>]) -> Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        events
+            .iter()
+            .try_for_each(|envelope| self.project_event(envelope))
+    }
+
+    fn shadow(&self) -> Result<Box<dyn SyncProjector<LabourEvent>>> {
+        Ok(Box::new(Self::create(self.repository.shadow()?)))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        self.repository.promote_shadow()
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        self.repository.discard_shadow()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fern_labour_event_sourcing_rs::{
+        Clock, EventMetadata, InMemorySyncRepository, testing::FixedClock,
+    };
+    use fern_labour_labour_shared::value_objects::{
+        LabourPhase, LabourUpdateTrigger, LabourUpdateType,
+    };
+    use uuid::Uuid;
+
+    fn envelope(sequence: i64, event: LabourEvent) -> EventEnvelope<LabourEvent> {
+        EventEnvelope {
+            metadata: EventMetadata {
+                aggregate_id: Uuid::now_v7(),
+                sequence,
+                event_version: 1,
+                timestamp: FixedClock::default().now(),
+                user_id: "mother".to_string(),
+                correlation_id: None,
+                causation_id: None,
+            },
+            event,
+        }
+    }
+
+    #[test]
+    fn scheduled_updates_are_removed_once_posted_or_cancelled() {
+        let repository = InMemorySyncRepository::new();
+        let projector =
+            ScheduledLabourUpdateReadModelProjector::create(Box::new(repository.clone()));
+        let labour_id = Uuid::now_v7();
+        let posted_id = Uuid::now_v7();
+        let cancelled_id = Uuid::now_v7();
+        let scheduled = |scheduled_update_id| {
+            LabourEvent::LabourUpdateScheduled(LabourUpdateScheduled {
+                labour_id,
+                scheduled_update_id,
+                labour_update_type: LabourUpdateType::ANNOUNCEMENT,
+                message: "Things are moving".to_string(),
+                trigger: LabourUpdateTrigger::OnPhase {
+                    phase: LabourPhase::ACTIVE,
+                },
+                scheduled_by: "mother".to_string(),
+            })
+        };
+
+        projector
+            .project_batch(&[
+                envelope(1, scheduled(posted_id)),
+                envelope(2, scheduled(cancelled_id)),
+            ])
+            .unwrap();
+        assert_eq!(repository.values().len(), 2);
+
+        projector
+            .project_batch(&[
+                envelope(
+                    3,
+                    LabourEvent::ScheduledLabourUpdatePosted(ScheduledLabourUpdatePosted {
+                        labour_id,
+                        scheduled_update_id: posted_id,
+                        labour_update_id: Uuid::now_v7(),
+                    }),
+                ),
+                envelope(
+                    4,
+                    LabourEvent::ScheduledLabourUpdateCancelled(ScheduledLabourUpdateCancelled {
+                        labour_id,
+                        scheduled_update_id: cancelled_id,
+                    }),
+                ),
+            ])
+            .unwrap();
+
+        assert!(repository.values().is_empty());
+    }
+}
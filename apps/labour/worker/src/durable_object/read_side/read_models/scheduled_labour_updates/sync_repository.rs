@@ -0,0 +1,180 @@
+use anyhow::{Context, Result, anyhow};
+use fern_labour_event_sourcing_rs::{DecodedCursor, SyncRepositoryTrait};
+use fern_labour_workers_shared::sql::{drop_shadow_table, promote_shadow_table, shadow_table_name};
+use uuid::Uuid;
+use worker::SqlStorage;
+
+use super::read_model::{ScheduledLabourUpdateReadModel, ScheduledLabourUpdateRow};
+
+pub struct SqlScheduledLabourUpdateRepository {
+    sql: SqlStorage,
+    table: String,
+}
+
+impl SqlScheduledLabourUpdateRepository {
+    const TABLE: &'static str = "scheduled_labour_updates";
+
+    pub fn create(sql: SqlStorage) -> Self {
+        Self {
+            sql,
+            table: Self::TABLE.to_string(),
+        }
+    }
+
+    pub fn init_schema(&self) -> Result<()> {
+        self.sql
+            .exec(
+                &format!(
+                    "CREATE TABLE IF NOT EXISTS {table} (
+                        scheduled_update_id TEXT PRIMARY KEY,
+                        labour_id TEXT NOT NULL,
+                        labour_update_type TEXT NOT NULL,
+                        message TEXT NOT NULL,
+                        send_at TEXT,
+                        on_phase TEXT,
+                        created_at TEXT NOT NULL
+                    )",
+                    table = self.table
+                ),
+                None,
+            )
+            .map_err(|err| anyhow!("Failed to create scheduled_labour_updates table: {err}"))?;
+
+        self.sql
+            .exec(
+                &format!(
+                    "CREATE INDEX IF NOT EXISTS idx_{table}_created_at
+                     ON {table}(created_at DESC)",
+                    table = self.table
+                ),
+                None,
+            )
+            .context("Failed to create created_at index")?;
+
+        Ok(())
+    }
+}
+
+impl SyncRepositoryTrait<ScheduledLabourUpdateReadModel> for SqlScheduledLabourUpdateRepository {
+    fn get_by_id(&self, scheduled_update_id: Uuid) -> Result<ScheduledLabourUpdateReadModel> {
+        let rows: Vec<ScheduledLabourUpdateRow> = self
+            .sql
+            .exec(
+                &format!(
+                    "SELECT * FROM {table} WHERE scheduled_update_id = ?1",
+                    table = self.table
+                ),
+                Some(vec![scheduled_update_id.to_string().into()]),
+            )
+            .context("Failed to execute scheduled labour update query")?
+            .to_array()
+            .context("Failed to fetch scheduled labour update")?;
+
+        match rows.into_iter().next() {
+            Some(row) => row.into_read_model(),
+            None => Err(anyhow::anyhow!("Scheduled labour update not found")),
+        }
+    }
+
+    fn get(
+        &self,
+        limit: usize,
+        cursor: Option<DecodedCursor>,
+    ) -> Result<Vec<ScheduledLabourUpdateReadModel>> {
+        let mut query = format!("SELECT * FROM {table}", table = self.table);
+        let mut bindings = vec![];
+
+        if let Some(cur) = cursor {
+            query.push_str(
+                " WHERE created_at < ?1 OR (created_at = ?1 AND scheduled_update_id < ?2)",
+            );
+            bindings.push(cur.last_updated_at.to_rfc3339().into());
+            bindings.push(cur.last_id.to_string().into());
+        }
+
+        let limit_param_index = bindings.len() + 1;
+        query.push_str(&format!(
+            " ORDER BY created_at DESC, scheduled_update_id DESC LIMIT ?{}",
+            limit_param_index
+        ));
+
+        let plus_one_limit = limit + 1;
+        bindings.push((plus_one_limit as f64).into());
+
+        let rows: Vec<ScheduledLabourUpdateRow> = self
+            .sql
+            .exec(&query, Some(bindings))
+            .context("Failed to execute scheduled labour updates query")?
+            .to_array()
+            .context("Failed to fetch scheduled labour updates")?;
+
+        rows.into_iter().map(|row| row.into_read_model()).collect()
+    }
+
+    /// Scheduled updates are never edited, so an upsert replaces the row.
+    fn upsert(&self, scheduled_update: &ScheduledLabourUpdateReadModel) -> Result<()> {
+        self.overwrite(scheduled_update)
+    }
+
+    fn delete(&self, scheduled_update_id: Uuid) -> Result<()> {
+        self.sql
+            .exec(
+                &format!(
+                    "DELETE FROM {table} WHERE scheduled_update_id = ?1",
+                    table = self.table
+                ),
+                Some(vec![scheduled_update_id.to_string().into()]),
+            )
+            .context("Failed to delete scheduled labour update")?;
+
+        Ok(())
+    }
+
+    fn overwrite(&self, scheduled_update: &ScheduledLabourUpdateReadModel) -> Result<()> {
+        let row = ScheduledLabourUpdateRow::from_read_model(scheduled_update)
+            .context("Failed to convert scheduled labour update to row")?;
+
+        let bindings = vec![
+            row.scheduled_update_id.into(),
+            row.labour_id.into(),
+            row.labour_update_type.into(),
+            row.message.into(),
+            row.send_at.into(),
+            row.on_phase.into(),
+            row.created_at.into(),
+        ];
+
+        self.sql
+            .exec(
+                &format!(
+                    "INSERT OR REPLACE INTO {table} (
+                        scheduled_update_id, labour_id, labour_update_type, message,
+                        send_at, on_phase, created_at
+                     )
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                    table = self.table
+                ),
+                Some(bindings),
+            )
+            .context("Failed to overwrite scheduled labour update")?;
+
+        Ok(())
+    }
+
+    fn shadow(&self) -> Result<Box<dyn SyncRepositoryTrait<ScheduledLabourUpdateReadModel>>> {
+        let shadow = Self {
+            sql: self.sql.clone(),
+            table: shadow_table_name(&self.table),
+        };
+        shadow.init_schema()?;
+        Ok(Box::new(shadow))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        promote_shadow_table(&self.sql, &self.table)
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        drop_shadow_table(&self.sql, &self.table)
+    }
+}
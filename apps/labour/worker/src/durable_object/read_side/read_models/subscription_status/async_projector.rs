@@ -6,10 +6,14 @@ use fern_labour_labour_shared::value_objects::subscriber::status::SubscriberStat
 use tracing::{debug, info, warn};
 
 use fern_labour_event_sourcing_rs::{
-    AsyncRepositoryTrait, CacheExt, CacheTrait, CachedReadModelState, EventEnvelope,
-    IncrementalAsyncProjector,
+    AsyncRepositoryTrait, CacheExt, CacheTrait, CachedReadModelState, EventDescriptor,
+    EventEnvelope, IncrementalAsyncProjector, shadow_cache_key,
 };
 
+use crate::durable_object::write_side::domain::events::{
+    SubscriberApprovalRevoked, SubscriberApproved, SubscriberBlocked, SubscriberRemoved,
+    SubscriberRequested, SubscriberUnblocked, SubscriberUnsubscribed,
+};
 use crate::durable_object::{
     read_side::read_models::subscription_status::SubscriptionStatusReadModel,
     write_side::domain::LabourEvent,
@@ -22,10 +26,12 @@ pub struct SubscriptionStatusReadModelProjector {
 }
 
 impl SubscriptionStatusReadModelProjector {
+    pub const NAME: &'static str = "SubscriptionStatusReadModelProjector";
+
     pub fn create(repository: Box<dyn AsyncRepositoryTrait<SubscriptionStatusReadModel>>) -> Self {
         Self {
-            name: "SubscriptionStatusReadModelProjector".to_string(),
-            cache_key: "read_model_cache:SubscriptionStatusReadModelProjector".to_string(),
+            name: Self::NAME.to_string(),
+            cache_key: format!("read_model_cache:{}", Self::NAME),
             repository,
         }
     }
@@ -56,6 +62,12 @@ impl SubscriptionStatusReadModelProjector {
                 Some(subscription)
             }
 
+            LabourEvent::SubscriberApprovalRevoked(_) => {
+                let mut subscription = model?;
+                subscription.status = SubscriberStatus::REQUESTED;
+                Some(subscription)
+            }
+
             LabourEvent::SubscriberRemoved(_) => {
                 let mut subscription = model?;
                 subscription.status = SubscriberStatus::REMOVED;
@@ -83,6 +95,43 @@ impl SubscriptionStatusReadModelProjector {
             _ => model,
         }
     }
+
+    fn load_state(
+        &self,
+        cache: &Rc<dyn CacheTrait>,
+        key: &str,
+    ) -> CachedReadModelState<SubscriptionStatusReadModel> {
+        cache
+            .get(key.to_string())
+            .ok()
+            .flatten()
+            .unwrap_or_else(CachedReadModelState::empty)
+    }
+
+    async fn persist_change(
+        &self,
+        before: &Option<SubscriptionStatusReadModel>,
+        after: &Option<SubscriptionStatusReadModel>,
+    ) -> Result<()> {
+        match (before, after) {
+            (Some(old_model), None) => {
+                info!(projector = %self.name, "Model deleted, removing from D1");
+                self.repository
+                    .delete(old_model.subscription_id)
+                    .await
+                    .map_err(|e| anyhow!("Failed to delete: {e}"))?;
+            }
+            (_, Some(new_model)) => {
+                info!(projector = %self.name, "Model changed, persisting to D1");
+                self.repository
+                    .overwrite(new_model)
+                    .await
+                    .map_err(|e| anyhow!("Failed to persist: {e}"))?;
+            }
+            (None, None) => {}
+        }
+        Ok(())
+    }
 }
 
 #[async_trait(?Send)]
@@ -91,13 +140,20 @@ impl IncrementalAsyncProjector<LabourEvent> for SubscriptionStatusReadModelProje
         &self.name
     }
 
+    fn event_types(&self) -> Option<&'static [&'static str]> {
+        Some(&[
+            SubscriberRequested::EVENT_TYPE,
+            SubscriberApproved::EVENT_TYPE,
+            SubscriberApprovalRevoked::EVENT_TYPE,
+            SubscriberRemoved::EVENT_TYPE,
+            SubscriberBlocked::EVENT_TYPE,
+            SubscriberUnblocked::EVENT_TYPE,
+            SubscriberUnsubscribed::EVENT_TYPE,
+        ])
+    }
+
     fn get_cached_sequence(&self, cache: &Rc<dyn CacheTrait>) -> i64 {
-        cache
-            .get::<CachedReadModelState<SubscriptionStatusReadModel>>(self.cache_key.clone())
-            .ok()
-            .flatten()
-            .map(|s| s.sequence)
-            .unwrap_or(0)
+        self.load_state(cache, &self.cache_key).sequence
     }
 
     async fn process(
@@ -106,11 +162,7 @@ impl IncrementalAsyncProjector<LabourEvent> for SubscriptionStatusReadModelProje
         events: &[EventEnvelope<LabourEvent>],
         max_sequence: i64,
     ) -> Result<()> {
-        let cached_state: CachedReadModelState<SubscriptionStatusReadModel> = cache
-            .get(self.cache_key.clone())
-            .ok()
-            .flatten()
-            .unwrap_or_else(CachedReadModelState::empty);
+        let cached_state = self.load_state(cache, &self.cache_key);
 
         if events.is_empty() {
             debug!(projector = %self.name, "No new events to process");
@@ -125,23 +177,7 @@ impl IncrementalAsyncProjector<LabourEvent> for SubscriptionStatusReadModelProje
         }
 
         if before != current_model {
-            match (&before, &current_model) {
-                (Some(old_model), None) => {
-                    info!(projector = %self.name, "Model deleted, removing from D1");
-                    self.repository
-                        .delete(old_model.subscription_id)
-                        .await
-                        .map_err(|e| anyhow!("Failed to delete: {e}"))?;
-                }
-                (_, Some(new_model)) => {
-                    info!(projector = %self.name, "Model changed, persisting to D1");
-                    self.repository
-                        .overwrite(new_model)
-                        .await
-                        .map_err(|e| anyhow!("Failed to persist: {e}"))?;
-                }
-                (None, None) => {}
-            }
+            self.persist_change(&before, &current_model).await?;
         } else {
             debug!(projector = %self.name, "Model unchanged, skipping D1 write");
         }
@@ -153,4 +189,86 @@ impl IncrementalAsyncProjector<LabourEvent> for SubscriptionStatusReadModelProje
 
         Ok(())
     }
+
+    fn skip_to(&self, cache: &Rc<dyn CacheTrait>, sequence: i64) -> Result<()> {
+        let state = self.load_state(cache, &self.cache_key);
+        if state.sequence >= sequence {
+            return Ok(());
+        }
+        cache
+            .set(
+                self.cache_key.clone(),
+                &CachedReadModelState::new(sequence, state.model),
+            )
+            .map_err(|e| anyhow!("Failed to update cache: {e}"))
+    }
+
+    async fn process_shadow(
+        &self,
+        cache: &Rc<dyn CacheTrait>,
+        events: &[EventEnvelope<LabourEvent>],
+        max_sequence: i64,
+    ) -> Result<()> {
+        let shadow_key = shadow_cache_key(&self.cache_key);
+        let mut model = self.load_state(cache, &shadow_key).model;
+
+        for envelope in events {
+            model = self.project_event(model, envelope);
+        }
+
+        cache
+            .set(shadow_key, &CachedReadModelState::new(max_sequence, model))
+            .map_err(|e| anyhow!("Failed to update shadow cache: {e}"))
+    }
+
+    async fn promote_shadow(&self, cache: &Rc<dyn CacheTrait>) -> Result<()> {
+        let shadow_key = shadow_cache_key(&self.cache_key);
+        let shadow = self.load_state(cache, &shadow_key);
+        let live = self.load_state(cache, &self.cache_key);
+
+        if live.model != shadow.model {
+            self.persist_change(&live.model, &shadow.model).await?;
+        }
+
+        cache
+            .set(self.cache_key.clone(), &shadow)
+            .map_err(|e| anyhow!("Failed to update cache: {e}"))?;
+        self.discard_shadow(cache)
+    }
+
+    fn discard_shadow(&self, cache: &Rc<dyn CacheTrait>) -> Result<()> {
+        cache
+            .clear(shadow_cache_key(&self.cache_key))
+            .map_err(|e| anyhow!("Failed to clear shadow cache: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fern_labour_event_sourcing_rs::Event;
+
+    use super::*;
+    use crate::durable_object::read_side::read_models::event_coverage::{
+        UnusedAsyncRepository, every_event,
+    };
+
+    #[test]
+    fn undeclared_events_leave_the_model_unchanged() {
+        let projector =
+            SubscriptionStatusReadModelProjector::create(Box::new(UnusedAsyncRepository));
+        let declared = projector.event_types().unwrap();
+        let mut model = None;
+
+        for envelope in every_event() {
+            let event_type = envelope.event.event_type();
+            let projected = projector.project_event(model.clone(), &envelope);
+            if !declared.contains(&event_type) {
+                assert_eq!(
+                    projected, model,
+                    "{event_type} is projected but not declared"
+                );
+            }
+            model = projected;
+        }
+    }
 }
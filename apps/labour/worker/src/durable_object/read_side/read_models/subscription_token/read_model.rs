@@ -1,5 +1,6 @@
 use anyhow::{Result, anyhow};
 use chrono::{DateTime, Utc};
+use fern_labour_event_sourcing_rs::Cursor;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -22,6 +23,16 @@ impl SubscriptionTokenReadModel {
     }
 }
 
+impl Cursor for SubscriptionTokenReadModel {
+    fn id(&self) -> Uuid {
+        self.labour_id
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SubscriptionTokenRow {
     pub labour_id: String,
@@ -1,8 +1,13 @@
 use anyhow::Result;
 use async_trait::async_trait;
 
-use fern_labour_event_sourcing_rs::{EventEnvelope, SyncProjector, SyncRepositoryTrait};
+use fern_labour_event_sourcing_rs::{
+    EventDescriptor, EventEnvelope, SyncProjector, SyncRepositoryTrait,
+};
 
+use crate::durable_object::write_side::domain::events::{
+    SubscriptionTokenInvalidated, SubscriptionTokenSet,
+};
 use crate::durable_object::{
     read_side::read_models::subscription_token::SubscriptionTokenReadModel,
     write_side::domain::LabourEvent,
@@ -14,9 +19,11 @@ pub struct SubscriptionTokenProjector {
 }
 
 impl SubscriptionTokenProjector {
+    pub const NAME: &'static str = "SubscriptionTokenReadModelProjector";
+
     pub fn create(repository: Box<dyn SyncRepositoryTrait<SubscriptionTokenReadModel>>) -> Self {
         Self {
-            name: "SubscriptionTokenReadModelProjector".to_string(),
+            name: Self::NAME.to_string(),
             repository,
         }
     }
@@ -44,6 +51,13 @@ impl SyncProjector<LabourEvent> for SubscriptionTokenProjector {
         &self.name
     }
 
+    fn event_types(&self) -> Option<&'static [&'static str]> {
+        Some(&[
+            SubscriptionTokenSet::EVENT_TYPE,
+            SubscriptionTokenInvalidated::EVENT_TYPE,
+        ])
+    }
+
     fn project_batch(&self, events: &[EventEnvelope<LabourEvent>]) -> Result<()> {
         if events.is_empty() {
             return Ok(());
@@ -53,4 +67,16 @@ impl SyncProjector<LabourEvent> for SubscriptionTokenProjector {
             .iter()
             .try_for_each(|envelope| self.project_event(envelope))
     }
+
+    fn shadow(&self) -> Result<Box<dyn SyncProjector<LabourEvent>>> {
+        Ok(Box::new(Self::create(self.repository.shadow()?)))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        self.repository.promote_shadow()
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        self.repository.discard_shadow()
+    }
 }
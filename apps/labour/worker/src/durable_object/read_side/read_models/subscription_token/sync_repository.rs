@@ -1,5 +1,6 @@
 use anyhow::{Context, Result, anyhow};
 use fern_labour_event_sourcing_rs::{DecodedCursor, SyncRepositoryTrait};
+use fern_labour_workers_shared::sql::{drop_shadow_table, promote_shadow_table, shadow_table_name};
 use uuid::Uuid;
 use worker::SqlStorage;
 
@@ -13,22 +14,31 @@ pub trait SubscriptionTokenRepositoryTrait:
 
 pub struct SqlSubscriptionTokenRepository {
     sql: SqlStorage,
+    table: String,
 }
 
 impl SqlSubscriptionTokenRepository {
+    const TABLE: &'static str = "subscription_token";
+
     pub fn create(sql: SqlStorage) -> Self {
-        Self { sql }
+        Self {
+            sql,
+            table: Self::TABLE.to_string(),
+        }
     }
 
     pub fn init_schema(&self) -> Result<()> {
         self.sql
             .exec(
-                "CREATE TABLE IF NOT EXISTS subscription_token (
-                    labour_id TEXT PRIMARY KEY,
-                    token TEXT NOT NULL,
-                    created_at TEXT NOT NULL,
-                    updated_at TEXT NOT NULL
-                )",
+                &format!(
+                    "CREATE TABLE IF NOT EXISTS {table} (
+                        labour_id TEXT PRIMARY KEY,
+                        token TEXT NOT NULL,
+                        created_at TEXT NOT NULL,
+                        updated_at TEXT NOT NULL
+                    )",
+                    table = self.table
+                ),
                 None,
             )
             .map_err(|err| anyhow!("Failed to create subscription_token table: {err}"))?;
@@ -41,7 +51,10 @@ impl SubscriptionTokenRepositoryTrait for SqlSubscriptionTokenRepository {
     fn get_token(&self) -> Result<Option<SubscriptionTokenReadModel>> {
         let rows: Vec<SubscriptionTokenRow> = self
             .sql
-            .exec("SELECT * FROM subscription_token LIMIT 1", None)
+            .exec(
+                &format!("SELECT * FROM {table} LIMIT 1", table = self.table),
+                None,
+            )
             .context("Failed to execute subscription_token query")?
             .to_array()
             .context("Failed to fetch subscription_token")?;
@@ -58,7 +71,10 @@ impl SyncRepositoryTrait<SubscriptionTokenReadModel> for SqlSubscriptionTokenRep
         let rows: Vec<SubscriptionTokenRow> = self
             .sql
             .exec(
-                "SELECT * FROM subscription_token WHERE labour_id = ?1",
+                &format!(
+                    "SELECT * FROM {table} WHERE labour_id = ?1",
+                    table = self.table
+                ),
                 Some(vec![labour_id.to_string().into()]),
             )
             .context("Failed to execute subscription_token query")?
@@ -79,7 +95,10 @@ impl SyncRepositoryTrait<SubscriptionTokenReadModel> for SqlSubscriptionTokenRep
         // Only ever one row, ignoring pagination parameters
         let rows: Vec<SubscriptionTokenRow> = self
             .sql
-            .exec("SELECT * FROM subscription_token LIMIT 1", None)
+            .exec(
+                &format!("SELECT * FROM {table} LIMIT 1", table = self.table),
+                None,
+            )
             .context("Failed to execute subscription_token query")?
             .to_array()
             .context("Failed to fetch subscription_token")?;
@@ -100,14 +119,17 @@ impl SyncRepositoryTrait<SubscriptionTokenReadModel> for SqlSubscriptionTokenRep
 
         self.sql
             .exec(
-                "INSERT INTO subscription_token (
-                    labour_id, token, created_at, updated_at
-                 )
-                 VALUES (?1, ?2, ?3, ?4)
-                 ON CONFLICT(labour_id)
-                 DO UPDATE SET
-                    token = ?2,
-                    updated_at = ?4",
+                &format!(
+                    "INSERT INTO {table} (
+                        labour_id, token, created_at, updated_at
+                     )
+                     VALUES (?1, ?2, ?3, ?4)
+                     ON CONFLICT(labour_id)
+                     DO UPDATE SET
+                        token = ?2,
+                        updated_at = ?4",
+                    table = self.table
+                ),
                 Some(bindings),
             )
             .map_err(|err| anyhow!("Failed to upsert subscription token: {err}"))?;
@@ -118,7 +140,10 @@ impl SyncRepositoryTrait<SubscriptionTokenReadModel> for SqlSubscriptionTokenRep
     fn delete(&self, labour_id: Uuid) -> Result<()> {
         self.sql
             .exec(
-                "DELETE FROM subscription_token WHERE labour_id = ?1",
+                &format!(
+                    "DELETE FROM {table} WHERE labour_id = ?1",
+                    table = self.table
+                ),
                 Some(vec![labour_id.to_string().into()]),
             )
             .context("Failed to delete subscription token")?;
@@ -139,14 +164,34 @@ impl SyncRepositoryTrait<SubscriptionTokenReadModel> for SqlSubscriptionTokenRep
 
         self.sql
             .exec(
-                "INSERT OR REPLACE INTO subscription_token (
-                    labour_id, token, created_at, updated_at
-                 )
-                 VALUES (?1, ?2, ?3, ?4)",
+                &format!(
+                    "INSERT OR REPLACE INTO {table} (
+                        labour_id, token, created_at, updated_at
+                     )
+                     VALUES (?1, ?2, ?3, ?4)",
+                    table = self.table
+                ),
                 Some(bindings),
             )
             .context("Failed to overwrite subscription token")?;
 
         Ok(())
     }
+
+    fn shadow(&self) -> Result<Box<dyn SyncRepositoryTrait<SubscriptionTokenReadModel>>> {
+        let shadow = Self {
+            sql: self.sql.clone(),
+            table: shadow_table_name(&self.table),
+        };
+        shadow.init_schema()?;
+        Ok(Box::new(shadow))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        promote_shadow_table(&self.sql, &self.table)
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        drop_shadow_table(&self.sql, &self.table)
+    }
 }
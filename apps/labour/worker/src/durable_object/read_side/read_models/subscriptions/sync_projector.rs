@@ -4,8 +4,15 @@ use fern_labour_labour_shared::value_objects::{
     SubscriberAccessLevel, SubscriberRole, subscriber::status::SubscriberStatus,
 };
 
-use fern_labour_event_sourcing_rs::{EventEnvelope, SyncProjector, SyncRepositoryTrait};
+use fern_labour_event_sourcing_rs::{
+    EventDescriptor, EventEnvelope, SyncProjector, SyncRepositoryTrait,
+};
 
+use crate::durable_object::write_side::domain::events::{
+    SubscriberAccessLevelUpdated, SubscriberApprovalRevoked, SubscriberApproved, SubscriberBlocked,
+    SubscriberNotificationMethodsUpdated, SubscriberRemoved, SubscriberRequested,
+    SubscriberRoleUpdated, SubscriberUnblocked, SubscriberUnsubscribed,
+};
 use crate::durable_object::{
     read_side::read_models::subscriptions::SubscriptionReadModel, write_side::domain::LabourEvent,
 };
@@ -16,9 +23,11 @@ pub struct SubscriptionReadModelProjector {
 }
 
 impl SubscriptionReadModelProjector {
+    pub const NAME: &'static str = "SubscriptionReadModelProjector";
+
     pub fn create(repository: Box<dyn SyncRepositoryTrait<SubscriptionReadModel>>) -> Self {
         Self {
-            name: "SubscriptionReadModelProjector".to_string(),
+            name: Self::NAME.to_string(),
             repository,
         }
     }
@@ -62,6 +71,17 @@ impl SubscriptionReadModelProjector {
                 subscription.updated_at = timestamp;
                 self.repository.upsert(&subscription)
             }
+            LabourEvent::SubscriberApprovalRevoked(e) => {
+                let mut subscription =
+                    self.repository
+                        .get_by_id(e.subscription_id)
+                        .unwrap_or_else(|_| {
+                            panic!("No subscription found with id: {}", e.subscription_id)
+                        });
+                subscription.status = SubscriberStatus::REQUESTED;
+                subscription.updated_at = timestamp;
+                self.repository.upsert(&subscription)
+            }
             LabourEvent::SubscriberUnsubscribed(e) => {
                 let mut subscription =
                     self.repository
@@ -150,6 +170,21 @@ impl SyncProjector<LabourEvent> for SubscriptionReadModelProjector {
         &self.name
     }
 
+    fn event_types(&self) -> Option<&'static [&'static str]> {
+        Some(&[
+            SubscriberRequested::EVENT_TYPE,
+            SubscriberApproved::EVENT_TYPE,
+            SubscriberApprovalRevoked::EVENT_TYPE,
+            SubscriberUnsubscribed::EVENT_TYPE,
+            SubscriberRemoved::EVENT_TYPE,
+            SubscriberBlocked::EVENT_TYPE,
+            SubscriberUnblocked::EVENT_TYPE,
+            SubscriberRoleUpdated::EVENT_TYPE,
+            SubscriberAccessLevelUpdated::EVENT_TYPE,
+            SubscriberNotificationMethodsUpdated::EVENT_TYPE,
+        ])
+    }
+
     fn project_batch(&self, events: &[EventEnvelope<LabourEvent>]) -> Result<()> {
         if events.is_empty() {
             return Ok(());
@@ -159,4 +194,16 @@ impl SyncProjector<LabourEvent> for SubscriptionReadModelProjector {
             .iter()
             .try_for_each(|envelope| self.project_event(envelope))
     }
+
+    fn shadow(&self) -> Result<Box<dyn SyncProjector<LabourEvent>>> {
+        Ok(Box::new(Self::create(self.repository.shadow()?)))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        self.repository.promote_shadow()
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        self.repository.discard_shadow()
+    }
 }
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result, anyhow};
 use fern_labour_event_sourcing_rs::{DecodedCursor, SyncRepositoryTrait};
+use fern_labour_workers_shared::sql::{drop_shadow_table, promote_shadow_table, shadow_table_name};
 use uuid::Uuid;
 use worker::SqlStorage;
 
@@ -13,35 +14,47 @@ pub trait SubscriptionRepositoryTrait: SyncRepositoryTrait<SubscriptionReadModel
 
 pub struct SqlSubscriptionRepository {
     sql: SqlStorage,
+    table: String,
 }
 
 impl SqlSubscriptionRepository {
+    const TABLE: &'static str = "subscriptions";
+
     pub fn create(sql: SqlStorage) -> Self {
-        Self { sql }
+        Self {
+            sql,
+            table: Self::TABLE.to_string(),
+        }
     }
 
     pub fn init_schema(&self) -> Result<()> {
         self.sql
             .exec(
-                "CREATE TABLE IF NOT EXISTS subscriptions (
-                    subscription_id TEXT PRIMARY KEY,
-                    labour_id TEXT NOT NULL,
-                    subscriber_id TEXT NOT NULL,
-                    role TEXT NOT NULL,
-                    status TEXT NOT NULL,
-                    access_level TEXT NOT NULL,
-                    contact_methods TEXT NOT NULL,
-                    created_at TEXT NOT NULL,
-                    updated_at TEXT NOT NULL
-                )",
+                &format!(
+                    "CREATE TABLE IF NOT EXISTS {table} (
+                        subscription_id TEXT PRIMARY KEY,
+                        labour_id TEXT NOT NULL,
+                        subscriber_id TEXT NOT NULL,
+                        role TEXT NOT NULL,
+                        status TEXT NOT NULL,
+                        access_level TEXT NOT NULL,
+                        contact_methods TEXT NOT NULL,
+                        created_at TEXT NOT NULL,
+                        updated_at TEXT NOT NULL
+                    )",
+                    table = self.table
+                ),
                 None,
             )
             .map_err(|err| anyhow!("Failed to create subscriptions table: {err}"))?;
 
         self.sql
             .exec(
-                "CREATE INDEX IF NOT EXISTS idx_subscriptions_subscriber_id
-                 ON subscriptions(subscriber_id ASC)",
+                &format!(
+                    "CREATE INDEX IF NOT EXISTS idx_{table}_subscriber_id
+                     ON {table}(subscriber_id ASC)",
+                    table = self.table
+                ),
                 None,
             )
             .context("Failed to create subscriber_id index")?;
@@ -54,7 +67,13 @@ impl SubscriptionRepositoryTrait for SqlSubscriptionRepository {
     fn get_all(&self) -> Result<Vec<SubscriptionReadModel>> {
         let rows: Vec<SubscriptionRow> = self
             .sql
-            .exec("SELECT * FROM subscriptions ORDER BY created_at ASC", None)
+            .exec(
+                &format!(
+                    "SELECT * FROM {table} ORDER BY created_at ASC",
+                    table = self.table
+                ),
+                None,
+            )
             .context("Failed to execute subscriptions query")?
             .to_array()
             .context("Failed to fetch subscriptions")?;
@@ -66,7 +85,10 @@ impl SubscriptionRepositoryTrait for SqlSubscriptionRepository {
         let rows: Vec<SubscriptionRow> = self
             .sql
             .exec(
-                "SELECT * FROM subscriptions WHERE labour_id = ?1 ORDER BY created_at ASC",
+                &format!(
+                    "SELECT * FROM {table} WHERE labour_id = ?1 ORDER BY created_at ASC",
+                    table = self.table
+                ),
                 Some(vec![labour_id.to_string().into()]),
             )
             .context("Failed to execute subscriptions query")?
@@ -80,7 +102,10 @@ impl SubscriptionRepositoryTrait for SqlSubscriptionRepository {
         let rows: Vec<SubscriptionRow> = self
             .sql
             .exec(
-                "SELECT * FROM subscriptions WHERE subscriber_id = ?1 ORDER BY created_at ASC",
+                &format!(
+                    "SELECT * FROM {table} WHERE subscriber_id = ?1 ORDER BY created_at ASC",
+                    table = self.table
+                ),
                 Some(vec![subscriber_id.to_string().into()]),
             )
             .context("Failed to execute subscriptions query")?
@@ -99,7 +124,10 @@ impl SyncRepositoryTrait<SubscriptionReadModel> for SqlSubscriptionRepository {
         let rows: Vec<SubscriptionRow> = self
             .sql
             .exec(
-                "SELECT * FROM subscriptions WHERE subscription_id = ?1",
+                &format!(
+                    "SELECT * FROM {table} WHERE subscription_id = ?1",
+                    table = self.table
+                ),
                 Some(vec![subscription_id.to_string().into()]),
             )
             .context("Failed to execute subscription query")?
@@ -117,7 +145,7 @@ impl SyncRepositoryTrait<SubscriptionReadModel> for SqlSubscriptionRepository {
         limit: usize,
         cursor: Option<DecodedCursor>,
     ) -> Result<Vec<SubscriptionReadModel>> {
-        let mut query = "SELECT * FROM subscriptions".to_string();
+        let mut query = format!("SELECT * FROM {table}", table = self.table);
         let mut bindings = vec![];
 
         if let Some(cur) = cursor {
@@ -163,18 +191,21 @@ impl SyncRepositoryTrait<SubscriptionReadModel> for SqlSubscriptionRepository {
 
         self.sql
             .exec(
-                "INSERT INTO subscriptions (
-                    subscription_id, labour_id, subscriber_id, role, status, access_level,
-                    contact_methods, created_at, updated_at
-                 )
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
-                 ON CONFLICT(subscription_id)
-                 DO UPDATE SET
-                    role = ?4,
-                    status = ?5,
-                    access_level = ?6,
-                    contact_methods = ?7,
-                    updated_at = ?9",
+                &format!(
+                    "INSERT INTO {table} (
+                        subscription_id, labour_id, subscriber_id, role, status, access_level,
+                        contact_methods, created_at, updated_at
+                     )
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                     ON CONFLICT(subscription_id)
+                     DO UPDATE SET
+                        role = ?4,
+                        status = ?5,
+                        access_level = ?6,
+                        contact_methods = ?7,
+                        updated_at = ?9",
+                    table = self.table
+                ),
                 Some(bindings),
             )
             .map_err(|err| anyhow!("Failed to upsert subscription: {err}"))?;
@@ -185,7 +216,10 @@ impl SyncRepositoryTrait<SubscriptionReadModel> for SqlSubscriptionRepository {
     fn delete(&self, subscription_id: Uuid) -> Result<()> {
         self.sql
             .exec(
-                "DELETE FROM subscriptions WHERE subscription_id = ?1",
+                &format!(
+                    "DELETE FROM {table} WHERE subscription_id = ?1",
+                    table = self.table
+                ),
                 Some(vec![subscription_id.to_string().into()]),
             )
             .context("Failed to delete subscription")?;
@@ -211,15 +245,35 @@ impl SyncRepositoryTrait<SubscriptionReadModel> for SqlSubscriptionRepository {
 
         self.sql
             .exec(
-                "INSERT OR REPLACE INTO subscriptions (
-                    subscription_id, labour_id, subscriber_id, role, status, access_level,
-                    contact_methods, created_at, updated_at
-                 )
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                &format!(
+                    "INSERT OR REPLACE INTO {table} (
+                        subscription_id, labour_id, subscriber_id, role, status, access_level,
+                        contact_methods, created_at, updated_at
+                     )
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                    table = self.table
+                ),
                 Some(bindings),
             )
             .context("Failed to overwrite subscription")?;
 
         Ok(())
     }
+
+    fn shadow(&self) -> Result<Box<dyn SyncRepositoryTrait<SubscriptionReadModel>>> {
+        let shadow = Self {
+            sql: self.sql.clone(),
+            table: shadow_table_name(&self.table),
+        };
+        shadow.init_schema()?;
+        Ok(Box::new(shadow))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        promote_shadow_table(&self.sql, &self.table)
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        drop_shadow_table(&self.sql, &self.table)
+    }
 }
@@ -8,6 +8,8 @@ pub struct Config {
     pub default_batch_size: i64,
     pub notification_auth_token: String,
     pub stripe_secret_key: String,
+    pub max_contraction_duration_minutes: i64,
+    pub notify_on_abandoned_contraction: bool,
 }
 
 impl ConfigTrait<Config> for Config {
@@ -18,6 +20,15 @@ impl ConfigTrait<Config> for Config {
         let default_batch_size = Config::parse(env, "DEFAULT_BATCH_SIZE").unwrap_or(10000);
         let notification_auth_token = Config::parse(env, "NOTIFICATION_SERVICE_AUTH_TOKEN")?;
         let stripe_secret_key = Config::parse(env, "STRIPE_SECRET_KEY")?;
+        let max_contraction_duration_minutes =
+            Config::parse(env, "MAX_CONTRACTION_DURATION_MINUTES").unwrap_or(20);
+        if max_contraction_duration_minutes <= 0 {
+            return Err(SetupError::InvalidVariable(format!(
+                "MAX_CONTRACTION_DURATION_MINUTES must be positive, got {max_contraction_duration_minutes}"
+            )));
+        }
+        let notify_on_abandoned_contraction =
+            Config::parse(env, "NOTIFY_ON_ABANDONED_CONTRACTION").unwrap_or(true);
 
         Ok(Self {
             subscription_token_salt,
@@ -25,6 +36,8 @@ impl ConfigTrait<Config> for Config {
             default_batch_size,
             notification_auth_token,
             stripe_secret_key,
+            max_contraction_duration_minutes,
+            notify_on_abandoned_contraction,
         })
     }
 }
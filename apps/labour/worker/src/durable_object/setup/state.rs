@@ -1,21 +1,26 @@
 use std::rc::Rc;
 
 use anyhow::{Context, Result};
+use chrono::Duration;
 
 use fern_labour_workers_shared::{
-    ConfigTrait,
+    ConfigTrait, SqlEffectLedger, SqlProcessedCommandStore,
     clients::{FetcherNotificationClient, WorkerStripeClient},
 };
 use worker::{Env, State};
 
 use fern_labour_event_sourcing_rs::{
-    AggregateRepositoryTrait, CacheTrait, CachedAggregateRepository, CheckpointRepository,
-    EventStoreTrait, IncrementalAsyncProjector, SyncProjector,
+    AggregateRepositoryTrait, CacheTrait, CachedAggregateRepository, CheckpointRepository, Clock,
+    DEFAULT_MAX_PROJECTION_FAILURES, DeadLetterQueue, DeadLetterRepository, EffectLedgerTrait,
+    EventStoreTrait, EventStreamTransfer, IdempotencyGuard, IncrementalAsyncProjector,
+    PoisonEventPolicy, ProcessManager, ProcessedCommandStoreTrait, SnapshotStoreTrait, Snapshotter,
+    SyncProjector, SystemClock, UpcastingEventStore,
 };
 
 use crate::durable_object::{
     read_side::{
         checkpoint_repository::SqlCheckpointRepository,
+        dead_letter_repository::SqlDeadLetterRepository,
         projection_processors::{
             async_processor::AsyncProjectionProcessor, sync_processor::SyncProjectionProcessor,
         },
@@ -24,12 +29,21 @@ use crate::durable_object::{
                 ContractionReadModelProjector, ContractionReadModelQuery, SqlContractionRepository,
             },
             events::query::EventQuery,
+            history::query::HistoryQuery,
             labour::{LabourReadModelProjector, LabourReadModelQuery, SqlLabourRepository},
             labour_status::{D1LabourStatusRepository, LabourStatusReadModelProjector},
             labour_updates::{
                 LabourUpdateReadModelProjector, LabourUpdateReadModelQuery,
                 SqlLabourUpdateRepository,
             },
+            milestones::{
+                MilestoneReadModelProjector, MilestoneReadModelQuery, SqlMilestoneRepository,
+            },
+            projections::query::ProjectionQuery,
+            scheduled_labour_updates::{
+                ScheduledLabourUpdateReadModelProjector, ScheduledLabourUpdateReadModelQuery,
+                SqlScheduledLabourUpdateRepository,
+            },
             subscription_status::{
                 D1SubscriptionStatusRepository, SubscriptionStatusReadModelProjector,
             },
@@ -45,10 +59,15 @@ use crate::durable_object::{
     setup::config::Config,
     websocket::event_broadcaster::WebSocketEventBroadcaster,
     write_side::{
-        application::{AdminCommandProcessor, CheckoutService, LabourCommandProcessor},
-        domain::{Labour, LabourEvent},
-        infrastructure::{RandomTokenGenerator, SqlCache, SqlEventStore, UserStore},
-        process_manager::{EffectLedger, LabourEffectExecutor, ProcessManager},
+        application::{
+            AbandonedContractionMonitor, AdminCommandProcessor, CheckoutService,
+            LabourCommandProcessor, ScheduledLabourUpdatePublisher,
+        },
+        domain::{Labour, LabourEvent, events::upcasters::upcaster_registry},
+        infrastructure::{
+            RandomTokenGenerator, SqlCache, SqlEventStore, SqlSnapshotStore, UserStore,
+        },
+        process_manager::{Effect, LabourEffectExecutor},
     },
 };
 
@@ -57,15 +76,20 @@ pub struct WriteModel {
     pub admin_command_processor: AdminCommandProcessor,
     pub checkout_service: CheckoutService,
     pub user_store: UserStore,
+    pub event_stream_transfer: EventStreamTransfer,
 }
 
 pub struct ReadModel {
     pub aggregate_repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
     pub event_query: EventQuery,
+    pub history_query: HistoryQuery,
+    pub projection_query: ProjectionQuery,
     pub user_query: UserQuery,
     pub labour_query: LabourReadModelQuery,
     pub contraction_query: ContractionReadModelQuery,
     pub labour_update_query: LabourUpdateReadModelQuery,
+    pub milestone_query: MilestoneReadModelQuery,
+    pub scheduled_labour_update_query: ScheduledLabourUpdateReadModelQuery,
     pub subscription_query: SubscriptionQuery,
     pub subscription_token_query: SubscriptionTokenQuery,
 }
@@ -73,11 +97,14 @@ pub struct ReadModel {
 pub struct AsyncProcessors {
     pub async_projection_processor: AsyncProjectionProcessor,
     pub sync_projection_processor: SyncProjectionProcessor,
+    pub dead_letter_queue: Rc<DeadLetterQueue>,
     pub websocket_event_broadcaster: WebSocketEventBroadcaster,
 }
 
 pub struct ProcessManagement {
-    pub process_manager: ProcessManager<LabourEffectExecutor>,
+    pub process_manager: ProcessManager<Labour, Effect, LabourEffectExecutor>,
+    pub abandoned_contraction_monitor: AbandonedContractionMonitor,
+    pub scheduled_labour_update_publisher: ScheduledLabourUpdatePublisher,
 }
 
 pub struct LabourRoomServices {
@@ -85,16 +112,30 @@ pub struct LabourRoomServices {
     read_model: ReadModel,
     async_processors: AsyncProcessors,
     process_management: ProcessManagement,
+    clock: Rc<dyn Clock>,
 }
 
 impl LabourRoomServices {
     fn build_write_model(
         state: &State,
         config: &Config,
+        event_store: Rc<dyn EventStoreTrait>,
         aggregate_repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
+        clock: Rc<dyn Clock>,
     ) -> Result<WriteModel> {
         let sql = state.storage().sql();
-        let labour_command_processor = LabourCommandProcessor::new(aggregate_repository.clone());
+        let processed_command_store = SqlProcessedCommandStore::create(sql.clone());
+        processed_command_store
+            .init_schema()
+            .context("Processed command store initialization failed")?;
+        let idempotency = IdempotencyGuard::new(
+            Rc::new(processed_command_store),
+            Duration::hours(Self::IDEMPOTENCY_RETENTION_HOURS),
+        )
+        .with_clock(clock.clone());
+        let labour_command_processor =
+            LabourCommandProcessor::new(aggregate_repository.clone(), idempotency)
+                .with_clock(clock.clone());
 
         let stripe_client = Box::new(WorkerStripeClient::new(config.stripe_secret_key.clone()));
         let checkout_service = CheckoutService::new(aggregate_repository, stripe_client);
@@ -102,7 +143,16 @@ impl LabourRoomServices {
         let checkpoint_repository = Box::new(SqlCheckpointRepository::create(sql.clone()));
         checkpoint_repository.init_schema()?;
 
-        let admin_command_processor = AdminCommandProcessor::create(checkpoint_repository);
+        let admin_command_processor = AdminCommandProcessor::create(
+            checkpoint_repository,
+            Self::REBUILDABLE_PROJECTORS.to_vec(),
+        )
+        .with_clock(clock.clone());
+
+        let event_stream_transfer =
+            EventStreamTransfer::new(event_store, Rc::new(SqlEffectLedger::create(sql.clone())))
+                .with_checkpoints(Box::new(SqlCheckpointRepository::create(sql.clone())))
+                .with_clock(clock);
 
         let user_store = UserStore::create(sql);
         user_store
@@ -114,15 +164,20 @@ impl LabourRoomServices {
             admin_command_processor,
             checkout_service,
             user_store,
+            event_stream_transfer,
         })
     }
 
     fn build_read_model(
         state: &State,
+        event_store: Rc<dyn EventStoreTrait>,
         aggregate_repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
     ) -> Result<ReadModel> {
         let sql = state.storage().sql();
-        let event_query = EventQuery::new(aggregate_repository.clone());
+        let event_query = EventQuery::new(event_store.clone(), aggregate_repository.clone());
+        let history_query = HistoryQuery::new(event_store, aggregate_repository.clone());
+        let projection_query =
+            ProjectionQuery::new(Box::new(SqlCheckpointRepository::create(sql.clone())));
 
         let labour_repository = Box::new(SqlLabourRepository::create(sql.clone()));
         let labour_query = LabourReadModelQuery::create(labour_repository);
@@ -133,6 +188,14 @@ impl LabourRoomServices {
         let labour_update_repository = Box::new(SqlLabourUpdateRepository::create(sql.clone()));
         let labour_update_query = LabourUpdateReadModelQuery::create(labour_update_repository);
 
+        let milestone_repository = Box::new(SqlMilestoneRepository::create(sql.clone()));
+        let milestone_query = MilestoneReadModelQuery::create(milestone_repository);
+
+        let scheduled_labour_update_repository =
+            Box::new(SqlScheduledLabourUpdateRepository::create(sql.clone()));
+        let scheduled_labour_update_query =
+            ScheduledLabourUpdateReadModelQuery::create(scheduled_labour_update_repository);
+
         let subscription_repository = Box::new(SqlSubscriptionRepository::create(sql.clone()));
         let subscription_query = SubscriptionQuery::create(subscription_repository);
 
@@ -145,10 +208,14 @@ impl LabourRoomServices {
         Ok(ReadModel {
             aggregate_repository,
             event_query,
+            history_query,
+            projection_query,
             user_query,
             labour_query,
             contraction_query,
             labour_update_query,
+            milestone_query,
+            scheduled_labour_update_query,
             subscription_query,
             subscription_token_query,
         })
@@ -158,6 +225,8 @@ impl LabourRoomServices {
         state: &State,
         config: &Config,
         event_store: Rc<dyn EventStoreTrait>,
+        dead_letter_queue: Rc<DeadLetterQueue>,
+        clock: Rc<dyn Clock>,
     ) -> Result<SyncProjectionProcessor> {
         let sql = state.storage().sql();
 
@@ -183,6 +252,20 @@ impl LabourRoomServices {
             labour_update_repository,
         ));
 
+        let milestone_repository = Box::new(SqlMilestoneRepository::create(sql.clone()));
+        milestone_repository.init_schema()?;
+
+        let milestone_projector =
+            Box::new(MilestoneReadModelProjector::create(milestone_repository));
+
+        let scheduled_labour_update_repository =
+            Box::new(SqlScheduledLabourUpdateRepository::create(sql.clone()));
+        scheduled_labour_update_repository.init_schema()?;
+
+        let scheduled_labour_update_projector = Box::new(
+            ScheduledLabourUpdateReadModelProjector::create(scheduled_labour_update_repository),
+        );
+
         let subscription_repository = Box::new(SqlSubscriptionRepository::create(sql.clone()));
         subscription_repository.init_schema()?;
 
@@ -200,23 +283,38 @@ impl LabourRoomServices {
             labour_projector,
             contraction_projector,
             labour_update_projector,
+            milestone_projector,
+            scheduled_labour_update_projector,
             subscription_projector,
             subscription_token_projector,
         ];
 
+        // Skipping a token event could leave a revoked token usable, so the
+        // token projector stops instead of quarantining.
         Ok(SyncProjectionProcessor::create(
             event_store,
             checkpoint_repository,
+            dead_letter_queue,
             projectors,
             config.default_batch_size,
-        ))
+        )
+        .with_poison_event_policy(
+            SubscriptionTokenProjector::NAME,
+            PoisonEventPolicy::Halt {
+                max_failures: DEFAULT_MAX_PROJECTION_FAILURES,
+            },
+        )
+        .with_clock(clock))
     }
 
     fn build_async_projection_processor(
+        state: &State,
         env: &Env,
         config: &Config,
         event_store: Rc<dyn EventStoreTrait>,
         cache: Rc<dyn CacheTrait>,
+        dead_letter_queue: Rc<DeadLetterQueue>,
+        clock: Rc<dyn Clock>,
     ) -> Result<AsyncProjectionProcessor> {
         let binding = "READ_MODEL_DB";
 
@@ -238,12 +336,18 @@ impl LabourRoomServices {
             Box::new(subscription_status_projector),
         ];
 
+        let checkpoint_repository =
+            Box::new(SqlCheckpointRepository::create(state.storage().sql()));
+
         Ok(AsyncProjectionProcessor::create(
             event_store,
             cache,
+            checkpoint_repository,
+            dead_letter_queue,
             projectors,
             config.default_batch_size,
-        ))
+        )
+        .with_clock(clock))
     }
 
     fn build_async_processors(
@@ -252,17 +356,42 @@ impl LabourRoomServices {
         config: &Config,
         event_store: Rc<dyn EventStoreTrait>,
         cache: Rc<dyn CacheTrait>,
+        clock: Rc<dyn Clock>,
     ) -> Result<AsyncProcessors> {
         let websocket_event_broadcaster =
             WebSocketEventBroadcaster::create(event_store.clone(), config.default_batch_size);
-        let async_projection_processor =
-            Self::build_async_projection_processor(env, config, event_store.clone(), cache)?;
-        let sync_projection_processor =
-            Self::build_sync_projection_processor(state, config, event_store.clone())?;
+        let sql = state.storage().sql();
+        let dead_letter_repository = SqlDeadLetterRepository::create(sql.clone());
+        dead_letter_repository.init_schema()?;
+        let dead_letter_queue = Rc::new(
+            DeadLetterQueue::new(
+                Box::new(dead_letter_repository),
+                Box::new(SqlCheckpointRepository::create(sql)),
+            )
+            .with_clock(clock.clone()),
+        );
+
+        let async_projection_processor = Self::build_async_projection_processor(
+            state,
+            env,
+            config,
+            event_store.clone(),
+            cache,
+            dead_letter_queue.clone(),
+            clock.clone(),
+        )?;
+        let sync_projection_processor = Self::build_sync_projection_processor(
+            state,
+            config,
+            event_store.clone(),
+            dead_letter_queue.clone(),
+            clock,
+        )?;
 
         Ok(AsyncProcessors {
             async_projection_processor,
             sync_projection_processor,
+            dead_letter_queue,
             websocket_event_broadcaster,
         })
     }
@@ -274,10 +403,11 @@ impl LabourRoomServices {
         event_store: Rc<dyn EventStoreTrait>,
         aggregate_repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
         command_processor: Rc<LabourCommandProcessor>,
+        clock: Rc<dyn Clock>,
     ) -> Result<ProcessManagement> {
         let sql = state.storage().sql();
 
-        let ledger = EffectLedger::create(sql.clone());
+        let ledger = Rc::new(SqlEffectLedger::create(sql.clone()));
         ledger
             .init_schema()
             .context("Failed to initialize effect ledger schema")?;
@@ -295,6 +425,20 @@ impl LabourRoomServices {
 
         let user_storage = UserStore::create(sql);
 
+        let abandoned_contraction_monitor = AbandonedContractionMonitor::new(
+            aggregate_repository.clone(),
+            command_processor.clone(),
+            Duration::minutes(config.max_contraction_duration_minutes),
+            config.notify_on_abandoned_contraction,
+        )
+        .with_clock(clock.clone());
+
+        let scheduled_labour_update_publisher = ScheduledLabourUpdatePublisher::new(
+            aggregate_repository.clone(),
+            command_processor.clone(),
+        )
+        .with_clock(clock);
+
         let executor = LabourEffectExecutor::new(
             user_storage,
             notification_client,
@@ -312,16 +456,37 @@ impl LabourRoomServices {
             6,
         );
 
-        Ok(ProcessManagement { process_manager })
+        Ok(ProcessManagement {
+            process_manager,
+            abandoned_contraction_monitor,
+            scheduled_labour_update_publisher,
+        })
     }
 
     const AGGREGATE_CACHE_KEY: &'static str = "aggregate:labour";
+    const REBUILDABLE_PROJECTORS: [&'static str; 9] = [
+        LabourReadModelProjector::NAME,
+        ContractionReadModelProjector::NAME,
+        LabourUpdateReadModelProjector::NAME,
+        MilestoneReadModelProjector::NAME,
+        ScheduledLabourUpdateReadModelProjector::NAME,
+        SubscriptionReadModelProjector::NAME,
+        SubscriptionTokenProjector::NAME,
+        LabourStatusReadModelProjector::NAME,
+        SubscriptionStatusReadModelProjector::NAME,
+    ];
+    const SNAPSHOT_INTERVAL: i64 = 100;
+    const IDEMPOTENCY_RETENTION_HOURS: i64 = 24;
 
     pub fn from_worker_state(state: &State, env: &Env) -> Result<Self> {
         let config = Config::from_env(env)?;
         let sql = state.storage().sql();
+        let clock: Rc<dyn Clock> = Rc::new(SystemClock);
 
-        let event_store: Rc<dyn EventStoreTrait> = Rc::new(SqlEventStore::create(sql.clone()));
+        let event_store: Rc<dyn EventStoreTrait> = Rc::new(UpcastingEventStore::new(
+            SqlEventStore::create(sql.clone()).with_clock(clock.clone()),
+            upcaster_registry(),
+        ));
         event_store
             .init_schema()
             .context("Event store initialization failed")?;
@@ -330,19 +495,41 @@ impl LabourRoomServices {
         cache.init_schema().context("Cache initialization failed")?;
         let cache: Rc<dyn CacheTrait> = Rc::new(cache);
 
-        let aggregate_repository = Rc::new(CachedAggregateRepository::new(
-            event_store.clone(),
-            cache.clone(),
-            Self::AGGREGATE_CACHE_KEY.to_string(),
-        ));
+        let snapshot_store: Rc<dyn SnapshotStoreTrait> =
+            Rc::new(SqlSnapshotStore::create(sql.clone()));
+        snapshot_store
+            .init_schema()
+            .context("Snapshot store initialization failed")?;
+
+        let aggregate_repository = Rc::new(
+            CachedAggregateRepository::new(
+                event_store.clone(),
+                cache.clone(),
+                Self::AGGREGATE_CACHE_KEY.to_string(),
+            )
+            .with_snapshots(Snapshotter::new(snapshot_store, Self::SNAPSHOT_INTERVAL)),
+        );
 
-        let write_model = Self::build_write_model(state, &config, aggregate_repository.clone())?;
+        let write_model = Self::build_write_model(
+            state,
+            &config,
+            event_store.clone(),
+            aggregate_repository.clone(),
+            clock.clone(),
+        )?;
 
         let command_processor = Rc::new(write_model.labour_command_processor.clone());
 
-        let read_model = Self::build_read_model(state, aggregate_repository.clone())?;
-        let async_processors =
-            Self::build_async_processors(state, env, &config, event_store.clone(), cache)?;
+        let read_model =
+            Self::build_read_model(state, event_store.clone(), aggregate_repository.clone())?;
+        let async_processors = Self::build_async_processors(
+            state,
+            env,
+            &config,
+            event_store.clone(),
+            cache,
+            clock.clone(),
+        )?;
         let process_management = Self::build_process_management(
             state,
             env,
@@ -350,6 +537,7 @@ impl LabourRoomServices {
             event_store.clone(),
             aggregate_repository.clone(),
             command_processor,
+            clock.clone(),
         )?;
 
         Ok(Self {
@@ -357,6 +545,7 @@ impl LabourRoomServices {
             read_model,
             async_processors,
             process_management,
+            clock,
         })
     }
 
@@ -375,4 +564,8 @@ impl LabourRoomServices {
     pub fn process_management(&self) -> &ProcessManagement {
         &self.process_management
     }
+
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
 }
@@ -1,12 +1,42 @@
 use std::rc::Rc;
 
-use fern_labour_event_sourcing_rs::EventStoreTrait;
+use fern_labour_event_sourcing_rs::{EventDescriptor, EventStoreTrait, EventTypeRegistry};
 use tracing::{debug, warn};
 use worker::State;
 
+use crate::durable_object::write_side::domain::{
+    LabourEvent,
+    events::{
+        ActionUndone, BirthRecordAmended, BirthRecorded, LabourUpdateScheduled,
+        ScheduledLabourUpdateCancelled, ScheduledLabourUpdatePosted,
+    },
+};
+
+/// Event types connected clients are never sent: unpublished birth records,
+/// scheduled labour updates that have not been posted yet, and undo markers
+/// whose compensating events are sent instead. Every other `LabourEvent` is
+/// broadcast.
+pub const UNBROADCAST_EVENT_TYPES: &[&str] = &[
+    BirthRecorded::EVENT_TYPE,
+    BirthRecordAmended::EVENT_TYPE,
+    LabourUpdateScheduled::EVENT_TYPE,
+    ScheduledLabourUpdateCancelled::EVENT_TYPE,
+    ScheduledLabourUpdatePosted::EVENT_TYPE,
+    ActionUndone::EVENT_TYPE,
+];
+
+pub fn broadcast_event_types() -> Vec<&'static str> {
+    LabourEvent::EVENT_TYPES
+        .iter()
+        .map(|info| info.event_type)
+        .filter(|event_type| !UNBROADCAST_EVENT_TYPES.contains(event_type))
+        .collect()
+}
+
 pub struct WebSocketEventBroadcaster {
     event_store: Rc<dyn EventStoreTrait>,
     default_batch_size: i64,
+    event_types: Vec<&'static str>,
 }
 
 impl WebSocketEventBroadcaster {
@@ -14,6 +44,7 @@ impl WebSocketEventBroadcaster {
         Self {
             event_store,
             default_batch_size,
+            event_types: broadcast_event_types(),
         }
     }
 
@@ -21,9 +52,16 @@ impl WebSocketEventBroadcaster {
         // TODO: not all websocket clients need or should receive all domain events.
         // They should be filtered by permissions and even potentially have info redacted.
 
-        let new_events = self
+        let Some(page) = self
             .event_store
-            .events_since(since_sequence, self.default_batch_size)?;
+            .pages_since(since_sequence, self.default_batch_size)
+            .only(&self.event_types)
+            .next()
+            .transpose()?
+        else {
+            return Ok(());
+        };
+        let new_events = page.rows;
 
         if new_events.is_empty() {
             return Ok(());
@@ -48,3 +86,33 @@ impl WebSocketEventBroadcaster {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::durable_object::write_side::domain::events::{
+        ContractionPatternDetected, LabourPhaseChanged,
+    };
+
+    #[test]
+    fn excluded_event_types_are_labour_events() {
+        for event_type in UNBROADCAST_EVENT_TYPES {
+            assert!(
+                LabourEvent::event_type_info(event_type).is_some(),
+                "{event_type} is not a LabourEvent"
+            );
+        }
+    }
+
+    #[test]
+    fn phase_changes_and_detected_patterns_are_broadcast() {
+        let broadcast = broadcast_event_types();
+
+        assert!(broadcast.contains(&LabourPhaseChanged::EVENT_TYPE));
+        assert!(broadcast.contains(&ContractionPatternDetected::EVENT_TYPE));
+        assert_eq!(
+            broadcast.len() + UNBROADCAST_EVENT_TYPES.len(),
+            LabourEvent::EVENT_TYPES.len()
+        );
+    }
+}
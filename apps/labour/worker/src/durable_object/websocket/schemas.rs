@@ -1,4 +1,4 @@
-use fern_labour_labour_shared::{ApiCommand, ApiQuery};
+use fern_labour_labour_shared::{ApiCommand, ApiQuery, CommandBatch};
 use serde::{Deserialize, Serialize};
 use tracing::error;
 use worker::{Result, WebSocketIncomingMessage};
@@ -10,6 +10,10 @@ pub enum WebSocketRequest {
         #[serde(flatten)]
         command: ApiCommand,
     },
+    CommandBatch {
+        #[serde(flatten)]
+        batch: CommandBatch,
+    },
     Query {
         #[serde(flatten)]
         query: ApiQuery,
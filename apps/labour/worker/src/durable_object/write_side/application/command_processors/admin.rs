@@ -1,20 +1,36 @@
-use anyhow::{Result, anyhow};
-use fern_labour_event_sourcing_rs::{CheckpointRepository, CommandEnvelope};
+use std::rc::Rc;
+
+use anyhow::{Context, Result, bail};
+use fern_labour_event_sourcing_rs::{
+    CheckpointRepository, Clock, CommandEnvelope, ProjectionCheckpoint, SystemClock,
+};
 use fern_labour_labour_shared::AdminCommand;
 use fern_labour_workers_shared::User;
 use tracing::info;
 
 pub struct AdminCommandProcessor {
     checkpoint_repository: Box<dyn CheckpointRepository>,
+    projector_names: Vec<&'static str>,
+    clock: Rc<dyn Clock>,
 }
 
 impl AdminCommandProcessor {
-    pub fn create(checkpoint_repository: Box<dyn CheckpointRepository>) -> Self {
+    pub fn create(
+        checkpoint_repository: Box<dyn CheckpointRepository>,
+        projector_names: Vec<&'static str>,
+    ) -> Self {
         Self {
             checkpoint_repository,
+            projector_names,
+            clock: Rc::new(SystemClock),
         }
     }
 
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     pub fn handle(
         &self,
         command_envelope: CommandEnvelope<AdminCommand>,
@@ -28,21 +44,139 @@ impl AdminCommandProcessor {
                     aggregate_id = %aggregate_id,
                     "Rebuilding read models"
                 );
-                let result = self
-                    .checkpoint_repository
-                    .get_all_checkpoints()?
+                self.request_rebuilds(&self.projector_names)
+            }
+            AdminCommand::RebuildProjections {
+                aggregate_id,
+                projectors,
+            } => {
+                info!(
+                    aggregate_id = %aggregate_id,
+                    projectors = ?projectors,
+                    "Rebuilding projections"
+                );
+                if projectors.is_empty() {
+                    bail!("At least one projector must be named");
+                }
+                if let Some(unknown) = projectors
                     .iter()
-                    .map(|c| {
-                        self.checkpoint_repository
-                            .reset_checkpoint(&c.projector_name)
-                    })
-                    .all(|r| r.is_ok());
-
-                match result {
-                    true => Ok(()),
-                    false => Err(anyhow!("Failed to reset checkpoints")),
+                    .find(|name| !self.projector_names.contains(&name.as_str()))
+                {
+                    bail!("Unknown projector {unknown}");
                 }
+                self.request_rebuilds(&projectors)
             }
         }
     }
+
+    /// Records a rebuild checkpoint for each projector. The projection
+    /// processors pick these up on the next alarm, so live read models stay
+    /// readable until their rebuilt copies are swapped in. Requesting a
+    /// rebuild that is already running starts it again from the beginning.
+    fn request_rebuilds(&self, projector_names: &[impl AsRef<str>]) -> Result<()> {
+        for projector_name in projector_names {
+            self.checkpoint_repository
+                .update_checkpoint(&ProjectionCheckpoint::rebuild_requested(
+                    projector_name.as_ref(),
+                    self.clock.as_ref(),
+                ))
+                .with_context(|| {
+                    format!("Failed to request rebuild of {}", projector_name.as_ref())
+                })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fern_labour_event_sourcing_rs::{
+        CheckpointStatus, CommandMetadata, rebuild_checkpoint_name,
+        testing::{FixedClock, InMemoryCheckpointRepository},
+    };
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn processor() -> AdminCommandProcessor {
+        AdminCommandProcessor::create(
+            Box::new(InMemoryCheckpointRepository::new()),
+            vec![
+                "ContractionReadModelProjector",
+                "LabourStatusReadModelProjector",
+            ],
+        )
+        .with_clock(Rc::new(FixedClock::default()))
+    }
+
+    fn envelope(command: AdminCommand) -> CommandEnvelope<AdminCommand> {
+        let metadata = CommandMetadata::new(
+            command.labour_id(),
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            "admin".to_string(),
+            &FixedClock::default(),
+        );
+        CommandEnvelope::new(metadata, command)
+    }
+
+    fn admin() -> User {
+        User {
+            user_id: "admin".to_string(),
+            issuer: "test".to_string(),
+            email: None,
+            first_name: None,
+            last_name: None,
+            name: None,
+            phone_number: None,
+        }
+    }
+
+    #[test]
+    fn requests_a_rebuild_for_each_named_projector() {
+        let processor = processor();
+
+        processor
+            .handle(
+                envelope(AdminCommand::RebuildProjections {
+                    aggregate_id: Uuid::now_v7(),
+                    projectors: vec!["LabourStatusReadModelProjector".to_string()],
+                }),
+                admin(),
+            )
+            .unwrap();
+
+        let checkpoints = processor
+            .checkpoint_repository
+            .get_all_checkpoints()
+            .unwrap();
+        assert_eq!(checkpoints.len(), 1);
+        assert_eq!(
+            checkpoints[0].projector_name,
+            rebuild_checkpoint_name("LabourStatusReadModelProjector")
+        );
+        assert_eq!(checkpoints[0].status, CheckpointStatus::Rebuilding);
+    }
+
+    #[test]
+    fn rejects_unknown_projectors() {
+        let processor = processor();
+
+        let result = processor.handle(
+            envelope(AdminCommand::RebuildProjections {
+                aggregate_id: Uuid::now_v7(),
+                projectors: vec!["MissingProjector".to_string()],
+            }),
+            admin(),
+        );
+
+        assert!(result.is_err());
+        assert!(
+            processor
+                .checkpoint_repository
+                .get_all_checkpoints()
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
@@ -1,43 +1,265 @@
-use std::rc::Rc;
+use std::{collections::HashSet, rc::Rc};
 
 use anyhow::{Result, anyhow};
-use fern_labour_event_sourcing_rs::{Aggregate, AggregateRepositoryTrait};
+use chrono::Duration;
+use fern_labour_event_sourcing_rs::{
+    Aggregate, AggregateRepositoryTrait, CausationContext, Clock, IdempotencyGuard, SystemClock,
+    retry_on_conflict,
+};
+use fern_labour_labour_shared::{
+    CommandBatch,
+    commands::batch::{BatchCommandResult, CommandBatchResult},
+};
 use fern_labour_workers_shared::User;
+use uuid::Uuid;
 
 use crate::durable_object::{
     authorization::{Action, Authorizer, resolve_principal},
-    write_side::domain::{Labour, LabourCommand},
+    write_side::{
+        command_translator::CommandTranslator,
+        domain::{
+            Labour, LabourCommand, LabourEvent,
+            command_handlers::undo::UNDO_WINDOW_SECONDS,
+            commands::{
+                contraction::RecordContraction,
+                undo::{UndoLastAction, UndoableAction},
+            },
+        },
+    },
 };
 
+const MAX_CONCURRENCY_ATTEMPTS: usize = 3;
+
 #[derive(Clone)]
 pub struct LabourCommandProcessor {
     repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
+    idempotency: IdempotencyGuard,
     authorizer: Authorizer,
+    clock: Rc<dyn Clock>,
 }
 
 impl LabourCommandProcessor {
-    pub fn new(repository: Rc<dyn AggregateRepositoryTrait<Labour>>) -> Self {
+    pub fn new(
+        repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
+        idempotency: IdempotencyGuard,
+    ) -> Self {
         Self {
             repository,
+            idempotency,
             authorizer: Authorizer::new(),
+            clock: Rc::new(SystemClock),
         }
     }
 
-    pub fn handle_command(&self, command: LabourCommand, user: User) -> Result<()> {
-        let aggregate = self.repository.load()?;
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Handles `command` at most once per `idempotency_key`; repeats within the
+    /// retention window return the original outcome without executing again.
+    pub fn handle_idempotent_command(
+        &self,
+        command: LabourCommand,
+        user: User,
+        causation: CausationContext,
+        idempotency_key: &str,
+    ) -> Result<()> {
+        self.idempotency.execute(idempotency_key, || {
+            self.handle_command(command, user, causation)
+        })
+    }
+
+    pub fn handle_command(
+        &self,
+        command: LabourCommand,
+        user: User,
+        causation: CausationContext,
+    ) -> Result<()> {
+        retry_on_conflict(MAX_CONCURRENCY_ATTEMPTS, || {
+            self.try_handle_command(command.clone(), &user, causation)
+        })
+        .map(|_| ())
+    }
 
-        let principal = resolve_principal(&user, aggregate.as_ref());
+    /// Applies an offline batch in order, giving each command its own result.
+    /// Commands are idempotent on their client id, so a batch resent after a
+    /// lost response is not applied twice.
+    ///
+    /// Conflicts are settled in favour of what the server already has: a
+    /// contraction overlapping one already recorded, typically by a birth
+    /// partner while the mother was offline, is not applied, and later
+    /// commands in the batch for it are skipped. A contraction started and
+    /// ended within the batch is recorded whole, so it can fill a gap between
+    /// contractions others logged in the meantime, unless an earlier batch
+    /// already started it.
+    pub fn handle_command_batch(&self, batch: CommandBatch, user: User) -> CommandBatchResult {
+        let now = self.clock.now();
+        let causation = CausationContext::new_request();
+
+        let mut labour = match self.repository.load() {
+            Ok(labour) => labour,
+            Err(e) => {
+                let results = batch
+                    .commands
+                    .into_iter()
+                    .map(|batched| BatchCommandResult::rejected(batched.client_id, e.to_string()))
+                    .collect();
+                return CommandBatchResult { results };
+            }
+        };
+
+        let mut commands = batch
+            .commands
+            .into_iter()
+            .map(|batched| {
+                let client_id = batched.client_id.clone();
+                let command = if batched.command.labour_id() != batch.labour_id {
+                    Err(anyhow!("Command is for a different labour"))
+                } else {
+                    CommandTranslator::translate(
+                        batched.into_command_at(now),
+                        &user,
+                        self.clock.as_ref(),
+                    )
+                };
+                (client_id, command)
+            })
+            .peekable();
+
+        let mut dropped_contractions = HashSet::new();
+        let mut results = vec![];
+
+        while let Some((client_id, command)) = commands.next() {
+            let command = match command {
+                Ok(command) => command,
+                Err(e) => {
+                    results.push(BatchCommandResult::rejected(client_id, e.to_string()));
+                    continue;
+                }
+            };
+
+            if contraction_id(&command).is_some_and(|id| dropped_contractions.contains(&id)) {
+                results.push(BatchCommandResult::skipped(client_id));
+                continue;
+            }
+
+            let mut client_ids = vec![client_id];
+            let command = match command {
+                LabourCommand::StartContraction(start)
+                    if !is_in_progress(labour.as_ref(), start.contraction_id) =>
+                {
+                    match commands.next_if(|(_, next)| {
+                        matches!(next, Ok(LabourCommand::EndContraction(end))
+                            if end.contraction_id == start.contraction_id)
+                    }) {
+                        Some((end_client_id, Ok(LabourCommand::EndContraction(end)))) => {
+                            client_ids.push(end_client_id);
+                            LabourCommand::RecordContraction(RecordContraction {
+                                labour_id: start.labour_id,
+                                contraction_id: start.contraction_id,
+                                start_time: start.start_time,
+                                end_time: end.end_time,
+                                intensity: end.intensity,
+                            })
+                        }
+                        _ => LabourCommand::StartContraction(start),
+                    }
+                }
+                command => command,
+            };
+
+            let idempotency_key = format!("batch:{}:{}", user.user_id, client_ids.join("+"));
+            let outcome = match conflicting_contraction(labour.as_ref(), &command) {
+                Some(conflicting_id) => Ok(Some(conflicting_id)),
+                None => self
+                    .handle_batched_command(command.clone(), &user, causation, &idempotency_key)
+                    .map(|events| {
+                        labour = match labour.take() {
+                            Some(mut labour) => {
+                                events.iter().for_each(|event| labour.apply(event));
+                                Some(labour)
+                            }
+                            None => Labour::from_events(&events),
+                        };
+                        None
+                    }),
+            };
+
+            if !matches!(outcome, Ok(None))
+                && let Some(id) = new_contraction_id(&command)
+            {
+                dropped_contractions.insert(id);
+            }
+
+            results.extend(client_ids.into_iter().map(|client_id| match &outcome {
+                Ok(None) => BatchCommandResult::applied(client_id),
+                Ok(Some(conflicting_id)) => {
+                    BatchCommandResult::conflict(client_id, *conflicting_id)
+                }
+                Err(e) => BatchCommandResult::rejected(client_id, e.to_string()),
+            }));
+        }
+
+        CommandBatchResult { results }
+    }
+
+    /// Handles a batched command at most once per `idempotency_key`,
+    /// returning the events it recorded. A command already processed by an
+    /// earlier batch records none, its effect being part of the state the
+    /// batch started from.
+    fn handle_batched_command(
+        &self,
+        command: LabourCommand,
+        user: &User,
+        causation: CausationContext,
+        idempotency_key: &str,
+    ) -> Result<Vec<LabourEvent>> {
+        let mut events = vec![];
+        self.idempotency.execute(idempotency_key, || {
+            events = retry_on_conflict(MAX_CONCURRENCY_ATTEMPTS, || {
+                self.try_handle_command(command.clone(), user, causation)
+            })?;
+            Ok(())
+        })?;
+        Ok(events)
+    }
+
+    fn try_handle_command(
+        &self,
+        command: LabourCommand,
+        user: &User,
+        causation: CausationContext,
+    ) -> Result<Vec<LabourEvent>> {
+        let (aggregate, version) = self.repository.load_with_version()?;
+
+        let command = match command {
+            LabourCommand::UndoLastAction(cmd) => {
+                // Only actions inside the undo window can be undone, and an
+                // undo is always recorded after the action it reverses.
+                let events = self.repository.load_events_since_time(
+                    self.clock.now() - Duration::seconds(UNDO_WINDOW_SECONDS),
+                )?;
+                LabourCommand::UndoLastAction(UndoLastAction {
+                    last_action: UndoableAction::latest_for_user(&events, &user.user_id),
+                    ..cmd
+                })
+            }
+            command => command,
+        };
+
+        let principal = resolve_principal(user, aggregate.as_ref());
         let action = Action::Command(command.clone());
 
         self.authorizer
             .authorize(&principal, &action, aggregate.as_ref())
             .map_err(|e| anyhow!("Authorization failed: {}", e))?;
 
-        let events = Labour::handle_command(aggregate.as_ref(), command)
+        let events = Labour::handle_command(aggregate.as_ref(), command, self.clock.as_ref())
             .map_err(|e| anyhow!("Domain error: {}", e))?;
 
         if events.is_empty() {
-            return Ok(());
+            return Ok(events);
         }
 
         let updated_aggregate = match aggregate {
@@ -50,9 +272,568 @@ impl LabourCommandProcessor {
             None => Labour::from_events(&events),
         };
 
-        self.repository
-            .save(updated_aggregate.as_ref(), &events, user.user_id)?;
+        self.repository.save(
+            updated_aggregate.as_ref(),
+            &events,
+            user.user_id.clone(),
+            causation,
+            version,
+        )?;
+
+        Ok(events)
+    }
+}
+
+/// The recorded contraction that `command` would add an overlapping
+/// contraction to. Contractions already recorded under the same id are
+/// resubmissions, which the idempotency guard deals with instead.
+fn conflicting_contraction(labour: Option<&Labour>, command: &LabourCommand) -> Option<Uuid> {
+    let (contraction_id, start_time, end_time) = match command {
+        LabourCommand::StartContraction(cmd) => (cmd.contraction_id, cmd.start_time, None),
+        LabourCommand::RecordContraction(cmd) => {
+            (cmd.contraction_id, cmd.start_time, Some(cmd.end_time))
+        }
+        _ => return None,
+    };
+
+    let labour = labour?;
+    if labour.find_contraction(contraction_id).is_some() {
+        return None;
+    }
+
+    labour
+        .find_overlapping_contraction(start_time, end_time)
+        .map(|contraction| contraction.id())
+}
+
+/// Whether `contraction_id` was started by an earlier request and not yet
+/// ended, so its end must be applied on its own rather than merged.
+fn is_in_progress(labour: Option<&Labour>, contraction_id: Uuid) -> bool {
+    labour
+        .and_then(|labour| labour.find_contraction(contraction_id))
+        .is_some_and(|contraction| contraction.is_active())
+}
+
+fn contraction_id(command: &LabourCommand) -> Option<Uuid> {
+    match command {
+        LabourCommand::StartContraction(cmd) => Some(cmd.contraction_id),
+        LabourCommand::EndContraction(cmd) => Some(cmd.contraction_id),
+        LabourCommand::RecordContraction(cmd) => Some(cmd.contraction_id),
+        LabourCommand::UpdateContraction(cmd) => Some(cmd.contraction_id),
+        LabourCommand::DeleteContraction(cmd) => Some(cmd.contraction_id),
+        _ => None,
+    }
+}
+
+fn new_contraction_id(command: &LabourCommand) -> Option<Uuid> {
+    match command {
+        LabourCommand::StartContraction(cmd) => Some(cmd.contraction_id),
+        LabourCommand::RecordContraction(cmd) => Some(cmd.contraction_id),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Duration, Utc};
+    use fern_labour_event_sourcing_rs::{
+        EffectExecutor, ProcessEffect, ProcessManager,
+        testing::{
+            AggregateTestHarness, FixedClock, InMemoryEffectLedger, InMemoryProcessedCommandStore,
+        },
+    };
+    use fern_labour_labour_shared::{
+        ApiCommand, ContractionCommand,
+        commands::batch::{BatchCommandStatus, BatchedCommand},
+        value_objects::LabourUpdateType,
+    };
+    use futures::executor::block_on;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::durable_object::write_side::{
+        domain::{
+            LabourEvent,
+            commands::{
+                labour::{BeginLabour, PlanLabour},
+                labour_update::PostLabourUpdate,
+            },
+            events::{
+                ContractionEnded, ContractionStarted, LabourPlanned, SubscriberApproved,
+                SubscriberRequested,
+            },
+        },
+        process_manager::Effect,
+    };
+
+    const MOTHER_ID: &str = "mother_123";
+
+    fn user(user_id: &str) -> User {
+        User {
+            user_id: user_id.to_string(),
+            issuer: "test".to_string(),
+            email: None,
+            phone_number: None,
+            first_name: None,
+            last_name: None,
+            name: None,
+        }
+    }
+
+    fn processor(harness: &AggregateTestHarness<Labour>) -> LabourCommandProcessor {
+        LabourCommandProcessor::new(
+            harness.repository(),
+            IdempotencyGuard::new(
+                Rc::new(InMemoryProcessedCommandStore::new()),
+                Duration::hours(1),
+            ),
+        )
+        .with_clock(harness.clock())
+    }
+
+    fn plan_labour(labour_id: Uuid) -> LabourCommand {
+        LabourCommand::PlanLabour(PlanLabour {
+            labour_id,
+            mother_id: MOTHER_ID.to_string(),
+            mother_name: "Test Mother".to_string(),
+            first_labour: true,
+            due_date: FixedClock::default().now(),
+            labour_name: None,
+        })
+    }
+
+    #[test]
+    fn planning_labour_generates_a_subscription_token() {
+        let harness = AggregateTestHarness::<Labour>::new();
+        let processor = processor(&harness);
+        let labour_id = Uuid::now_v7();
+
+        let outcome = harness.when_processed(|| {
+            processor.handle_command(
+                plan_labour(labour_id),
+                user(MOTHER_ID),
+                CausationContext::new_request(),
+            )
+        });
+        let effects: Vec<Effect> = outcome.then_route_policies();
+
+        assert!(matches!(
+            outcome.events().as_slice(),
+            [
+                LabourEvent::LabourPlanned(..),
+                LabourEvent::LabourPhaseChanged(..)
+            ]
+        ));
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::GenerateSubscriptionToken { labour_id: id, .. }] if *id == labour_id
+        ));
+    }
+
+    #[test]
+    fn unauthorized_users_cannot_modify_labour() {
+        let labour_id = Uuid::now_v7();
+        let harness = AggregateTestHarness::<Labour>::new();
+        let processor = processor(&harness);
+        harness
+            .when_processed(|| {
+                processor.handle_command(
+                    plan_labour(labour_id),
+                    user(MOTHER_ID),
+                    CausationContext::new_request(),
+                )
+            })
+            .envelopes();
+
+        harness
+            .when_processed(|| {
+                processor.handle_command(
+                    LabourCommand::BeginLabour(BeginLabour { labour_id }),
+                    user("stranger"),
+                    CausationContext::new_request(),
+                )
+            })
+            .then_expect_error("Authorization failed");
+    }
+
+    #[test]
+    fn undo_reverses_the_users_last_action_once() {
+        let harness = AggregateTestHarness::<Labour>::new();
+        let processor = processor(&harness);
+        let labour_id = Uuid::now_v7();
+        for command in [
+            plan_labour(labour_id),
+            LabourCommand::BeginLabour(BeginLabour { labour_id }),
+            LabourCommand::PostLabourUpdate(PostLabourUpdate {
+                labour_id,
+                labour_update_type: LabourUpdateType::ANNOUNCEMENT,
+                message: "Baby is here!".to_string(),
+                scheduled_update_id: None,
+            }),
+        ] {
+            processor
+                .handle_command(command, user(MOTHER_ID), CausationContext::new_request())
+                .unwrap();
+        }
+        let posted_sequence = harness.event_store().max_sequence().unwrap().unwrap();
+        let undo = || {
+            processor.handle_command(
+                LabourCommand::UndoLastAction(UndoLastAction {
+                    labour_id,
+                    last_action: None,
+                }),
+                user(MOTHER_ID),
+                CausationContext::new_request(),
+            )
+        };
+
+        let outcome = harness.when_processed(undo);
+
+        assert!(matches!(
+            outcome.events().as_slice(),
+            [
+                LabourEvent::LabourUpdateDeleted(..),
+                LabourEvent::ActionUndone(e),
+            ] if e.undone_sequence == posted_sequence
+        ));
+        harness
+            .when_processed(undo)
+            .then_expect_error("Nothing to undo");
+    }
+
+    #[derive(Default)]
+    struct RecordingExecutor {
+        executed: RefCell<Vec<Effect>>,
+    }
+
+    #[async_trait(?Send)]
+    impl EffectExecutor<Effect> for Rc<RecordingExecutor> {
+        async fn execute(&self, effect: &Effect, _: CausationContext) -> Result<()> {
+            self.executed.borrow_mut().push(effect.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn undo_before_dispatch_cancels_the_actions_notifications() {
+        let labour_id = Uuid::now_v7();
+        let subscription_id = Uuid::now_v7();
+        // The undo window is measured from when events were stored, which
+        // the in-memory event store takes from the system clock.
+        let harness = AggregateTestHarness::<Labour>::new()
+            .with_user(MOTHER_ID)
+            .with_clock(FixedClock::new(Utc::now()))
+            .given([
+                LabourEvent::LabourPlanned(LabourPlanned {
+                    labour_id,
+                    mother_id: MOTHER_ID.to_string(),
+                    mother_name: "Test Mother".to_string(),
+                    first_labour: true,
+                    due_date: Utc::now(),
+                    labour_name: None,
+                }),
+                LabourEvent::SubscriberRequested(SubscriberRequested {
+                    labour_id,
+                    subscriber_id: "subscriber".to_string(),
+                    subscription_id,
+                }),
+                LabourEvent::SubscriberApproved(SubscriberApproved {
+                    labour_id,
+                    subscription_id,
+                }),
+            ]);
+        let processor = processor(&harness);
+        let ledger = Rc::new(InMemoryEffectLedger::new());
+        let executor = Rc::new(RecordingExecutor::default());
+        let process_manager = ProcessManager::new(
+            ledger.clone(),
+            executor.clone(),
+            harness.event_store(),
+            harness.repository(),
+            100,
+            3,
+        );
+        let approved_effects = || {
+            ledger
+                .effects()
+                .into_iter()
+                .filter(|record| record.event_sequence == 3)
+                .collect::<Vec<_>>()
+        };
+
+        harness.clock().advance(Duration::minutes(1));
+        processor
+            .handle_command(
+                LabourCommand::UndoLastAction(UndoLastAction {
+                    labour_id,
+                    last_action: None,
+                }),
+                user(MOTHER_ID),
+                CausationContext::new_request(),
+            )
+            .unwrap();
+        block_on(process_manager.on_alarm()).unwrap();
+
+        assert!(!approved_effects().is_empty());
+        assert!(
+            approved_effects()
+                .iter()
+                .all(|record| record.status == "CANCELLED")
+        );
+        let approved_keys: Vec<String> = approved_effects()
+            .into_iter()
+            .map(|record| record.idempotency_key)
+            .collect();
+        assert!(
+            executor
+                .executed
+                .borrow()
+                .iter()
+                .all(|effect| !approved_keys.contains(&effect.idempotency_key().0))
+        );
+    }
+
+    #[test]
+    fn idempotent_commands_only_execute_once() {
+        let harness = AggregateTestHarness::<Labour>::new();
+        let processor = processor(&harness);
+        let labour_id = Uuid::now_v7();
+        let handle = || {
+            processor.handle_idempotent_command(
+                plan_labour(labour_id),
+                user(MOTHER_ID),
+                CausationContext::new_request(),
+                "plan-labour",
+            )
+        };
+
+        assert_eq!(harness.when_processed(handle).events().len(), 2);
+        harness.when_processed(handle).then_expect_no_events();
+    }
+
+    /// A begun labour with one contraction the birth partner recorded from
+    /// `partner_start` for a minute.
+    fn labour_with_partner_contraction(
+        labour_id: Uuid,
+        partner_contraction_id: Uuid,
+        partner_start: DateTime<Utc>,
+    ) -> AggregateTestHarness<Labour> {
+        let harness = AggregateTestHarness::<Labour>::new();
+        let processor = processor(&harness);
+        for command in [
+            plan_labour(labour_id),
+            LabourCommand::BeginLabour(BeginLabour { labour_id }),
+        ] {
+            processor
+                .handle_command(command, user(MOTHER_ID), CausationContext::new_request())
+                .unwrap();
+        }
+
+        harness.given([
+            LabourEvent::ContractionStarted(ContractionStarted {
+                labour_id,
+                contraction_id: partner_contraction_id,
+                start_time: partner_start,
+            }),
+            LabourEvent::ContractionEnded(ContractionEnded {
+                labour_id,
+                contraction_id: partner_contraction_id,
+                end_time: partner_start + Duration::minutes(1),
+                intensity: 5,
+            }),
+        ])
+    }
+
+    /// Start and end commands for a contraction tapped on a device at
+    /// `start`, lasting a minute, with times left to the server.
+    fn offline_contraction(
+        labour_id: Uuid,
+        contraction_id: Uuid,
+        start: DateTime<Utc>,
+    ) -> [BatchedCommand; 2] {
+        [
+            BatchedCommand {
+                client_id: format!("{contraction_id}:start"),
+                device_timestamp: start,
+                command: ApiCommand::Contraction(ContractionCommand::StartContraction {
+                    labour_id,
+                    contraction_id,
+                    start_time: None,
+                }),
+            },
+            BatchedCommand {
+                client_id: format!("{contraction_id}:end"),
+                device_timestamp: start + Duration::minutes(1),
+                command: ApiCommand::Contraction(ContractionCommand::EndContraction {
+                    labour_id,
+                    contraction_id,
+                    end_time: None,
+                    intensity: 6,
+                }),
+            },
+        ]
+    }
+
+    fn statuses(result: &CommandBatchResult) -> Vec<BatchCommandStatus> {
+        result.results.iter().map(|r| r.status.clone()).collect()
+    }
+
+    #[test]
+    fn offline_contractions_fill_gaps_but_lose_to_overlapping_ones() {
+        let labour_id = Uuid::now_v7();
+        let partner_contraction_id = Uuid::now_v7();
+        let base = FixedClock::default().now() - Duration::hours(1);
+        let harness = labour_with_partner_contraction(
+            labour_id,
+            partner_contraction_id,
+            base + Duration::minutes(10),
+        );
+        let processor = processor(&harness);
+
+        let overlapping_id = Uuid::now_v7();
+        let mut commands = vec![];
+        commands.extend(offline_contraction(labour_id, Uuid::now_v7(), base));
+        commands.extend(offline_contraction(
+            labour_id,
+            overlapping_id,
+            base + Duration::seconds(630),
+        ));
+        commands.push(BatchedCommand {
+            client_id: "update".to_string(),
+            device_timestamp: base + Duration::minutes(12),
+            command: ApiCommand::Contraction(ContractionCommand::UpdateContraction {
+                labour_id,
+                contraction_id: overlapping_id,
+                start_time: None,
+                end_time: None,
+                intensity: Some(8),
+            }),
+        });
+
+        let result = processor.handle_command_batch(
+            CommandBatch {
+                labour_id,
+                commands,
+            },
+            user(MOTHER_ID),
+        );
+
+        assert_eq!(
+            statuses(&result),
+            [
+                BatchCommandStatus::APPLIED,
+                BatchCommandStatus::APPLIED,
+                BatchCommandStatus::CONFLICT,
+                BatchCommandStatus::CONFLICT,
+                BatchCommandStatus::SKIPPED,
+            ]
+        );
+        assert_eq!(
+            result.results[2].conflicting_contraction_id,
+            Some(partner_contraction_id)
+        );
+
+        let labour = harness.state().unwrap();
+        let starts: Vec<_> = labour
+            .contractions()
+            .iter()
+            .map(|c| *c.start_time())
+            .collect();
+        assert_eq!(starts, [base, base + Duration::minutes(10)]);
+    }
+
+    #[test]
+    fn resent_batches_are_not_applied_twice() {
+        let labour_id = Uuid::now_v7();
+        let base = FixedClock::default().now() - Duration::hours(1);
+        let harness = labour_with_partner_contraction(
+            labour_id,
+            Uuid::now_v7(),
+            base + Duration::minutes(10),
+        );
+        let processor = processor(&harness);
+        let batch = CommandBatch {
+            labour_id,
+            commands: offline_contraction(labour_id, Uuid::now_v7(), base).to_vec(),
+        };
+
+        processor.handle_command_batch(batch.clone(), user(MOTHER_ID));
+        let mut resent = None;
+        harness
+            .when_processed(|| {
+                resent = Some(processor.handle_command_batch(batch, user(MOTHER_ID)));
+                Ok(())
+            })
+            .then_expect_no_events();
+
+        assert_eq!(
+            statuses(&resent.unwrap()),
+            [BatchCommandStatus::APPLIED, BatchCommandStatus::APPLIED]
+        );
+    }
+
+    #[test]
+    fn an_end_resent_with_an_already_applied_start_is_still_applied() {
+        let labour_id = Uuid::now_v7();
+        let contraction_id = Uuid::now_v7();
+        let base = FixedClock::default().now() - Duration::hours(1);
+        let harness = labour_with_partner_contraction(
+            labour_id,
+            Uuid::now_v7(),
+            base + Duration::minutes(10),
+        );
+        let processor = processor(&harness);
+        let [start, end] =
+            offline_contraction(labour_id, contraction_id, base + Duration::minutes(20));
+
+        processor.handle_command_batch(
+            CommandBatch {
+                labour_id,
+                commands: vec![start.clone()],
+            },
+            user(MOTHER_ID),
+        );
+        let result = processor.handle_command_batch(
+            CommandBatch {
+                labour_id,
+                commands: vec![start, end],
+            },
+            user(MOTHER_ID),
+        );
+
+        assert_eq!(
+            statuses(&result),
+            [BatchCommandStatus::APPLIED, BatchCommandStatus::APPLIED]
+        );
+        let labour = harness.state().unwrap();
+        assert!(!labour.find_contraction(contraction_id).unwrap().is_active());
+    }
+
+    #[test]
+    fn commands_for_another_labour_are_rejected() {
+        let labour_id = Uuid::now_v7();
+        let base = FixedClock::default().now() - Duration::hours(1);
+        let harness = labour_with_partner_contraction(
+            labour_id,
+            Uuid::now_v7(),
+            base + Duration::minutes(10),
+        );
+        let processor = processor(&harness);
+
+        let result = processor.handle_command_batch(
+            CommandBatch {
+                labour_id,
+                commands: offline_contraction(Uuid::now_v7(), Uuid::now_v7(), base).to_vec(),
+            },
+            user(MOTHER_ID),
+        );
 
-        Ok(())
+        assert_eq!(
+            statuses(&result),
+            [BatchCommandStatus::REJECTED, BatchCommandStatus::REJECTED]
+        );
     }
 }
@@ -3,4 +3,7 @@ pub mod services;
 
 pub use command_processors::admin::AdminCommandProcessor;
 pub use command_processors::labour::LabourCommandProcessor;
-pub use services::{CheckoutService, CheckoutSessionResult};
+pub use services::{
+    AbandonedContractionMonitor, CheckoutService, CheckoutSessionResult,
+    ScheduledLabourUpdatePublisher,
+};
@@ -0,0 +1,180 @@
+use std::rc::Rc;
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use fern_labour_event_sourcing_rs::{
+    AggregateRepositoryTrait, CausationContext, Clock, SystemClock,
+};
+use fern_labour_workers_shared::User;
+
+use crate::durable_object::write_side::{
+    application::LabourCommandProcessor,
+    domain::{Labour, LabourCommand, commands::contraction::AbandonContraction},
+};
+
+/// Closes contractions left running when the app was killed mid-contraction,
+/// which would otherwise block new contractions indefinitely.
+pub struct AbandonedContractionMonitor {
+    repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
+    command_processor: Rc<LabourCommandProcessor>,
+    max_duration: Duration,
+    notify_mother: bool,
+    clock: Rc<dyn Clock>,
+}
+
+impl AbandonedContractionMonitor {
+    pub fn new(
+        repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
+        command_processor: Rc<LabourCommandProcessor>,
+        max_duration: Duration,
+        notify_mother: bool,
+    ) -> Self {
+        Self {
+            repository,
+            command_processor,
+            max_duration,
+            notify_mother,
+            clock: Rc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Abandons the active contraction once it has run for the maximum
+    /// duration, ending it at that limit. Returns when the active contraction
+    /// next needs checking, or `None` when nothing is being timed.
+    pub fn check(&self) -> Result<Option<DateTime<Utc>>> {
+        let Some(labour) = self.repository.load()? else {
+            return Ok(None);
+        };
+        let Some(contraction) = labour.find_active_contraction() else {
+            return Ok(None);
+        };
+
+        let deadline = *contraction.start_time() + self.max_duration;
+        if self.clock.now() < deadline {
+            return Ok(Some(deadline));
+        }
+
+        let command = LabourCommand::AbandonContraction(AbandonContraction {
+            labour_id: labour.id(),
+            contraction_id: contraction.id(),
+            end_time: deadline,
+            notify_mother: self.notify_mother,
+        });
+        self.command_processor.handle_idempotent_command(
+            command,
+            User::internal("contraction-monitor"),
+            CausationContext::new_request(),
+            &format!("abandon-contraction:{}", contraction.id()),
+        )?;
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fern_labour_event_sourcing_rs::{
+        IdempotencyGuard,
+        testing::{AggregateTestHarness, FixedClock, InMemoryProcessedCommandStore},
+    };
+    use fern_labour_labour_shared::value_objects::LabourPhase;
+    use uuid::Uuid;
+
+    use crate::durable_object::write_side::domain::{
+        LabourEvent,
+        events::{
+            ContractionAbandoned, ContractionStarted, LabourBegun, LabourPhaseChanged,
+            LabourPlanned,
+        },
+    };
+
+    const MAX_DURATION_MINUTES: i64 = 20;
+
+    fn monitor(harness: &AggregateTestHarness<Labour>) -> AbandonedContractionMonitor {
+        let processor = LabourCommandProcessor::new(
+            harness.repository(),
+            IdempotencyGuard::new(
+                Rc::new(InMemoryProcessedCommandStore::new()),
+                Duration::hours(1),
+            ),
+        )
+        .with_clock(harness.clock());
+
+        AbandonedContractionMonitor::new(
+            harness.repository(),
+            Rc::new(processor),
+            Duration::minutes(MAX_DURATION_MINUTES),
+            true,
+        )
+        .with_clock(harness.clock())
+    }
+
+    fn labour_with_active_contraction(
+        labour_id: Uuid,
+        contraction_id: Uuid,
+        start_time: DateTime<Utc>,
+    ) -> AggregateTestHarness<Labour> {
+        AggregateTestHarness::<Labour>::new().given([
+            LabourEvent::LabourPlanned(LabourPlanned {
+                labour_id,
+                mother_id: "mother_123".to_string(),
+                mother_name: "Test Mother".to_string(),
+                first_labour: true,
+                due_date: start_time,
+                labour_name: None,
+            }),
+            LabourEvent::LabourBegun(LabourBegun {
+                labour_id,
+                start_time,
+            }),
+            LabourEvent::LabourPhaseChanged(LabourPhaseChanged {
+                labour_id,
+                labour_phase: LabourPhase::EARLY,
+            }),
+            LabourEvent::ContractionStarted(ContractionStarted {
+                labour_id,
+                contraction_id,
+                start_time,
+            }),
+        ])
+    }
+
+    #[test]
+    fn contractions_within_the_limit_are_checked_again_at_the_limit() {
+        let start_time = FixedClock::default().now() - Duration::minutes(5);
+        let harness = labour_with_active_contraction(Uuid::now_v7(), Uuid::now_v7(), start_time);
+
+        let next_check = monitor(&harness).check().unwrap();
+
+        assert_eq!(
+            next_check,
+            Some(start_time + Duration::minutes(MAX_DURATION_MINUTES))
+        );
+        assert!(harness.state().unwrap().find_active_contraction().is_some());
+    }
+
+    #[test]
+    fn contractions_past_the_limit_are_abandoned_at_the_limit() {
+        let labour_id = Uuid::now_v7();
+        let contraction_id = Uuid::now_v7();
+        let start_time = FixedClock::default().now() - Duration::hours(2);
+        let harness = labour_with_active_contraction(labour_id, contraction_id, start_time);
+        let monitor = monitor(&harness);
+
+        let outcome = harness.when_processed(|| monitor.check().map(|_| ()));
+
+        outcome.then_expect_events(&[LabourEvent::ContractionAbandoned(ContractionAbandoned {
+            labour_id,
+            contraction_id,
+            end_time: start_time + Duration::minutes(MAX_DURATION_MINUTES),
+            notify_mother: true,
+        })]);
+        assert_eq!(monitor.check().unwrap(), None);
+    }
+}
@@ -1,3 +1,7 @@
+pub mod abandoned_contraction_monitor;
 pub mod checkout;
+pub mod scheduled_labour_update_publisher;
 
+pub use abandoned_contraction_monitor::AbandonedContractionMonitor;
 pub use checkout::{CheckoutService, CheckoutSessionResult};
+pub use scheduled_labour_update_publisher::ScheduledLabourUpdatePublisher;
@@ -0,0 +1,323 @@
+use std::{collections::HashSet, rc::Rc};
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use fern_labour_event_sourcing_rs::{
+    AggregateRepositoryTrait, CausationContext, Clock, SystemClock,
+};
+use fern_labour_labour_shared::value_objects::LabourUpdateType;
+use fern_labour_workers_shared::User;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::durable_object::write_side::{
+    application::LabourCommandProcessor,
+    domain::{
+        Labour, LabourCommand,
+        commands::labour_update::PostLabourUpdate,
+        entities::{
+            labour_update::ANNOUNCEMENT_COOLDOWN_SECONDS,
+            scheduled_labour_update::ScheduledLabourUpdate,
+        },
+    },
+};
+
+/// Posts scheduled labour updates once their trigger fires.
+pub struct ScheduledLabourUpdatePublisher {
+    repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
+    command_processor: Rc<LabourCommandProcessor>,
+    clock: Rc<dyn Clock>,
+}
+
+impl ScheduledLabourUpdatePublisher {
+    pub fn new(
+        repository: Rc<dyn AggregateRepositoryTrait<Labour>>,
+        command_processor: Rc<LabourCommandProcessor>,
+    ) -> Self {
+        Self {
+            repository,
+            command_processor,
+            clock: Rc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Posts the scheduled updates that are due as the user who scheduled
+    /// them, holding back announcements until the announcement cooldown has
+    /// passed. Each due update is tried once; one that fails is logged and
+    /// left for a later check rather than holding up the rest. Returns when a
+    /// held back or timed update next needs checking, or `None` when only
+    /// phase triggers remain, since phase changes come from commands that
+    /// re-run this check.
+    pub fn check(&self) -> Result<Option<DateTime<Utc>>> {
+        let Some(labour) = self.repository.load()? else {
+            return Ok(None);
+        };
+        let now = self.clock.now();
+
+        let mut failed = HashSet::new();
+        let mut announcement_posted = false;
+        for scheduled_update in labour.scheduled_labour_updates() {
+            let is_announcement =
+                scheduled_update.labour_update_type() == &LabourUpdateType::ANNOUNCEMENT;
+            // Posting one announcement starts the cooldown for the others.
+            if !Self::is_due(&labour, scheduled_update, now)
+                || (is_announcement && announcement_posted)
+            {
+                continue;
+            }
+
+            match self.publish(&labour, scheduled_update) {
+                Ok(()) => announcement_posted |= is_announcement,
+                Err(e) => {
+                    error!(
+                        scheduled_update_id = %scheduled_update.id(),
+                        error = %e,
+                        "Failed to publish scheduled labour update"
+                    );
+                    failed.insert(scheduled_update.id());
+                }
+            }
+        }
+
+        let Some(labour) = self.repository.load()? else {
+            return Ok(None);
+        };
+        Ok(Self::next_check(&labour, now, &failed))
+    }
+
+    fn publish(&self, labour: &Labour, scheduled_update: &ScheduledLabourUpdate) -> Result<()> {
+        let command = LabourCommand::PostLabourUpdate(PostLabourUpdate {
+            labour_id: labour.id(),
+            labour_update_type: scheduled_update.labour_update_type().clone(),
+            message: scheduled_update.message().to_string(),
+            scheduled_update_id: Some(scheduled_update.id()),
+        });
+        self.command_processor.handle_idempotent_command(
+            command,
+            User::acting_for(scheduled_update.scheduled_by()),
+            CausationContext::new_request(),
+            &format!("publish-scheduled-update:{}", scheduled_update.id()),
+        )
+    }
+
+    fn is_due(
+        labour: &Labour,
+        scheduled_update: &ScheduledLabourUpdate,
+        now: DateTime<Utc>,
+    ) -> bool {
+        scheduled_update.trigger().is_due(now, labour.phase())
+            && (scheduled_update.labour_update_type() != &LabourUpdateType::ANNOUNCEMENT
+                || labour.can_send_announcement(now))
+    }
+
+    /// Updates that failed to post are left out, so a failure that persists
+    /// does not keep the alarm firing.
+    fn next_check(
+        labour: &Labour,
+        now: DateTime<Utc>,
+        failed: &HashSet<Uuid>,
+    ) -> Option<DateTime<Utc>> {
+        labour
+            .scheduled_labour_updates()
+            .iter()
+            .filter(|su| !failed.contains(&su.id()))
+            .filter_map(|su| {
+                if !su.trigger().is_due(now, labour.phase()) {
+                    return su.trigger().send_at();
+                }
+                // Only announcements held back by the cooldown are left due.
+                labour.find_last_announcement().map(|last| {
+                    *last.sent_time() + Duration::seconds(ANNOUNCEMENT_COOLDOWN_SECONDS + 1)
+                })
+            })
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fern_labour_event_sourcing_rs::{
+        EventDescriptor, IdempotencyGuard,
+        testing::{AggregateTestHarness, FixedClock, InMemoryProcessedCommandStore},
+    };
+    use fern_labour_labour_shared::value_objects::{LabourPhase, LabourUpdateTrigger};
+
+    use crate::durable_object::write_side::domain::{
+        LabourEvent,
+        events::{LabourPlanned, LabourUpdatePosted, LabourUpdateScheduled},
+    };
+
+    fn publisher(harness: &AggregateTestHarness<Labour>) -> ScheduledLabourUpdatePublisher {
+        let processor = LabourCommandProcessor::new(
+            harness.repository(),
+            IdempotencyGuard::new(
+                Rc::new(InMemoryProcessedCommandStore::new()),
+                Duration::hours(1),
+            ),
+        )
+        .with_clock(harness.clock());
+
+        ScheduledLabourUpdatePublisher::new(harness.repository(), Rc::new(processor))
+            .with_clock(harness.clock())
+    }
+
+    fn labour_with_scheduled_updates(
+        labour_id: Uuid,
+        scheduled: &[(Uuid, LabourUpdateType, LabourUpdateTrigger)],
+    ) -> AggregateTestHarness<Labour> {
+        let scheduled: Vec<_> = scheduled
+            .iter()
+            .map(|(id, labour_update_type, trigger)| {
+                (
+                    *id,
+                    labour_update_type.clone(),
+                    trigger.clone(),
+                    "mother_123",
+                )
+            })
+            .collect();
+        labour_with_updates_scheduled_by(labour_id, &scheduled)
+    }
+
+    fn labour_with_updates_scheduled_by(
+        labour_id: Uuid,
+        scheduled: &[(Uuid, LabourUpdateType, LabourUpdateTrigger, &str)],
+    ) -> AggregateTestHarness<Labour> {
+        let mut events = vec![LabourEvent::LabourPlanned(LabourPlanned {
+            labour_id,
+            mother_id: "mother_123".to_string(),
+            mother_name: "Test Mother".to_string(),
+            first_labour: true,
+            due_date: FixedClock::default().now(),
+            labour_name: None,
+        })];
+        events.extend(
+            scheduled
+                .iter()
+                .map(|(id, labour_update_type, trigger, scheduled_by)| {
+                    LabourEvent::LabourUpdateScheduled(LabourUpdateScheduled {
+                        labour_id,
+                        scheduled_update_id: *id,
+                        labour_update_type: labour_update_type.clone(),
+                        message: "Scheduled".to_string(),
+                        trigger: trigger.clone(),
+                        scheduled_by: scheduled_by.to_string(),
+                    })
+                }),
+        );
+        AggregateTestHarness::<Labour>::new().given(events)
+    }
+
+    #[test]
+    fn updates_not_yet_due_are_checked_again_at_their_send_time() {
+        let send_at = FixedClock::default().now() + Duration::hours(1);
+        let harness = labour_with_scheduled_updates(
+            Uuid::now_v7(),
+            &[
+                (
+                    Uuid::now_v7(),
+                    LabourUpdateType::STATUS_UPDATE,
+                    LabourUpdateTrigger::AtTime { send_at },
+                ),
+                (
+                    Uuid::now_v7(),
+                    LabourUpdateType::ANNOUNCEMENT,
+                    LabourUpdateTrigger::OnPhase {
+                        phase: LabourPhase::ACTIVE,
+                    },
+                ),
+            ],
+        );
+
+        assert_eq!(publisher(&harness).check().unwrap(), Some(send_at));
+        assert_eq!(harness.state().unwrap().scheduled_labour_updates().len(), 2);
+    }
+
+    #[test]
+    fn due_announcements_are_spaced_out_by_the_cooldown() {
+        let due = LabourUpdateTrigger::AtTime {
+            send_at: FixedClock::default().now() - Duration::minutes(1),
+        };
+        let harness = labour_with_scheduled_updates(
+            Uuid::now_v7(),
+            &[
+                (Uuid::now_v7(), LabourUpdateType::ANNOUNCEMENT, due.clone()),
+                (Uuid::now_v7(), LabourUpdateType::ANNOUNCEMENT, due.clone()),
+                (Uuid::now_v7(), LabourUpdateType::STATUS_UPDATE, due),
+            ],
+        );
+
+        let next_check = publisher(&harness).check().unwrap();
+
+        let labour = harness.state().unwrap();
+        assert_eq!(labour.scheduled_labour_updates().len(), 1);
+        assert_eq!(
+            next_check,
+            Some(
+                *labour.find_last_announcement().unwrap().sent_time()
+                    + Duration::seconds(ANNOUNCEMENT_COOLDOWN_SECONDS + 1)
+            )
+        );
+    }
+
+    #[test]
+    fn due_updates_are_posted_as_the_user_who_scheduled_them() {
+        let due = LabourUpdateTrigger::AtTime {
+            send_at: FixedClock::default().now() - Duration::minutes(1),
+        };
+        let harness = labour_with_scheduled_updates(
+            Uuid::now_v7(),
+            &[(Uuid::now_v7(), LabourUpdateType::STATUS_UPDATE, due)],
+        );
+
+        assert_eq!(publisher(&harness).check().unwrap(), None);
+
+        let posted: Vec<_> = harness
+            .event_store()
+            .load()
+            .unwrap()
+            .into_iter()
+            .filter(|row| row.event_type == LabourUpdatePosted::EVENT_TYPE)
+            .collect();
+        assert_eq!(posted.len(), 1);
+        assert_eq!(posted[0].user_id, "mother_123");
+    }
+
+    #[test]
+    fn an_update_that_fails_to_post_does_not_hold_up_the_others() {
+        let due = LabourUpdateTrigger::AtTime {
+            send_at: FixedClock::default().now() - Duration::minutes(1),
+        };
+        let failing_id = Uuid::now_v7();
+        let harness = labour_with_updates_scheduled_by(
+            Uuid::now_v7(),
+            &[
+                (
+                    failing_id,
+                    LabourUpdateType::STATUS_UPDATE,
+                    due.clone(),
+                    "stranger",
+                ),
+                (
+                    Uuid::now_v7(),
+                    LabourUpdateType::STATUS_UPDATE,
+                    due,
+                    "mother_123",
+                ),
+            ],
+        );
+
+        let next_check = publisher(&harness).check().unwrap();
+
+        let labour = harness.state().unwrap();
+        assert_eq!(labour.scheduled_labour_updates().len(), 1);
+        assert_eq!(labour.scheduled_labour_updates()[0].id(), failing_id);
+        assert_eq!(next_check, None);
+    }
+}
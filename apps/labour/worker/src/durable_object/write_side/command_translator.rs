@@ -1,4 +1,5 @@
 use anyhow::{Result, anyhow};
+use fern_labour_event_sourcing_rs::Clock;
 use fern_labour_labour_shared::ApiCommand;
 use fern_labour_workers_shared::User;
 
@@ -7,12 +8,13 @@ use crate::durable_object::write_side::domain::LabourCommand;
 pub struct CommandTranslator;
 
 impl CommandTranslator {
-    pub fn translate(command: ApiCommand, user: &User) -> Result<LabourCommand> {
+    pub fn translate(command: ApiCommand, user: &User, clock: &dyn Clock) -> Result<LabourCommand> {
         match command {
             ApiCommand::Admin(_) => Err(anyhow!("Admin commands must use the admin endpoint")),
             ApiCommand::Labour(cmd) => Ok(LabourCommand::from(cmd)),
-            ApiCommand::LabourUpdate(cmd) => Ok(LabourCommand::from(cmd)),
-            ApiCommand::Contraction(cmd) => Ok(LabourCommand::from(cmd)),
+            ApiCommand::LabourUpdate(cmd) => Ok(LabourCommand::from((cmd, user.user_id.clone()))),
+            ApiCommand::Contraction(cmd) => Ok(LabourCommand::from((cmd, clock))),
+            ApiCommand::Milestone(cmd) => Ok(LabourCommand::from(cmd)),
             ApiCommand::Subscriber(cmd) => Ok(LabourCommand::from((cmd, user.user_id.clone()))),
             ApiCommand::Subscription(cmd) => Ok(LabourCommand::from(cmd)),
         }
@@ -22,7 +24,7 @@ impl CommandTranslator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Utc;
+    use fern_labour_event_sourcing_rs::testing::FixedClock;
     use fern_labour_labour_shared::ContractionCommand;
     use uuid::Uuid;
 
@@ -40,17 +42,18 @@ mod tests {
 
     #[test]
     fn translates_contraction_command() {
+        let clock = FixedClock::default();
         let api_cmd = ApiCommand::Contraction(ContractionCommand::StartContraction {
             labour_id: Uuid::now_v7(),
             contraction_id: Uuid::now_v7(),
-            start_time: Some(Utc::now()),
+            start_time: None,
         });
 
-        let result = CommandTranslator::translate(api_cmd, &test_user());
+        let result = CommandTranslator::translate(api_cmd, &test_user(), &clock);
         assert!(result.is_ok());
         assert!(matches!(
             result.unwrap(),
-            LabourCommand::StartContraction(..)
+            LabourCommand::StartContraction(cmd) if cmd.start_time == clock.now()
         ));
     }
 
@@ -61,7 +64,7 @@ mod tests {
                 aggregate_id: Uuid::now_v7(),
             });
 
-        let result = CommandTranslator::translate(api_cmd, &test_user());
+        let result = CommandTranslator::translate(api_cmd, &test_user(), &FixedClock::default());
         assert!(result.is_err());
     }
 }
@@ -1,18 +1,24 @@
 use std::fmt::Debug;
 
 use chrono::{DateTime, Duration, Utc};
-use fern_labour_labour_shared::value_objects::{LabourPhase, LabourUpdateType};
+use fern_labour_labour_shared::value_objects::{
+    LabourMilestoneType, LabourPhase, LabourUpdateType, PhaseProgressionPolicy,
+    contraction::ContractionPatternRule,
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use fern_labour_event_sourcing_rs::Aggregate;
+use fern_labour_event_sourcing_rs::{Aggregate, Clock};
 
 use crate::durable_object::write_side::domain::{
     LabourCommand, LabourError, LabourEvent,
     command_handlers::{subscription::handle_invalidate_subscription_token, *},
     entities::{
+        birth_record::BirthRecord,
         contraction::Contraction,
         labour_update::{ANNOUNCEMENT_COOLDOWN_SECONDS, LabourUpdate},
+        milestone::Milestone,
+        scheduled_labour_update::ScheduledLabourUpdate,
         subscription::Subscription,
     },
 };
@@ -22,15 +28,25 @@ pub struct Labour {
     id: Uuid,
     mother_id: String,
     phase: LabourPhase,
+    first_labour: bool,
+    custom_phase_progression_policy: Option<PhaseProgressionPolicy>,
     subscription_token: Option<String>,
     contractions: Vec<Contraction>,
+    detected_contraction_patterns: Vec<ContractionPatternRule>,
     labour_updates: Vec<LabourUpdate>,
+    scheduled_labour_updates: Vec<ScheduledLabourUpdate>,
+    milestones: Vec<Milestone>,
     subscriptions: Vec<Subscription>,
+    birth_record: Option<BirthRecord>,
     start_time: Option<DateTime<Utc>>,
     end_time: Option<DateTime<Utc>>,
 }
 
 impl Labour {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
     pub fn mother_id(&self) -> &str {
         &self.mother_id
     }
@@ -39,6 +55,14 @@ impl Labour {
         &self.phase
     }
 
+    /// The policy set for this labour, or the preset for a first or
+    /// subsequent labour.
+    pub fn phase_progression_policy(&self) -> PhaseProgressionPolicy {
+        self.custom_phase_progression_policy
+            .clone()
+            .unwrap_or_else(|| PhaseProgressionPolicy::for_labour(self.first_labour))
+    }
+
     pub fn subscriptions(&self) -> &[Subscription] {
         &self.subscriptions
     }
@@ -51,6 +75,14 @@ impl Labour {
         &self.contractions
     }
 
+    pub fn detected_contraction_patterns(&self) -> &[ContractionPatternRule] {
+        &self.detected_contraction_patterns
+    }
+
+    pub fn birth_record(&self) -> Option<&BirthRecord> {
+        self.birth_record.as_ref()
+    }
+
     pub fn find_active_contraction(&self) -> Option<&Contraction> {
         self.contractions.iter().find(|c| c.is_active())
     }
@@ -72,15 +104,40 @@ impl Labour {
             .max_by_key(|lu| lu.sent_time())
     }
 
-    pub fn can_send_announcement(&self) -> bool {
+    pub fn can_send_announcement(&self, now: DateTime<Utc>) -> bool {
         match self.find_last_announcement() {
             None => true,
-            Some(last) => {
-                Utc::now() - last.sent_time() > Duration::seconds(ANNOUNCEMENT_COOLDOWN_SECONDS)
-            }
+            Some(last) => now - last.sent_time() > Duration::seconds(ANNOUNCEMENT_COOLDOWN_SECONDS),
         }
     }
 
+    pub fn scheduled_labour_updates(&self) -> &[ScheduledLabourUpdate] {
+        &self.scheduled_labour_updates
+    }
+
+    pub fn find_scheduled_labour_update(
+        &self,
+        scheduled_update_id: Uuid,
+    ) -> Option<&ScheduledLabourUpdate> {
+        self.scheduled_labour_updates
+            .iter()
+            .find(|su| su.id() == scheduled_update_id)
+    }
+
+    pub fn milestones(&self) -> &[Milestone] {
+        &self.milestones
+    }
+
+    pub fn find_milestone(&self, milestone_id: Uuid) -> Option<&Milestone> {
+        self.milestones.iter().find(|m| m.id() == milestone_id)
+    }
+
+    pub fn has_milestone(&self, milestone_type: &LabourMilestoneType) -> bool {
+        self.milestones
+            .iter()
+            .any(|m| m.milestone_type() == milestone_type)
+    }
+
     pub fn find_subscription_from_subscriber_id(
         &self,
         subscriber_id: &str,
@@ -115,7 +172,16 @@ impl Labour {
         let new_end = end_time.unwrap_or(*updated_contraction.end_time());
 
         for contraction in &self.contractions {
-            if contraction.id() == updated_contraction_id || contraction.is_active() {
+            if contraction.id() == updated_contraction_id {
+                continue;
+            }
+
+            // An active contraction is still running, so it occupies
+            // everything from its start onwards.
+            if contraction.is_active() {
+                if new_end > *contraction.start_time() {
+                    return true;
+                }
                 continue;
             }
 
@@ -126,6 +192,28 @@ impl Labour {
 
         false
     }
+
+    /// The first contraction that one from `start_time` to `end_time` would
+    /// overlap. A `None` end, like an active contraction, runs indefinitely.
+    pub fn find_overlapping_contraction(
+        &self,
+        start_time: DateTime<Utc>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> Option<&Contraction> {
+        self.contractions.iter().find(|contraction| {
+            let ends_after_start = contraction.is_active() || *contraction.end_time() > start_time;
+            let starts_before_end = end_time.is_none_or(|end| *contraction.start_time() < end);
+            ends_after_start && starts_before_end
+        })
+    }
+
+    /// Whether a contraction starting at `start_time` would begin before an
+    /// existing contraction has ended.
+    pub fn starts_within_contractions(&self, start_time: DateTime<Utc>) -> bool {
+        self.contractions
+            .iter()
+            .any(|contraction| *contraction.end_time() > start_time)
+    }
 }
 
 impl Aggregate for Labour {
@@ -133,6 +221,8 @@ impl Aggregate for Labour {
     type Error = LabourError;
     type Event = LabourEvent;
 
+    const SNAPSHOT_SCHEMA_VERSION: i64 = 5;
+
     fn aggregate_id(&self) -> String {
         self.id.to_string()
     }
@@ -143,10 +233,32 @@ impl Aggregate for Labour {
                 self.id = e.labour_id;
                 self.mother_id = e.mother_id.clone();
                 self.phase = LabourPhase::PLANNED;
+                self.first_labour = e.first_labour;
+            }
+            LabourEvent::LabourPlanUpdated(e) => {
+                self.first_labour = e.first_labour;
+            }
+            LabourEvent::PhaseProgressionPolicyUpdated(e) => {
+                self.custom_phase_progression_policy = e.policy.clone();
             }
             LabourEvent::LabourBegun(e) => {
                 self.start_time = Some(e.start_time);
             }
+            LabourEvent::BirthRecorded(e) => {
+                if let Ok(birth_record) = BirthRecord::create(e.time_of_birth, e.babies.clone()) {
+                    self.birth_record = Some(birth_record);
+                }
+            }
+            LabourEvent::BirthRecordAmended(e) => {
+                if let Some(birth_record) = self.birth_record.as_mut() {
+                    let _ = birth_record.amend(Some(e.time_of_birth), Some(e.babies.clone()));
+                }
+            }
+            LabourEvent::BirthAnnouncementPublished(_) => {
+                if let Some(birth_record) = self.birth_record.as_mut() {
+                    birth_record.publish();
+                }
+            }
             LabourEvent::LabourCompleted(e) => {
                 self.end_time = Some(e.end_time);
             }
@@ -157,14 +269,39 @@ impl Aggregate for Labour {
                 if let Ok(contraction) =
                     Contraction::start(e.contraction_id, e.labour_id, e.start_time)
                 {
-                    self.contractions.push(contraction);
+                    // Recorded contractions can be backfilled between
+                    // existing ones, so keep them in start order.
+                    let index = self
+                        .contractions
+                        .partition_point(|c| c.start_time() <= contraction.start_time());
+                    self.contractions.insert(index, contraction);
                 }
             }
             LabourEvent::ContractionEnded(e) => {
-                if let Some(contraction) = self.contractions.last_mut() {
-                    contraction
-                        .end(e.end_time, e.intensity)
-                        .expect("Failed to end contraction");
+                if let Some(contraction) = self
+                    .contractions
+                    .iter_mut()
+                    .find(|c| c.id() == e.contraction_id)
+                {
+                    let _ = contraction.end(e.end_time, e.intensity);
+                }
+            }
+            LabourEvent::ContractionAbandoned(e) => {
+                if let Some(contraction) = self
+                    .contractions
+                    .iter_mut()
+                    .find(|c| c.id() == e.contraction_id)
+                {
+                    let _ = contraction.abandon(e.end_time);
+                }
+            }
+            LabourEvent::ContractionResumed(e) => {
+                if let Some(contraction) = self
+                    .contractions
+                    .iter_mut()
+                    .find(|c| c.id() == e.contraction_id)
+                {
+                    contraction.resume();
                 }
             }
             LabourEvent::ContractionUpdated(e) => {
@@ -173,13 +310,14 @@ impl Aggregate for Labour {
                     .iter_mut()
                     .find(|c| c.id() == e.contraction_id)
                 {
-                    contraction
-                        .update(e.start_time, e.end_time, e.intensity)
-                        .expect("Failed to update contraction");
+                    let _ = contraction.update(e.start_time, e.end_time, e.intensity);
                 }
             }
             LabourEvent::ContractionDeleted(e) => {
-                self.contractions.pop_if(|c| c.id() == e.contraction_id);
+                self.contractions.retain(|c| c.id() != e.contraction_id);
+            }
+            LabourEvent::ContractionPatternDetected(e) => {
+                self.detected_contraction_patterns.push(e.rule);
             }
             LabourEvent::LabourUpdatePosted(e) => {
                 let labour_update = LabourUpdate::create(
@@ -211,7 +349,48 @@ impl Aggregate for Labour {
                 }
             }
             LabourEvent::LabourUpdateDeleted(e) => {
-                self.labour_updates.pop_if(|c| c.id() == e.labour_update_id);
+                self.labour_updates
+                    .retain(|lu| lu.id() != e.labour_update_id);
+            }
+            LabourEvent::LabourUpdateScheduled(e) => {
+                self.scheduled_labour_updates
+                    .push(ScheduledLabourUpdate::create(
+                        e.scheduled_update_id,
+                        e.labour_id,
+                        e.labour_update_type.clone(),
+                        e.message.clone(),
+                        e.trigger.clone(),
+                        e.scheduled_by.clone(),
+                    ));
+            }
+            LabourEvent::ScheduledLabourUpdateCancelled(e) => {
+                self.scheduled_labour_updates
+                    .retain(|su| su.id() != e.scheduled_update_id);
+            }
+            LabourEvent::ScheduledLabourUpdatePosted(e) => {
+                self.scheduled_labour_updates
+                    .retain(|su| su.id() != e.scheduled_update_id);
+            }
+            LabourEvent::MilestoneRecorded(e) => {
+                self.milestones.push(Milestone::create(
+                    e.milestone_id,
+                    e.labour_id,
+                    e.milestone_type.clone(),
+                    e.occurred_at,
+                    e.notes.clone(),
+                ));
+            }
+            LabourEvent::MilestoneUpdated(e) => {
+                if let Some(milestone) = self
+                    .milestones
+                    .iter_mut()
+                    .find(|m| m.id() == e.milestone_id)
+                {
+                    milestone.update(e.occurred_at, e.notes.clone());
+                }
+            }
+            LabourEvent::MilestoneDeleted(e) => {
+                self.milestones.retain(|m| m.id() != e.milestone_id);
             }
             LabourEvent::SubscriberRequested(e) => {
                 if let Some(subscription) = self
@@ -265,6 +444,15 @@ impl Aggregate for Labour {
                     subscription.approve();
                 }
             }
+            LabourEvent::SubscriberApprovalRevoked(e) => {
+                if let Some(subscription) = self
+                    .subscriptions
+                    .iter_mut()
+                    .find(|s| s.id() == e.subscription_id)
+                {
+                    subscription.request();
+                }
+            }
             LabourEvent::SubscriberRemoved(e) => {
                 if let Some(subscription) = self
                     .subscriptions
@@ -307,44 +495,68 @@ impl Aggregate for Labour {
             LabourEvent::SubscriptionTokenInvalidated(_) => {
                 self.subscription_token = None;
             }
-            LabourEvent::LabourPlanUpdated(_)
-            | LabourEvent::LabourInviteSent(_)
-            | LabourEvent::LabourDeleted(_) => {}
+            LabourEvent::LabourInviteSent(_)
+            | LabourEvent::LabourDeleted(_)
+            | LabourEvent::ActionUndone(_) => {}
         }
     }
 
     fn handle_command(
         state: Option<&Self>,
         command: Self::Command,
+        clock: &dyn Clock,
     ) -> std::result::Result<Vec<Self::Event>, Self::Error> {
         match command {
             // Labour commands
             LabourCommand::PlanLabour(cmd) => handle_plan_labour(state, cmd),
             LabourCommand::UpdateLabourPlan(cmd) => handle_update_labour_plan(state, cmd),
-            LabourCommand::BeginLabour(cmd) => handle_begin_labour(state, cmd),
-            LabourCommand::CompleteLabour(cmd) => handle_complete_labour(state, cmd),
+            LabourCommand::BeginLabour(cmd) => handle_begin_labour(state, cmd, clock),
+            LabourCommand::CompleteLabour(cmd) => handle_complete_labour(state, cmd, clock),
             LabourCommand::SendLabourInvite(cmd) => handle_send_labour_invite(state, cmd),
             LabourCommand::DeleteLabour(cmd) => handle_delete_labour(state, cmd),
             LabourCommand::AdvanceLabourPhase(cmd) => handle_advance_labour_phase(state, cmd),
+            LabourCommand::UpdatePhaseProgressionPolicy(cmd) => {
+                handle_update_phase_progression_policy(state, cmd)
+            }
+
+            // Birth record commands
+            LabourCommand::RecordBirth(cmd) => handle_record_birth(state, cmd, clock),
+            LabourCommand::AmendBirthRecord(cmd) => handle_amend_birth_record(state, cmd, clock),
+            LabourCommand::PublishBirthAnnouncement(cmd) => {
+                handle_publish_birth_announcement(state, cmd, clock)
+            }
 
             // Contraction commands
             LabourCommand::StartContraction(cmd) => handle_start_contraction(state, cmd),
             LabourCommand::EndContraction(cmd) => handle_end_contraction(state, cmd),
+            LabourCommand::RecordContraction(cmd) => handle_record_contraction(state, cmd),
+            LabourCommand::AbandonContraction(cmd) => handle_abandon_contraction(state, cmd),
             LabourCommand::UpdateContraction(cmd) => handle_update_contraction(state, cmd),
             LabourCommand::DeleteContraction(cmd) => handle_delete_contraction(state, cmd),
 
             // Labour update commands
-            LabourCommand::PostLabourUpdate(cmd) => handle_post_labour_update(state, cmd),
+            LabourCommand::PostLabourUpdate(cmd) => handle_post_labour_update(state, cmd, clock),
             LabourCommand::PostApplicationLabourUpdate(cmd) => {
-                handle_post_application_labour_update(state, cmd)
+                handle_post_application_labour_update(state, cmd, clock)
             }
             LabourCommand::UpdateLabourUpdateType(cmd) => {
-                handle_update_labour_update_type(state, cmd)
+                handle_update_labour_update_type(state, cmd, clock)
             }
             LabourCommand::UpdateLabourUpdateMessage(cmd) => {
                 handle_update_labour_update_message(state, cmd)
             }
             LabourCommand::DeleteLabourUpdate(cmd) => handle_delete_labour_update(state, cmd),
+            LabourCommand::ScheduleLabourUpdate(cmd) => {
+                handle_schedule_labour_update(state, cmd, clock)
+            }
+            LabourCommand::CancelScheduledLabourUpdate(cmd) => {
+                handle_cancel_scheduled_labour_update(state, cmd)
+            }
+
+            // Milestone commands
+            LabourCommand::RecordMilestone(cmd) => handle_record_milestone(state, cmd, clock),
+            LabourCommand::UpdateMilestone(cmd) => handle_update_milestone(state, cmd, clock),
+            LabourCommand::DeleteMilestone(cmd) => handle_delete_milestone(state, cmd),
 
             // Subscriber commands
             LabourCommand::RequestAccess(cmd) => handle_request_access(state, cmd),
@@ -364,6 +576,9 @@ impl Aggregate for Labour {
             LabourCommand::BlockSubscriber(cmd) => handle_block_subscriber(state, cmd),
             LabourCommand::UnblockSubscriber(cmd) => handle_unblock_subscriber(state, cmd),
             LabourCommand::UpdateSubscriberRole(cmd) => handle_update_subscriber_role(state, cmd),
+
+            // Undo commands
+            LabourCommand::UndoLastAction(cmd) => handle_undo_last_action(state, cmd, clock),
         }
     }
 
@@ -373,10 +588,16 @@ impl Aggregate for Labour {
                 id: e.labour_id,
                 mother_id: e.mother_id.clone(),
                 phase: LabourPhase::PLANNED,
+                first_labour: e.first_labour,
+                custom_phase_progression_policy: None,
                 subscription_token: None,
                 contractions: vec![],
+                detected_contraction_patterns: vec![],
                 labour_updates: vec![],
+                scheduled_labour_updates: vec![],
+                milestones: vec![],
                 subscriptions: vec![],
+                birth_record: None,
                 start_time: None,
                 end_time: None,
             },
@@ -396,24 +617,35 @@ mod tests {
     use super::*;
     use crate::durable_object::write_side::domain::{
         commands::{
-            contraction::{EndContraction, StartContraction},
+            contraction::{
+                AbandonContraction, EndContraction, RecordContraction, StartContraction,
+            },
             labour::{BeginLabour, CompleteLabour, PlanLabour},
         },
         events::*,
     };
     use chrono::TimeZone;
+    use fern_labour_event_sourcing_rs::testing::FixedClock;
+
+    fn now() -> DateTime<Utc> {
+        FixedClock::default().now()
+    }
 
     struct AggregateTestHarness {
         events: Vec<LabourEvent>,
+        clock: FixedClock,
     }
 
     impl AggregateTestHarness {
         fn given(events: Vec<LabourEvent>) -> Self {
-            Self { events }
+            Self {
+                events,
+                clock: FixedClock::default(),
+            }
         }
 
         fn given_no_events() -> Self {
-            Self { events: vec![] }
+            Self::given(vec![])
         }
 
         fn state(&self) -> Option<Labour> {
@@ -425,7 +657,7 @@ mod tests {
         }
 
         fn when(&self, command: LabourCommand) -> Result<Vec<LabourEvent>, LabourError> {
-            Labour::handle_command(self.state().as_ref(), command)
+            Labour::handle_command(self.state().as_ref(), command, &self.clock)
         }
     }
 
@@ -439,7 +671,7 @@ mod tests {
             mother_id: "mother_123".to_string(),
             mother_name: "Test Mother".to_string(),
             first_labour: true,
-            due_date: Utc::now(),
+            due_date: now(),
             labour_name: Some("Baby Smith".to_string()),
         })
     }
@@ -451,7 +683,7 @@ mod tests {
                 mother_id: "mother_123".to_string(),
                 mother_name: "Test Mother".to_string(),
                 first_labour: true,
-                due_date: Utc::now(),
+                due_date: now(),
                 labour_name: Some("Baby Smith".to_string()),
             }),
             LabourEvent::LabourPhaseChanged(LabourPhaseChanged {
@@ -466,7 +698,7 @@ mod tests {
         events.extend(vec![
             LabourEvent::LabourBegun(LabourBegun {
                 labour_id: labour_id(),
-                start_time: Utc::now(),
+                start_time: now(),
             }),
             LabourEvent::LabourPhaseChanged(LabourPhaseChanged {
                 labour_id: labour_id(),
@@ -478,7 +710,7 @@ mod tests {
                 labour_update_type: LabourUpdateType::PRIVATE_NOTE,
                 message: "labour_begun".to_string(),
                 application_generated: true,
-                sent_time: Utc::now(),
+                sent_time: now(),
             }),
         ]);
         events
@@ -624,7 +856,7 @@ mod tests {
             events.push(LabourEvent::ContractionStarted(ContractionStarted {
                 labour_id: labour_id(),
                 contraction_id: Uuid::now_v7(),
-                start_time: Utc::now(),
+                start_time: now(),
             }));
             let harness = AggregateTestHarness::given(events);
 
@@ -651,7 +883,7 @@ mod tests {
             let result = harness.when(LabourCommand::StartContraction(StartContraction {
                 labour_id: labour_id(),
                 contraction_id: Uuid::now_v7(),
-                start_time: Utc::now(),
+                start_time: now(),
             }));
 
             // Then
@@ -669,7 +901,7 @@ mod tests {
             let result = harness.when(LabourCommand::StartContraction(StartContraction {
                 labour_id: labour_id(),
                 contraction_id: Uuid::now_v7(),
-                start_time: Utc::now(),
+                start_time: now(),
             }));
 
             // Then - should emit LabourBegun before ContractionStarted
@@ -688,12 +920,12 @@ mod tests {
             events.push(LabourEvent::ContractionStarted(ContractionStarted {
                 labour_id: labour_id(),
                 contraction_id: contraction_id,
-                start_time: Utc::now(),
+                start_time: now(),
             }));
             events.push(LabourEvent::ContractionEnded(ContractionEnded {
                 labour_id: labour_id(),
                 contraction_id,
-                end_time: Utc::now(),
+                end_time: now(),
                 intensity: 5,
             }));
             let harness = AggregateTestHarness::given(events);
@@ -702,7 +934,7 @@ mod tests {
             let result = harness.when(LabourCommand::StartContraction(StartContraction {
                 labour_id: labour_id(),
                 contraction_id,
-                start_time: Utc::now(),
+                start_time: now(),
             }));
             // Then
             assert!(matches!(result, Err(LabourError::InvalidCommand(_))));
@@ -716,12 +948,12 @@ mod tests {
             events.push(LabourEvent::ContractionStarted(ContractionStarted {
                 labour_id: labour_id(),
                 contraction_id: contraction_id,
-                start_time: Utc::now(),
+                start_time: now(),
             }));
             events.push(LabourEvent::ContractionEnded(ContractionEnded {
                 labour_id: labour_id(),
                 contraction_id,
-                end_time: Utc::now(),
+                end_time: now() + chrono::Duration::minutes(1),
                 intensity: 5,
             }));
             let harness = AggregateTestHarness::given(events);
@@ -730,12 +962,123 @@ mod tests {
             let result = harness.when(LabourCommand::EndContraction(EndContraction {
                 labour_id: labour_id(),
                 contraction_id,
-                end_time: Utc::now(),
+                end_time: now() + chrono::Duration::minutes(2),
                 intensity: 5,
             }));
             // Then
             assert!(matches!(result, Err(LabourError::InvalidCommand(_))));
         }
+
+        /// Begun labour with a one-minute contraction starting ten minutes
+        /// after `now()`.
+        fn later_contraction_events() -> Vec<LabourEvent> {
+            let mut events = begun_labour_events();
+            let contraction_id = Uuid::now_v7();
+            events.push(LabourEvent::ContractionStarted(ContractionStarted {
+                labour_id: labour_id(),
+                contraction_id,
+                start_time: now() + chrono::Duration::minutes(10),
+            }));
+            events.push(LabourEvent::ContractionEnded(ContractionEnded {
+                labour_id: labour_id(),
+                contraction_id,
+                end_time: now() + chrono::Duration::minutes(11),
+                intensity: 5,
+            }));
+            events
+        }
+
+        fn record_contraction_cmd(start_offset_secs: i64) -> LabourCommand {
+            let start_time = now() + chrono::Duration::seconds(start_offset_secs);
+            LabourCommand::RecordContraction(RecordContraction {
+                labour_id: labour_id(),
+                contraction_id: Uuid::now_v7(),
+                start_time,
+                end_time: start_time + chrono::Duration::minutes(1),
+                intensity: 6,
+            })
+        }
+
+        #[test]
+        fn given_later_contraction_when_record_earlier_one_then_inserted_in_order() {
+            // Given
+            let harness = AggregateTestHarness::given(later_contraction_events());
+
+            // When
+            let events = harness
+                .when(record_contraction_cmd(0))
+                .expect("should succeed");
+
+            // Then
+            assert!(matches!(events[0], LabourEvent::ContractionStarted(_)));
+            assert!(matches!(events[1], LabourEvent::ContractionEnded(_)));
+            let mut labour = harness.state().unwrap();
+            for event in &events {
+                labour.apply(event);
+            }
+            assert_eq!(*labour.contractions()[0].start_time(), now());
+        }
+
+        #[test]
+        fn given_later_contraction_when_record_overlapping_one_then_validation_error() {
+            // Given
+            let harness = AggregateTestHarness::given(later_contraction_events());
+
+            // When
+            let result = harness.when(record_contraction_cmd(570));
+
+            // Then
+            assert!(matches!(result, Err(LabourError::ValidationError(_))));
+        }
+
+        fn abandon_contraction_cmd(contraction_id: Uuid) -> LabourCommand {
+            LabourCommand::AbandonContraction(AbandonContraction {
+                labour_id: labour_id(),
+                contraction_id,
+                end_time: now() + chrono::Duration::minutes(20),
+                notify_mother: false,
+            })
+        }
+
+        #[test]
+        fn given_active_contraction_when_abandoned_then_auto_closed_and_new_one_can_start() {
+            // Given
+            let contraction_id = Uuid::now_v7();
+            let mut events = begun_labour_events();
+            events.push(LabourEvent::ContractionStarted(ContractionStarted {
+                labour_id: labour_id(),
+                contraction_id,
+                start_time: now(),
+            }));
+            let harness = AggregateTestHarness::given(events);
+
+            // When
+            let events = harness
+                .when(abandon_contraction_cmd(contraction_id))
+                .expect("should succeed");
+
+            // Then
+            assert_eq!(events.len(), 1);
+            let mut labour = harness.state().unwrap();
+            labour.apply(&events[0]);
+            let contraction = labour.find_contraction(contraction_id).unwrap();
+            assert!(contraction.is_auto_closed());
+            assert_eq!(contraction.intensity(), None);
+            assert!(labour.find_active_contraction().is_none());
+        }
+
+        #[test]
+        fn given_ended_contraction_when_abandoned_then_invalid_command() {
+            // Given
+            let harness = AggregateTestHarness::given(later_contraction_events());
+            let contraction_id = harness.state().unwrap().contractions()[0].id();
+
+            // When
+            let result = harness.when(abandon_contraction_cmd(contraction_id));
+
+            // Then
+            assert!(matches!(result, Err(LabourError::InvalidCommand(_))));
+        }
     }
 
     mod phase_progression {
@@ -751,7 +1094,7 @@ mod tests {
             events.push(LabourEvent::ContractionStarted(ContractionStarted {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                start_time: Utc::now(),
+                start_time: now(),
             }));
             let harness = AggregateTestHarness::given(events);
 
@@ -759,7 +1102,7 @@ mod tests {
             let result = harness.when(LabourCommand::EndContraction(EndContraction {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                end_time: Utc::now(),
+                end_time: now() + chrono::Duration::seconds(30),
                 intensity: 5,
             }));
 
@@ -779,7 +1122,7 @@ mod tests {
             events.push(LabourEvent::ContractionStarted(ContractionStarted {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                start_time: Utc::now(),
+                start_time: now(),
             }));
             let harness = AggregateTestHarness::given(events);
 
@@ -787,7 +1130,7 @@ mod tests {
             let result = harness.when(LabourCommand::EndContraction(EndContraction {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                end_time: Utc::now() + chrono::Duration::minutes(1),
+                end_time: now() + chrono::Duration::minutes(1),
                 intensity: 7,
             }));
 
@@ -812,7 +1155,7 @@ mod tests {
             events.push(LabourEvent::ContractionStarted(ContractionStarted {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                start_time: Utc::now(),
+                start_time: now(),
             }));
             let harness = AggregateTestHarness::given(events);
 
@@ -820,7 +1163,7 @@ mod tests {
             let result = harness.when(LabourCommand::EndContraction(EndContraction {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                end_time: Utc::now() + chrono::Duration::seconds(90),
+                end_time: now() + chrono::Duration::seconds(90),
                 intensity: 8,
             }));
 
@@ -846,7 +1189,7 @@ mod tests {
             events.push(LabourEvent::ContractionStarted(ContractionStarted {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                start_time: Utc::now(),
+                start_time: now(),
             }));
             let harness = AggregateTestHarness::given(events);
 
@@ -854,7 +1197,7 @@ mod tests {
             let result = harness.when(LabourCommand::EndContraction(EndContraction {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                end_time: Utc::now() + chrono::Duration::seconds(90),
+                end_time: now() + chrono::Duration::seconds(90),
                 intensity: 9,
             }));
 
@@ -881,7 +1224,7 @@ mod tests {
             events.push(LabourEvent::ContractionStarted(ContractionStarted {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                start_time: Utc::now(),
+                start_time: now(),
             }));
             let harness = AggregateTestHarness::given(events);
 
@@ -889,7 +1232,7 @@ mod tests {
             let result = harness.when(LabourCommand::EndContraction(EndContraction {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                end_time: Utc::now() + chrono::Duration::minutes(2),
+                end_time: now() + chrono::Duration::minutes(2),
                 intensity: 9,
             }));
 
@@ -914,7 +1257,7 @@ mod tests {
             events.push(LabourEvent::ContractionStarted(ContractionStarted {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                start_time: Utc::now(),
+                start_time: now(),
             }));
             let harness = AggregateTestHarness::given(events);
 
@@ -922,7 +1265,7 @@ mod tests {
             let result = harness.when(LabourCommand::EndContraction(EndContraction {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                end_time: Utc::now() + chrono::Duration::minutes(1),
+                end_time: now() + chrono::Duration::minutes(1),
                 intensity: 6,
             }));
 
@@ -948,7 +1291,7 @@ mod tests {
             events.push(LabourEvent::ContractionStarted(ContractionStarted {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                start_time: Utc::now(),
+                start_time: now(),
             }));
             let harness = AggregateTestHarness::given(events);
 
@@ -956,7 +1299,7 @@ mod tests {
             let result = harness.when(LabourCommand::EndContraction(EndContraction {
                 labour_id: labour_id(),
                 contraction_id: active_contraction_id,
-                end_time: Utc::now() + chrono::Duration::seconds(30),
+                end_time: now() + chrono::Duration::seconds(30),
                 intensity: 4,
             }));
 
@@ -966,4 +1309,880 @@ mod tests {
             assert!(matches!(events[0], LabourEvent::ContractionEnded(_)));
         }
     }
+
+    mod phase_progression_policy {
+        use super::*;
+        use crate::durable_object::write_side::domain::commands::labour::UpdatePhaseProgressionPolicy;
+
+        fn update_policy_cmd(policy: Option<PhaseProgressionPolicy>) -> LabourCommand {
+            LabourCommand::UpdatePhaseProgressionPolicy(UpdatePhaseProgressionPolicy {
+                labour_id: labour_id(),
+                policy,
+            })
+        }
+
+        #[test]
+        fn given_planned_labour_when_update_policy_then_policy_applies() {
+            let harness = AggregateTestHarness::given(planned_labour_events());
+            let policy = PhaseProgressionPolicy {
+                automatic: false,
+                ..PhaseProgressionPolicy::first_labour()
+            };
+
+            let events = harness
+                .when(update_policy_cmd(Some(policy.clone())))
+                .expect("should succeed");
+
+            let mut labour = harness.state().unwrap();
+            labour.apply(&events[0]);
+            assert_eq!(labour.phase_progression_policy(), policy);
+        }
+
+        #[test]
+        fn given_custom_policy_when_cleared_then_preset_for_labour_applies() {
+            let mut events = planned_labour_events();
+            events.push(LabourEvent::PhaseProgressionPolicyUpdated(
+                PhaseProgressionPolicyUpdated {
+                    labour_id: labour_id(),
+                    policy: Some(PhaseProgressionPolicy::subsequent_labour()),
+                },
+            ));
+            events.push(LabourEvent::PhaseProgressionPolicyUpdated(
+                PhaseProgressionPolicyUpdated {
+                    labour_id: labour_id(),
+                    policy: None,
+                },
+            ));
+
+            let labour = Labour::from_events(&events).unwrap();
+            assert_eq!(
+                labour.phase_progression_policy(),
+                PhaseProgressionPolicy::first_labour()
+            );
+        }
+
+        #[test]
+        fn given_invalid_policy_when_update_then_validation_error() {
+            let harness = AggregateTestHarness::given(planned_labour_events());
+            let policy = PhaseProgressionPolicy {
+                recent_contraction_count: 0,
+                ..PhaseProgressionPolicy::first_labour()
+            };
+
+            let result = harness.when(update_policy_cmd(Some(policy)));
+
+            assert!(matches!(result, Err(LabourError::ValidationError(_))));
+        }
+
+        #[test]
+        fn given_no_labour_when_update_policy_then_not_found() {
+            let harness = AggregateTestHarness::given_no_events();
+
+            let result = harness.when(update_policy_cmd(None));
+
+            assert!(matches!(result, Err(LabourError::NotFound)));
+        }
+    }
+
+    mod birth_record {
+        use super::*;
+        use crate::durable_object::write_side::domain::commands::birth_record::{
+            AmendBirthRecord, PublishBirthAnnouncement, RecordBirth,
+        };
+        use fern_labour_labour_shared::value_objects::{Baby, BabySex};
+
+        fn baby(name: &str) -> Baby {
+            Baby {
+                name: Some(name.to_string()),
+                weight_grams: Some(3400),
+                sex: Some(BabySex::FEMALE),
+            }
+        }
+
+        fn record_birth_cmd(babies: Vec<Baby>) -> LabourCommand {
+            LabourCommand::RecordBirth(RecordBirth {
+                labour_id: labour_id(),
+                time_of_birth: now(),
+                babies,
+            })
+        }
+
+        fn publish_cmd() -> LabourCommand {
+            LabourCommand::PublishBirthAnnouncement(PublishBirthAnnouncement {
+                labour_id: labour_id(),
+            })
+        }
+
+        fn recorded_birth_events() -> Vec<LabourEvent> {
+            let mut events = begun_labour_events();
+            events.push(LabourEvent::BirthRecorded(BirthRecorded {
+                labour_id: labour_id(),
+                time_of_birth: now(),
+                babies: vec![baby("Ada")],
+            }));
+            events
+        }
+
+        #[test]
+        fn given_planned_labour_when_record_birth_then_invalid_command() {
+            let harness = AggregateTestHarness::given(planned_labour_events());
+
+            let result = harness.when(record_birth_cmd(vec![baby("Ada")]));
+
+            assert!(matches!(result, Err(LabourError::InvalidCommand(_))));
+        }
+
+        #[test]
+        fn given_begun_labour_when_record_twins_then_birth_recorded_unpublished() {
+            let harness = AggregateTestHarness::given(begun_labour_events());
+
+            let events = harness
+                .when(record_birth_cmd(vec![baby("Ada"), baby("Eve")]))
+                .expect("should succeed");
+
+            let mut labour = harness.state().unwrap();
+            labour.apply(&events[0]);
+            let record = labour.birth_record().expect("birth recorded");
+            assert_eq!(record.babies().len(), 2);
+            assert!(!record.is_published());
+        }
+
+        #[test]
+        fn given_begun_labour_when_record_birth_in_future_then_validation_error() {
+            let harness = AggregateTestHarness::given(begun_labour_events());
+
+            let result = harness.when(LabourCommand::RecordBirth(RecordBirth {
+                labour_id: labour_id(),
+                time_of_birth: now() + chrono::Duration::hours(1),
+                babies: vec![baby("Ada")],
+            }));
+
+            assert!(matches!(result, Err(LabourError::ValidationError(_))));
+        }
+
+        #[test]
+        fn given_recorded_birth_when_amend_then_unchanged_fields_are_kept() {
+            let harness = AggregateTestHarness::given(recorded_birth_events());
+
+            let events = harness
+                .when(LabourCommand::AmendBirthRecord(AmendBirthRecord {
+                    labour_id: labour_id(),
+                    time_of_birth: None,
+                    babies: Some(vec![baby("Ada Rose")]),
+                }))
+                .expect("should succeed");
+
+            assert!(matches!(
+                &events[0],
+                LabourEvent::BirthRecordAmended(e)
+                    if e.time_of_birth == now() && e.babies == vec![baby("Ada Rose")]
+            ));
+        }
+
+        #[test]
+        fn given_no_birth_record_when_publish_then_invalid_command() {
+            let harness = AggregateTestHarness::given(begun_labour_events());
+
+            let result = harness.when(publish_cmd());
+
+            assert!(matches!(result, Err(LabourError::InvalidCommand(_))));
+        }
+
+        #[test]
+        fn given_recorded_birth_when_publish_then_announcement_carries_details() {
+            let harness = AggregateTestHarness::given(recorded_birth_events());
+
+            let events = harness.when(publish_cmd()).expect("should succeed");
+
+            assert!(matches!(
+                &events[0],
+                LabourEvent::BirthAnnouncementPublished(e) if e.babies == vec![baby("Ada")]
+            ));
+        }
+
+        #[test]
+        fn given_published_birth_when_publish_again_then_invalid_command() {
+            let mut events = recorded_birth_events();
+            events.push(LabourEvent::BirthAnnouncementPublished(
+                BirthAnnouncementPublished {
+                    labour_id: labour_id(),
+                    time_of_birth: now(),
+                    babies: vec![baby("Ada")],
+                    published_at: now(),
+                },
+            ));
+            let harness = AggregateTestHarness::given(events);
+
+            let result = harness.when(publish_cmd());
+
+            assert!(matches!(result, Err(LabourError::InvalidCommand(_))));
+        }
+    }
+
+    mod milestones {
+        use super::*;
+        use crate::durable_object::write_side::domain::commands::milestone::{
+            DeleteMilestone, RecordMilestone, UpdateMilestone,
+        };
+        use fern_labour_labour_shared::value_objects::LabourMilestoneType;
+
+        fn record_milestone_cmd(milestone_type: LabourMilestoneType) -> LabourCommand {
+            LabourCommand::RecordMilestone(RecordMilestone {
+                labour_id: labour_id(),
+                milestone_type,
+                occurred_at: None,
+                notes: None,
+                notify_subscribers: true,
+            })
+        }
+
+        fn waters_broken_events() -> Vec<LabourEvent> {
+            let mut events = begun_labour_events();
+            events.push(LabourEvent::MilestoneRecorded(MilestoneRecorded {
+                labour_id: labour_id(),
+                milestone_id: Uuid::now_v7(),
+                milestone_type: LabourMilestoneType::WATERS_BROKEN,
+                occurred_at: now(),
+                notes: None,
+                notify_subscribers: false,
+            }));
+            events
+        }
+
+        #[test]
+        fn given_begun_labour_when_record_milestone_then_milestone_recorded_at_now() {
+            let harness = AggregateTestHarness::given(begun_labour_events());
+
+            let events = harness
+                .when(record_milestone_cmd(
+                    LabourMilestoneType::LEFT_FOR_BIRTH_LOCATION,
+                ))
+                .expect("should succeed");
+
+            assert_eq!(events.len(), 1);
+            assert!(matches!(
+                &events[0],
+                LabourEvent::MilestoneRecorded(e)
+                    if e.occurred_at == now() && e.notify_subscribers
+            ));
+        }
+
+        #[test]
+        fn given_waters_broken_when_record_again_then_invalid_command() {
+            let harness = AggregateTestHarness::given(waters_broken_events());
+
+            let result = harness.when(record_milestone_cmd(LabourMilestoneType::WATERS_BROKEN));
+
+            assert!(matches!(result, Err(LabourError::InvalidCommand(_))));
+        }
+
+        #[test]
+        fn given_repeatable_milestone_when_record_again_then_succeeds() {
+            let harness = AggregateTestHarness::given(begun_labour_events());
+            let mut labour = harness.state().unwrap();
+            for event in harness
+                .when(record_milestone_cmd(LabourMilestoneType::EPIDURAL_GIVEN))
+                .unwrap()
+            {
+                labour.apply(&event);
+            }
+
+            let result = Labour::handle_command(
+                Some(&labour),
+                record_milestone_cmd(LabourMilestoneType::EPIDURAL_GIVEN),
+                &harness.clock,
+            );
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn given_moderate_contractions_when_waters_break_then_phase_advances_to_active() {
+            let mut events = begun_labour_events();
+            events.extend(contraction_events(3, 0.8, 5));
+            let harness = AggregateTestHarness::given(events);
+
+            let events = harness
+                .when(record_milestone_cmd(LabourMilestoneType::WATERS_BROKEN))
+                .expect("should succeed");
+
+            assert_eq!(events.len(), 2);
+            assert!(matches!(
+                &events[1],
+                LabourEvent::LabourPhaseChanged(e) if e.labour_phase == LabourPhase::ACTIVE
+            ));
+        }
+
+        #[test]
+        fn given_unknown_milestone_when_update_or_delete_then_invalid_command() {
+            let harness = AggregateTestHarness::given(waters_broken_events());
+
+            let update = harness.when(LabourCommand::UpdateMilestone(UpdateMilestone {
+                labour_id: labour_id(),
+                milestone_id: Uuid::now_v7(),
+                occurred_at: None,
+                notes: Some("Clear".to_string()),
+            }));
+            let delete = harness.when(LabourCommand::DeleteMilestone(DeleteMilestone {
+                labour_id: labour_id(),
+                milestone_id: Uuid::now_v7(),
+            }));
+
+            assert!(matches!(update, Err(LabourError::InvalidCommand(_))));
+            assert!(matches!(delete, Err(LabourError::InvalidCommand(_))));
+        }
+    }
+
+    mod contraction_patterns {
+        use super::*;
+
+        /// Begun labour with `count` one-minute contractions five minutes
+        /// apart, followed by one still in progress.
+        fn five_minute_contractions(count: i64) -> (Vec<LabourEvent>, Uuid, DateTime<Utc>) {
+            let base_time = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+            let mut events = begun_labour_events();
+            for i in 0..count {
+                let contraction_id = Uuid::now_v7();
+                let start = base_time + chrono::Duration::minutes(i * 5);
+                events.push(LabourEvent::ContractionStarted(ContractionStarted {
+                    labour_id: labour_id(),
+                    contraction_id,
+                    start_time: start,
+                }));
+                events.push(LabourEvent::ContractionEnded(ContractionEnded {
+                    labour_id: labour_id(),
+                    contraction_id,
+                    end_time: start + chrono::Duration::minutes(1),
+                    intensity: 4,
+                }));
+            }
+            let active_contraction_id = Uuid::now_v7();
+            let active_start = base_time + chrono::Duration::minutes(count * 5);
+            events.push(LabourEvent::ContractionStarted(ContractionStarted {
+                labour_id: labour_id(),
+                contraction_id: active_contraction_id,
+                start_time: active_start,
+            }));
+            (events, active_contraction_id, active_start)
+        }
+
+        fn end_contraction(contraction_id: Uuid, start: DateTime<Utc>) -> LabourCommand {
+            LabourCommand::EndContraction(EndContraction {
+                labour_id: labour_id(),
+                contraction_id,
+                end_time: start + chrono::Duration::minutes(1),
+                intensity: 4,
+            })
+        }
+
+        #[test]
+        fn given_an_hour_of_five_minute_contractions_when_end_contraction_then_five_one_one_detected()
+         {
+            let (events, contraction_id, start) = five_minute_contractions(12);
+            let harness = AggregateTestHarness::given(events);
+
+            let events = harness
+                .when(end_contraction(contraction_id, start))
+                .expect("should succeed");
+
+            let detected: Vec<_> = events
+                .iter()
+                .filter_map(|event| match event {
+                    LabourEvent::ContractionPatternDetected(e) => Some(e.rule),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(detected, vec![ContractionPatternRule::FIVE_ONE_ONE]);
+        }
+
+        #[test]
+        fn given_pattern_already_detected_when_end_contraction_then_not_reported_again() {
+            let (mut events, contraction_id, start) = five_minute_contractions(12);
+            events.extend(
+                AggregateTestHarness::given(events.clone())
+                    .when(end_contraction(contraction_id, start))
+                    .unwrap(),
+            );
+            let next_contraction_id = Uuid::now_v7();
+            let next_start = start + chrono::Duration::minutes(5);
+            events.push(LabourEvent::ContractionStarted(ContractionStarted {
+                labour_id: labour_id(),
+                contraction_id: next_contraction_id,
+                start_time: next_start,
+            }));
+            let harness = AggregateTestHarness::given(events);
+
+            let events = harness
+                .when(end_contraction(next_contraction_id, next_start))
+                .expect("should succeed");
+
+            assert!(
+                !events
+                    .iter()
+                    .any(|event| matches!(event, LabourEvent::ContractionPatternDetected(_)))
+            );
+        }
+    }
+
+    mod announcements {
+        use super::*;
+        use crate::durable_object::write_side::domain::commands::labour_update::PostLabourUpdate;
+
+        fn post_announcement() -> LabourCommand {
+            LabourCommand::PostLabourUpdate(PostLabourUpdate {
+                labour_id: labour_id(),
+                labour_update_type: LabourUpdateType::ANNOUNCEMENT,
+                message: "Baby is here!".to_string(),
+                scheduled_update_id: None,
+            })
+        }
+
+        #[test]
+        fn given_recent_announcement_when_post_announcement_then_error_until_cooldown_passes() {
+            // Given
+            let mut events = begun_labour_events();
+            events.push(LabourEvent::LabourUpdatePosted(LabourUpdatePosted {
+                labour_id: labour_id(),
+                labour_update_id: Uuid::now_v7(),
+                labour_update_type: LabourUpdateType::ANNOUNCEMENT,
+                message: "Labour has started".to_string(),
+                application_generated: false,
+                sent_time: now(),
+            }));
+            let harness = AggregateTestHarness::given(events);
+
+            // When / Then
+            assert!(matches!(
+                harness.when(post_announcement()),
+                Err(LabourError::InvalidCommand(_))
+            ));
+
+            harness
+                .clock
+                .advance(Duration::seconds(ANNOUNCEMENT_COOLDOWN_SECONDS + 1));
+            let events = harness.when(post_announcement()).expect("should succeed");
+            assert!(matches!(
+                &events[0],
+                LabourEvent::LabourUpdatePosted(e) if e.sent_time == harness.clock.now()
+            ));
+        }
+    }
+
+    mod scheduled_updates {
+        use super::*;
+        use crate::durable_object::write_side::domain::commands::labour_update::{
+            CancelScheduledLabourUpdate, PostLabourUpdate, ScheduleLabourUpdate,
+        };
+        use crate::durable_object::write_side::domain::entities::labour_update::MAX_LABOUR_UPDATE_MESSAGE_LENGTH;
+        use fern_labour_labour_shared::value_objects::LabourUpdateTrigger;
+
+        fn schedule(trigger: LabourUpdateTrigger) -> LabourCommand {
+            LabourCommand::ScheduleLabourUpdate(ScheduleLabourUpdate {
+                labour_id: labour_id(),
+                labour_update_type: LabourUpdateType::ANNOUNCEMENT,
+                message: "Things are moving".to_string(),
+                trigger,
+                scheduled_by: "mother_123".to_string(),
+            })
+        }
+
+        #[test]
+        fn given_begun_labour_when_schedule_for_reached_phase_or_past_time_then_error() {
+            // Given
+            let harness = AggregateTestHarness::given(begun_labour_events());
+
+            // When / Then
+            assert!(matches!(
+                harness.when(schedule(LabourUpdateTrigger::OnPhase {
+                    phase: LabourPhase::EARLY
+                })),
+                Err(LabourError::ValidationError(_))
+            ));
+            assert!(matches!(
+                harness.when(schedule(LabourUpdateTrigger::AtTime { send_at: now() })),
+                Err(LabourError::ValidationError(_))
+            ));
+        }
+
+        #[test]
+        fn given_scheduled_update_when_posted_then_no_longer_pending() {
+            // Given
+            let mut harness = AggregateTestHarness::given(begun_labour_events());
+            let scheduled = harness
+                .when(schedule(LabourUpdateTrigger::OnPhase {
+                    phase: LabourPhase::ACTIVE,
+                }))
+                .expect("should succeed");
+            let [LabourEvent::LabourUpdateScheduled(e)] = scheduled.as_slice() else {
+                panic!("expected LabourUpdateScheduled, got {scheduled:?}");
+            };
+            let scheduled_update_id = e.scheduled_update_id;
+            harness.events.extend(scheduled);
+            let post = LabourCommand::PostLabourUpdate(PostLabourUpdate {
+                labour_id: labour_id(),
+                labour_update_type: LabourUpdateType::ANNOUNCEMENT,
+                message: "Things are moving".to_string(),
+                scheduled_update_id: Some(scheduled_update_id),
+            });
+
+            // When
+            let posted = harness.when(post.clone()).expect("should succeed");
+
+            // Then
+            assert!(matches!(
+                posted.as_slice(),
+                [
+                    LabourEvent::LabourUpdatePosted(p),
+                    LabourEvent::ScheduledLabourUpdatePosted(s),
+                ] if s.labour_update_id == p.labour_update_id
+                    && s.scheduled_update_id == scheduled_update_id
+            ));
+            harness.events.extend(posted);
+            assert!(matches!(
+                harness.when(post),
+                Err(LabourError::InvalidCommand(_))
+            ));
+            assert!(matches!(
+                harness.when(LabourCommand::CancelScheduledLabourUpdate(
+                    CancelScheduledLabourUpdate {
+                        labour_id: labour_id(),
+                        scheduled_update_id,
+                    }
+                )),
+                Err(LabourError::InvalidCommand(_))
+            ));
+        }
+
+        #[test]
+        fn given_begun_labour_when_schedule_empty_or_over_long_message_then_error() {
+            // Given
+            let harness = AggregateTestHarness::given(begun_labour_events());
+            let with_message = |message: String| {
+                LabourCommand::ScheduleLabourUpdate(ScheduleLabourUpdate {
+                    labour_id: labour_id(),
+                    labour_update_type: LabourUpdateType::STATUS_UPDATE,
+                    message,
+                    trigger: LabourUpdateTrigger::OnPhase {
+                        phase: LabourPhase::ACTIVE,
+                    },
+                    scheduled_by: "mother_123".to_string(),
+                })
+            };
+
+            // When / Then
+            assert!(matches!(
+                harness.when(with_message("   ".to_string())),
+                Err(LabourError::ValidationError(_))
+            ));
+            assert!(matches!(
+                harness.when(with_message(
+                    "a".repeat(MAX_LABOUR_UPDATE_MESSAGE_LENGTH + 1)
+                )),
+                Err(LabourError::ValidationError(_))
+            ));
+            assert!(
+                harness
+                    .when(with_message("a".repeat(MAX_LABOUR_UPDATE_MESSAGE_LENGTH)))
+                    .is_ok()
+            );
+        }
+
+        #[test]
+        fn given_scheduled_updates_when_complete_then_timed_updates_cancelled() {
+            // Given
+            let mut harness = AggregateTestHarness::given(begun_labour_events());
+            let mut scheduled_update_ids = vec![];
+            for trigger in [
+                LabourUpdateTrigger::AtTime {
+                    send_at: now() + Duration::hours(1),
+                },
+                LabourUpdateTrigger::OnPhase {
+                    phase: LabourPhase::ACTIVE,
+                },
+            ] {
+                let scheduled = harness.when(schedule(trigger)).expect("should succeed");
+                let [LabourEvent::LabourUpdateScheduled(e)] = scheduled.as_slice() else {
+                    panic!("expected LabourUpdateScheduled, got {scheduled:?}");
+                };
+                scheduled_update_ids.push(e.scheduled_update_id);
+                harness.events.extend(scheduled);
+            }
+
+            // When
+            let events = harness
+                .when(LabourCommand::CompleteLabour(CompleteLabour {
+                    labour_id: labour_id(),
+                    notes: None,
+                }))
+                .expect("should succeed");
+
+            // Then
+            let cancelled: Vec<_> = events
+                .iter()
+                .filter_map(|event| match event {
+                    LabourEvent::ScheduledLabourUpdateCancelled(e) => Some(e.scheduled_update_id),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(cancelled, vec![scheduled_update_ids[0]]);
+        }
+    }
+
+    mod undo {
+        use super::*;
+        use crate::durable_object::write_side::domain::command_handlers::undo::UNDO_WINDOW_SECONDS;
+        use crate::durable_object::write_side::domain::commands::{
+            labour_update::PostLabourUpdate,
+            undo::{UndoLastAction, UndoableAction},
+        };
+
+        fn undo(sequence: i64, event: LabourEvent) -> LabourCommand {
+            LabourCommand::UndoLastAction(UndoLastAction {
+                labour_id: labour_id(),
+                last_action: Some(UndoableAction {
+                    sequence,
+                    performed_at: now(),
+                    event,
+                }),
+            })
+        }
+
+        #[test]
+        fn given_announcement_when_undone_then_deleted_and_cooldown_released() {
+            // Given
+            let announcement = LabourEvent::LabourUpdatePosted(LabourUpdatePosted {
+                labour_id: labour_id(),
+                labour_update_id: Uuid::now_v7(),
+                labour_update_type: LabourUpdateType::ANNOUNCEMENT,
+                message: "Sent to the wrong people".to_string(),
+                application_generated: false,
+                sent_time: now(),
+            });
+            let mut events = begun_labour_events();
+            events.push(announcement.clone());
+            let mut harness = AggregateTestHarness::given(events);
+
+            // When
+            let undone = harness.when(undo(6, announcement)).expect("should succeed");
+
+            // Then
+            assert!(matches!(
+                undone.as_slice(),
+                [
+                    LabourEvent::LabourUpdateDeleted(_),
+                    LabourEvent::ActionUndone(e),
+                ] if e.undone_sequence == 6
+            ));
+            harness.events.extend(undone);
+            assert!(
+                harness
+                    .when(LabourCommand::PostLabourUpdate(PostLabourUpdate {
+                        labour_id: labour_id(),
+                        labour_update_type: LabourUpdateType::ANNOUNCEMENT,
+                        message: "Baby is here!".to_string(),
+                        scheduled_update_id: None,
+                    }))
+                    .is_ok()
+            );
+        }
+
+        #[test]
+        fn given_ended_contraction_when_undone_then_resumed_until_window_passes() {
+            // Given
+            let mut events = begun_labour_events();
+            let contraction_events = contraction_events(1, 1.0, 5);
+            let ended = contraction_events[1].clone();
+            events.extend(contraction_events);
+            let harness = AggregateTestHarness::given(events);
+
+            // When
+            let undone = harness
+                .when(undo(7, ended.clone()))
+                .expect("should succeed");
+
+            // Then
+            assert!(matches!(&undone[0], LabourEvent::ContractionResumed(_)));
+            let mut labour = harness.state().unwrap();
+            for event in &undone {
+                labour.apply(event);
+            }
+            let active = labour.find_active_contraction().expect("should be active");
+            assert_eq!(active.intensity(), None);
+
+            harness
+                .clock
+                .advance(Duration::seconds(UNDO_WINDOW_SECONDS + 1));
+            assert!(matches!(
+                harness.when(undo(7, ended)),
+                Err(LabourError::InvalidCommand(_))
+            ));
+        }
+
+        #[test]
+        fn given_nothing_to_undo_when_undo_then_error() {
+            let harness = AggregateTestHarness::given(begun_labour_events());
+
+            let result = harness.when(LabourCommand::UndoLastAction(UndoLastAction {
+                labour_id: labour_id(),
+                last_action: None,
+            }));
+
+            assert!(matches!(result, Err(LabourError::InvalidCommand(_))));
+        }
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use chrono::TimeZone;
+    use fern_labour_event_sourcing_rs::testing::InvariantHarness;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::durable_object::write_side::domain::commands::{
+        contraction::{DeleteContraction, EndContraction, StartContraction, UpdateContraction},
+        labour::{AdvanceLabourPhase, BeginLabour, CompleteLabour, PlanLabour},
+    };
+
+    const CONTRACTION_IDS: [u128; 4] = [1, 2, 3, 4];
+
+    fn labour_id() -> Uuid {
+        Uuid::from_u128(0xfe)
+    }
+
+    fn at(minutes: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap() + Duration::minutes(minutes)
+    }
+
+    fn contraction_id() -> impl Strategy<Value = Uuid> {
+        prop::sample::select(CONTRACTION_IDS.to_vec()).prop_map(Uuid::from_u128)
+    }
+
+    fn phase() -> impl Strategy<Value = LabourPhase> {
+        prop::sample::select(vec![
+            LabourPhase::PLANNED,
+            LabourPhase::EARLY,
+            LabourPhase::ACTIVE,
+            LabourPhase::TRANSITION,
+            LabourPhase::PUSHING,
+            LabourPhase::COMPLETE,
+        ])
+    }
+
+    fn command() -> impl Strategy<Value = LabourCommand> {
+        prop_oneof![
+            1 => Just(LabourCommand::BeginLabour(BeginLabour {
+                labour_id: labour_id()
+            })),
+            1 => Just(LabourCommand::CompleteLabour(CompleteLabour {
+                labour_id: labour_id(),
+                notes: None,
+            })),
+            1 => phase().prop_map(|labour_phase| {
+                LabourCommand::AdvanceLabourPhase(AdvanceLabourPhase {
+                    labour_id: labour_id(),
+                    labour_phase,
+                })
+            }),
+            3 => (contraction_id(), 0..120i64).prop_map(|(contraction_id, start)| {
+                LabourCommand::StartContraction(StartContraction {
+                    labour_id: labour_id(),
+                    contraction_id,
+                    start_time: at(start),
+                })
+            }),
+            3 => (contraction_id(), 0..120i64, 1..=10u8).prop_map(
+                |(contraction_id, end, intensity)| {
+                    LabourCommand::EndContraction(EndContraction {
+                        labour_id: labour_id(),
+                        contraction_id,
+                        end_time: at(end),
+                        intensity,
+                    })
+                }
+            ),
+            1 => (
+                contraction_id(),
+                prop::option::of(0..120i64),
+                prop::option::of(0..120i64),
+                prop::option::of(1..=10u8),
+            )
+                .prop_map(|(contraction_id, start, end, intensity)| {
+                    LabourCommand::UpdateContraction(UpdateContraction {
+                        labour_id: labour_id(),
+                        contraction_id,
+                        start_time: start.map(at),
+                        end_time: end.map(at),
+                        intensity,
+                    })
+                }),
+            1 => contraction_id().prop_map(|contraction_id| {
+                LabourCommand::DeleteContraction(DeleteContraction {
+                    labour_id: labour_id(),
+                    contraction_id,
+                })
+            }),
+        ]
+    }
+
+    fn commands() -> impl Strategy<Value = Vec<LabourCommand>> {
+        prop::collection::vec(command(), 0..40).prop_map(|commands| {
+            let plan = LabourCommand::PlanLabour(PlanLabour {
+                labour_id: labour_id(),
+                mother_id: "mother".to_string(),
+                mother_name: "Mother".to_string(),
+                first_labour: true,
+                due_date: at(0),
+                labour_name: None,
+            });
+            std::iter::once(plan).chain(commands).collect()
+        })
+    }
+
+    fn harness() -> InvariantHarness<Labour> {
+        InvariantHarness::new()
+            .with_invariant("at most one active contraction", |_, labour: &Labour| {
+                let active = labour
+                    .contractions()
+                    .iter()
+                    .filter(|c| c.is_active())
+                    .count();
+                (active <= 1)
+                    .then_some(())
+                    .ok_or_else(|| format!("{active} active contractions"))
+            })
+            .with_invariant("ended contractions do not overlap", |_, labour: &Labour| {
+                let ended: Vec<&Contraction> = labour
+                    .contractions()
+                    .iter()
+                    .filter(|c| !c.is_active())
+                    .collect();
+                for (index, a) in ended.iter().enumerate() {
+                    for b in &ended[index + 1..] {
+                        if a.start_time() < b.end_time() && b.start_time() < a.end_time() {
+                            return Err(format!("{} overlaps {}", a.id(), b.id()));
+                        }
+                    }
+                }
+                Ok(())
+            })
+            .with_invariant(
+                "phase never moves backwards",
+                |before, after| match before {
+                    Some(before) if after.phase() < before.phase() => Err(format!(
+                        "phase went from {} to {}",
+                        before.phase(),
+                        after.phase()
+                    )),
+                    _ => Ok(()),
+                },
+            )
+    }
+
+    proptest! {
+        #[test]
+        fn labour_invariants_hold_for_any_command_sequence(commands in commands()) {
+            harness().check(commands)?;
+        }
+    }
 }
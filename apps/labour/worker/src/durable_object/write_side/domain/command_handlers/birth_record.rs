@@ -0,0 +1,116 @@
+use fern_labour_event_sourcing_rs::Clock;
+use fern_labour_labour_shared::value_objects::LabourPhase;
+
+use crate::durable_object::write_side::domain::{
+    Labour, LabourError, LabourEvent,
+    commands::birth_record::{AmendBirthRecord, PublishBirthAnnouncement, RecordBirth},
+    entities::birth_record::BirthRecord,
+    events::{BirthAnnouncementPublished, BirthRecordAmended, BirthRecorded},
+};
+
+pub fn handle_record_birth(
+    state: Option<&Labour>,
+    cmd: RecordBirth,
+    clock: &dyn Clock,
+) -> Result<Vec<LabourEvent>, LabourError> {
+    let Some(labour) = state else {
+        return Err(LabourError::NotFound);
+    };
+
+    if labour.phase() == &LabourPhase::PLANNED {
+        return Err(LabourError::InvalidCommand(
+            "Cannot record birth before labour has begun".to_string(),
+        ));
+    }
+
+    if labour.birth_record().is_some() {
+        return Err(LabourError::InvalidCommand(
+            "Birth already recorded, amend it instead".to_string(),
+        ));
+    }
+
+    if cmd.time_of_birth > clock.now() {
+        return Err(LabourError::ValidationError(
+            "Time of birth cannot be in the future".to_string(),
+        ));
+    }
+
+    BirthRecord::create(cmd.time_of_birth, cmd.babies.clone())
+        .map_err(|e| LabourError::ValidationError(e.to_string()))?;
+
+    Ok(vec![LabourEvent::BirthRecorded(BirthRecorded {
+        labour_id: cmd.labour_id,
+        time_of_birth: cmd.time_of_birth,
+        babies: cmd.babies,
+    })])
+}
+
+/// Amending a published record corrects what is shown in the app but does
+/// not announce the birth again.
+pub fn handle_amend_birth_record(
+    state: Option<&Labour>,
+    cmd: AmendBirthRecord,
+    clock: &dyn Clock,
+) -> Result<Vec<LabourEvent>, LabourError> {
+    let Some(labour) = state else {
+        return Err(LabourError::NotFound);
+    };
+
+    let Some(birth_record) = labour.birth_record() else {
+        return Err(LabourError::InvalidCommand(
+            "No birth record to amend".to_string(),
+        ));
+    };
+
+    if cmd.time_of_birth.is_none() && cmd.babies.is_none() {
+        return Err(LabourError::ValidationError("Nothing to amend".to_string()));
+    }
+
+    if cmd.time_of_birth.is_some_and(|time| time > clock.now()) {
+        return Err(LabourError::ValidationError(
+            "Time of birth cannot be in the future".to_string(),
+        ));
+    }
+
+    let mut amended = birth_record.clone();
+    amended
+        .amend(cmd.time_of_birth, cmd.babies)
+        .map_err(|e| LabourError::ValidationError(e.to_string()))?;
+
+    Ok(vec![LabourEvent::BirthRecordAmended(BirthRecordAmended {
+        labour_id: cmd.labour_id,
+        time_of_birth: *amended.time_of_birth(),
+        babies: amended.babies().to_vec(),
+    })])
+}
+
+pub fn handle_publish_birth_announcement(
+    state: Option<&Labour>,
+    cmd: PublishBirthAnnouncement,
+    clock: &dyn Clock,
+) -> Result<Vec<LabourEvent>, LabourError> {
+    let Some(labour) = state else {
+        return Err(LabourError::NotFound);
+    };
+
+    let Some(birth_record) = labour.birth_record() else {
+        return Err(LabourError::InvalidCommand(
+            "Cannot publish a birth announcement before the birth is recorded".to_string(),
+        ));
+    };
+
+    if birth_record.is_published() {
+        return Err(LabourError::InvalidCommand(
+            "Birth announcement already published".to_string(),
+        ));
+    }
+
+    Ok(vec![LabourEvent::BirthAnnouncementPublished(
+        BirthAnnouncementPublished {
+            labour_id: cmd.labour_id,
+            time_of_birth: *birth_record.time_of_birth(),
+            babies: birth_record.babies().to_vec(),
+            published_at: clock.now(),
+        },
+    )])
+}
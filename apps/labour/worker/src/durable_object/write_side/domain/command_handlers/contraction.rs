@@ -1,16 +1,18 @@
 use fern_labour_event_sourcing_rs::Aggregate;
 use fern_labour_labour_shared::value_objects::LabourPhase;
+use uuid::Uuid;
 
 use crate::durable_object::write_side::domain::{
     Labour, LabourError, LabourEvent,
     commands::contraction::{
-        DeleteContraction, EndContraction, StartContraction, UpdateContraction,
+        AbandonContraction, DeleteContraction, EndContraction, RecordContraction, StartContraction,
+        UpdateContraction,
     },
     events::{
-        ContractionDeleted, ContractionEnded, ContractionStarted, ContractionUpdated, LabourBegun,
-        LabourPhaseChanged,
+        ContractionAbandoned, ContractionDeleted, ContractionEnded, ContractionPatternDetected,
+        ContractionStarted, ContractionUpdated, LabourBegun, LabourPhaseChanged,
     },
-    services::LabourPhaseProgression,
+    services::{ContractionAnalytics, LabourPhaseProgression},
 };
 
 pub fn handle_start_contraction(
@@ -41,6 +43,12 @@ pub fn handle_start_contraction(
         ));
     }
 
+    if labour.starts_within_contractions(cmd.start_time) {
+        return Err(LabourError::ValidationError(
+            "Contraction would overlap with existing contractions".to_string(),
+        ));
+    }
+
     let mut events = vec![];
 
     if current_phase == &LabourPhase::PLANNED {
@@ -89,6 +97,12 @@ pub fn handle_end_contraction(
         ));
     }
 
+    if cmd.end_time <= *contraction.start_time() {
+        return Err(LabourError::ValidationError(
+            "Contraction must end after it started".to_string(),
+        ));
+    }
+
     let contraction_ended = LabourEvent::ContractionEnded(ContractionEnded {
         labour_id: cmd.labour_id,
         contraction_id: cmd.contraction_id,
@@ -107,6 +121,121 @@ pub fn handle_end_contraction(
             labour_phase: new_phase,
         }));
     }
+    events.extend(detect_contraction_patterns(&updated_labour, cmd.labour_id));
+
+    Ok(events)
+}
+
+pub fn handle_abandon_contraction(
+    state: Option<&Labour>,
+    cmd: AbandonContraction,
+) -> Result<Vec<LabourEvent>, LabourError> {
+    let Some(labour) = state else {
+        return Err(LabourError::NotFound);
+    };
+
+    let Some(contraction) = labour.find_contraction(cmd.contraction_id) else {
+        return Err(LabourError::InvalidCommand(
+            "Contraction not found".to_string(),
+        ));
+    };
+
+    if !contraction.is_active() {
+        return Err(LabourError::InvalidCommand(
+            "Contraction has already been ended".to_string(),
+        ));
+    }
+
+    if cmd.end_time <= *contraction.start_time() {
+        return Err(LabourError::ValidationError(
+            "Contraction must end after it started".to_string(),
+        ));
+    }
+
+    Ok(vec![LabourEvent::ContractionAbandoned(
+        ContractionAbandoned {
+            labour_id: cmd.labour_id,
+            contraction_id: cmd.contraction_id,
+            end_time: cmd.end_time,
+            notify_mother: cmd.notify_mother,
+        },
+    )])
+}
+
+pub fn handle_record_contraction(
+    state: Option<&Labour>,
+    cmd: RecordContraction,
+) -> Result<Vec<LabourEvent>, LabourError> {
+    let Some(labour) = state else {
+        return Err(LabourError::NotFound);
+    };
+
+    let current_phase = labour.phase();
+
+    if current_phase == &LabourPhase::COMPLETE {
+        return Err(LabourError::InvalidCommand(
+            "Cannot record contraction in completed labour".to_string(),
+        ));
+    }
+
+    if labour.find_contraction(cmd.contraction_id).is_some() {
+        return Err(LabourError::InvalidCommand(
+            "Contraction already exists with ID".to_string(),
+        ));
+    }
+
+    if cmd.end_time <= cmd.start_time {
+        return Err(LabourError::ValidationError(
+            "Contraction must end after it started".to_string(),
+        ));
+    }
+
+    if labour
+        .find_overlapping_contraction(cmd.start_time, Some(cmd.end_time))
+        .is_some()
+    {
+        return Err(LabourError::ValidationError(
+            "Contraction would overlap with existing contractions".to_string(),
+        ));
+    }
+
+    let mut events = vec![];
+
+    if current_phase == &LabourPhase::PLANNED {
+        events.push(LabourEvent::LabourBegun(LabourBegun {
+            labour_id: cmd.labour_id,
+            start_time: cmd.start_time,
+        }));
+        events.push(LabourEvent::LabourPhaseChanged(LabourPhaseChanged {
+            labour_id: cmd.labour_id,
+            labour_phase: LabourPhase::EARLY,
+        }));
+    }
+
+    events.push(LabourEvent::ContractionStarted(ContractionStarted {
+        labour_id: cmd.labour_id,
+        contraction_id: cmd.contraction_id,
+        start_time: cmd.start_time,
+    }));
+    events.push(LabourEvent::ContractionEnded(ContractionEnded {
+        labour_id: cmd.labour_id,
+        contraction_id: cmd.contraction_id,
+        end_time: cmd.end_time,
+        intensity: cmd.intensity,
+    }));
+
+    let mut updated_labour = labour.clone();
+    for event in &events {
+        updated_labour.apply(event);
+    }
+
+    if let Some(new_phase) = LabourPhaseProgression::evaluate(&updated_labour) {
+        events.push(LabourEvent::LabourPhaseChanged(LabourPhaseChanged {
+            labour_id: cmd.labour_id,
+            labour_phase: new_phase,
+        }));
+    }
+    events.extend(detect_contraction_patterns(&updated_labour, cmd.labour_id));
 
     Ok(events)
 }
@@ -137,6 +266,14 @@ pub fn handle_update_contraction(
         ));
     }
 
+    let new_start = cmd.start_time.unwrap_or(*contraction.start_time());
+    let new_end = cmd.end_time.unwrap_or(*contraction.end_time());
+    if new_start >= new_end {
+        return Err(LabourError::ValidationError(
+            "Contraction must end after it started".to_string(),
+        ));
+    }
+
     if (cmd.start_time.is_some() || cmd.end_time.is_some())
         && labour.has_overlapping_contractions(cmd.contraction_id, cmd.start_time, cmd.end_time)
     {
@@ -165,11 +302,37 @@ pub fn handle_update_contraction(
                 labour_phase: new_phase,
             }));
         }
+        events.extend(detect_contraction_patterns(&updated_labour, cmd.labour_id));
     }
 
     Ok(events)
 }
 
+/// Reports each preset pattern the contractions meet for the first time,
+/// timed at the end of the latest contraction.
+fn detect_contraction_patterns(labour: &Labour, labour_id: Uuid) -> Vec<LabourEvent> {
+    let Some(detected_at) = labour
+        .contractions()
+        .iter()
+        .filter(|c| !c.is_active())
+        .map(|c| *c.end_time())
+        .max()
+    else {
+        return vec![];
+    };
+
+    ContractionAnalytics::newly_detected_patterns(labour)
+        .into_iter()
+        .map(|rule| {
+            LabourEvent::ContractionPatternDetected(ContractionPatternDetected {
+                labour_id,
+                rule,
+                detected_at,
+            })
+        })
+        .collect()
+}
+
 pub fn handle_delete_contraction(
     state: Option<&Labour>,
     cmd: DeleteContraction,
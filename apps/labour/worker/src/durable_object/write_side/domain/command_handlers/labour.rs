@@ -1,4 +1,4 @@
-use chrono::Utc;
+use fern_labour_event_sourcing_rs::Clock;
 use fern_labour_labour_shared::value_objects::{LabourPhase, LabourUpdateType};
 use uuid::Uuid;
 
@@ -6,11 +6,12 @@ use crate::durable_object::write_side::domain::{
     Labour, LabourError, LabourEvent,
     commands::labour::{
         AdvanceLabourPhase, BeginLabour, CompleteLabour, DeleteLabour, PlanLabour,
-        SendLabourInvite, UpdateLabourPlan,
+        SendLabourInvite, UpdateLabourPlan, UpdatePhaseProgressionPolicy,
     },
     events::{
         LabourBegun, LabourCompleted, LabourDeleted, LabourInviteSent, LabourPhaseChanged,
-        LabourPlanUpdated, LabourPlanned, LabourUpdatePosted,
+        LabourPlanUpdated, LabourPlanned, LabourUpdatePosted, PhaseProgressionPolicyUpdated,
+        ScheduledLabourUpdateCancelled,
     },
 };
 
@@ -60,6 +61,7 @@ pub fn handle_update_labour_plan(
 pub fn handle_begin_labour(
     state: Option<&Labour>,
     cmd: BeginLabour,
+    clock: &dyn Clock,
 ) -> Result<Vec<LabourEvent>, LabourError> {
     let Some(labour) = state else {
         return Err(LabourError::NotFound);
@@ -74,7 +76,7 @@ pub fn handle_begin_labour(
         ));
     }
 
-    let now = Utc::now();
+    let now = clock.now();
 
     Ok(vec![
         LabourEvent::LabourBegun(LabourBegun {
@@ -99,6 +101,7 @@ pub fn handle_begin_labour(
 pub fn handle_complete_labour(
     state: Option<&Labour>,
     cmd: CompleteLabour,
+    clock: &dyn Clock,
 ) -> Result<Vec<LabourEvent>, LabourError> {
     let Some(labour) = state else {
         return Err(LabourError::NotFound);
@@ -119,17 +122,34 @@ pub fn handle_complete_labour(
         ));
     }
 
-    Ok(vec![
+    let mut events = vec![
         LabourEvent::LabourCompleted(LabourCompleted {
             labour_id: cmd.labour_id,
             notes: cmd.notes,
-            end_time: Utc::now(),
+            end_time: clock.now(),
         }),
         LabourEvent::LabourPhaseChanged(LabourPhaseChanged {
             labour_id: cmd.labour_id,
             labour_phase: LabourPhase::COMPLETE,
         }),
-    ])
+    ];
+
+    // Timed updates were written for a labour still in progress. Phase
+    // triggered ones stay, as reaching COMPLETE is what fires them.
+    events.extend(
+        labour
+            .scheduled_labour_updates()
+            .iter()
+            .filter(|su| su.trigger().send_at().is_some())
+            .map(|su| {
+                LabourEvent::ScheduledLabourUpdateCancelled(ScheduledLabourUpdateCancelled {
+                    labour_id: cmd.labour_id,
+                    scheduled_update_id: su.id(),
+                })
+            }),
+    );
+
+    Ok(events)
 }
 
 pub fn handle_send_labour_invite(
@@ -199,8 +219,41 @@ pub fn handle_advance_labour_phase(
         )));
     }
 
+    if &cmd.labour_phase < current_phase {
+        return Err(LabourError::InvalidStateTransition(
+            current_phase.to_string(),
+            cmd.labour_phase.to_string(),
+        ));
+    }
+
     Ok(vec![LabourEvent::LabourPhaseChanged(LabourPhaseChanged {
         labour_id: cmd.labour_id,
         labour_phase: cmd.labour_phase,
     })])
 }
+
+pub fn handle_update_phase_progression_policy(
+    state: Option<&Labour>,
+    cmd: UpdatePhaseProgressionPolicy,
+) -> Result<Vec<LabourEvent>, LabourError> {
+    let Some(labour) = state else {
+        return Err(LabourError::NotFound);
+    };
+
+    if labour.phase() == &LabourPhase::COMPLETE {
+        return Err(LabourError::InvalidCommand(
+            "Cannot update phase progression policy of completed labour".to_string(),
+        ));
+    }
+
+    if let Some(policy) = &cmd.policy {
+        policy.validate().map_err(LabourError::ValidationError)?;
+    }
+
+    Ok(vec![LabourEvent::PhaseProgressionPolicyUpdated(
+        PhaseProgressionPolicyUpdated {
+            labour_id: cmd.labour_id,
+            policy: cmd.policy,
+        },
+    )])
+}
@@ -1,46 +1,78 @@
-use chrono::Utc;
-use fern_labour_labour_shared::value_objects::LabourUpdateType;
+use fern_labour_event_sourcing_rs::Clock;
+use fern_labour_labour_shared::value_objects::{
+    LabourPhase, LabourUpdateTrigger, LabourUpdateType,
+};
 use uuid::Uuid;
 
 use crate::durable_object::write_side::domain::{
     Labour, LabourError, LabourEvent,
     commands::labour_update::{
-        DeleteLabourUpdate, PostApplicationLabourUpdate, PostLabourUpdate,
-        UpdateLabourUpdateMessage, UpdateLabourUpdateType,
+        CancelScheduledLabourUpdate, DeleteLabourUpdate, PostApplicationLabourUpdate,
+        PostLabourUpdate, ScheduleLabourUpdate, UpdateLabourUpdateMessage, UpdateLabourUpdateType,
     },
+    entities::labour_update::MAX_LABOUR_UPDATE_MESSAGE_LENGTH,
     events::{
-        LabourUpdateDeleted, LabourUpdateMessageUpdated, LabourUpdatePosted,
-        LabourUpdateTypeUpdated,
+        LabourUpdateDeleted, LabourUpdateMessageUpdated, LabourUpdatePosted, LabourUpdateScheduled,
+        LabourUpdateTypeUpdated, ScheduledLabourUpdateCancelled, ScheduledLabourUpdatePosted,
     },
 };
 
 pub fn handle_post_labour_update(
     state: Option<&Labour>,
     cmd: PostLabourUpdate,
+    clock: &dyn Clock,
 ) -> Result<Vec<LabourEvent>, LabourError> {
     let Some(labour) = state else {
         return Err(LabourError::NotFound);
     };
 
-    if cmd.labour_update_type == LabourUpdateType::ANNOUNCEMENT && !labour.can_send_announcement() {
+    validate_message(&cmd.message)?;
+
+    if let Some(scheduled_update_id) = cmd.scheduled_update_id
+        && labour
+            .find_scheduled_labour_update(scheduled_update_id)
+            .is_none()
+    {
+        return Err(LabourError::InvalidCommand(
+            "Scheduled labour update not found".to_string(),
+        ));
+    }
+
+    if cmd.labour_update_type == LabourUpdateType::ANNOUNCEMENT
+        && !labour.can_send_announcement(clock.now())
+    {
         return Err(LabourError::InvalidCommand(
             "Too soon since last announcement".to_string(),
         ));
     }
 
-    Ok(vec![LabourEvent::LabourUpdatePosted(LabourUpdatePosted {
+    let labour_update_id = Uuid::now_v7();
+    let mut events = vec![LabourEvent::LabourUpdatePosted(LabourUpdatePosted {
         labour_id: cmd.labour_id,
-        labour_update_id: Uuid::now_v7(),
+        labour_update_id,
         labour_update_type: cmd.labour_update_type,
         message: cmd.message,
         application_generated: false,
-        sent_time: Utc::now(),
-    })])
+        sent_time: clock.now(),
+    })];
+
+    if let Some(scheduled_update_id) = cmd.scheduled_update_id {
+        events.push(LabourEvent::ScheduledLabourUpdatePosted(
+            ScheduledLabourUpdatePosted {
+                labour_id: cmd.labour_id,
+                scheduled_update_id,
+                labour_update_id,
+            },
+        ));
+    }
+
+    Ok(events)
 }
 
 pub fn handle_post_application_labour_update(
     state: Option<&Labour>,
     cmd: PostApplicationLabourUpdate,
+    clock: &dyn Clock,
 ) -> Result<Vec<LabourEvent>, LabourError> {
     if state.is_none() {
         return Err(LabourError::NotFound);
@@ -52,13 +84,14 @@ pub fn handle_post_application_labour_update(
         labour_update_type: LabourUpdateType::PRIVATE_NOTE,
         message: cmd.message,
         application_generated: true,
-        sent_time: Utc::now(),
+        sent_time: clock.now(),
     })])
 }
 
 pub fn handle_update_labour_update_type(
     state: Option<&Labour>,
     cmd: UpdateLabourUpdateType,
+    clock: &dyn Clock,
 ) -> Result<Vec<LabourEvent>, LabourError> {
     let Some(labour) = state else {
         return Err(LabourError::NotFound);
@@ -76,7 +109,9 @@ pub fn handle_update_labour_update_type(
         ));
     }
 
-    if cmd.labour_update_type == LabourUpdateType::ANNOUNCEMENT && !labour.can_send_announcement() {
+    if cmd.labour_update_type == LabourUpdateType::ANNOUNCEMENT
+        && !labour.can_send_announcement(clock.now())
+    {
         return Err(LabourError::InvalidCommand(
             "Too soon since last announcement".to_string(),
         ));
@@ -111,6 +146,8 @@ pub fn handle_update_labour_update_message(
         ));
     }
 
+    validate_message(&cmd.message)?;
+
     Ok(vec![LabourEvent::LabourUpdateMessageUpdated(
         LabourUpdateMessageUpdated {
             labour_id: cmd.labour_id,
@@ -141,3 +178,82 @@ pub fn handle_delete_labour_update(
         },
     )])
 }
+
+pub fn handle_schedule_labour_update(
+    state: Option<&Labour>,
+    cmd: ScheduleLabourUpdate,
+    clock: &dyn Clock,
+) -> Result<Vec<LabourEvent>, LabourError> {
+    let Some(labour) = state else {
+        return Err(LabourError::NotFound);
+    };
+
+    if labour.phase() == &LabourPhase::COMPLETE {
+        return Err(LabourError::InvalidCommand(
+            "Cannot schedule labour update for completed labour".to_string(),
+        ));
+    }
+
+    validate_message(&cmd.message)?;
+
+    match &cmd.trigger {
+        LabourUpdateTrigger::AtTime { send_at } if *send_at <= clock.now() => {
+            return Err(LabourError::ValidationError(
+                "Scheduled labour update must be sent in the future".to_string(),
+            ));
+        }
+        LabourUpdateTrigger::OnPhase { phase } if phase <= labour.phase() => {
+            return Err(LabourError::ValidationError(
+                "Labour has already reached the trigger phase".to_string(),
+            ));
+        }
+        _ => {}
+    }
+
+    Ok(vec![LabourEvent::LabourUpdateScheduled(
+        LabourUpdateScheduled {
+            labour_id: cmd.labour_id,
+            scheduled_update_id: Uuid::now_v7(),
+            labour_update_type: cmd.labour_update_type,
+            message: cmd.message,
+            trigger: cmd.trigger,
+            scheduled_by: cmd.scheduled_by,
+        },
+    )])
+}
+
+pub fn handle_cancel_scheduled_labour_update(
+    state: Option<&Labour>,
+    cmd: CancelScheduledLabourUpdate,
+) -> Result<Vec<LabourEvent>, LabourError> {
+    let Some(labour) = state else {
+        return Err(LabourError::NotFound);
+    };
+
+    let Some(_) = labour.find_scheduled_labour_update(cmd.scheduled_update_id) else {
+        return Err(LabourError::InvalidCommand(
+            "Scheduled labour update not found".to_string(),
+        ));
+    };
+
+    Ok(vec![LabourEvent::ScheduledLabourUpdateCancelled(
+        ScheduledLabourUpdateCancelled {
+            labour_id: cmd.labour_id,
+            scheduled_update_id: cmd.scheduled_update_id,
+        },
+    )])
+}
+
+fn validate_message(message: &str) -> Result<(), LabourError> {
+    if message.trim().is_empty() {
+        return Err(LabourError::ValidationError(
+            "Labour update message cannot be empty".to_string(),
+        ));
+    }
+    if message.chars().count() > MAX_LABOUR_UPDATE_MESSAGE_LENGTH {
+        return Err(LabourError::ValidationError(format!(
+            "Labour update message cannot be longer than {MAX_LABOUR_UPDATE_MESSAGE_LENGTH} characters"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use fern_labour_event_sourcing_rs::{Aggregate, Clock};
+use fern_labour_labour_shared::value_objects::LabourPhase;
+use uuid::Uuid;
+
+use crate::durable_object::write_side::domain::{
+    Labour, LabourError, LabourEvent,
+    commands::milestone::{DeleteMilestone, RecordMilestone, UpdateMilestone},
+    events::{LabourPhaseChanged, MilestoneDeleted, MilestoneRecorded, MilestoneUpdated},
+    services::LabourPhaseProgression,
+};
+
+pub fn handle_record_milestone(
+    state: Option<&Labour>,
+    cmd: RecordMilestone,
+    clock: &dyn Clock,
+) -> Result<Vec<LabourEvent>, LabourError> {
+    let Some(labour) = state else {
+        return Err(LabourError::NotFound);
+    };
+
+    if labour.phase() == &LabourPhase::COMPLETE {
+        return Err(LabourError::InvalidCommand(
+            "Cannot record milestone for completed labour".to_string(),
+        ));
+    }
+
+    if cmd.milestone_type.occurs_once() && labour.has_milestone(&cmd.milestone_type) {
+        return Err(LabourError::InvalidCommand(format!(
+            "{} has already been recorded",
+            cmd.milestone_type
+        )));
+    }
+
+    let now = clock.now();
+    let occurred_at = cmd.occurred_at.unwrap_or(now);
+    if occurred_at > now {
+        return Err(LabourError::ValidationError(
+            "Milestone cannot be in the future".to_string(),
+        ));
+    }
+    validate_notes(cmd.notes.as_deref())?;
+
+    let milestone_recorded = LabourEvent::MilestoneRecorded(MilestoneRecorded {
+        labour_id: cmd.labour_id,
+        milestone_id: Uuid::now_v7(),
+        milestone_type: cmd.milestone_type,
+        occurred_at,
+        notes: cmd.notes,
+        notify_subscribers: cmd.notify_subscribers,
+    });
+
+    let mut updated_labour = labour.clone();
+    updated_labour.apply(&milestone_recorded);
+
+    let mut events = vec![milestone_recorded];
+
+    if let Some(new_phase) = LabourPhaseProgression::evaluate(&updated_labour) {
+        events.push(LabourEvent::LabourPhaseChanged(LabourPhaseChanged {
+            labour_id: cmd.labour_id,
+            labour_phase: new_phase,
+        }));
+    }
+
+    Ok(events)
+}
+
+pub fn handle_update_milestone(
+    state: Option<&Labour>,
+    cmd: UpdateMilestone,
+    clock: &dyn Clock,
+) -> Result<Vec<LabourEvent>, LabourError> {
+    let Some(labour) = state else {
+        return Err(LabourError::NotFound);
+    };
+
+    if labour.find_milestone(cmd.milestone_id).is_none() {
+        return Err(LabourError::InvalidCommand(
+            "Milestone not found".to_string(),
+        ));
+    }
+
+    if cmd.occurred_at.is_none() && cmd.notes.is_none() {
+        return Err(LabourError::ValidationError(
+            "Nothing to update".to_string(),
+        ));
+    }
+
+    if cmd.occurred_at.is_some_and(|time| time > clock.now()) {
+        return Err(LabourError::ValidationError(
+            "Milestone cannot be in the future".to_string(),
+        ));
+    }
+    validate_notes(cmd.notes.as_deref())?;
+
+    Ok(vec![LabourEvent::MilestoneUpdated(MilestoneUpdated {
+        labour_id: cmd.labour_id,
+        milestone_id: cmd.milestone_id,
+        occurred_at: cmd.occurred_at,
+        notes: cmd.notes,
+    })])
+}
+
+pub fn handle_delete_milestone(
+    state: Option<&Labour>,
+    cmd: DeleteMilestone,
+) -> Result<Vec<LabourEvent>, LabourError> {
+    let Some(labour) = state else {
+        return Err(LabourError::NotFound);
+    };
+
+    if labour.find_milestone(cmd.milestone_id).is_none() {
+        return Err(LabourError::InvalidCommand(
+            "Milestone not found".to_string(),
+        ));
+    }
+
+    Ok(vec![LabourEvent::MilestoneDeleted(MilestoneDeleted {
+        labour_id: cmd.labour_id,
+        milestone_id: cmd.milestone_id,
+    })])
+}
+
+fn validate_notes(notes: Option<&str>) -> Result<(), LabourError> {
+    if notes.is_some_and(|notes| notes.trim().is_empty()) {
+        return Err(LabourError::ValidationError(
+            "Milestone notes cannot be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
@@ -1,24 +1,36 @@
+pub mod birth_record;
 pub mod contraction;
 pub mod labour;
 pub mod labour_update;
+pub mod milestone;
 pub mod subscriber;
 pub mod subscription;
+pub mod undo;
+
+pub use birth_record::{
+    handle_amend_birth_record, handle_publish_birth_announcement, handle_record_birth,
+};
 
 pub use contraction::{
-    handle_delete_contraction, handle_end_contraction, handle_start_contraction,
-    handle_update_contraction,
+    handle_abandon_contraction, handle_delete_contraction, handle_end_contraction,
+    handle_record_contraction, handle_start_contraction, handle_update_contraction,
 };
 
 pub use labour::{
     handle_advance_labour_phase, handle_begin_labour, handle_complete_labour, handle_delete_labour,
     handle_plan_labour, handle_send_labour_invite, handle_update_labour_plan,
+    handle_update_phase_progression_policy,
 };
 
 pub use labour_update::{
-    handle_delete_labour_update, handle_post_application_labour_update, handle_post_labour_update,
-    handle_update_labour_update_message, handle_update_labour_update_type,
+    handle_cancel_scheduled_labour_update, handle_delete_labour_update,
+    handle_post_application_labour_update, handle_post_labour_update,
+    handle_schedule_labour_update, handle_update_labour_update_message,
+    handle_update_labour_update_type,
 };
 
+pub use milestone::{handle_delete_milestone, handle_record_milestone, handle_update_milestone};
+
 pub use subscriber::{
     handle_request_access, handle_unsubscribe, handle_update_access_level,
     handle_update_notification_methods,
@@ -28,3 +40,5 @@ pub use subscription::{
     handle_approve_subscriber, handle_block_subscriber, handle_remove_subscriber,
     handle_set_subscription_token, handle_unblock_subscriber, handle_update_subscriber_role,
 };
+
+pub use undo::handle_undo_last_action;
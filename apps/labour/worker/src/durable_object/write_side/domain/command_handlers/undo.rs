@@ -0,0 +1,87 @@
+use chrono::Duration;
+use fern_labour_event_sourcing_rs::Clock;
+use fern_labour_labour_shared::value_objects::{LabourPhase, subscriber::status::SubscriberStatus};
+
+use crate::durable_object::write_side::domain::{
+    Labour, LabourError, LabourEvent,
+    commands::undo::UndoLastAction,
+    events::{ActionUndone, ContractionResumed, LabourUpdateDeleted, SubscriberApprovalRevoked},
+};
+
+/// How long after an action it can still be undone.
+pub const UNDO_WINDOW_SECONDS: i64 = 300;
+
+/// Emits the events that reverse the last action, followed by
+/// `ActionUndone`. Phase changes and detected patterns that came with an
+/// ended contraction are kept, as neither moves backwards.
+pub fn handle_undo_last_action(
+    state: Option<&Labour>,
+    cmd: UndoLastAction,
+    clock: &dyn Clock,
+) -> Result<Vec<LabourEvent>, LabourError> {
+    let Some(labour) = state else {
+        return Err(LabourError::NotFound);
+    };
+
+    let Some(action) = cmd.last_action else {
+        return Err(LabourError::InvalidCommand("Nothing to undo".to_string()));
+    };
+
+    if clock.now() - action.performed_at > Duration::seconds(UNDO_WINDOW_SECONDS) {
+        return Err(LabourError::InvalidCommand(
+            "Too late to undo the last action".to_string(),
+        ));
+    }
+
+    let cannot_undo =
+        || LabourError::InvalidCommand("Last action can no longer be undone".to_string());
+
+    let compensation = match action.event {
+        LabourEvent::ContractionEnded(e) => {
+            let is_latest = labour
+                .contractions()
+                .last()
+                .is_some_and(|c| c.id() == e.contraction_id);
+            if labour.phase() == &LabourPhase::COMPLETE
+                || !is_latest
+                || labour.find_active_contraction().is_some()
+            {
+                return Err(cannot_undo());
+            }
+            LabourEvent::ContractionResumed(ContractionResumed {
+                labour_id: cmd.labour_id,
+                contraction_id: e.contraction_id,
+            })
+        }
+        LabourEvent::LabourUpdatePosted(e) => {
+            if labour.find_labour_update(e.labour_update_id).is_none() {
+                return Err(cannot_undo());
+            }
+            LabourEvent::LabourUpdateDeleted(LabourUpdateDeleted {
+                labour_id: cmd.labour_id,
+                labour_update_id: e.labour_update_id,
+            })
+        }
+        LabourEvent::SubscriberApproved(e) => {
+            if labour
+                .find_subscription(e.subscription_id)
+                .is_none_or(|s| s.status() != &SubscriberStatus::SUBSCRIBED)
+            {
+                return Err(cannot_undo());
+            }
+            LabourEvent::SubscriberApprovalRevoked(SubscriberApprovalRevoked {
+                labour_id: cmd.labour_id,
+                subscription_id: e.subscription_id,
+            })
+        }
+        _ => return Err(cannot_undo()),
+    };
+
+    Ok(vec![
+        compensation,
+        LabourEvent::ActionUndone(ActionUndone {
+            labour_id: cmd.labour_id,
+            undone_sequence: action.sequence,
+        }),
+    ])
+}
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use fern_labour_labour_shared::value_objects::Baby;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RecordBirth {
+    pub labour_id: Uuid,
+    pub time_of_birth: DateTime<Utc>,
+    pub babies: Vec<Baby>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AmendBirthRecord {
+    pub labour_id: Uuid,
+    pub time_of_birth: Option<DateTime<Utc>>,
+    pub babies: Option<Vec<Baby>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PublishBirthAnnouncement {
+    pub labour_id: Uuid,
+}
@@ -17,6 +17,27 @@ pub struct EndContraction {
     pub intensity: u8,
 }
 
+/// A contraction that has already finished, such as one timed offline and
+/// synced later.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RecordContraction {
+    pub labour_id: Uuid,
+    pub contraction_id: Uuid,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    pub intensity: u8,
+}
+
+/// Issued by the server to close a contraction left running past the
+/// maximum contraction duration.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AbandonContraction {
+    pub labour_id: Uuid,
+    pub contraction_id: Uuid,
+    pub end_time: DateTime<Utc>,
+    pub notify_mother: bool,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct UpdateContraction {
     pub labour_id: Uuid,
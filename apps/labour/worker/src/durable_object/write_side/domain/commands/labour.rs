@@ -1,5 +1,5 @@
 use chrono::{DateTime, Utc};
-use fern_labour_labour_shared::value_objects::LabourPhase;
+use fern_labour_labour_shared::value_objects::{LabourPhase, PhaseProgressionPolicy};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -48,3 +48,9 @@ pub struct AdvanceLabourPhase {
     pub labour_id: Uuid,
     pub labour_phase: LabourPhase,
 }
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UpdatePhaseProgressionPolicy {
+    pub labour_id: Uuid,
+    pub policy: Option<PhaseProgressionPolicy>,
+}
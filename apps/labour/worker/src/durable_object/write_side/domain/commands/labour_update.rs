@@ -1,4 +1,4 @@
-use fern_labour_labour_shared::value_objects::LabourUpdateType;
+use fern_labour_labour_shared::value_objects::{LabourUpdateTrigger, LabourUpdateType};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -7,6 +7,9 @@ pub struct PostLabourUpdate {
     pub labour_id: Uuid,
     pub labour_update_type: LabourUpdateType,
     pub message: String,
+    /// Set when this posts a pending scheduled update.
+    #[serde(default)]
+    pub scheduled_update_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -34,3 +37,18 @@ pub struct DeleteLabourUpdate {
     pub labour_id: Uuid,
     pub labour_update_id: Uuid,
 }
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScheduleLabourUpdate {
+    pub labour_id: Uuid,
+    pub labour_update_type: LabourUpdateType,
+    pub message: String,
+    pub trigger: LabourUpdateTrigger,
+    pub scheduled_by: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CancelScheduledLabourUpdate {
+    pub labour_id: Uuid,
+    pub scheduled_update_id: Uuid,
+}
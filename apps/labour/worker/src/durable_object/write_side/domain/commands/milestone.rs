@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use fern_labour_labour_shared::value_objects::LabourMilestoneType;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RecordMilestone {
+    pub labour_id: Uuid,
+    pub milestone_type: LabourMilestoneType,
+    pub occurred_at: Option<DateTime<Utc>>,
+    pub notes: Option<String>,
+    pub notify_subscribers: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UpdateMilestone {
+    pub labour_id: Uuid,
+    pub milestone_id: Uuid,
+    pub occurred_at: Option<DateTime<Utc>>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DeleteMilestone {
+    pub labour_id: Uuid,
+    pub milestone_id: Uuid,
+}
@@ -1,29 +1,39 @@
+pub mod birth_record;
 pub mod contraction;
 pub mod labour;
 pub mod labour_update;
+pub mod milestone;
 pub mod subscriber;
 pub mod subscription;
+pub mod undo;
 
-use chrono::Utc;
+use fern_labour_event_sourcing_rs::Clock;
 use fern_labour_labour_shared::{
-    ContractionCommand, LabourUpdateCommand, SubscriberCommand, SubscriptionCommand,
-    commands::labour::LabourCommand as LabourApiCommand,
+    ContractionCommand, LabourUpdateCommand, MilestoneCommand, SubscriberCommand,
+    SubscriptionCommand, commands::labour::LabourCommand as LabourApiCommand,
 };
 use serde::{Deserialize, Serialize};
 
-use contraction::{DeleteContraction, EndContraction, StartContraction, UpdateContraction};
+use birth_record::{AmendBirthRecord, PublishBirthAnnouncement, RecordBirth};
+use contraction::{
+    AbandonContraction, DeleteContraction, EndContraction, RecordContraction, StartContraction,
+    UpdateContraction,
+};
 use labour::{
     BeginLabour, CompleteLabour, DeleteLabour, PlanLabour, SendLabourInvite, UpdateLabourPlan,
+    UpdatePhaseProgressionPolicy,
 };
 use labour_update::{
-    DeleteLabourUpdate, PostApplicationLabourUpdate, PostLabourUpdate, UpdateLabourUpdateMessage,
-    UpdateLabourUpdateType,
+    CancelScheduledLabourUpdate, DeleteLabourUpdate, PostApplicationLabourUpdate, PostLabourUpdate,
+    ScheduleLabourUpdate, UpdateLabourUpdateMessage, UpdateLabourUpdateType,
 };
+use milestone::{DeleteMilestone, RecordMilestone, UpdateMilestone};
 use subscriber::{RequestAccess, Unsubscribe, UpdateAccessLevel, UpdateNotificationMethods};
 use subscription::{
     ApproveSubscriber, BlockSubscriber, RemoveSubscriber, SetSubscriptionToken, UnblockSubscriber,
     UpdateSubscriberRole,
 };
+use undo::UndoLastAction;
 
 use crate::durable_object::write_side::domain::commands::{
     labour::AdvanceLabourPhase, subscription::InvalidateSubscriptionToken,
@@ -38,9 +48,16 @@ pub enum LabourCommand {
     SendLabourInvite(SendLabourInvite),
     DeleteLabour(DeleteLabour),
     AdvanceLabourPhase(AdvanceLabourPhase),
+    UpdatePhaseProgressionPolicy(UpdatePhaseProgressionPolicy),
+    // Birth Record Commands
+    RecordBirth(RecordBirth),
+    AmendBirthRecord(AmendBirthRecord),
+    PublishBirthAnnouncement(PublishBirthAnnouncement),
     // Contraction Commands
     StartContraction(StartContraction),
     EndContraction(EndContraction),
+    RecordContraction(RecordContraction),
+    AbandonContraction(AbandonContraction),
     UpdateContraction(UpdateContraction),
     DeleteContraction(DeleteContraction),
     // Labour Update Commands
@@ -49,6 +66,12 @@ pub enum LabourCommand {
     UpdateLabourUpdateMessage(UpdateLabourUpdateMessage),
     UpdateLabourUpdateType(UpdateLabourUpdateType),
     DeleteLabourUpdate(DeleteLabourUpdate),
+    ScheduleLabourUpdate(ScheduleLabourUpdate),
+    CancelScheduledLabourUpdate(CancelScheduledLabourUpdate),
+    // Milestone Commands
+    RecordMilestone(RecordMilestone),
+    UpdateMilestone(UpdateMilestone),
+    DeleteMilestone(DeleteMilestone),
     // Subscriber Commands
     RequestAccess(RequestAccess),
     Unsubscribe(Unsubscribe),
@@ -62,6 +85,8 @@ pub enum LabourCommand {
     BlockSubscriber(BlockSubscriber),
     UnblockSubscriber(UnblockSubscriber),
     UpdateSubscriberRole(UpdateSubscriberRole),
+    // Undo Commands
+    UndoLastAction(UndoLastAction),
 }
 
 impl From<LabourApiCommand> for LabourCommand {
@@ -109,12 +134,45 @@ impl From<LabourApiCommand> for LabourCommand {
             LabourApiCommand::DeleteLabour { labour_id } => {
                 LabourCommand::DeleteLabour(DeleteLabour { labour_id })
             }
+            LabourApiCommand::UpdatePhaseProgressionPolicy { labour_id, policy } => {
+                LabourCommand::UpdatePhaseProgressionPolicy(UpdatePhaseProgressionPolicy {
+                    labour_id,
+                    policy,
+                })
+            }
+            LabourApiCommand::RecordBirth {
+                labour_id,
+                time_of_birth,
+                babies,
+            } => LabourCommand::RecordBirth(RecordBirth {
+                labour_id,
+                time_of_birth,
+                babies,
+            }),
+            LabourApiCommand::AmendBirthRecord {
+                labour_id,
+                time_of_birth,
+                babies,
+            } => LabourCommand::AmendBirthRecord(AmendBirthRecord {
+                labour_id,
+                time_of_birth,
+                babies,
+            }),
+            LabourApiCommand::PublishBirthAnnouncement { labour_id } => {
+                LabourCommand::PublishBirthAnnouncement(PublishBirthAnnouncement { labour_id })
+            }
+            LabourApiCommand::UndoLastAction { labour_id } => {
+                LabourCommand::UndoLastAction(UndoLastAction {
+                    labour_id,
+                    last_action: None,
+                })
+            }
         }
     }
 }
 
-impl From<ContractionCommand> for LabourCommand {
-    fn from(cmd: ContractionCommand) -> Self {
+impl From<(ContractionCommand, &dyn Clock)> for LabourCommand {
+    fn from((cmd, clock): (ContractionCommand, &dyn Clock)) -> Self {
         match cmd {
             ContractionCommand::StartContraction {
                 labour_id,
@@ -123,7 +181,7 @@ impl From<ContractionCommand> for LabourCommand {
             } => {
                 let datetime = match start_time {
                     Some(datetime) => datetime,
-                    None => Utc::now(),
+                    None => clock.now(),
                 };
 
                 LabourCommand::StartContraction(StartContraction {
@@ -140,7 +198,7 @@ impl From<ContractionCommand> for LabourCommand {
             } => {
                 let datetime = match end_time {
                     Some(datetime) => datetime,
-                    None => Utc::now(),
+                    None => clock.now(),
                 };
 
                 LabourCommand::EndContraction(EndContraction {
@@ -174,8 +232,46 @@ impl From<ContractionCommand> for LabourCommand {
     }
 }
 
-impl From<LabourUpdateCommand> for LabourCommand {
-    fn from(cmd: LabourUpdateCommand) -> Self {
+impl From<MilestoneCommand> for LabourCommand {
+    fn from(cmd: MilestoneCommand) -> Self {
+        match cmd {
+            MilestoneCommand::RecordMilestone {
+                labour_id,
+                milestone_type,
+                occurred_at,
+                notes,
+                notify_subscribers,
+            } => LabourCommand::RecordMilestone(RecordMilestone {
+                labour_id,
+                milestone_type,
+                occurred_at,
+                notes,
+                notify_subscribers,
+            }),
+            MilestoneCommand::UpdateMilestone {
+                labour_id,
+                milestone_id,
+                occurred_at,
+                notes,
+            } => LabourCommand::UpdateMilestone(UpdateMilestone {
+                labour_id,
+                milestone_id,
+                occurred_at,
+                notes,
+            }),
+            MilestoneCommand::DeleteMilestone {
+                labour_id,
+                milestone_id,
+            } => LabourCommand::DeleteMilestone(DeleteMilestone {
+                labour_id,
+                milestone_id,
+            }),
+        }
+    }
+}
+
+impl From<(LabourUpdateCommand, String)> for LabourCommand {
+    fn from((cmd, user_id): (LabourUpdateCommand, String)) -> Self {
         match cmd {
             LabourUpdateCommand::PostLabourUpdate {
                 labour_id,
@@ -185,6 +281,7 @@ impl From<LabourUpdateCommand> for LabourCommand {
                 labour_id,
                 labour_update_type,
                 message,
+                scheduled_update_id: None,
             }),
             LabourUpdateCommand::UpdateLabourUpdateMessage {
                 labour_id,
@@ -211,6 +308,25 @@ impl From<LabourUpdateCommand> for LabourCommand {
                 labour_id,
                 labour_update_id,
             }),
+            LabourUpdateCommand::ScheduleLabourUpdate {
+                labour_id,
+                labour_update_type,
+                message,
+                trigger,
+            } => LabourCommand::ScheduleLabourUpdate(ScheduleLabourUpdate {
+                labour_id,
+                labour_update_type,
+                message,
+                trigger,
+                scheduled_by: user_id,
+            }),
+            LabourUpdateCommand::CancelScheduledLabourUpdate {
+                labour_id,
+                scheduled_update_id,
+            } => LabourCommand::CancelScheduledLabourUpdate(CancelScheduledLabourUpdate {
+                labour_id,
+                scheduled_update_id,
+            }),
         }
     }
 }
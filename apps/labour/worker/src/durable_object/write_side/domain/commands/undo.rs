@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use fern_labour_event_sourcing_rs::EventEnvelope;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::durable_object::write_side::domain::LabourEvent;
+
+/// An event found in the stream that its user may be able to take back.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UndoableAction {
+    pub sequence: i64,
+    pub performed_at: DateTime<Utc>,
+    pub event: LabourEvent,
+}
+
+impl UndoableAction {
+    /// The latest undoable event caused by `user_id` that has not already
+    /// been undone.
+    pub fn latest_for_user(
+        envelopes: &[EventEnvelope<LabourEvent>],
+        user_id: &str,
+    ) -> Option<Self> {
+        let undone: HashSet<i64> = envelopes
+            .iter()
+            .filter_map(|envelope| match &envelope.event {
+                LabourEvent::ActionUndone(e) => Some(e.undone_sequence),
+                _ => None,
+            })
+            .collect();
+
+        envelopes
+            .iter()
+            .rev()
+            .find(|envelope| {
+                envelope.metadata.user_id == user_id
+                    && envelope.event.is_undoable()
+                    && !undone.contains(&envelope.metadata.sequence)
+            })
+            .map(|envelope| Self {
+                sequence: envelope.metadata.sequence,
+                performed_at: envelope.metadata.timestamp,
+                event: envelope.event.clone(),
+            })
+    }
+}
+
+/// Takes back the issuing user's last undoable action. `last_action` is
+/// looked up from the event stream before the command reaches the aggregate.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UndoLastAction {
+    pub labour_id: Uuid,
+    pub last_action: Option<UndoableAction>,
+}
@@ -0,0 +1,122 @@
+use anyhow::{Result, bail};
+use chrono::{DateTime, Utc};
+use fern_labour_labour_shared::value_objects::Baby;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_BABIES: usize = 8;
+pub const MAX_BABY_WEIGHT_GRAMS: u32 = 10_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BirthRecord {
+    time_of_birth: DateTime<Utc>,
+    babies: Vec<Baby>,
+    published: bool,
+}
+
+impl BirthRecord {
+    pub fn create(time_of_birth: DateTime<Utc>, babies: Vec<Baby>) -> Result<Self> {
+        Self::validate_babies(&babies)?;
+        Ok(Self {
+            time_of_birth,
+            babies,
+            published: false,
+        })
+    }
+
+    pub fn amend(
+        &mut self,
+        time_of_birth: Option<DateTime<Utc>>,
+        babies: Option<Vec<Baby>>,
+    ) -> Result<()> {
+        if let Some(babies) = babies {
+            Self::validate_babies(&babies)?;
+            self.babies = babies;
+        }
+        if let Some(time_of_birth) = time_of_birth {
+            self.time_of_birth = time_of_birth;
+        }
+        Ok(())
+    }
+
+    pub fn publish(&mut self) {
+        self.published = true;
+    }
+
+    pub fn time_of_birth(&self) -> &DateTime<Utc> {
+        &self.time_of_birth
+    }
+
+    pub fn babies(&self) -> &[Baby] {
+        &self.babies
+    }
+
+    pub fn is_published(&self) -> bool {
+        self.published
+    }
+
+    fn validate_babies(babies: &[Baby]) -> Result<()> {
+        if babies.is_empty() {
+            bail!("A birth record needs at least one baby");
+        }
+        if babies.len() > MAX_BABIES {
+            bail!("A birth record can have at most {MAX_BABIES} babies");
+        }
+        for baby in babies {
+            if baby
+                .name
+                .as_ref()
+                .is_some_and(|name| name.trim().is_empty())
+            {
+                bail!("Baby name cannot be empty");
+            }
+            if baby
+                .weight_grams
+                .is_some_and(|weight| weight == 0 || weight > MAX_BABY_WEIGHT_GRAMS)
+            {
+                bail!("Baby weight must be between 1 and {MAX_BABY_WEIGHT_GRAMS} grams");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fern_labour_labour_shared::value_objects::BabySex;
+
+    fn baby(name: &str) -> Baby {
+        Baby {
+            name: Some(name.to_string()),
+            weight_grams: Some(3400),
+            sex: Some(BabySex::FEMALE),
+        }
+    }
+
+    #[test]
+    fn create_rejects_no_babies() {
+        assert!(BirthRecord::create(Utc::now(), vec![]).is_err());
+    }
+
+    #[test]
+    fn create_rejects_implausible_weights() {
+        let mut heavy = baby("Ada");
+        heavy.weight_grams = Some(MAX_BABY_WEIGHT_GRAMS + 1);
+
+        assert!(BirthRecord::create(Utc::now(), vec![heavy]).is_err());
+    }
+
+    #[test]
+    fn amend_keeps_unchanged_fields() {
+        let time_of_birth = Utc::now();
+        let mut record = BirthRecord::create(time_of_birth, vec![baby("Ada")]).unwrap();
+
+        record
+            .amend(None, Some(vec![baby("Ada"), baby("Max")]))
+            .unwrap();
+
+        assert_eq!(record.time_of_birth(), &time_of_birth);
+        assert_eq!(record.babies().len(), 2);
+        assert!(!record.is_published());
+    }
+}
@@ -10,6 +10,8 @@ pub struct Contraction {
     labour_id: Uuid,
     duration: Duration,
     intensity: Option<u8>,
+    #[serde(default)]
+    auto_closed: bool,
 }
 
 impl Contraction {
@@ -20,6 +22,7 @@ impl Contraction {
             labour_id,
             duration,
             intensity: None,
+            auto_closed: false,
         })
     }
 
@@ -30,6 +33,22 @@ impl Contraction {
         Ok(())
     }
 
+    /// Closes a contraction nobody ended. It keeps no intensity, so it is left
+    /// out of phase progression and pattern detection.
+    pub fn abandon(&mut self, end_time: DateTime<Utc>) -> Result<()> {
+        let duration = Duration::create(*self.start_time(), end_time)?;
+        self.duration = duration;
+        self.auto_closed = true;
+        Ok(())
+    }
+
+    /// Puts an ended contraction back in progress from its original start.
+    pub fn resume(&mut self) {
+        self.duration = Duration::instant(*self.start_time());
+        self.intensity = None;
+        self.auto_closed = false;
+    }
+
     pub fn update(
         &mut self,
         start_time: Option<DateTime<Utc>>,
@@ -42,6 +61,11 @@ impl Contraction {
         let duration = Duration::create(new_start, new_end)?;
         self.duration = duration;
 
+        // Correcting the end of an auto-closed contraction makes it a real one.
+        if end_time.is_some() {
+            self.auto_closed = false;
+        }
+
         if let Some(intensity_value) = intensity {
             self.intensity = Some(intensity_value);
         }
@@ -65,6 +89,10 @@ impl Contraction {
         self.duration.end_time()
     }
 
+    pub fn is_auto_closed(&self) -> bool {
+        self.auto_closed
+    }
+
     pub fn intensity(&self) -> Option<u8> {
         self.intensity
     }
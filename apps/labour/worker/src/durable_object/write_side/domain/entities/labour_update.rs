@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 pub const ANNOUNCEMENT_COOLDOWN_SECONDS: i64 = 10;
+/// Longest message a labour update can carry, in characters.
+pub const MAX_LABOUR_UPDATE_MESSAGE_LENGTH: usize = 1000;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LabourUpdate {
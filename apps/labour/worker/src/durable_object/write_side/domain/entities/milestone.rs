@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use fern_labour_labour_shared::value_objects::LabourMilestoneType;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Milestone {
+    id: Uuid,
+    labour_id: Uuid,
+    milestone_type: LabourMilestoneType,
+    occurred_at: DateTime<Utc>,
+    notes: Option<String>,
+    edited: bool,
+}
+
+impl Milestone {
+    pub fn create(
+        milestone_id: Uuid,
+        labour_id: Uuid,
+        milestone_type: LabourMilestoneType,
+        occurred_at: DateTime<Utc>,
+        notes: Option<String>,
+    ) -> Self {
+        Self {
+            id: milestone_id,
+            labour_id,
+            milestone_type,
+            occurred_at,
+            notes,
+            edited: false,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn milestone_type(&self) -> &LabourMilestoneType {
+        &self.milestone_type
+    }
+
+    pub fn occurred_at(&self) -> &DateTime<Utc> {
+        &self.occurred_at
+    }
+
+    pub fn notes(&self) -> Option<&str> {
+        self.notes.as_deref()
+    }
+
+    pub fn update(&mut self, occurred_at: Option<DateTime<Utc>>, notes: Option<String>) {
+        if let Some(occurred_at) = occurred_at {
+            self.occurred_at = occurred_at;
+        }
+        if notes.is_some() {
+            self.notes = notes;
+        }
+        self.edited = true;
+    }
+}
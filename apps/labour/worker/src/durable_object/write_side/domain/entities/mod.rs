@@ -1,3 +1,6 @@
+pub mod birth_record;
 pub mod contraction;
 pub mod labour_update;
+pub mod milestone;
+pub mod scheduled_labour_update;
 pub mod subscription;
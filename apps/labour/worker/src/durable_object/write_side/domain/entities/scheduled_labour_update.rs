@@ -0,0 +1,55 @@
+use fern_labour_labour_shared::value_objects::{LabourUpdateTrigger, LabourUpdateType};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A labour update waiting for its trigger before it is posted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledLabourUpdate {
+    id: Uuid,
+    labour_id: Uuid,
+    labour_update_type: LabourUpdateType,
+    message: String,
+    trigger: LabourUpdateTrigger,
+    scheduled_by: String,
+}
+
+impl ScheduledLabourUpdate {
+    pub fn create(
+        scheduled_update_id: Uuid,
+        labour_id: Uuid,
+        labour_update_type: LabourUpdateType,
+        message: String,
+        trigger: LabourUpdateTrigger,
+        scheduled_by: String,
+    ) -> Self {
+        Self {
+            id: scheduled_update_id,
+            labour_id,
+            labour_update_type,
+            message,
+            trigger,
+            scheduled_by,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn labour_update_type(&self) -> &LabourUpdateType {
+        &self.labour_update_type
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn trigger(&self) -> &LabourUpdateTrigger {
+        &self.trigger
+    }
+
+    /// The user the update is posted as once it is due.
+    pub fn scheduled_by(&self) -> &str {
+        &self.scheduled_by
+    }
+}
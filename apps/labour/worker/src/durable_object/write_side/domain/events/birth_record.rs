@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use fern_labour_event_sourcing_rs::DomainEvent;
+use fern_labour_labour_shared::value_objects::Baby;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct BirthRecorded {
+    pub labour_id: Uuid,
+    pub time_of_birth: DateTime<Utc>,
+    pub babies: Vec<Baby>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct BirthRecordAmended {
+    pub labour_id: Uuid,
+    pub time_of_birth: DateTime<Utc>,
+    pub babies: Vec<Baby>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct BirthAnnouncementPublished {
+    pub labour_id: Uuid,
+    pub time_of_birth: DateTime<Utc>,
+    pub babies: Vec<Baby>,
+    pub published_at: DateTime<Utc>,
+}
@@ -1,16 +1,19 @@
 use chrono::{DateTime, Utc};
-use fern_labour_event_sourcing_rs::{Event, impl_event};
+use fern_labour_event_sourcing_rs::DomainEvent;
+use fern_labour_labour_shared::value_objects::contraction::ContractionPatternRule;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct ContractionStarted {
     pub labour_id: Uuid,
     pub contraction_id: Uuid,
     pub start_time: DateTime<Utc>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct ContractionEnded {
     pub labour_id: Uuid,
     pub contraction_id: Uuid,
@@ -18,7 +21,28 @@ pub struct ContractionEnded {
     pub intensity: u8,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+/// An active contraction closed by the server after running for longer than
+/// any real contraction would, usually because the app was closed mid-timing.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct ContractionAbandoned {
+    pub labour_id: Uuid,
+    pub contraction_id: Uuid,
+    pub end_time: DateTime<Utc>,
+    pub notify_mother: bool,
+}
+
+/// An ended contraction put back in progress, compensating for an end that
+/// was undone.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct ContractionResumed {
+    pub labour_id: Uuid,
+    pub contraction_id: Uuid,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct ContractionUpdated {
     pub labour_id: Uuid,
     pub contraction_id: Uuid,
@@ -27,13 +51,17 @@ pub struct ContractionUpdated {
     pub intensity: Option<u8>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct ContractionDeleted {
     pub labour_id: Uuid,
     pub contraction_id: Uuid,
 }
 
-impl_event!(ContractionStarted, labour_id);
-impl_event!(ContractionEnded, labour_id);
-impl_event!(ContractionUpdated, labour_id);
-impl_event!(ContractionDeleted, labour_id);
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct ContractionPatternDetected {
+    pub labour_id: Uuid,
+    pub rule: ContractionPatternRule,
+    pub detected_at: DateTime<Utc>,
+}
@@ -0,0 +1,262 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use fern_labour_labour_shared::value_objects::{
+    Baby, LabourMilestoneType, LabourPhase, LabourUpdateTrigger, LabourUpdateType,
+    SubscriberAccessLevel, SubscriberContactMethod, SubscriberRole,
+    contraction::ContractionPatternRule,
+};
+use uuid::Uuid;
+
+use super::*;
+
+pub const LABOUR_ID: Uuid = Uuid::from_u128(0x1ab0);
+pub const CONTRACTION_ID: Uuid = Uuid::from_u128(0xc0);
+pub const LABOUR_UPDATE_ID: Uuid = Uuid::from_u128(0x1d);
+pub const SCHEDULED_UPDATE_ID: Uuid = Uuid::from_u128(0x5c);
+pub const MILESTONE_ID: Uuid = Uuid::from_u128(0x311);
+pub const SUBSCRIPTION_ID: Uuid = Uuid::from_u128(0x5b);
+
+/// Sequence of the `ContractionEnded` in [`one_of_each`].
+pub const CONTRACTION_ENDED_SEQUENCE: i64 = 14;
+
+fn at(minutes: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap() + Duration::minutes(minutes)
+}
+
+fn babies() -> Vec<Baby> {
+    vec![Baby {
+        name: Some("Ada".to_string()),
+        weight_grams: Some(3400),
+        sex: None,
+    }]
+}
+
+/// One event of every `LabourEvent` variant, in an order a labour could
+/// plausibly record them, so consumers that declare the event types they
+/// handle can be checked against the events they actually react to.
+pub fn one_of_each() -> Vec<LabourEvent> {
+    let labour_id = LABOUR_ID;
+    vec![
+        LabourEvent::LabourPlanned(LabourPlanned {
+            labour_id,
+            mother_id: "mother".to_string(),
+            mother_name: "Mother".to_string(),
+            first_labour: true,
+            due_date: at(0),
+            labour_name: None,
+        }),
+        LabourEvent::LabourPlanUpdated(LabourPlanUpdated {
+            labour_id,
+            first_labour: true,
+            due_date: at(60),
+            labour_name: Some("Baby".to_string()),
+        }),
+        LabourEvent::PhaseProgressionPolicyUpdated(PhaseProgressionPolicyUpdated {
+            labour_id,
+            policy: None,
+        }),
+        LabourEvent::SubscriptionTokenSet(SubscriptionTokenSet {
+            labour_id,
+            token: "token".to_string(),
+        }),
+        LabourEvent::SubscriberRequested(SubscriberRequested {
+            labour_id,
+            subscriber_id: "subscriber".to_string(),
+            subscription_id: SUBSCRIPTION_ID,
+        }),
+        LabourEvent::SubscriberApproved(SubscriberApproved {
+            labour_id,
+            subscription_id: SUBSCRIPTION_ID,
+        }),
+        LabourEvent::SubscriberNotificationMethodsUpdated(SubscriberNotificationMethodsUpdated {
+            labour_id,
+            subscription_id: SUBSCRIPTION_ID,
+            notification_methods: vec![SubscriberContactMethod::EMAIL],
+        }),
+        LabourEvent::SubscriberAccessLevelUpdated(SubscriberAccessLevelUpdated {
+            labour_id,
+            subscription_id: SUBSCRIPTION_ID,
+            access_level: SubscriberAccessLevel::SUPPORTER,
+        }),
+        LabourEvent::SubscriberRoleUpdated(SubscriberRoleUpdated {
+            labour_id,
+            subscription_id: SUBSCRIPTION_ID,
+            role: SubscriberRole::BIRTH_PARTNER,
+        }),
+        LabourEvent::LabourInviteSent(LabourInviteSent {
+            labour_id,
+            invite_email: "friend@example.com".to_string(),
+        }),
+        LabourEvent::LabourBegun(LabourBegun {
+            labour_id,
+            start_time: at(120),
+        }),
+        LabourEvent::LabourPhaseChanged(LabourPhaseChanged {
+            labour_id,
+            labour_phase: LabourPhase::EARLY,
+        }),
+        LabourEvent::ContractionStarted(ContractionStarted {
+            labour_id,
+            contraction_id: CONTRACTION_ID,
+            start_time: at(130),
+        }),
+        LabourEvent::ContractionEnded(ContractionEnded {
+            labour_id,
+            contraction_id: CONTRACTION_ID,
+            end_time: at(131),
+            intensity: 5,
+        }),
+        LabourEvent::ContractionUpdated(ContractionUpdated {
+            labour_id,
+            contraction_id: CONTRACTION_ID,
+            start_time: None,
+            end_time: None,
+            intensity: Some(6),
+        }),
+        LabourEvent::ContractionResumed(ContractionResumed {
+            labour_id,
+            contraction_id: CONTRACTION_ID,
+        }),
+        LabourEvent::ContractionAbandoned(ContractionAbandoned {
+            labour_id,
+            contraction_id: CONTRACTION_ID,
+            end_time: at(132),
+            notify_mother: true,
+        }),
+        LabourEvent::ContractionPatternDetected(ContractionPatternDetected {
+            labour_id,
+            rule: ContractionPatternRule::FIVE_ONE_ONE,
+            detected_at: at(190),
+        }),
+        LabourEvent::LabourUpdatePosted(LabourUpdatePosted {
+            labour_id,
+            labour_update_id: LABOUR_UPDATE_ID,
+            labour_update_type: LabourUpdateType::ANNOUNCEMENT,
+            message: "On our way".to_string(),
+            application_generated: false,
+            sent_time: at(200),
+        }),
+        LabourEvent::LabourUpdateMessageUpdated(LabourUpdateMessageUpdated {
+            labour_id,
+            labour_update_id: LABOUR_UPDATE_ID,
+            message: "On our way now".to_string(),
+        }),
+        LabourEvent::LabourUpdateTypeUpdated(LabourUpdateTypeUpdated {
+            labour_id,
+            labour_update_id: LABOUR_UPDATE_ID,
+            labour_update_type: LabourUpdateType::ANNOUNCEMENT,
+        }),
+        LabourEvent::LabourUpdateScheduled(LabourUpdateScheduled {
+            labour_id,
+            scheduled_update_id: SCHEDULED_UPDATE_ID,
+            labour_update_type: LabourUpdateType::STATUS_UPDATE,
+            message: "Still going".to_string(),
+            trigger: LabourUpdateTrigger::AtTime { send_at: at(240) },
+            scheduled_by: "mother".to_string(),
+        }),
+        LabourEvent::ScheduledLabourUpdatePosted(ScheduledLabourUpdatePosted {
+            labour_id,
+            scheduled_update_id: SCHEDULED_UPDATE_ID,
+            labour_update_id: LABOUR_UPDATE_ID,
+        }),
+        LabourEvent::ScheduledLabourUpdateCancelled(ScheduledLabourUpdateCancelled {
+            labour_id,
+            scheduled_update_id: SCHEDULED_UPDATE_ID,
+        }),
+        LabourEvent::MilestoneRecorded(MilestoneRecorded {
+            labour_id,
+            milestone_id: MILESTONE_ID,
+            milestone_type: LabourMilestoneType::WATERS_BROKEN,
+            occurred_at: at(250),
+            notes: None,
+            notify_subscribers: true,
+        }),
+        LabourEvent::MilestoneUpdated(MilestoneUpdated {
+            labour_id,
+            milestone_id: MILESTONE_ID,
+            occurred_at: None,
+            notes: Some("Clear".to_string()),
+        }),
+        LabourEvent::ActionUndone(ActionUndone {
+            labour_id,
+            undone_sequence: CONTRACTION_ENDED_SEQUENCE,
+        }),
+        LabourEvent::BirthRecorded(BirthRecorded {
+            labour_id,
+            time_of_birth: at(300),
+            babies: babies(),
+        }),
+        LabourEvent::BirthRecordAmended(BirthRecordAmended {
+            labour_id,
+            time_of_birth: at(301),
+            babies: babies(),
+        }),
+        LabourEvent::BirthAnnouncementPublished(BirthAnnouncementPublished {
+            labour_id,
+            time_of_birth: at(301),
+            babies: babies(),
+            published_at: at(310),
+        }),
+        LabourEvent::LabourCompleted(LabourCompleted {
+            labour_id,
+            notes: None,
+            end_time: at(320),
+        }),
+        LabourEvent::SubscriberApprovalRevoked(SubscriberApprovalRevoked {
+            labour_id,
+            subscription_id: SUBSCRIPTION_ID,
+        }),
+        LabourEvent::SubscriberBlocked(SubscriberBlocked {
+            labour_id,
+            subscription_id: SUBSCRIPTION_ID,
+        }),
+        LabourEvent::SubscriberUnblocked(SubscriberUnblocked {
+            labour_id,
+            subscription_id: SUBSCRIPTION_ID,
+        }),
+        LabourEvent::SubscriberRemoved(SubscriberRemoved {
+            labour_id,
+            subscription_id: SUBSCRIPTION_ID,
+        }),
+        LabourEvent::SubscriberUnsubscribed(SubscriberUnsubscribed {
+            labour_id,
+            subscription_id: SUBSCRIPTION_ID,
+        }),
+        LabourEvent::SubscriptionTokenInvalidated(SubscriptionTokenInvalidated { labour_id }),
+        LabourEvent::MilestoneDeleted(MilestoneDeleted {
+            labour_id,
+            milestone_id: MILESTONE_ID,
+        }),
+        LabourEvent::LabourUpdateDeleted(LabourUpdateDeleted {
+            labour_id,
+            labour_update_id: LABOUR_UPDATE_ID,
+        }),
+        LabourEvent::ContractionDeleted(ContractionDeleted {
+            labour_id,
+            contraction_id: CONTRACTION_ID,
+        }),
+        LabourEvent::LabourDeleted(LabourDeleted { labour_id }),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use fern_labour_event_sourcing_rs::{Event, EventTypeRegistry};
+
+    use super::*;
+
+    #[test]
+    fn one_of_each_covers_every_event_type() {
+        let events = one_of_each();
+        let sampled: Vec<&str> = events.iter().map(|event| event.event_type()).collect();
+
+        for info in LabourEvent::EVENT_TYPES {
+            assert_eq!(
+                sampled.iter().filter(|t| **t == info.event_type).count(),
+                1,
+                "{} should appear exactly once",
+                info.event_type
+            );
+        }
+        assert_eq!(sampled.len(), LabourEvent::EVENT_TYPES.len());
+    }
+}
@@ -1,10 +1,11 @@
 use chrono::{DateTime, Utc};
-use fern_labour_event_sourcing_rs::{Event, impl_event};
-use fern_labour_labour_shared::value_objects::LabourPhase;
+use fern_labour_event_sourcing_rs::DomainEvent;
+use fern_labour_labour_shared::value_objects::{LabourPhase, PhaseProgressionPolicy};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct LabourPlanned {
     pub labour_id: Uuid,
     pub mother_id: String,
@@ -14,7 +15,8 @@ pub struct LabourPlanned {
     pub labour_name: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct LabourPlanUpdated {
     pub labour_id: Uuid,
     pub first_labour: bool,
@@ -22,40 +24,44 @@ pub struct LabourPlanUpdated {
     pub labour_name: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct LabourBegun {
     pub labour_id: Uuid,
     pub start_time: DateTime<Utc>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct LabourCompleted {
     pub labour_id: Uuid,
     pub notes: Option<String>,
     pub end_time: DateTime<Utc>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct LabourInviteSent {
     pub labour_id: Uuid,
     pub invite_email: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct LabourDeleted {
     pub labour_id: Uuid,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct LabourPhaseChanged {
     pub labour_id: Uuid,
     pub labour_phase: LabourPhase,
 }
 
-impl_event!(LabourPlanned, labour_id);
-impl_event!(LabourPlanUpdated, labour_id);
-impl_event!(LabourBegun, labour_id);
-impl_event!(LabourCompleted, labour_id);
-impl_event!(LabourInviteSent, labour_id);
-impl_event!(LabourDeleted, labour_id);
-impl_event!(LabourPhaseChanged, labour_id);
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct PhaseProgressionPolicyUpdated {
+    pub labour_id: Uuid,
+    pub policy: Option<PhaseProgressionPolicy>,
+}
@@ -1,10 +1,11 @@
 use chrono::{DateTime, Utc};
-use fern_labour_event_sourcing_rs::{Event, impl_event};
-use fern_labour_labour_shared::value_objects::LabourUpdateType;
+use fern_labour_event_sourcing_rs::DomainEvent;
+use fern_labour_labour_shared::value_objects::{LabourUpdateTrigger, LabourUpdateType};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct LabourUpdatePosted {
     pub labour_id: Uuid,
     pub labour_update_id: Uuid,
@@ -14,27 +15,52 @@ pub struct LabourUpdatePosted {
     pub sent_time: DateTime<Utc>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct LabourUpdateMessageUpdated {
     pub labour_id: Uuid,
     pub labour_update_id: Uuid,
     pub message: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct LabourUpdateTypeUpdated {
     pub labour_id: Uuid,
     pub labour_update_id: Uuid,
     pub labour_update_type: LabourUpdateType,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct LabourUpdateDeleted {
     pub labour_id: Uuid,
     pub labour_update_id: Uuid,
 }
 
-impl_event!(LabourUpdatePosted, labour_id);
-impl_event!(LabourUpdateMessageUpdated, labour_id);
-impl_event!(LabourUpdateTypeUpdated, labour_id);
-impl_event!(LabourUpdateDeleted, labour_id);
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct LabourUpdateScheduled {
+    pub labour_id: Uuid,
+    pub scheduled_update_id: Uuid,
+    pub labour_update_type: LabourUpdateType,
+    pub message: String,
+    pub trigger: LabourUpdateTrigger,
+    pub scheduled_by: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct ScheduledLabourUpdateCancelled {
+    pub labour_id: Uuid,
+    pub scheduled_update_id: Uuid,
+}
+
+/// Follows the `LabourUpdatePosted` that sent out a scheduled update.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct ScheduledLabourUpdatePosted {
+    pub labour_id: Uuid,
+    pub scheduled_update_id: Uuid,
+    pub labour_update_id: Uuid,
+}
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use fern_labour_event_sourcing_rs::DomainEvent;
+use fern_labour_labour_shared::value_objects::LabourMilestoneType;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct MilestoneRecorded {
+    pub labour_id: Uuid,
+    pub milestone_id: Uuid,
+    pub milestone_type: LabourMilestoneType,
+    pub occurred_at: DateTime<Utc>,
+    pub notes: Option<String>,
+    pub notify_subscribers: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct MilestoneUpdated {
+    pub labour_id: Uuid,
+    pub milestone_id: Uuid,
+    pub occurred_at: Option<DateTime<Utc>>,
+    pub notes: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct MilestoneDeleted {
+    pub labour_id: Uuid,
+    pub milestone_id: Uuid,
+}
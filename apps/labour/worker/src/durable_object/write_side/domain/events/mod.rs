@@ -1,20 +1,28 @@
+pub mod birth_record;
 pub mod contraction;
+#[cfg(test)]
+pub mod fixtures;
 pub mod labour;
 pub mod labour_update;
+pub mod milestone;
 pub mod subscriber;
 pub mod subscription;
+pub mod undo;
+pub mod upcasters;
 
+pub use birth_record::*;
 pub use contraction::*;
 pub use labour::*;
 pub use labour_update::*;
+pub use milestone::*;
 pub use subscriber::*;
 pub use subscription::*;
+pub use undo::*;
 
-use fern_labour_event_sourcing_rs::{Event, StoredEvent};
+use fern_labour_event_sourcing_rs::DomainEvent;
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, DomainEvent)]
 #[serde(tag = "type", content = "data")]
 pub enum LabourEvent {
     LabourPlanned(LabourPlanned),
@@ -24,16 +32,47 @@ pub enum LabourEvent {
     LabourInviteSent(LabourInviteSent),
     LabourDeleted(LabourDeleted),
     LabourPhaseChanged(LabourPhaseChanged),
+    PhaseProgressionPolicyUpdated(PhaseProgressionPolicyUpdated),
 
+    BirthRecorded(BirthRecorded),
+    BirthRecordAmended(BirthRecordAmended),
+    BirthAnnouncementPublished(BirthAnnouncementPublished),
+
+    #[event(contraction_id)]
     ContractionStarted(ContractionStarted),
+    #[event(contraction_id)]
     ContractionEnded(ContractionEnded),
+    #[event(contraction_id)]
+    ContractionAbandoned(ContractionAbandoned),
+    #[event(contraction_id)]
+    ContractionResumed(ContractionResumed),
+    #[event(contraction_id)]
     ContractionUpdated(ContractionUpdated),
+    #[event(contraction_id)]
     ContractionDeleted(ContractionDeleted),
+    ContractionPatternDetected(ContractionPatternDetected),
 
+    #[event(labour_update_id)]
     LabourUpdatePosted(LabourUpdatePosted),
+    #[event(labour_update_id)]
     LabourUpdateMessageUpdated(LabourUpdateMessageUpdated),
+    #[event(labour_update_id)]
     LabourUpdateTypeUpdated(LabourUpdateTypeUpdated),
+    #[event(labour_update_id)]
     LabourUpdateDeleted(LabourUpdateDeleted),
+    #[event(scheduled_update_id)]
+    LabourUpdateScheduled(LabourUpdateScheduled),
+    #[event(scheduled_update_id)]
+    ScheduledLabourUpdateCancelled(ScheduledLabourUpdateCancelled),
+    #[event(scheduled_update_id)]
+    ScheduledLabourUpdatePosted(ScheduledLabourUpdatePosted),
+
+    #[event(milestone_id)]
+    MilestoneRecorded(MilestoneRecorded),
+    #[event(milestone_id)]
+    MilestoneUpdated(MilestoneUpdated),
+    #[event(milestone_id)]
+    MilestoneDeleted(MilestoneDeleted),
 
     SubscriptionTokenSet(SubscriptionTokenSet),
     SubscriptionTokenInvalidated(SubscriptionTokenInvalidated),
@@ -43,90 +82,23 @@ pub enum LabourEvent {
     SubscriberNotificationMethodsUpdated(SubscriberNotificationMethodsUpdated),
     SubscriberAccessLevelUpdated(SubscriberAccessLevelUpdated),
     SubscriberApproved(SubscriberApproved),
+    SubscriberApprovalRevoked(SubscriberApprovalRevoked),
     SubscriberRemoved(SubscriberRemoved),
     SubscriberBlocked(SubscriberBlocked),
     SubscriberUnblocked(SubscriberUnblocked),
     SubscriberRoleUpdated(SubscriberRoleUpdated),
+
+    ActionUndone(ActionUndone),
 }
 
 impl LabourEvent {
-    pub fn into_stored_event(self) -> StoredEvent {
-        let event_str = serde_json::to_string(&self).unwrap();
-
-        StoredEvent {
-            aggregate_id: self.aggregate_id().to_string(),
-            event_type: self.event_type().to_string(),
-            event_data: event_str,
-            event_version: self.event_version(),
-        }
-    }
-
-    pub fn from_stored_event(event: StoredEvent) -> Self {
-        serde_json::from_str(&event.event_data).unwrap()
-    }
-
-    pub fn contraction_id(&self) -> Option<Uuid> {
+    /// Whether the user who caused this event can take it back with
+    /// `UndoLastAction`.
+    pub fn is_undoable(&self) -> bool {
         match self {
-            LabourEvent::ContractionStarted(e) => Some(e.contraction_id),
-            LabourEvent::ContractionEnded(e) => Some(e.contraction_id),
-            LabourEvent::ContractionUpdated(e) => Some(e.contraction_id),
-            LabourEvent::ContractionDeleted(e) => Some(e.contraction_id),
-            _ => None,
-        }
-    }
-
-    pub fn labour_update_id(&self) -> Option<Uuid> {
-        match self {
-            LabourEvent::LabourUpdatePosted(e) => Some(e.labour_update_id),
-            LabourEvent::LabourUpdateMessageUpdated(e) => Some(e.labour_update_id),
-            LabourEvent::LabourUpdateTypeUpdated(e) => Some(e.labour_update_id),
-            LabourEvent::LabourUpdateDeleted(e) => Some(e.labour_update_id),
-            _ => None,
+            LabourEvent::ContractionEnded(_) | LabourEvent::SubscriberApproved(_) => true,
+            LabourEvent::LabourUpdatePosted(e) => !e.application_generated,
+            _ => false,
         }
     }
 }
-
-macro_rules! delegate_event_impl {
-      ($($variant:ident),+ $(,)?) => {
-          impl Event for LabourEvent {
-              fn event_type(&self) -> &str {
-                  match self { $(LabourEvent::$variant(e) => e.event_type(),)+ }
-              }
-              fn event_version(&self) -> i64 {
-                  match self { $(LabourEvent::$variant(e) => e.event_version(),)+ }
-              }
-              fn aggregate_id(&self) -> Uuid {
-                  match self { $(LabourEvent::$variant(e) => e.aggregate_id(),)+ }
-              }
-          }
-      };
-  }
-
-delegate_event_impl!(
-    ContractionStarted,
-    ContractionUpdated,
-    ContractionEnded,
-    ContractionDeleted,
-    LabourPlanned,
-    LabourBegun,
-    LabourCompleted,
-    LabourDeleted,
-    LabourInviteSent,
-    LabourPlanUpdated,
-    LabourUpdateDeleted,
-    LabourUpdatePosted,
-    LabourUpdateMessageUpdated,
-    LabourUpdateTypeUpdated,
-    LabourPhaseChanged,
-    SubscriptionTokenSet,
-    SubscriptionTokenInvalidated,
-    SubscriberApproved,
-    SubscriberBlocked,
-    SubscriberRequested,
-    SubscriberRemoved,
-    SubscriberUnblocked,
-    SubscriberUnsubscribed,
-    SubscriberNotificationMethodsUpdated,
-    SubscriberAccessLevelUpdated,
-    SubscriberRoleUpdated,
-);
@@ -1,74 +1,82 @@
-use fern_labour_event_sourcing_rs::{Event, impl_event};
+use fern_labour_event_sourcing_rs::DomainEvent;
 use fern_labour_labour_shared::value_objects::{
     SubscriberAccessLevel, SubscriberContactMethod, SubscriberRole,
 };
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct SubscriberRequested {
     pub labour_id: Uuid,
     pub subscriber_id: String,
     pub subscription_id: Uuid,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct SubscriberUnsubscribed {
     pub labour_id: Uuid,
     pub subscription_id: Uuid,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct SubscriberNotificationMethodsUpdated {
     pub labour_id: Uuid,
     pub subscription_id: Uuid,
     pub notification_methods: Vec<SubscriberContactMethod>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct SubscriberAccessLevelUpdated {
     pub labour_id: Uuid,
     pub subscription_id: Uuid,
     pub access_level: SubscriberAccessLevel,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct SubscriberApproved {
     pub labour_id: Uuid,
     pub subscription_id: Uuid,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+/// Returns an approved subscription to `REQUESTED`, compensating for an
+/// approval that was undone.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct SubscriberApprovalRevoked {
+    pub labour_id: Uuid,
+    pub subscription_id: Uuid,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct SubscriberRemoved {
     pub labour_id: Uuid,
     pub subscription_id: Uuid,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct SubscriberBlocked {
     pub labour_id: Uuid,
     pub subscription_id: Uuid,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct SubscriberUnblocked {
     pub labour_id: Uuid,
     pub subscription_id: Uuid,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct SubscriberRoleUpdated {
     pub labour_id: Uuid,
     pub subscription_id: Uuid,
     pub role: SubscriberRole,
 }
-
-impl_event!(SubscriberRequested, labour_id);
-impl_event!(SubscriberUnsubscribed, labour_id);
-impl_event!(SubscriberNotificationMethodsUpdated, labour_id);
-impl_event!(SubscriberAccessLevelUpdated, labour_id);
-impl_event!(SubscriberApproved, labour_id);
-impl_event!(SubscriberRemoved, labour_id);
-impl_event!(SubscriberBlocked, labour_id);
-impl_event!(SubscriberUnblocked, labour_id);
-impl_event!(SubscriberRoleUpdated, labour_id);
@@ -1,17 +1,16 @@
-use fern_labour_event_sourcing_rs::{Event, impl_event};
+use fern_labour_event_sourcing_rs::DomainEvent;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct SubscriptionTokenSet {
     pub labour_id: Uuid,
     pub token: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
 pub struct SubscriptionTokenInvalidated {
     pub labour_id: Uuid,
 }
-
-impl_event!(SubscriptionTokenSet, labour_id);
-impl_event!(SubscriptionTokenInvalidated, labour_id);
@@ -0,0 +1,12 @@
+use fern_labour_event_sourcing_rs::DomainEvent;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Records that the event at `undone_sequence` was taken back. It follows the
+/// compensating events that reverse it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, DomainEvent)]
+#[event(aggregate_id = "labour_id")]
+pub struct ActionUndone {
+    pub labour_id: Uuid,
+    pub undone_sequence: i64,
+}
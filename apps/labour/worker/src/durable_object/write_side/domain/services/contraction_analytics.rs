@@ -0,0 +1,245 @@
+use chrono::{DateTime, Duration, Utc};
+use fern_labour_labour_shared::value_objects::contraction::ContractionPatternRule;
+use serde::Serialize;
+
+use crate::durable_object::write_side::domain::{Labour, entities::contraction::Contraction};
+
+pub const DEFAULT_STATISTICS_WINDOW_MINUTES: i64 = 60;
+pub const MAX_STATISTICS_WINDOW_MINUTES: i64 = 24 * 60;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ContractionStatistics {
+    pub window_minutes: i64,
+    pub window_end: Option<DateTime<Utc>>,
+    pub contraction_count: usize,
+    pub average_duration_minutes: Option<f64>,
+    pub average_interval_minutes: Option<f64>,
+    /// 1.0 when every interval in the window is the same length, falling
+    /// towards 0.0 as they vary.
+    pub interval_regularity: Option<f64>,
+    pub rule: ContractionPatternRule,
+    pub rule_met: bool,
+}
+
+pub struct ContractionAnalytics;
+
+impl ContractionAnalytics {
+    /// Statistics over the completed contractions that started within
+    /// `window_minutes` of the most recent one. Anchoring the window on the
+    /// contractions rather than the wall clock keeps the figures stable
+    /// while the mother is resting between contractions.
+    pub fn statistics(
+        contractions: &[Contraction],
+        window_minutes: i64,
+        rule: ContractionPatternRule,
+    ) -> ContractionStatistics {
+        let completed = Self::completed(contractions);
+        let window_end = completed.last().map(|c| *c.start_time());
+        let in_window: Vec<&Contraction> = match window_end {
+            Some(end) => {
+                let window_start = end - Duration::minutes(window_minutes);
+                completed
+                    .iter()
+                    .copied()
+                    .filter(|c| *c.start_time() >= window_start)
+                    .collect()
+            }
+            None => vec![],
+        };
+
+        let durations: Vec<f64> = in_window.iter().map(|c| c.duration_minutes()).collect();
+        let intervals = Self::intervals(&in_window);
+
+        ContractionStatistics {
+            window_minutes,
+            window_end,
+            contraction_count: in_window.len(),
+            average_duration_minutes: mean(&durations),
+            average_interval_minutes: mean(&intervals),
+            interval_regularity: regularity(&intervals),
+            rule,
+            rule_met: Self::rule_met(contractions, rule),
+        }
+    }
+
+    /// Whether the most recent contractions have met `rule` without a break
+    /// for at least its sustained period. The run must also hold as many
+    /// contractions as the rule's interval fits into that period, so one long
+    /// contraction cannot satisfy it on its own.
+    pub fn rule_met(contractions: &[Contraction], rule: ContractionPatternRule) -> bool {
+        let completed = Self::completed(contractions);
+        let minimum_count = (rule.sustained_minutes / rule.interval_minutes.max(1)).max(2) as usize;
+
+        let mut run_start = None;
+        let mut run_count = 0;
+        for (index, contraction) in completed.iter().enumerate().rev() {
+            if contraction.duration_minutes() < rule.duration_minutes as f64 {
+                break;
+            }
+            if let Some(next) = completed.get(index + 1)
+                && interval_minutes(contraction, next) > rule.interval_minutes as f64
+            {
+                break;
+            }
+            run_start = Some(*contraction);
+            run_count += 1;
+        }
+
+        match (run_start, completed.last()) {
+            (Some(first), Some(last)) => {
+                run_count >= minimum_count
+                    && *last.end_time() - *first.start_time()
+                        >= Duration::minutes(rule.sustained_minutes as i64)
+            }
+            _ => false,
+        }
+    }
+
+    /// Preset rules `labour`'s contractions now meet that have not already
+    /// been reported.
+    pub fn newly_detected_patterns(labour: &Labour) -> Vec<ContractionPatternRule> {
+        ContractionPatternRule::PRESETS
+            .into_iter()
+            .filter(|rule| !labour.detected_contraction_patterns().contains(rule))
+            .filter(|rule| Self::rule_met(labour.contractions(), *rule))
+            .collect()
+    }
+
+    fn completed(contractions: &[Contraction]) -> Vec<&Contraction> {
+        let mut completed: Vec<&Contraction> = contractions
+            .iter()
+            .filter(|c| !c.is_active() && !c.is_auto_closed())
+            .collect();
+        completed.sort_by_key(|c| *c.start_time());
+        completed
+    }
+
+    fn intervals(contractions: &[&Contraction]) -> Vec<f64> {
+        contractions
+            .windows(2)
+            .map(|pair| interval_minutes(pair[0], pair[1]))
+            .collect()
+    }
+}
+
+fn interval_minutes(earlier: &Contraction, later: &Contraction) -> f64 {
+    (*later.start_time() - *earlier.start_time()).num_milliseconds() as f64 / 60_000.0
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().sum::<f64>() / values.len() as f64)
+}
+
+fn regularity(intervals: &[f64]) -> Option<f64> {
+    let average = mean(intervals)?;
+    if average <= 0.0 {
+        return None;
+    }
+    let variance = intervals
+        .iter()
+        .map(|interval| (interval - average).powi(2))
+        .sum::<f64>()
+        / intervals.len() as f64;
+    Some((1.0 - variance.sqrt() / average).max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    /// Contractions of `duration_secs` starting every `interval_secs`.
+    fn contractions(count: i64, interval_secs: i64, duration_secs: i64) -> Vec<Contraction> {
+        let labour_id = Uuid::now_v7();
+        let base_time = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        (0..count)
+            .map(|i| {
+                let start = base_time + Duration::seconds(i * interval_secs);
+                let mut contraction = Contraction::start(Uuid::now_v7(), labour_id, start).unwrap();
+                contraction
+                    .end(start + Duration::seconds(duration_secs), 6)
+                    .unwrap();
+                contraction
+            })
+            .collect()
+    }
+
+    #[test]
+    fn statistics_are_empty_without_completed_contractions() {
+        let stats = ContractionAnalytics::statistics(&[], 60, ContractionPatternRule::default());
+
+        assert_eq!(stats.contraction_count, 0);
+        assert_eq!(stats.average_interval_minutes, None);
+        assert_eq!(stats.interval_regularity, None);
+        assert!(!stats.rule_met);
+    }
+
+    #[test]
+    fn statistics_only_cover_the_window() {
+        let stats = ContractionAnalytics::statistics(
+            &contractions(10, 300, 60),
+            20,
+            ContractionPatternRule::default(),
+        );
+
+        assert_eq!(stats.contraction_count, 5);
+        assert_eq!(stats.average_interval_minutes, Some(5.0));
+        assert_eq!(stats.average_duration_minutes, Some(1.0));
+        assert_eq!(stats.interval_regularity, Some(1.0));
+    }
+
+    #[test]
+    fn five_one_one_needs_an_hour_of_contractions() {
+        assert!(!ContractionAnalytics::rule_met(
+            &contractions(8, 300, 60),
+            ContractionPatternRule::FIVE_ONE_ONE
+        ));
+        assert!(ContractionAnalytics::rule_met(
+            &contractions(13, 300, 60),
+            ContractionPatternRule::FIVE_ONE_ONE
+        ));
+    }
+
+    #[test]
+    fn four_one_one_is_stricter_than_five_one_one() {
+        let labour = contractions(14, 290, 65);
+
+        assert!(ContractionAnalytics::rule_met(
+            &labour,
+            ContractionPatternRule::FIVE_ONE_ONE
+        ));
+        assert!(!ContractionAnalytics::rule_met(
+            &labour,
+            ContractionPatternRule::FOUR_ONE_ONE
+        ));
+    }
+
+    #[test]
+    fn one_long_contraction_does_not_meet_a_rule() {
+        assert!(!ContractionAnalytics::rule_met(
+            &contractions(1, 300, 61 * 60),
+            ContractionPatternRule::FIVE_ONE_ONE
+        ));
+        assert!(!ContractionAnalytics::rule_met(
+            &contractions(2, 300, 56 * 60),
+            ContractionPatternRule::FIVE_ONE_ONE
+        ));
+    }
+
+    #[test]
+    fn short_contractions_break_the_pattern() {
+        let mut labour = contractions(13, 300, 60);
+        let last = labour.last_mut().unwrap();
+        let start = *last.start_time();
+        last.end(start + Duration::seconds(30), 6).unwrap();
+
+        assert!(!ContractionAnalytics::rule_met(
+            &labour,
+            ContractionPatternRule::FIVE_ONE_ONE
+        ));
+    }
+}
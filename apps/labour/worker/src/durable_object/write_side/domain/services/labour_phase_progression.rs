@@ -1,13 +1,9 @@
-use fern_labour_labour_shared::value_objects::LabourPhase;
+use fern_labour_labour_shared::value_objects::{
+    LabourMilestoneType, LabourPhase, PhaseProgressionPolicy, labour::PhaseThresholds,
+};
 
 use crate::durable_object::write_side::domain::Labour;
 
-const RECENT_CONTRACTION_COUNT: usize = 3;
-const TRANSITION_INTENSITY_THRESHOLD: f64 = 8.0;
-const TRANSITION_DURATION_THRESHOLD_MINS: f64 = 1.5;
-const ACTIVE_INTENSITY_THRESHOLD: f64 = 6.0;
-const ACTIVE_DURATION_THRESHOLD_MINS: f64 = 1.0;
-
 pub struct LabourPhaseProgression;
 
 impl LabourPhaseProgression {
@@ -18,7 +14,12 @@ impl LabourPhaseProgression {
             return None;
         }
 
-        let new_phase = Self::calculate_phase_from_contractions(labour)?;
+        let policy = labour.phase_progression_policy();
+        if !policy.automatic {
+            return None;
+        }
+
+        let new_phase = Self::calculate_phase_from_contractions(labour, &policy)?;
 
         if new_phase > *current_phase {
             Some(new_phase)
@@ -27,19 +28,23 @@ impl LabourPhaseProgression {
         }
     }
 
-    fn calculate_phase_from_contractions(labour: &Labour) -> Option<LabourPhase> {
+    fn calculate_phase_from_contractions(
+        labour: &Labour,
+        policy: &PhaseProgressionPolicy,
+    ) -> Option<LabourPhase> {
         let contractions = labour.contractions();
 
-        let recent: Vec<_> = contractions
+        let mut recent: Vec<_> = contractions
             .iter()
             .rev()
             .filter(|c| c.intensity().is_some())
-            .take(RECENT_CONTRACTION_COUNT)
+            .take(policy.recent_contraction_count)
             .collect();
 
-        if recent.len() < RECENT_CONTRACTION_COUNT {
+        if recent.len() < policy.recent_contraction_count {
             return None;
         }
+        recent.sort_by_key(|c| *c.start_time());
 
         let count = recent.len() as f64;
         let avg_intensity: f64 = recent
@@ -48,14 +53,31 @@ impl LabourPhaseProgression {
             .sum::<f64>()
             / count;
         let avg_duration: f64 = recent.iter().map(|c| c.duration_minutes()).sum::<f64>() / count;
+        let avg_interval: Option<f64> = (recent.len() > 1).then(|| {
+            let (first, last) = (recent[0], recent[recent.len() - 1]);
+            (*last.start_time() - *first.start_time()).num_milliseconds() as f64
+                / 60_000.0
+                / (count - 1.0)
+        });
 
-        if avg_intensity >= TRANSITION_INTENSITY_THRESHOLD
-            && avg_duration >= TRANSITION_DURATION_THRESHOLD_MINS
-        {
+        let meets = |thresholds: &PhaseThresholds| {
+            avg_intensity >= thresholds.min_intensity
+                && avg_duration >= thresholds.min_duration_minutes
+                && thresholds
+                    .max_interval_minutes
+                    .is_none_or(|max| avg_interval.is_some_and(|interval| interval <= max))
+        };
+
+        // Once the waters have broken, labour often establishes on contractions
+        // that would otherwise be too mild to count as active.
+        let waters_broken_active = policy
+            .waters_broken_active
+            .as_ref()
+            .filter(|_| labour.has_milestone(&LabourMilestoneType::WATERS_BROKEN));
+
+        if meets(&policy.transition) {
             Some(LabourPhase::TRANSITION)
-        } else if avg_intensity >= ACTIVE_INTENSITY_THRESHOLD
-            && avg_duration >= ACTIVE_DURATION_THRESHOLD_MINS
-        {
+        } else if meets(&policy.active) || waters_broken_active.is_some_and(meets) {
             Some(LabourPhase::ACTIVE)
         } else {
             None
@@ -70,17 +92,34 @@ mod tests {
     use uuid::Uuid;
 
     use crate::durable_object::write_side::domain::{LabourEvent, events::*};
-    use fern_labour_event_sourcing_rs::Aggregate;
+    use fern_labour_event_sourcing_rs::{Aggregate, Clock, testing::FixedClock};
 
     fn create_labour_with_contractions(contraction_specs: &[(f64, u8)]) -> Labour {
+        create_labour(true, 10, contraction_specs)
+    }
+
+    fn create_labour(
+        first_labour: bool,
+        interval_mins: i64,
+        contraction_specs: &[(f64, u8)],
+    ) -> Labour {
+        create_labour_with_milestones(first_labour, interval_mins, contraction_specs, &[])
+    }
+
+    fn create_labour_with_milestones(
+        first_labour: bool,
+        interval_mins: i64,
+        contraction_specs: &[(f64, u8)],
+        milestones: &[LabourMilestoneType],
+    ) -> Labour {
         let labour_id = Uuid::now_v7();
         let mut events = vec![
             LabourEvent::LabourPlanned(LabourPlanned {
                 labour_id,
                 mother_id: "mother_1".to_string(),
                 mother_name: "Test Mother".to_string(),
-                first_labour: true,
-                due_date: Utc::now(),
+                first_labour,
+                due_date: FixedClock::default().now(),
                 labour_name: None,
             }),
             LabourEvent::LabourPhaseChanged(LabourPhaseChanged {
@@ -89,7 +128,7 @@ mod tests {
             }),
             LabourEvent::LabourBegun(LabourBegun {
                 labour_id,
-                start_time: Utc::now(),
+                start_time: FixedClock::default().now(),
             }),
             LabourEvent::LabourPhaseChanged(LabourPhaseChanged {
                 labour_id,
@@ -100,7 +139,7 @@ mod tests {
         let base_time = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
         for (i, (duration_mins, intensity)) in contraction_specs.iter().enumerate() {
             let contraction_id = Uuid::now_v7();
-            let start = base_time + chrono::Duration::minutes(i as i64 * 10);
+            let start = base_time + chrono::Duration::minutes(i as i64 * interval_mins);
             let end = start + chrono::Duration::seconds((duration_mins * 60.0) as i64);
 
             events.push(LabourEvent::ContractionStarted(ContractionStarted {
@@ -116,6 +155,17 @@ mod tests {
             }));
         }
 
+        for milestone_type in milestones {
+            events.push(LabourEvent::MilestoneRecorded(MilestoneRecorded {
+                labour_id,
+                milestone_id: Uuid::now_v7(),
+                milestone_type: milestone_type.clone(),
+                occurred_at: base_time,
+                notes: None,
+                notify_subscribers: false,
+            }));
+        }
+
         Labour::from_events(&events).unwrap()
     }
 
@@ -156,13 +206,13 @@ mod tests {
     }
 
     #[test]
-    fn uses_last_5_contractions() {
+    fn uses_last_3_contractions() {
+        // A fourth contraction would pull the average intensity below 6.
         let labour = create_labour_with_contractions(&[
             (0.5, 2),
             (0.5, 2),
             (0.5, 2),
-            (1.0, 6),
-            (1.0, 7),
+            (0.5, 2),
             (1.0, 6),
             (1.0, 7),
             (1.0, 6),
@@ -172,4 +222,73 @@ mod tests {
             Some(LabourPhase::ACTIVE)
         );
     }
+
+    #[test]
+    fn disabled_policy_never_progresses() {
+        let mut labour = create_labour_with_contractions(&[(1.5, 8), (1.6, 9), (1.7, 8)]);
+        labour.apply(&LabourEvent::PhaseProgressionPolicyUpdated(
+            PhaseProgressionPolicyUpdated {
+                labour_id: Uuid::now_v7(),
+                policy: Some(PhaseProgressionPolicy {
+                    automatic: false,
+                    ..PhaseProgressionPolicy::first_labour()
+                }),
+            },
+        ));
+
+        assert_eq!(LabourPhaseProgression::evaluate(&labour), None);
+    }
+
+    #[test]
+    fn subsequent_labour_requires_frequent_contractions() {
+        let specs = [(1.0, 6), (1.0, 6), (1.0, 6)];
+
+        assert_eq!(
+            LabourPhaseProgression::evaluate(&create_labour(false, 15, &specs)),
+            None
+        );
+        assert_eq!(
+            LabourPhaseProgression::evaluate(&create_labour(false, 8, &specs)),
+            Some(LabourPhase::ACTIVE)
+        );
+    }
+
+    #[test]
+    fn subsequent_labour_reaches_transition_at_lower_intensity() {
+        let labour = create_labour(false, 4, &[(1.3, 7), (1.3, 7), (1.3, 7)]);
+        assert_eq!(
+            LabourPhaseProgression::evaluate(&labour),
+            Some(LabourPhase::TRANSITION)
+        );
+    }
+
+    #[test]
+    fn waters_broken_loosens_active_threshold() {
+        let specs = [(0.8, 5), (0.8, 5), (0.8, 5)];
+
+        assert_eq!(
+            LabourPhaseProgression::evaluate(&create_labour(true, 8, &specs)),
+            None
+        );
+        assert_eq!(
+            LabourPhaseProgression::evaluate(&create_labour_with_milestones(
+                true,
+                8,
+                &specs,
+                &[LabourMilestoneType::WATERS_BROKEN]
+            )),
+            Some(LabourPhase::ACTIVE)
+        );
+    }
+
+    #[test]
+    fn other_milestones_do_not_affect_progression() {
+        let labour = create_labour_with_milestones(
+            true,
+            8,
+            &[(0.8, 5), (0.8, 5), (0.8, 5)],
+            &[LabourMilestoneType::LEFT_FOR_BIRTH_LOCATION],
+        );
+        assert_eq!(LabourPhaseProgression::evaluate(&labour), None);
+    }
 }
@@ -1,3 +1,5 @@
+pub mod contraction_analytics;
 pub mod labour_phase_progression;
 
+pub use contraction_analytics::{ContractionAnalytics, ContractionStatistics};
 pub use labour_phase_progression::LabourPhaseProgression;
@@ -1,4 +1,5 @@
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use worker::Storage;
 
 pub struct AlarmManager {
@@ -17,4 +18,12 @@ impl AlarmManager {
             .context("Failed to set alarm")?;
         Ok(())
     }
+
+    pub async fn set_alarm_at(&self, time: DateTime<Utc>) -> Result<()> {
+        self.storage
+            .set_alarm(time)
+            .await
+            .context("Failed to set alarm")?;
+        Ok(())
+    }
 }
@@ -5,5 +5,7 @@ pub mod token_generator;
 
 pub use aggregate_cache::SqlCache;
 pub use alarm_manager::AlarmManager;
-pub use persistence::{event_store::SqlEventStore, user_store::UserStore};
+pub use persistence::{
+    event_store::SqlEventStore, snapshot_store::SqlSnapshotStore, user_store::UserStore,
+};
 pub use token_generator::{RandomTokenGenerator, SubscriptionTokenGenerator};
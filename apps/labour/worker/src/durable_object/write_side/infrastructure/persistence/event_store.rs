@@ -1,10 +1,17 @@
+use std::rc::Rc;
+
 use anyhow::{Context, Result, anyhow};
 use async_trait::async_trait;
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::Deserialize;
 use worker::SqlStorage;
 
-use fern_labour_event_sourcing_rs::{AppendResult, EventStoreTrait, StoredEvent, StoredEventRow};
+use fern_labour_workers_shared::sql::add_column_if_missing;
+
+use fern_labour_event_sourcing_rs::{
+    AppendResult, Clock, ConcurrencyConflict, EventStoreTrait, StoredEvent, StoredEventRow,
+    SystemClock, seal_batch, seal_existing,
+};
 
 #[derive(Deserialize)]
 struct SequenceResult {
@@ -12,13 +19,93 @@ struct SequenceResult {
     created_at: String,
 }
 
+#[derive(Deserialize)]
+struct HashResult {
+    hash: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SealedResult {
+    sequence: i64,
+    hash: String,
+}
+
+/// Rows hashed per page when sealing events stored before the hash chain.
+const SEAL_PAGE_SIZE: i64 = 500;
+
 pub struct SqlEventStore {
     pub sql: SqlStorage,
+    clock: Rc<dyn Clock>,
 }
 
 impl SqlEventStore {
     pub fn create(sql: SqlStorage) -> SqlEventStore {
-        Self { sql }
+        Self {
+            sql,
+            clock: Rc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    fn previous_hash(&self, sequence: i64) -> Result<Option<String>> {
+        let rows: Vec<HashResult> = self
+            .sql
+            .exec(
+                "SELECT hash FROM events WHERE sequence = ?1",
+                Some(vec![(sequence as f64).into()]),
+            )
+            .context("Failed to query previous event hash")?
+            .to_array()
+            .context("Failed to deserialize previous event hash")?;
+
+        Ok(rows.into_iter().next().and_then(|row| row.hash))
+    }
+
+    /// Hashes the rows after the last sealed event, continuing the chain from
+    /// its hash. Streams that predate the hash chain are sealed from the
+    /// start, and a seal interrupted part way through resumes where it
+    /// stopped. Unhashed rows followed by a hashed one are left for
+    /// `verify_chain` to report.
+    fn seal_legacy_events(&self) -> Result<()> {
+        let last_sealed: Vec<SealedResult> = self
+            .sql
+            .exec(
+                "SELECT sequence, hash FROM events
+                 WHERE hash IS NOT NULL
+                 ORDER BY sequence DESC
+                 LIMIT 1",
+                None,
+            )
+            .context("Failed to query last sealed event")?
+            .to_array()
+            .context("Failed to deserialize last sealed event")?;
+
+        let (mut sequence, mut previous_hash) = match last_sealed.into_iter().next() {
+            Some(sealed) => (sealed.sequence, Some(sealed.hash)),
+            None => (0, None),
+        };
+
+        loop {
+            let page = self.events_since(sequence, SEAL_PAGE_SIZE)?;
+            let Some(last) = page.last() else {
+                return Ok(());
+            };
+            sequence = last.sequence;
+
+            for (sealed_sequence, hash) in seal_existing(previous_hash.as_deref(), &page) {
+                self.sql
+                    .exec(
+                        "UPDATE events SET hash = ?1 WHERE sequence = ?2",
+                        Some(vec![hash.clone().into(), (sealed_sequence as f64).into()]),
+                    )
+                    .context("Failed to seal legacy event")?;
+                previous_hash = Some(hash);
+            }
+        }
     }
 }
 
@@ -34,46 +121,110 @@ impl EventStoreTrait for SqlEventStore {
                     event_data TEXT NOT NULL,
                     event_version INTEGER NOT NULL DEFAULT 1,
                     created_at DATETIME DEFAULT CURRENT_TIMESTAMP NOT NULL,
-                    user_id TEXT NOT NULL
+                    user_id TEXT NOT NULL,
+                    correlation_id TEXT,
+                    causation_id TEXT,
+                    hash TEXT
                 )",
                 None,
             )
             .context("Failed to create events table")?;
-        Ok(())
+
+        add_column_if_missing(&self.sql, "events", "correlation_id", "TEXT")?;
+        add_column_if_missing(&self.sql, "events", "causation_id", "TEXT")?;
+        add_column_if_missing(&self.sql, "events", "hash", "TEXT")?;
+
+        self.sql
+            .exec(
+                "CREATE INDEX IF NOT EXISTS idx_events_event_type_sequence
+                 ON events (event_type, sequence)",
+                None,
+            )
+            .context("Failed to create event type index")?;
+
+        self.seal_legacy_events()
     }
 
-    fn append(
+    fn append_batch(
         &self,
-        aggregate_id: String,
-        event: StoredEvent,
+        events: Vec<StoredEvent>,
         user_id: String,
-    ) -> Result<AppendResult> {
-        let result = self
+        expected_version: i64,
+    ) -> Result<Vec<AppendResult>> {
+        if events.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let actual_version = self.stream_version()?;
+        if actual_version != expected_version {
+            return Err(ConcurrencyConflict {
+                expected_version,
+                actual_version,
+            }
+            .into());
+        }
+
+        let created_at = self.clock.now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let rows = seal_batch(
+            self.previous_hash(actual_version)?,
+            events,
+            &user_id,
+            actual_version + 1,
+            &created_at,
+        );
+
+        // The whole batch is written by a single INSERT ... SELECT statement so
+        // SQLite commits it atomically, and the bound parameter count stays
+        // constant regardless of how many events the command produced.
+        let batch = serde_json::to_string(&rows).context("Failed to serialize event batch")?;
+        let mut results = self
             .sql
             .exec(
-                "INSERT INTO events (aggregate_id, event_type, event_version, event_data, user_id)
-                 VALUES (?1, ?2, ?3, ?4, ?5)
+                "INSERT INTO events (
+                    sequence, aggregate_id, event_type, event_version, event_data,
+                    created_at, user_id, correlation_id, causation_id, hash
+                 )
+                 SELECT json_extract(value, '$.sequence'),
+                        json_extract(value, '$.aggregate_id'),
+                        json_extract(value, '$.event_type'),
+                        json_extract(value, '$.event_version'),
+                        json_extract(value, '$.event_data'),
+                        json_extract(value, '$.created_at'),
+                        json_extract(value, '$.user_id'),
+                        json_extract(value, '$.correlation_id'),
+                        json_extract(value, '$.causation_id'),
+                        json_extract(value, '$.hash')
+                 FROM json_each(?1)
+                 ORDER BY key ASC
                  RETURNING sequence, created_at",
-                Some(vec![
-                    aggregate_id.into(),
-                    event.event_type.into(),
-                    event.event_version.into(),
-                    event.event_data.into(),
-                    user_id.into(),
-                ]),
+                Some(vec![batch.into()]),
             )
-            .context("Failed to insert event into event store")?
-            .one::<SequenceResult>()
-            .context("Failed to parse sequence result")?;
-
-        let timestamp = NaiveDateTime::parse_from_str(&result.created_at, "%Y-%m-%d %H:%M:%S")
-            .context("Failed to parse created_at timestamp from SQLite")?
-            .and_utc();
-
-        Ok(AppendResult {
-            sequence: result.sequence,
-            timestamp,
-        })
+            .context("Failed to insert event batch into event store")?
+            .to_array::<SequenceResult>()
+            .context("Failed to parse sequence results")?;
+
+        if results.len() != rows.len() {
+            return Err(anyhow!(
+                "Event store appended {} of {} events",
+                results.len(),
+                rows.len()
+            ));
+        }
+        results.sort_by_key(|result| result.sequence);
+
+        results
+            .into_iter()
+            .map(|result| {
+                let timestamp =
+                    NaiveDateTime::parse_from_str(&result.created_at, "%Y-%m-%d %H:%M:%S")
+                        .context("Failed to parse created_at timestamp from SQLite")?
+                        .and_utc();
+                Ok(AppendResult {
+                    sequence: result.sequence,
+                    timestamp,
+                })
+            })
+            .collect()
     }
 
     fn load(&self) -> Result<Vec<StoredEventRow>> {
@@ -101,6 +252,81 @@ impl EventStoreTrait for SqlEventStore {
         Ok(rows)
     }
 
+    fn events_since_filtered(
+        &self,
+        sequence: i64,
+        limit: i64,
+        event_types: &[&str],
+    ) -> Result<Vec<StoredEventRow>> {
+        let event_types =
+            serde_json::to_string(event_types).context("Failed to serialize event types")?;
+        let rows: Vec<StoredEventRow> = self
+            .sql
+            .exec(
+                "SELECT * FROM events
+                 WHERE sequence > ?1
+                   AND event_type IN (SELECT value FROM json_each(?2))
+                 ORDER BY sequence ASC
+                 LIMIT ?3",
+                Some(vec![
+                    (sequence as f64).into(),
+                    event_types.into(),
+                    (limit as f64).into(),
+                ]),
+            )
+            .context("Failed to load filtered events since sequence")?
+            .to_array()
+            .context("Failed to deserialize event rows")?;
+
+        Ok(rows)
+    }
+
+    fn events_until_sequence(&self, sequence: i64) -> Result<Vec<StoredEventRow>> {
+        let rows: Vec<StoredEventRow> = self
+            .sql
+            .exec(
+                "SELECT * FROM events WHERE sequence <= ?1 ORDER BY sequence ASC",
+                Some(vec![(sequence as f64).into()]),
+            )
+            .context("Failed to load events until sequence")?
+            .to_array()
+            .context("Failed to deserialize event rows")?;
+
+        Ok(rows)
+    }
+
+    fn events_until_time(&self, timestamp: DateTime<Utc>) -> Result<Vec<StoredEventRow>> {
+        let rows: Vec<StoredEventRow> = self
+            .sql
+            .exec(
+                "SELECT * FROM events WHERE created_at <= ?1 ORDER BY sequence ASC",
+                Some(vec![
+                    timestamp.format("%Y-%m-%d %H:%M:%S").to_string().into(),
+                ]),
+            )
+            .context("Failed to load events until timestamp")?
+            .to_array()
+            .context("Failed to deserialize event rows")?;
+
+        Ok(rows)
+    }
+
+    fn events_since_time(&self, timestamp: DateTime<Utc>) -> Result<Vec<StoredEventRow>> {
+        let rows: Vec<StoredEventRow> = self
+            .sql
+            .exec(
+                "SELECT * FROM events WHERE created_at >= ?1 ORDER BY sequence ASC",
+                Some(vec![
+                    timestamp.format("%Y-%m-%d %H:%M:%S").to_string().into(),
+                ]),
+            )
+            .context("Failed to load events since timestamp")?
+            .to_array()
+            .context("Failed to deserialize event rows")?;
+
+        Ok(rows)
+    }
+
     fn max_sequence(&self) -> Result<Option<i64>> {
         #[derive(Deserialize)]
         struct MaxSequenceResult {
@@ -119,4 +345,38 @@ impl EventStoreTrait for SqlEventStore {
             _ => Err(anyhow!("No max sequence results found")),
         }
     }
+
+    fn import_rows(&self, rows: Vec<StoredEventRow>) -> Result<()> {
+        if self.stream_version()? != 0 {
+            return Err(anyhow!("Event store already contains events"));
+        }
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let batch = serde_json::to_string(&rows).context("Failed to serialize imported rows")?;
+        self.sql
+            .exec(
+                "INSERT INTO events (
+                    sequence, aggregate_id, event_type, event_version, event_data,
+                    created_at, user_id, correlation_id, causation_id, hash
+                 )
+                 SELECT json_extract(value, '$.sequence'),
+                        json_extract(value, '$.aggregate_id'),
+                        json_extract(value, '$.event_type'),
+                        json_extract(value, '$.event_version'),
+                        json_extract(value, '$.event_data'),
+                        json_extract(value, '$.created_at'),
+                        json_extract(value, '$.user_id'),
+                        json_extract(value, '$.correlation_id'),
+                        json_extract(value, '$.causation_id'),
+                        json_extract(value, '$.hash')
+                 FROM json_each(?1)
+                 ORDER BY key ASC",
+                Some(vec![batch.into()]),
+            )
+            .context("Failed to insert imported events")?;
+
+        Ok(())
+    }
 }
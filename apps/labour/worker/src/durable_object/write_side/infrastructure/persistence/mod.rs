@@ -1,2 +1,3 @@
 pub mod event_store;
+pub mod snapshot_store;
 pub mod user_store;
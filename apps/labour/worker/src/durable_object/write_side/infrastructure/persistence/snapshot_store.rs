@@ -0,0 +1,76 @@
+use anyhow::{Context, Result};
+use worker::SqlStorage;
+
+use fern_labour_event_sourcing_rs::{AggregateSnapshot, SnapshotStoreTrait};
+
+pub struct SqlSnapshotStore {
+    sql: SqlStorage,
+}
+
+impl SqlSnapshotStore {
+    pub fn create(sql: SqlStorage) -> SqlSnapshotStore {
+        Self { sql }
+    }
+}
+
+impl SnapshotStoreTrait for SqlSnapshotStore {
+    fn init_schema(&self) -> Result<()> {
+        self.sql
+            .exec(
+                "CREATE TABLE IF NOT EXISTS snapshots (
+                    sequence INTEGER PRIMARY KEY,
+                    aggregate_data TEXT NOT NULL,
+                    schema_version INTEGER NOT NULL,
+                    created_at DATETIME DEFAULT CURRENT_TIMESTAMP NOT NULL
+                )",
+                None,
+            )
+            .context("Failed to create snapshots table")?;
+        Ok(())
+    }
+
+    fn latest(&self) -> Result<Option<AggregateSnapshot>> {
+        let snapshots: Vec<AggregateSnapshot> = self
+            .sql
+            .exec(
+                "SELECT sequence, aggregate_data, schema_version
+                 FROM snapshots ORDER BY sequence DESC LIMIT 1",
+                None,
+            )
+            .context("Failed to load latest snapshot")?
+            .to_array()
+            .context("Failed to deserialize snapshot row")?;
+
+        Ok(snapshots.into_iter().next())
+    }
+
+    fn save(&self, snapshot: AggregateSnapshot) -> Result<()> {
+        let sequence = snapshot.sequence as f64;
+        self.sql
+            .exec(
+                "INSERT OR REPLACE INTO snapshots (sequence, aggregate_data, schema_version)
+                 VALUES (?1, ?2, ?3)",
+                Some(vec![
+                    sequence.into(),
+                    snapshot.aggregate_data.into(),
+                    (snapshot.schema_version as f64).into(),
+                ]),
+            )
+            .context("Failed to insert snapshot")?;
+
+        self.sql
+            .exec(
+                "DELETE FROM snapshots WHERE sequence < ?1",
+                Some(vec![sequence.into()]),
+            )
+            .context("Failed to prune old snapshots")?;
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<()> {
+        self.sql
+            .exec("DELETE FROM snapshots", None)
+            .context("Failed to clear snapshots")?;
+        Ok(())
+    }
+}
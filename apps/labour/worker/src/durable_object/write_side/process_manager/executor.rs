@@ -1,6 +1,7 @@
 use anyhow::{Context, Result, anyhow};
 use async_trait::async_trait;
-use fern_labour_labour_shared::value_objects::SubscriberContactMethod;
+use fern_labour_event_sourcing_rs::{CausationContext, EffectExecutor};
+use fern_labour_labour_shared::value_objects::{Baby, SubscriberContactMethod};
 use fern_labour_notifications_shared::{
     service_clients::notification::NotificationClient,
     value_objects::{
@@ -18,11 +19,6 @@ use crate::durable_object::write_side::{
     process_manager::types::*,
 };
 
-#[async_trait(?Send)]
-pub trait EffectExecutor {
-    async fn execute(&self, effect: &Effect) -> Result<()>;
-}
-
 pub struct LabourEffectExecutor {
     user_storage: UserStore,
     notification_client: Box<dyn NotificationClient>,
@@ -85,7 +81,11 @@ impl LabourEffectExecutor {
         }
     }
 
-    async fn send_notification(&self, intent: &NotificationIntent) -> Result<()> {
+    async fn send_notification(
+        &self,
+        intent: &NotificationIntent,
+        causation: CausationContext,
+    ) -> Result<()> {
         match &intent.context {
             NotificationContext::Subscriber {
                 recipient_user_id,
@@ -99,6 +99,7 @@ impl LabourEffectExecutor {
                     channel,
                     sender_id,
                     notification,
+                    causation,
                 )
                 .await
             }
@@ -107,15 +108,20 @@ impl LabourEffectExecutor {
                 channel,
                 notification,
             } => {
-                self.send_labour_owner_notification(recipient_user_id, channel, notification)
-                    .await
+                self.send_labour_owner_notification(
+                    recipient_user_id,
+                    channel,
+                    notification,
+                    causation,
+                )
+                .await
             }
             NotificationContext::Email {
                 email,
                 sender_id,
                 notification,
             } => {
-                self.send_email_notification(email, sender_id, notification)
+                self.send_email_notification(email, sender_id, notification, causation)
                     .await
             }
         }
@@ -127,6 +133,7 @@ impl LabourEffectExecutor {
         channel: &SubscriberContactMethod,
         sender_id: &str,
         notification: &SubscriberNotification,
+        causation: CausationContext,
     ) -> Result<()> {
         let recipient = self.get_user(recipient_user_id)?;
         let sender = self.get_user(sender_id)?;
@@ -186,6 +193,38 @@ impl LabourEffectExecutor {
                     link: self.web_app_url.clone(),
                 }
             }
+            SubscriberNotification::ContractionPatternDetected { rule, .. } => {
+                NotificationTemplateData::SubscriberContractionPatternDetectedData {
+                    birthing_person_first_name: sender_first_name,
+                    subscriber_first_name: recipient_first_name,
+                    rule: rule.to_string(),
+                    link: self.web_app_url.clone(),
+                }
+            }
+            SubscriberNotification::BirthAnnouncementPublished {
+                time_of_birth,
+                babies,
+                ..
+            } => NotificationTemplateData::BirthAnnouncementData {
+                birthing_person_name: sender_name,
+                birthing_person_first_name: sender_first_name,
+                subscriber_first_name: recipient_first_name,
+                babies: Baby::describe_all(babies),
+                time_of_birth: time_of_birth.format("%-d %B %Y at %H:%M UTC").to_string(),
+                link: self.web_app_url.clone(),
+            },
+            SubscriberNotification::MilestoneRecorded {
+                milestone_type,
+                occurred_at,
+                ..
+            } => NotificationTemplateData::MilestoneRecordedData {
+                birthing_person_name: sender_name,
+                birthing_person_first_name: sender_first_name,
+                subscriber_first_name: recipient_first_name,
+                milestone: milestone_type.description().to_string(),
+                occurred_at: occurred_at.format("%-d %B %Y at %H:%M UTC").to_string(),
+                link: self.web_app_url.clone(),
+            },
         };
 
         self.notification_client
@@ -195,6 +234,7 @@ impl LabourEffectExecutor {
                 template_data,
                 None,
                 NotificationPriority::default(),
+                causation,
             )
             .await
             .map_err(|e| anyhow!(e.to_string()))
@@ -205,6 +245,7 @@ impl LabourEffectExecutor {
         recipient_user_id: &str,
         channel: &SubscriberContactMethod,
         notification: &MotherNotification,
+        causation: CausationContext,
     ) -> Result<()> {
         let recipient = self.get_user(recipient_user_id)?;
         let destination = Self::get_user_destination(&recipient, channel)?;
@@ -227,6 +268,20 @@ impl LabourEffectExecutor {
                     link: self.web_app_url.clone(),
                 }
             }
+            MotherNotification::ContractionPatternDetected { rule, .. } => {
+                NotificationTemplateData::ContractionPatternDetectedData {
+                    birthing_person_first_name: recipient_first_name,
+                    rule: rule.to_string(),
+                    link: self.web_app_url.clone(),
+                }
+            }
+            MotherNotification::ContractionAutoClosed { start_time, .. } => {
+                NotificationTemplateData::ContractionAutoClosedData {
+                    birthing_person_first_name: recipient_first_name,
+                    started_at: start_time.format("%H:%M UTC").to_string(),
+                    link: self.web_app_url.clone(),
+                }
+            }
         };
 
         self.notification_client
@@ -236,6 +291,7 @@ impl LabourEffectExecutor {
                 template_data,
                 None,
                 NotificationPriority::default(),
+                causation,
             )
             .await
             .map_err(|e| anyhow!(e.to_string()))
@@ -246,6 +302,7 @@ impl LabourEffectExecutor {
         email: &str,
         sender_id: &str,
         notification: &EmailNotification,
+        causation: CausationContext,
     ) -> Result<()> {
         let sender = self.get_user(sender_id)?;
         let sender_name = sender.name.clone().unwrap_or_else(|| "Unknown".to_string());
@@ -268,6 +325,7 @@ impl LabourEffectExecutor {
                 template_data,
                 None,
                 NotificationPriority::default(),
+                causation,
             )
             .await
             .map_err(|e| anyhow!(e.to_string()))
@@ -275,18 +333,29 @@ impl LabourEffectExecutor {
 }
 
 #[async_trait(?Send)]
-impl EffectExecutor for LabourEffectExecutor {
-    async fn execute(&self, effect: &Effect) -> Result<()> {
+impl EffectExecutor<Effect> for LabourEffectExecutor {
+    async fn execute(&self, effect: &Effect, causation: CausationContext) -> Result<()> {
         match effect {
-            Effect::SendNotification(intent) => self.send_notification(intent).await,
-            Effect::IssueCommand { command, .. } => {
+            Effect::SendNotification(intent) => self.send_notification(intent, causation).await,
+            Effect::IssueCommand {
+                command,
+                idempotency_key,
+            } => {
                 let system_user = User::internal("process-manager");
                 self.command_processor
-                    .handle_command(command.clone(), system_user)
+                    .handle_idempotent_command(
+                        command.clone(),
+                        system_user,
+                        causation,
+                        &idempotency_key.0,
+                    )
                     .context("Failed to handle internal command")?;
                 Ok(())
             }
-            Effect::GenerateSubscriptionToken { labour_id, .. } => {
+            Effect::GenerateSubscriptionToken {
+                labour_id,
+                idempotency_key,
+            } => {
                 let token = self.token_generator.generate();
                 let command = LabourCommand::SetSubscriptionToken(SetSubscriptionToken {
                     labour_id: *labour_id,
@@ -294,7 +363,7 @@ impl EffectExecutor for LabourEffectExecutor {
                 });
                 let system_user = User::internal("process-manager");
                 self.command_processor
-                    .handle_command(command, system_user)
+                    .handle_idempotent_command(command, system_user, causation, &idempotency_key.0)
                     .context("Failed to handle internal command")?;
                 Ok(())
             }
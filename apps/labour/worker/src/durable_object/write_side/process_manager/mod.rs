@@ -1,11 +1,7 @@
 pub mod executor;
-pub mod ledger;
-pub mod manager;
 pub mod policies;
 pub mod types;
 
 pub use executor::*;
-pub use ledger::*;
-pub use manager::*;
 pub use policies::*;
 pub use types::*;
@@ -0,0 +1,52 @@
+use fern_labour_event_sourcing_rs::{HasPolicies, IdempotencyKey, PolicyContext, PolicyFn};
+use fern_labour_labour_shared::value_objects::subscriber::status::SubscriberStatus;
+
+use crate::durable_object::write_side::{
+    domain::{Labour, events::BirthAnnouncementPublished},
+    process_manager::types::{
+        Effect, NotificationContext, NotificationIntent, SubscriberNotification,
+    },
+};
+
+impl HasPolicies<Labour, Effect> for BirthAnnouncementPublished {
+    fn policies() -> &'static [PolicyFn<Self, Labour, Effect>] {
+        &[notify_subscribers_on_birth_announcement]
+    }
+}
+
+fn notify_subscribers_on_birth_announcement(
+    event: &BirthAnnouncementPublished,
+    ctx: &PolicyContext<Labour>,
+) -> Vec<Effect> {
+    let sender_id = ctx.state.mother_id().to_string();
+
+    ctx.state
+        .subscriptions()
+        .iter()
+        .filter(|s| s.status() == &SubscriberStatus::SUBSCRIBED)
+        .flat_map(|subscription| {
+            let sender_id = sender_id.clone();
+            subscription.contact_methods().iter().map(move |channel| {
+                Effect::SendNotification(NotificationIntent {
+                    idempotency_key: IdempotencyKey::for_notification(
+                        event.labour_id,
+                        ctx.sequence,
+                        subscription.subscriber_id(),
+                        "birth_announcement_published",
+                    ),
+                    context: NotificationContext::Subscriber {
+                        recipient_user_id: subscription.subscriber_id().to_string(),
+                        subscription_id: subscription.id(),
+                        channel: channel.clone(),
+                        sender_id: sender_id.clone(),
+                        notification: SubscriberNotification::BirthAnnouncementPublished {
+                            labour_id: event.labour_id,
+                            time_of_birth: event.time_of_birth,
+                            babies: event.babies.clone(),
+                        },
+                    },
+                })
+            })
+        })
+        .collect()
+}
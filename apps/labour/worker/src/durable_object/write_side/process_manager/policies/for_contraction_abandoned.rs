@@ -0,0 +1,46 @@
+use fern_labour_event_sourcing_rs::{HasPolicies, IdempotencyKey, PolicyContext, PolicyFn};
+use fern_labour_labour_shared::value_objects::SubscriberContactMethod;
+
+use crate::durable_object::write_side::{
+    domain::{Labour, events::ContractionAbandoned},
+    process_manager::types::{Effect, MotherNotification, NotificationContext, NotificationIntent},
+};
+
+impl HasPolicies<Labour, Effect> for ContractionAbandoned {
+    fn policies() -> &'static [PolicyFn<Self, Labour, Effect>] {
+        &[notify_mother_on_abandoned_contraction]
+    }
+}
+
+fn notify_mother_on_abandoned_contraction(
+    event: &ContractionAbandoned,
+    ctx: &PolicyContext<Labour>,
+) -> Vec<Effect> {
+    if !event.notify_mother {
+        return vec![];
+    }
+
+    let Some(contraction) = ctx.state.find_contraction(event.contraction_id) else {
+        return vec![];
+    };
+
+    let mother_id = ctx.state.mother_id().to_string();
+
+    vec![Effect::SendNotification(NotificationIntent {
+        idempotency_key: IdempotencyKey::for_notification(
+            event.labour_id,
+            ctx.sequence,
+            &mother_id,
+            "contraction_abandoned",
+        ),
+        context: NotificationContext::Mother {
+            recipient_user_id: mother_id,
+            channel: SubscriberContactMethod::EMAIL,
+            notification: MotherNotification::ContractionAutoClosed {
+                labour_id: event.labour_id,
+                contraction_id: event.contraction_id,
+                start_time: *contraction.start_time(),
+            },
+        },
+    })]
+}
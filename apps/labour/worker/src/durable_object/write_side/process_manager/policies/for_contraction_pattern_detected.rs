@@ -0,0 +1,78 @@
+use fern_labour_event_sourcing_rs::{HasPolicies, IdempotencyKey, PolicyContext, PolicyFn};
+use fern_labour_labour_shared::value_objects::{
+    SubscriberContactMethod, SubscriberRole, subscriber::status::SubscriberStatus,
+};
+
+use crate::durable_object::write_side::{
+    domain::{Labour, events::ContractionPatternDetected},
+    process_manager::types::{
+        Effect, MotherNotification, NotificationContext, NotificationIntent, SubscriberNotification,
+    },
+};
+
+impl HasPolicies<Labour, Effect> for ContractionPatternDetected {
+    fn policies() -> &'static [PolicyFn<Self, Labour, Effect>] {
+        &[notify_mother_on_pattern, notify_birth_partners_on_pattern]
+    }
+}
+
+fn notify_mother_on_pattern(
+    event: &ContractionPatternDetected,
+    ctx: &PolicyContext<Labour>,
+) -> Vec<Effect> {
+    let mother_id = ctx.state.mother_id().to_string();
+
+    vec![Effect::SendNotification(NotificationIntent {
+        idempotency_key: IdempotencyKey::for_notification(
+            event.labour_id,
+            ctx.sequence,
+            &mother_id,
+            "contraction_pattern_detected",
+        ),
+        context: NotificationContext::Mother {
+            recipient_user_id: mother_id,
+            channel: SubscriberContactMethod::EMAIL,
+            notification: MotherNotification::ContractionPatternDetected {
+                labour_id: event.labour_id,
+                rule: event.rule,
+            },
+        },
+    })]
+}
+
+fn notify_birth_partners_on_pattern(
+    event: &ContractionPatternDetected,
+    ctx: &PolicyContext<Labour>,
+) -> Vec<Effect> {
+    let sender_id = ctx.state.mother_id().to_string();
+
+    ctx.state
+        .subscriptions()
+        .iter()
+        .filter(|s| s.status() == &SubscriberStatus::SUBSCRIBED)
+        .filter(|s| s.role() == &SubscriberRole::BIRTH_PARTNER)
+        .flat_map(|subscription| {
+            let sender_id = sender_id.clone();
+            subscription.contact_methods().iter().map(move |channel| {
+                Effect::SendNotification(NotificationIntent {
+                    idempotency_key: IdempotencyKey::for_notification(
+                        event.labour_id,
+                        ctx.sequence,
+                        subscription.subscriber_id(),
+                        "contraction_pattern_detected",
+                    ),
+                    context: NotificationContext::Subscriber {
+                        recipient_user_id: subscription.subscriber_id().to_string(),
+                        subscription_id: subscription.id(),
+                        channel: channel.clone(),
+                        sender_id: sender_id.clone(),
+                        notification: SubscriberNotification::ContractionPatternDetected {
+                            labour_id: event.labour_id,
+                            rule: event.rule,
+                        },
+                    },
+                })
+            })
+        })
+        .collect()
+}
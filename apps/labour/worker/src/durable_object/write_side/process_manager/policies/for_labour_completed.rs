@@ -1,10 +1,10 @@
-use fern_labour_event_sourcing_rs::{HasPolicies, PolicyContext, PolicyFn};
+use fern_labour_event_sourcing_rs::{HasPolicies, IdempotencyKey, PolicyContext, PolicyFn};
 use fern_labour_labour_shared::value_objects::subscriber::status::SubscriberStatus;
 
 use crate::durable_object::write_side::{
     domain::{Labour, events::LabourCompleted},
     process_manager::types::{
-        Effect, IdempotencyKey, NotificationContext, NotificationIntent, SubscriberNotification,
+        Effect, NotificationContext, NotificationIntent, SubscriberNotification,
     },
 };
 
@@ -1,10 +1,8 @@
-use fern_labour_event_sourcing_rs::{HasPolicies, PolicyContext, PolicyFn};
+use fern_labour_event_sourcing_rs::{HasPolicies, IdempotencyKey, PolicyContext, PolicyFn};
 
 use crate::durable_object::write_side::{
     domain::{Labour, events::LabourInviteSent},
-    process_manager::types::{
-        Effect, EmailNotification, IdempotencyKey, NotificationContext, NotificationIntent,
-    },
+    process_manager::types::{Effect, EmailNotification, NotificationContext, NotificationIntent},
 };
 
 impl HasPolicies<Labour, Effect> for LabourInviteSent {
@@ -1,4 +1,4 @@
-use fern_labour_event_sourcing_rs::{HasPolicies, PolicyContext, PolicyFn};
+use fern_labour_event_sourcing_rs::{HasPolicies, IdempotencyKey, PolicyContext, PolicyFn};
 use fern_labour_labour_shared::value_objects::{
     LabourUpdateType, subscriber::status::SubscriberStatus,
 };
@@ -6,7 +6,7 @@ use fern_labour_labour_shared::value_objects::{
 use crate::durable_object::write_side::{
     domain::{Labour, events::LabourUpdatePosted},
     process_manager::types::{
-        Effect, IdempotencyKey, NotificationContext, NotificationIntent, SubscriberNotification,
+        Effect, NotificationContext, NotificationIntent, SubscriberNotification,
     },
 };
 
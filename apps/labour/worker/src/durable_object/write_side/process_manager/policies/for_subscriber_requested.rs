@@ -1,11 +1,9 @@
-use fern_labour_event_sourcing_rs::{HasPolicies, PolicyContext, PolicyFn};
+use fern_labour_event_sourcing_rs::{HasPolicies, IdempotencyKey, PolicyContext, PolicyFn};
 use fern_labour_labour_shared::value_objects::SubscriberContactMethod;
 
 use crate::durable_object::write_side::{
     domain::{Labour, events::SubscriberRequested},
-    process_manager::types::{
-        Effect, IdempotencyKey, MotherNotification, NotificationContext, NotificationIntent,
-    },
+    process_manager::types::{Effect, MotherNotification, NotificationContext, NotificationIntent},
 };
 
 impl HasPolicies<Labour, Effect> for SubscriberRequested {
@@ -1,8 +1,8 @@
-use fern_labour_event_sourcing_rs::{HasPolicies, PolicyContext, PolicyFn};
+use fern_labour_event_sourcing_rs::{HasPolicies, IdempotencyKey, PolicyContext, PolicyFn};
 
 use crate::durable_object::write_side::{
     domain::{Labour, events::SubscriptionTokenInvalidated},
-    process_manager::types::{Effect, IdempotencyKey},
+    process_manager::types::Effect,
 };
 
 impl HasPolicies<Labour, Effect> for SubscriptionTokenInvalidated {
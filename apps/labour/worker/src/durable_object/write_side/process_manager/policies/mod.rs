@@ -1,8 +1,100 @@
+pub mod for_birth_announcement_published;
+pub mod for_contraction_abandoned;
+pub mod for_contraction_pattern_detected;
 pub mod for_labour_completed;
 pub mod for_labour_invite_sent;
 pub mod for_labour_planned;
 pub mod for_labour_update_posted;
 pub mod for_labour_update_type_updated;
+pub mod for_milestone_recorded;
 pub mod for_subscriber_approved;
 pub mod for_subscriber_requested;
 pub mod for_subscription_token_invalidated;
+
+use fern_labour_event_sourcing_rs::{EventDescriptor, HasPolicies, PolicyContext, PolicyRouter};
+
+use crate::durable_object::write_side::{
+    domain::{
+        Labour, LabourEvent,
+        events::{
+            ActionUndone, BirthAnnouncementPublished, ContractionAbandoned,
+            ContractionPatternDetected, LabourCompleted, LabourInviteSent, LabourPlanned,
+            LabourUpdatePosted, LabourUpdateTypeUpdated, MilestoneRecorded, SubscriberApproved,
+            SubscriberRequested, SubscriptionTokenInvalidated,
+        },
+    },
+    process_manager::types::Effect,
+};
+
+impl PolicyRouter<Labour, Effect> for LabourEvent {
+    const ROUTED_EVENT_TYPES: &'static [&'static str] = &[
+        LabourPlanned::EVENT_TYPE,
+        LabourCompleted::EVENT_TYPE,
+        LabourUpdatePosted::EVENT_TYPE,
+        SubscriberApproved::EVENT_TYPE,
+        SubscriberRequested::EVENT_TYPE,
+        LabourInviteSent::EVENT_TYPE,
+        LabourUpdateTypeUpdated::EVENT_TYPE,
+        SubscriptionTokenInvalidated::EVENT_TYPE,
+        ContractionPatternDetected::EVENT_TYPE,
+        ContractionAbandoned::EVENT_TYPE,
+        BirthAnnouncementPublished::EVENT_TYPE,
+        MilestoneRecorded::EVENT_TYPE,
+        ActionUndone::EVENT_TYPE,
+    ];
+
+    fn route_policies(&self, ctx: &PolicyContext<'_, Labour>) -> Vec<Effect> {
+        match self {
+            LabourEvent::LabourPlanned(e) => e.apply_policies(ctx),
+            LabourEvent::LabourCompleted(e) => e.apply_policies(ctx),
+            LabourEvent::LabourUpdatePosted(e) => e.apply_policies(ctx),
+            LabourEvent::SubscriberApproved(e) => e.apply_policies(ctx),
+            LabourEvent::SubscriberRequested(e) => e.apply_policies(ctx),
+            LabourEvent::LabourInviteSent(e) => e.apply_policies(ctx),
+            LabourEvent::LabourUpdateTypeUpdated(e) => e.apply_policies(ctx),
+            LabourEvent::SubscriptionTokenInvalidated(e) => e.apply_policies(ctx),
+            LabourEvent::ContractionPatternDetected(e) => e.apply_policies(ctx),
+            LabourEvent::ContractionAbandoned(e) => e.apply_policies(ctx),
+            LabourEvent::BirthAnnouncementPublished(e) => e.apply_policies(ctx),
+            LabourEvent::MilestoneRecorded(e) => e.apply_policies(ctx),
+            _ => vec![],
+        }
+    }
+
+    fn compensated_sequence(&self) -> Option<i64> {
+        match self {
+            LabourEvent::ActionUndone(e) => Some(e.undone_sequence),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fern_labour_event_sourcing_rs::{Aggregate, Event};
+
+    use super::*;
+    use crate::durable_object::write_side::domain::events::fixtures::one_of_each;
+
+    #[test]
+    fn routed_event_types_match_the_events_with_policies() {
+        let events = one_of_each();
+        let posted = events
+            .iter()
+            .position(|event| matches!(event, LabourEvent::LabourUpdatePosted(_)))
+            .unwrap();
+        let state = Labour::from_events(&events[..=posted]).unwrap();
+
+        for (index, event) in events.iter().enumerate() {
+            let ctx = PolicyContext::new(&state, index as i64 + 1);
+            let routed =
+                !event.route_policies(&ctx).is_empty() || event.compensated_sequence().is_some();
+            assert_eq!(
+                routed,
+                LabourEvent::ROUTED_EVENT_TYPES.contains(&event.event_type()),
+                "{} routing does not match ROUTED_EVENT_TYPES",
+                event.event_type()
+            );
+        }
+    }
+}
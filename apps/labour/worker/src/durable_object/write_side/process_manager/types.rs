@@ -1,33 +1,13 @@
-use fern_labour_labour_shared::value_objects::SubscriberContactMethod;
+use chrono::{DateTime, Utc};
+use fern_labour_event_sourcing_rs::{IdempotencyKey, ProcessEffect};
+use fern_labour_labour_shared::value_objects::{
+    Baby, LabourMilestoneType, SubscriberContactMethod, contraction::ContractionPatternRule,
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::durable_object::write_side::domain::LabourCommand;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct IdempotencyKey(pub String);
-
-impl IdempotencyKey {
-    pub fn for_notification(
-        aggregate_id: Uuid,
-        event_sequence: i64,
-        recipient_id: &str,
-        notification_type: &str,
-    ) -> Self {
-        Self(format!(
-            "{}:{}:notify:{}:{}",
-            aggregate_id, event_sequence, recipient_id, notification_type
-        ))
-    }
-
-    pub fn for_command(aggregate_id: Uuid, event_sequence: i64, command_type: &str) -> Self {
-        Self(format!(
-            "{}:{}:cmd:{}",
-            aggregate_id, event_sequence, command_type
-        ))
-    }
-}
-
 #[derive(Clone, Serialize, Deserialize)]
 pub enum Effect {
     SendNotification(NotificationIntent),
@@ -41,8 +21,8 @@ pub enum Effect {
     },
 }
 
-impl Effect {
-    pub fn idempotency_key(&self) -> &IdempotencyKey {
+impl ProcessEffect for Effect {
+    fn idempotency_key(&self) -> &IdempotencyKey {
         match self {
             Effect::SendNotification(intent) => &intent.idempotency_key,
             Effect::IssueCommand {
@@ -54,7 +34,7 @@ impl Effect {
         }
     }
 
-    pub fn effect_type(&self) -> &'static str {
+    fn effect_type(&self) -> &'static str {
         match self {
             Effect::SendNotification(_) => "NOTIFICATION",
             Effect::IssueCommand { .. } => "COMMAND",
@@ -108,6 +88,20 @@ pub enum SubscriberNotification {
     SubscriptionApproved {
         labour_id: Uuid,
     },
+    ContractionPatternDetected {
+        labour_id: Uuid,
+        rule: ContractionPatternRule,
+    },
+    BirthAnnouncementPublished {
+        labour_id: Uuid,
+        time_of_birth: DateTime<Utc>,
+        babies: Vec<Baby>,
+    },
+    MilestoneRecorded {
+        labour_id: Uuid,
+        milestone_type: LabourMilestoneType,
+        occurred_at: DateTime<Utc>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -118,6 +112,15 @@ pub enum MotherNotification {
         subscription_id: Uuid,
         requester_user_id: String,
     },
+    ContractionPatternDetected {
+        labour_id: Uuid,
+        rule: ContractionPatternRule,
+    },
+    ContractionAutoClosed {
+        labour_id: Uuid,
+        contraction_id: Uuid,
+        start_time: DateTime<Utc>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -125,22 +128,3 @@ pub enum MotherNotification {
 pub enum EmailNotification {
     LabourInvite { labour_id: Uuid },
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub enum EffectStatus {
-    Pending,
-    Dispatched,
-    Completed,
-    Failed,
-}
-
-impl std::fmt::Display for EffectStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            EffectStatus::Pending => write!(f, "PENDING"),
-            EffectStatus::Dispatched => write!(f, "DISPATCHED"),
-            EffectStatus::Completed => write!(f, "COMPLETED"),
-            EffectStatus::Failed => write!(f, "FAILED"),
-        }
-    }
-}
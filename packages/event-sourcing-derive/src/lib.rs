@@ -0,0 +1,256 @@
+//! `#[derive(DomainEvent)]` for event payload structs and the tagged enums
+//! that wrap them. Re-exported from `fern-labour-event-sourcing-rs`, whose
+//! paths the generated code refers to.
+//!
+//! On a payload struct, `#[event(aggregate_id = "labour_id", version = 2)]`
+//! names the field holding the aggregate id and the current payload version
+//! (defaulting to 1). The event type is the struct name.
+//!
+//! On an enum of single-field variants, the `Event` impl delegates to each
+//! payload, `into_stored_event`/`from_stored_event` are generated, and an
+//! `EventTypeRegistry` lists every variant's event type and version. A
+//! variant annotated `#[event(contraction_id)]` contributes an arm to a
+//! generated `contraction_id(&self) -> Option<Uuid>` accessor.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DataEnum, DeriveInput, Fields, Ident, LitInt, LitStr, Type, parse_macro_input};
+
+#[proc_macro_derive(DomainEvent, attributes(event))]
+pub fn derive_domain_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let expanded = match &input.data {
+        Data::Struct(_) => expand_payload(&input),
+        Data::Enum(data) => expand_enum(&input, data),
+        Data::Union(_) => Err(syn::Error::new_spanned(
+            &input.ident,
+            "DomainEvent cannot be derived for unions",
+        )),
+    };
+
+    expanded
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_payload(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut aggregate_id: Option<LitStr> = None;
+    let mut version: Option<LitInt> = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("event"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("aggregate_id") {
+                aggregate_id = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("version") {
+                version = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `aggregate_id = \"...\"` or `version = N`"))
+            }
+        })?;
+    }
+
+    let aggregate_id = aggregate_id.ok_or_else(|| {
+        syn::Error::new_spanned(
+            name,
+            "DomainEvent payloads need #[event(aggregate_id = \"field\")]",
+        )
+    })?;
+    let aggregate_field = Ident::new(&aggregate_id.value(), aggregate_id.span());
+
+    let Data::Struct(data) = &input.data else {
+        unreachable!("expand_payload is only called for structs");
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            name,
+            "DomainEvent payloads must be structs with named fields",
+        ));
+    };
+    if !fields
+        .named
+        .iter()
+        .any(|field| field.ident.as_ref() == Some(&aggregate_field))
+    {
+        return Err(syn::Error::new_spanned(
+            &aggregate_id,
+            format!("{name} has no field named `{}`", aggregate_id.value()),
+        ));
+    }
+
+    let version = match version {
+        Some(version) => version.base10_parse::<i64>()?,
+        None => 1,
+    };
+    let event_type = name.to_string();
+
+    Ok(quote! {
+        impl #impl_generics ::fern_labour_event_sourcing_rs::EventDescriptor
+            for #name #ty_generics #where_clause
+        {
+            const EVENT_TYPE: &'static str = #event_type;
+            const EVENT_VERSION: i64 = #version;
+        }
+
+        impl #impl_generics ::fern_labour_event_sourcing_rs::Event
+            for #name #ty_generics #where_clause
+        {
+            fn event_type(&self) -> &str {
+                <Self as ::fern_labour_event_sourcing_rs::EventDescriptor>::EVENT_TYPE
+            }
+
+            fn event_version(&self) -> i64 {
+                <Self as ::fern_labour_event_sourcing_rs::EventDescriptor>::EVENT_VERSION
+            }
+
+            fn aggregate_id(&self) -> ::fern_labour_event_sourcing_rs::__private::Uuid {
+                self.#aggregate_field
+            }
+        }
+    })
+}
+
+struct Variant<'a> {
+    ident: &'a Ident,
+    payload: &'a Type,
+    accessors: Vec<Ident>,
+}
+
+fn parse_variants(data: &DataEnum) -> syn::Result<Vec<Variant<'_>>> {
+    data.variants
+        .iter()
+        .map(|variant| {
+            let payload = match &variant.fields {
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "DomainEvent enum variants must wrap exactly one payload",
+                    ));
+                }
+            };
+
+            let mut accessors = Vec::new();
+            for attr in variant
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("event"))
+            {
+                attr.parse_nested_meta(|meta| {
+                    let accessor = meta
+                        .path
+                        .get_ident()
+                        .cloned()
+                        .ok_or_else(|| meta.error("expected an accessor name"))?;
+                    accessors.push(accessor);
+                    Ok(())
+                })?;
+            }
+
+            Ok(Variant {
+                ident: &variant.ident,
+                payload,
+                accessors,
+            })
+        })
+        .collect()
+}
+
+fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            name,
+            "DomainEvent enums need at least one variant",
+        ));
+    }
+    let variants = parse_variants(data)?;
+
+    let idents: Vec<&Ident> = variants.iter().map(|variant| variant.ident).collect();
+    let payloads: Vec<&Type> = variants.iter().map(|variant| variant.payload).collect();
+
+    let mut accessor_names: Vec<&Ident> = Vec::new();
+    for accessor in variants.iter().flat_map(|variant| &variant.accessors) {
+        if !accessor_names.contains(&accessor) {
+            accessor_names.push(accessor);
+        }
+    }
+    let accessors = accessor_names.into_iter().map(|accessor| {
+        let arms = variants
+            .iter()
+            .filter(|variant| variant.accessors.contains(accessor))
+            .map(|variant| {
+                let ident = variant.ident;
+                quote! { Self::#ident(event) => Some(event.#accessor), }
+            });
+        quote! {
+            #[allow(unreachable_patterns)]
+            pub fn #accessor(&self) -> Option<::fern_labour_event_sourcing_rs::__private::Uuid> {
+                match self {
+                    #(#arms)*
+                    _ => None,
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::fern_labour_event_sourcing_rs::Event
+            for #name #ty_generics #where_clause
+        {
+            fn event_type(&self) -> &str {
+                match self {
+                    #(Self::#idents(event) => ::fern_labour_event_sourcing_rs::Event::event_type(event),)*
+                }
+            }
+
+            fn event_version(&self) -> i64 {
+                match self {
+                    #(Self::#idents(event) => ::fern_labour_event_sourcing_rs::Event::event_version(event),)*
+                }
+            }
+
+            fn aggregate_id(&self) -> ::fern_labour_event_sourcing_rs::__private::Uuid {
+                match self {
+                    #(Self::#idents(event) => ::fern_labour_event_sourcing_rs::Event::aggregate_id(event),)*
+                }
+            }
+        }
+
+        impl #impl_generics ::fern_labour_event_sourcing_rs::EventTypeRegistry
+            for #name #ty_generics #where_clause
+        {
+            const EVENT_TYPES: &'static [::fern_labour_event_sourcing_rs::EventTypeInfo] = &[
+                #(::fern_labour_event_sourcing_rs::EventTypeInfo {
+                    event_type: <#payloads as ::fern_labour_event_sourcing_rs::EventDescriptor>::EVENT_TYPE,
+                    version: <#payloads as ::fern_labour_event_sourcing_rs::EventDescriptor>::EVENT_VERSION,
+                },)*
+            ];
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn into_stored_event(self) -> ::fern_labour_event_sourcing_rs::StoredEvent {
+                <Self as ::fern_labour_event_sourcing_rs::Event>::into_stored_event(self)
+            }
+
+            pub fn from_stored_event(
+                event: ::fern_labour_event_sourcing_rs::StoredEvent,
+            ) -> ::fern_labour_event_sourcing_rs::__private::Result<Self> {
+                ::fern_labour_event_sourcing_rs::decode_stored_event(event)
+            }
+
+            #(#accessors)*
+        }
+    })
+}
@@ -1,5 +1,17 @@
+extern crate self as fern_labour_event_sourcing_rs;
+
 pub mod read_side;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod write_side;
 
 pub use read_side::*;
 pub use write_side::*;
+
+pub use fern_labour_event_sourcing_derive::DomainEvent;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use anyhow::Result;
+    pub use uuid::Uuid;
+}
@@ -36,6 +36,11 @@ pub trait AsyncProjector<E> {
 pub trait IncrementalAsyncProjector<E> {
     fn name(&self) -> &str;
 
+    /// Event types this projector reacts to, or `None` for every event.
+    fn event_types(&self) -> Option<&'static [&'static str]> {
+        None
+    }
+
     fn get_cached_sequence(&self, cache: &Rc<dyn CacheTrait>) -> i64;
 
     async fn process(
@@ -44,4 +49,29 @@ pub trait IncrementalAsyncProjector<E> {
         events: &[EventEnvelope<E>],
         max_sequence: i64,
     ) -> Result<()>;
+
+    /// Moves the cached sequence past `sequence` without projecting it, so a
+    /// quarantined event is not picked up again.
+    fn skip_to(&self, cache: &Rc<dyn CacheTrait>, sequence: i64) -> Result<()>;
+
+    /// Folds `events` into the projector's shadow cache entry without
+    /// writing to the backing store, so a rebuild never exposes a partial
+    /// model to readers.
+    async fn process_shadow(
+        &self,
+        cache: &Rc<dyn CacheTrait>,
+        events: &[EventEnvelope<E>],
+        max_sequence: i64,
+    ) -> Result<()>;
+
+    /// Persists the shadow model, makes it the live cache entry and clears
+    /// the shadow.
+    async fn promote_shadow(&self, cache: &Rc<dyn CacheTrait>) -> Result<()>;
+
+    fn discard_shadow(&self, cache: &Rc<dyn CacheTrait>) -> Result<()>;
+}
+
+/// Cache key holding the shadow state for the projector cached at `cache_key`.
+pub fn shadow_cache_key(cache_key: &str) -> String {
+    format!("{cache_key}:rebuild")
 }
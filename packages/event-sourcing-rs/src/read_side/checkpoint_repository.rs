@@ -4,6 +4,8 @@ use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::Clock;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectionCheckpoint {
     pub projector_name: String,
@@ -21,6 +23,9 @@ pub enum CheckpointStatus {
     Healthy,
     Error,
     Stale,
+    /// The projector is being rebuilt into shadow storage; the sequence is
+    /// how far the rebuild has got.
+    Rebuilding,
 }
 
 impl CheckpointStatus {
@@ -29,6 +34,7 @@ impl CheckpointStatus {
             CheckpointStatus::Healthy => "healthy",
             CheckpointStatus::Error => "error",
             CheckpointStatus::Stale => "stale",
+            CheckpointStatus::Rebuilding => "rebuilding",
         }
     }
 }
@@ -39,12 +45,99 @@ impl FromStr for CheckpointStatus {
         let val = match s {
             "error" => CheckpointStatus::Error,
             "stale" => CheckpointStatus::Stale,
+            "rebuilding" => CheckpointStatus::Rebuilding,
             _ => CheckpointStatus::Healthy,
         };
         Ok(val)
     }
 }
 
+impl ProjectionCheckpoint {
+    /// Checkpoint for a projector that has not processed any events yet.
+    pub fn initial(projector_name: &str, clock: &dyn Clock) -> Self {
+        let now = clock.now();
+        Self {
+            projector_name: projector_name.to_string(),
+            last_processed_sequence: 0,
+            last_processed_at: now,
+            updated_at: now,
+            status: CheckpointStatus::Healthy,
+            error_message: None,
+            error_count: 0,
+        }
+    }
+
+    /// Moves the checkpoint past an event projected at `processed_at`. A
+    /// stale projector stays stale, since its quarantined events are still
+    /// missing from the read model.
+    pub fn advanced_to(
+        self,
+        sequence: i64,
+        processed_at: DateTime<Utc>,
+        clock: &dyn Clock,
+    ) -> Self {
+        let stale = self.status == CheckpointStatus::Stale;
+        Self {
+            last_processed_sequence: sequence,
+            last_processed_at: processed_at,
+            updated_at: clock.now(),
+            status: if stale {
+                CheckpointStatus::Stale
+            } else {
+                CheckpointStatus::Healthy
+            },
+            error_message: if stale { self.error_message } else { None },
+            error_count: 0,
+            ..self
+        }
+    }
+
+    /// Records another failed attempt at the event after this checkpoint.
+    pub fn failed(self, error_message: String, clock: &dyn Clock) -> Self {
+        Self {
+            updated_at: clock.now(),
+            status: CheckpointStatus::Error,
+            error_message: Some(error_message),
+            error_count: self.error_count + 1,
+            ..self
+        }
+    }
+
+    /// Checkpoint recording a requested rebuild of `projector_name` that has
+    /// not processed any events yet.
+    pub fn rebuild_requested(projector_name: &str, clock: &dyn Clock) -> Self {
+        let now = clock.now();
+        Self {
+            projector_name: rebuild_checkpoint_name(projector_name),
+            last_processed_sequence: 0,
+            last_processed_at: now,
+            updated_at: now,
+            status: CheckpointStatus::Rebuilding,
+            error_message: None,
+            error_count: 0,
+        }
+    }
+
+    /// Records a failed rebuild attempt. The sequence goes back to zero so
+    /// the next attempt starts again from a fresh shadow.
+    pub fn rebuild_failed(self, error_message: String, clock: &dyn Clock) -> Self {
+        Self {
+            last_processed_sequence: 0,
+            updated_at: clock.now(),
+            status: CheckpointStatus::Error,
+            error_message: Some(error_message),
+            error_count: self.error_count + 1,
+            ..self
+        }
+    }
+}
+
+/// Name of the checkpoint tracking a rebuild of `projector_name`, kept apart
+/// from the projector's live checkpoint until the rebuild is swapped in.
+pub fn rebuild_checkpoint_name(projector_name: &str) -> String {
+    format!("{projector_name}:rebuild")
+}
+
 pub trait CheckpointRepository {
     fn init_schema(&self) -> Result<()>;
     fn get_checkpoint(&self, projector_name: &str) -> Result<Option<ProjectionCheckpoint>>;
@@ -0,0 +1,246 @@
+use std::rc::Rc;
+
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    CheckpointRepository, CheckpointStatus, Clock, ProjectionCheckpoint, StoredEventRow,
+    SystemClock,
+};
+
+pub const DEFAULT_MAX_PROJECTION_FAILURES: i64 = 5;
+
+/// An event a projector gave up on, kept so it can be retried or skipped
+/// once the cause is understood.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadLetter {
+    pub projector_name: String,
+    pub sequence: i64,
+    pub event_type: String,
+    pub error_message: String,
+    pub failure_count: i64,
+    pub quarantined_at: DateTime<Utc>,
+}
+
+pub trait DeadLetterRepository {
+    fn init_schema(&self) -> Result<()>;
+    fn insert(&self, dead_letter: &DeadLetter) -> Result<()>;
+    fn get(&self, projector_name: &str, sequence: i64) -> Result<Option<DeadLetter>>;
+    fn get_all(&self) -> Result<Vec<DeadLetter>>;
+    fn get_for_projector(&self, projector_name: &str) -> Result<Vec<DeadLetter>>;
+    fn remove(&self, projector_name: &str, sequence: i64) -> Result<()>;
+}
+
+/// What a projection processor does once the same event has failed to
+/// project `max_failures` times in a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoisonEventPolicy {
+    /// Stop the projector until an admin resets or rebuilds it.
+    Halt { max_failures: i64 },
+    /// Move the event to the dead-letter store, mark the projector stale and
+    /// carry on with the next event.
+    Quarantine { max_failures: i64 },
+}
+
+impl Default for PoisonEventPolicy {
+    fn default() -> Self {
+        PoisonEventPolicy::Quarantine {
+            max_failures: DEFAULT_MAX_PROJECTION_FAILURES,
+        }
+    }
+}
+
+impl PoisonEventPolicy {
+    pub fn max_failures(&self) -> i64 {
+        match self {
+            PoisonEventPolicy::Halt { max_failures }
+            | PoisonEventPolicy::Quarantine { max_failures } => *max_failures,
+        }
+    }
+
+    /// Whether an event that has now failed `failure_count` times should be
+    /// quarantined rather than retried.
+    pub fn should_quarantine(&self, failure_count: i64) -> bool {
+        matches!(self, PoisonEventPolicy::Quarantine { .. }) && failure_count >= self.max_failures()
+    }
+
+    /// Whether a projector at `checkpoint` has given up and should be skipped.
+    pub fn is_halted(&self, checkpoint: &ProjectionCheckpoint) -> bool {
+        checkpoint.status == CheckpointStatus::Error
+            && checkpoint.error_count >= self.max_failures()
+    }
+}
+
+/// Dead letters together with the checkpoints they affect. A projector stays
+/// `Stale` while any of its events are quarantined.
+pub struct DeadLetterQueue {
+    dead_letters: Box<dyn DeadLetterRepository>,
+    checkpoints: Box<dyn CheckpointRepository>,
+    clock: Rc<dyn Clock>,
+}
+
+impl DeadLetterQueue {
+    pub fn new(
+        dead_letters: Box<dyn DeadLetterRepository>,
+        checkpoints: Box<dyn CheckpointRepository>,
+    ) -> Self {
+        Self {
+            dead_letters,
+            checkpoints,
+            clock: Rc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Quarantines `row` for the projector of the failed `checkpoint` and
+    /// returns the checkpoint moved past it. The caller persists the
+    /// returned checkpoint.
+    pub fn quarantine(
+        &self,
+        checkpoint: ProjectionCheckpoint,
+        row: &StoredEventRow,
+    ) -> Result<ProjectionCheckpoint> {
+        self.dead_letters
+            .insert(&DeadLetter {
+                projector_name: checkpoint.projector_name.clone(),
+                sequence: row.sequence,
+                event_type: row.event_type.clone(),
+                error_message: checkpoint.error_message.clone().unwrap_or_default(),
+                failure_count: checkpoint.error_count,
+                quarantined_at: self.clock.now(),
+            })
+            .context("Failed to quarantine event")?;
+
+        Ok(ProjectionCheckpoint {
+            last_processed_sequence: row.sequence,
+            updated_at: self.clock.now(),
+            status: CheckpointStatus::Stale,
+            error_count: 0,
+            ..checkpoint
+        })
+    }
+
+    pub fn list(&self) -> Result<Vec<DeadLetter>> {
+        self.dead_letters
+            .get_all()
+            .context("Failed to load dead letters")
+    }
+
+    pub fn get(&self, projector_name: &str, sequence: i64) -> Result<DeadLetter> {
+        self.dead_letters
+            .get(projector_name, sequence)?
+            .ok_or_else(|| anyhow!("No dead letter for {projector_name} at sequence {sequence}"))
+    }
+
+    /// Removes a dead letter once it has been retried or skipped, marking the
+    /// projector healthy again when it was the last one.
+    pub fn resolve(&self, projector_name: &str, sequence: i64) -> Result<()> {
+        self.get(projector_name, sequence)?;
+        self.dead_letters.remove(projector_name, sequence)?;
+
+        if self
+            .dead_letters
+            .get_for_projector(projector_name)?
+            .is_empty()
+            && let Some(checkpoint) = self.checkpoints.get_checkpoint(projector_name)?
+            && checkpoint.status == CheckpointStatus::Stale
+        {
+            self.checkpoints
+                .update_checkpoint(&ProjectionCheckpoint {
+                    updated_at: self.clock.now(),
+                    status: CheckpointStatus::Healthy,
+                    error_message: None,
+                    ..checkpoint
+                })
+                .context("Failed to mark projector healthy")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{FixedClock, InMemoryCheckpointRepository, InMemoryDeadLetterRepository};
+
+    fn row(sequence: i64) -> StoredEventRow {
+        StoredEventRow {
+            sequence,
+            aggregate_id: "aggregate".to_string(),
+            event_type: "Noted".to_string(),
+            event_data: "{}".to_string(),
+            event_version: 1,
+            created_at: "2026-01-01 10:00:00".to_string(),
+            user_id: "user".to_string(),
+            correlation_id: None,
+            causation_id: None,
+            hash: None,
+        }
+    }
+
+    #[test]
+    fn policies_decide_when_to_give_up() {
+        let quarantine = PoisonEventPolicy::Quarantine { max_failures: 2 };
+        let halt = PoisonEventPolicy::Halt { max_failures: 2 };
+
+        assert!(!quarantine.should_quarantine(1));
+        assert!(quarantine.should_quarantine(2));
+        assert!(!halt.should_quarantine(2));
+
+        let clock = FixedClock::default();
+        let failed = ProjectionCheckpoint::initial("Projector", &clock)
+            .failed("boom".to_string(), &clock)
+            .failed("boom".to_string(), &clock);
+        assert!(halt.is_halted(&failed));
+        assert!(!PoisonEventPolicy::default().is_halted(&failed));
+    }
+
+    #[test]
+    fn quarantined_events_keep_the_projector_stale_until_resolved() {
+        let clock = Rc::new(FixedClock::default());
+        let queue = DeadLetterQueue::new(
+            Box::new(InMemoryDeadLetterRepository::new()),
+            Box::new(InMemoryCheckpointRepository::new()),
+        )
+        .with_clock(clock.clone());
+
+        let failed = ProjectionCheckpoint::initial("Projector", clock.as_ref())
+            .failed("boom".to_string(), clock.as_ref());
+        let checkpoint = queue.quarantine(failed, &row(1)).unwrap();
+        let checkpoint = queue
+            .quarantine(
+                checkpoint.failed("boom".to_string(), clock.as_ref()),
+                &row(2),
+            )
+            .unwrap();
+        queue.checkpoints.update_checkpoint(&checkpoint).unwrap();
+
+        assert_eq!(checkpoint.last_processed_sequence, 2);
+        assert_eq!(checkpoint.status, CheckpointStatus::Stale);
+        assert_eq!(checkpoint.error_count, 0);
+        let dead_letters = queue.list().unwrap();
+        assert_eq!(dead_letters.len(), 2);
+        assert_eq!(dead_letters[0].error_message, "boom");
+
+        queue.resolve("Projector", 1).unwrap();
+        let status = |queue: &DeadLetterQueue| {
+            queue
+                .checkpoints
+                .get_checkpoint("Projector")
+                .unwrap()
+                .unwrap()
+                .status
+        };
+        assert_eq!(status(&queue), CheckpointStatus::Stale);
+
+        queue.resolve("Projector", 2).unwrap();
+        assert_eq!(status(&queue), CheckpointStatus::Healthy);
+        assert!(queue.list().unwrap().is_empty());
+        assert!(queue.resolve("Projector", 2).is_err());
+    }
+}
@@ -0,0 +1,215 @@
+use std::{cell::RefCell, rc::Rc};
+
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+
+use crate::{Cursor, DecodedCursor, SyncRepositoryTrait};
+
+/// Sync repository that keeps read models in memory, in the order they were
+/// first written. Clones share the same storage, so a projector can own one
+/// handle while the caller reads the projected values through another.
+///
+/// Used to build throwaway read-model views, such as replaying a stream up to
+/// a point in history without touching the persisted read models.
+pub struct InMemorySyncRepository<T> {
+    values: Rc<RefCell<Vec<T>>>,
+    shadow: Rc<RefCell<Option<InMemorySyncRepository<T>>>>,
+}
+
+impl<T> Clone for InMemorySyncRepository<T> {
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            shadow: self.shadow.clone(),
+        }
+    }
+}
+
+impl<T> Default for InMemorySyncRepository<T> {
+    fn default() -> Self {
+        Self {
+            values: Rc::new(RefCell::new(Vec::new())),
+            shadow: Rc::new(RefCell::new(None)),
+        }
+    }
+}
+
+impl<T: Cursor + Clone> InMemorySyncRepository<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn values(&self) -> Vec<T> {
+        self.values.borrow().clone()
+    }
+}
+
+impl<T: Cursor + Clone + 'static> SyncRepositoryTrait<T> for InMemorySyncRepository<T> {
+    fn get_by_id(&self, id: Uuid) -> Result<T> {
+        self.values
+            .borrow()
+            .iter()
+            .find(|value| value.id() == id)
+            .cloned()
+            .ok_or_else(|| anyhow!("Read model {id} not found"))
+    }
+
+    fn get(&self, limit: usize, cursor: Option<DecodedCursor>) -> Result<Vec<T>> {
+        let mut values: Vec<T> = self
+            .values
+            .borrow()
+            .iter()
+            .filter(|value| match &cursor {
+                Some(cur) => (value.updated_at(), value.id()) < (cur.last_updated_at, cur.last_id),
+                None => true,
+            })
+            .cloned()
+            .collect();
+
+        values.sort_by_key(|value| std::cmp::Reverse((value.updated_at(), value.id())));
+        values.truncate(limit + 1);
+        Ok(values)
+    }
+
+    fn upsert(&self, value: &T) -> Result<()> {
+        let mut values = self.values.borrow_mut();
+        match values
+            .iter_mut()
+            .find(|existing| existing.id() == value.id())
+        {
+            Some(existing) => *existing = value.clone(),
+            None => values.push(value.clone()),
+        }
+        Ok(())
+    }
+
+    fn delete(&self, id: Uuid) -> Result<()> {
+        self.values.borrow_mut().retain(|value| value.id() != id);
+        Ok(())
+    }
+
+    fn overwrite(&self, value: &T) -> Result<()> {
+        self.upsert(value)
+    }
+
+    fn shadow(&self) -> Result<Box<dyn SyncRepositoryTrait<T>>> {
+        let shadow = self
+            .shadow
+            .borrow_mut()
+            .get_or_insert_with(Self::new)
+            .clone();
+        Ok(Box::new(shadow))
+    }
+
+    fn promote_shadow(&self) -> Result<()> {
+        let shadow = self
+            .shadow
+            .borrow_mut()
+            .take()
+            .ok_or_else(|| anyhow!("No shadow read model to promote"))?;
+        *self.values.borrow_mut() = shadow.values();
+        Ok(())
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        self.shadow.borrow_mut().take();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, Utc};
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Note {
+        id: Uuid,
+        updated_at: DateTime<Utc>,
+        body: &'static str,
+    }
+
+    impl Cursor for Note {
+        fn id(&self) -> Uuid {
+            self.id
+        }
+
+        fn updated_at(&self) -> DateTime<Utc> {
+            self.updated_at
+        }
+    }
+
+    #[test]
+    fn clones_share_storage_and_pages_newest_first() {
+        let repository = InMemorySyncRepository::<Note>::new();
+        let projector_handle = repository.clone();
+        let now = Utc::now();
+        let first = Note {
+            id: Uuid::now_v7(),
+            updated_at: now - Duration::minutes(1),
+            body: "first",
+        };
+        let second = Note {
+            id: Uuid::now_v7(),
+            updated_at: now,
+            body: "second",
+        };
+
+        projector_handle.upsert(&first).unwrap();
+        projector_handle.upsert(&second).unwrap();
+        projector_handle
+            .overwrite(&Note {
+                body: "edited",
+                ..first.clone()
+            })
+            .unwrap();
+
+        assert_eq!(repository.values().len(), 2);
+        assert_eq!(repository.get_by_id(first.id).unwrap().body, "edited");
+
+        let page = repository.get(1, None).unwrap();
+        assert_eq!(page[0].id, second.id);
+
+        let cursor = DecodedCursor {
+            last_updated_at: second.updated_at,
+            last_id: second.id,
+        };
+        let next = repository.get(1, Some(cursor)).unwrap();
+        assert_eq!(next.len(), 1);
+        assert_eq!(next[0].id, first.id);
+
+        repository.delete(second.id).unwrap();
+        assert!(projector_handle.get_by_id(second.id).is_err());
+    }
+
+    #[test]
+    fn shadow_replaces_live_values_only_when_promoted() {
+        let repository = InMemorySyncRepository::<Note>::new();
+        let live = Note {
+            id: Uuid::now_v7(),
+            updated_at: Utc::now(),
+            body: "live",
+        };
+        let rebuilt = Note {
+            id: Uuid::now_v7(),
+            updated_at: Utc::now(),
+            body: "rebuilt",
+        };
+        repository.upsert(&live).unwrap();
+
+        repository.shadow().unwrap().upsert(&rebuilt).unwrap();
+        assert_eq!(repository.values(), vec![live.clone()]);
+
+        repository.promote_shadow().unwrap();
+        assert_eq!(repository.values(), vec![rebuilt]);
+        assert!(
+            repository
+                .shadow()
+                .unwrap()
+                .get(10, None)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
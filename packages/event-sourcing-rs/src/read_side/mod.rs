@@ -1,6 +1,8 @@
 pub mod async_projector;
 pub mod async_repository;
 pub mod checkpoint_repository;
+pub mod dead_letter;
+pub mod in_memory_sync_repository;
 pub mod pagination;
 pub mod sync_projector;
 pub mod sync_repository;
@@ -8,6 +10,8 @@ pub mod sync_repository;
 pub use async_projector::*;
 pub use async_repository::*;
 pub use checkpoint_repository::*;
+pub use dead_letter::*;
+pub use in_memory_sync_repository::*;
 pub use pagination::*;
 pub use sync_projector::*;
 pub use sync_repository::*;
@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 
 use crate::EventEnvelope;
 
@@ -6,4 +6,25 @@ pub trait SyncProjector<E> {
     fn project_batch(&self, events: &[EventEnvelope<E>]) -> Result<()>;
 
     fn name(&self) -> &str;
+
+    /// Event types this projector reacts to, or `None` for every event.
+    /// Processors only load matching events for the projector.
+    fn event_types(&self) -> Option<&'static [&'static str]> {
+        None
+    }
+
+    /// A projector writing into this projector's shadow storage, used to
+    /// rebuild the read model without emptying the live copy.
+    fn shadow(&self) -> Result<Box<dyn SyncProjector<E>>> {
+        bail!("{} does not support rebuilds", self.name())
+    }
+
+    /// Swaps the rebuilt shadow storage in for the live read model.
+    fn promote_shadow(&self) -> Result<()> {
+        bail!("{} does not support rebuilds", self.name())
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        bail!("{} does not support rebuilds", self.name())
+    }
 }
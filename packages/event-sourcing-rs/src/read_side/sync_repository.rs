@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 use uuid::Uuid;
 
 use crate::DecodedCursor;
@@ -9,4 +9,21 @@ pub trait SyncRepositoryTrait<T> {
     fn upsert(&self, value: &T) -> Result<()>;
     fn delete(&self, id: Uuid) -> Result<()>;
     fn overwrite(&self, value: &T) -> Result<()>;
+
+    /// A repository over the shadow copy of this read model, created empty if
+    /// it does not exist yet. Rebuilds write here while readers keep using
+    /// the live copy.
+    fn shadow(&self) -> Result<Box<dyn SyncRepositoryTrait<T>>> {
+        bail!("Read model does not support shadow rebuilds")
+    }
+
+    /// Replaces the live read model with the shadow copy in one step and
+    /// removes the shadow.
+    fn promote_shadow(&self) -> Result<()> {
+        bail!("Read model does not support shadow rebuilds")
+    }
+
+    fn discard_shadow(&self) -> Result<()> {
+        bail!("Read model does not support shadow rebuilds")
+    }
 }
@@ -0,0 +1,34 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use crate::{CacheError, CacheTrait};
+
+#[derive(Default)]
+pub struct InMemoryCache {
+    entries: RefCell<HashMap<String, Vec<u8>>>,
+}
+
+impl InMemoryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.borrow().contains_key(key)
+    }
+}
+
+impl CacheTrait for InMemoryCache {
+    fn set_bytes(&self, key: String, value: Vec<u8>) -> Result<(), CacheError> {
+        self.entries.borrow_mut().insert(key, value);
+        Ok(())
+    }
+
+    fn get_bytes(&self, key: String) -> Result<Option<Vec<u8>>, CacheError> {
+        Ok(self.entries.borrow().get(&key).cloned())
+    }
+
+    fn clear(&self, key: String) -> Result<(), CacheError> {
+        self.entries.borrow_mut().remove(&key);
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use std::{cell::RefCell, collections::BTreeMap};
+
+use anyhow::Result;
+
+use crate::{CheckpointRepository, ProjectionCheckpoint};
+
+#[derive(Default)]
+pub struct InMemoryCheckpointRepository {
+    checkpoints: RefCell<BTreeMap<String, ProjectionCheckpoint>>,
+}
+
+impl InMemoryCheckpointRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CheckpointRepository for InMemoryCheckpointRepository {
+    fn init_schema(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn get_checkpoint(&self, projector_name: &str) -> Result<Option<ProjectionCheckpoint>> {
+        Ok(self.checkpoints.borrow().get(projector_name).cloned())
+    }
+
+    fn update_checkpoint(&self, checkpoint: &ProjectionCheckpoint) -> Result<()> {
+        self.checkpoints
+            .borrow_mut()
+            .insert(checkpoint.projector_name.clone(), checkpoint.clone());
+        Ok(())
+    }
+
+    fn reset_checkpoint(&self, projector_name: &str) -> Result<()> {
+        self.checkpoints.borrow_mut().remove(projector_name);
+        Ok(())
+    }
+
+    fn get_all_checkpoints(&self) -> Result<Vec<ProjectionCheckpoint>> {
+        Ok(self.checkpoints.borrow().values().cloned().collect())
+    }
+}
@@ -0,0 +1,58 @@
+use std::cell::Cell;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use crate::Clock;
+
+/// A clock that only moves when told to.
+#[derive(Debug, Clone)]
+pub struct FixedClock {
+    now: Cell<DateTime<Utc>>,
+}
+
+impl Default for FixedClock {
+    /// Midday on 2026-01-01 UTC.
+    fn default() -> Self {
+        Self::new(Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap())
+    }
+}
+
+impl FixedClock {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Cell::new(now),
+        }
+    }
+
+    pub fn set(&self, now: DateTime<Utc>) {
+        self.now.set(now);
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.now.set(self.now.get() + by);
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.now.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_moves_when_advanced_or_set() {
+        let clock = FixedClock::default();
+        let start = clock.now();
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::minutes(5));
+        assert_eq!(clock.now(), start + Duration::minutes(5));
+
+        clock.set(start);
+        assert_eq!(clock.now(), start);
+    }
+}
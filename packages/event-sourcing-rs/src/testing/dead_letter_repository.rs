@@ -0,0 +1,59 @@
+use std::{cell::RefCell, collections::BTreeMap};
+
+use anyhow::Result;
+
+use crate::{DeadLetter, DeadLetterRepository};
+
+#[derive(Default)]
+pub struct InMemoryDeadLetterRepository {
+    dead_letters: RefCell<BTreeMap<(String, i64), DeadLetter>>,
+}
+
+impl InMemoryDeadLetterRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl DeadLetterRepository for InMemoryDeadLetterRepository {
+    fn init_schema(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn insert(&self, dead_letter: &DeadLetter) -> Result<()> {
+        self.dead_letters.borrow_mut().insert(
+            (dead_letter.projector_name.clone(), dead_letter.sequence),
+            dead_letter.clone(),
+        );
+        Ok(())
+    }
+
+    fn get(&self, projector_name: &str, sequence: i64) -> Result<Option<DeadLetter>> {
+        Ok(self
+            .dead_letters
+            .borrow()
+            .get(&(projector_name.to_string(), sequence))
+            .cloned())
+    }
+
+    fn get_all(&self) -> Result<Vec<DeadLetter>> {
+        Ok(self.dead_letters.borrow().values().cloned().collect())
+    }
+
+    fn get_for_projector(&self, projector_name: &str) -> Result<Vec<DeadLetter>> {
+        Ok(self
+            .dead_letters
+            .borrow()
+            .values()
+            .filter(|dead_letter| dead_letter.projector_name == projector_name)
+            .cloned()
+            .collect())
+    }
+
+    fn remove(&self, projector_name: &str, sequence: i64) -> Result<()> {
+        self.dead_letters
+            .borrow_mut()
+            .remove(&(projector_name.to_string(), sequence));
+        Ok(())
+    }
+}
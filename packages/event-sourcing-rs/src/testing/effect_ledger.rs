@@ -0,0 +1,135 @@
+use std::cell::{Cell, RefCell};
+
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{CausationContext, EffectLedgerTrait, EffectRecord, EffectStatus, SerializedEffect};
+
+#[derive(Default)]
+pub struct InMemoryEffectLedger {
+    last_processed_sequence: Cell<i64>,
+    effects: RefCell<Vec<EffectRecord>>,
+}
+
+impl InMemoryEffectLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn effects(&self) -> Vec<EffectRecord> {
+        self.effects.borrow().clone()
+    }
+
+    fn update(&self, effect_id: &str, change: impl FnOnce(&mut EffectRecord)) -> Result<()> {
+        let mut effects = self.effects.borrow_mut();
+        let record = effects
+            .iter_mut()
+            .find(|record| record.effect_id == effect_id)
+            .ok_or_else(|| anyhow!("Unknown effect {effect_id}"))?;
+        change(record);
+        Ok(())
+    }
+
+    fn is_pending(record: &EffectRecord, max_attempts: i64) -> bool {
+        let pending = [EffectStatus::Pending, EffectStatus::Dispatched].map(|s| s.to_string());
+        pending.contains(&record.status) && record.attempts < max_attempts
+    }
+}
+
+impl EffectLedgerTrait for InMemoryEffectLedger {
+    fn init_schema(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn get_last_processed_sequence(&self) -> Result<i64> {
+        Ok(self.last_processed_sequence.get())
+    }
+
+    fn persist_effects(
+        &self,
+        effects: &[SerializedEffect],
+        sequence: i64,
+        causation: CausationContext,
+    ) -> Result<()> {
+        let mut records = self.effects.borrow_mut();
+        for effect in effects {
+            if records
+                .iter()
+                .any(|record| record.idempotency_key == effect.idempotency_key)
+            {
+                continue;
+            }
+            records.push(EffectRecord {
+                effect_id: Uuid::now_v7().to_string(),
+                event_sequence: sequence,
+                effect_type: effect.effect_type.clone(),
+                effect_payload: effect.effect_payload.clone(),
+                idempotency_key: effect.idempotency_key.clone(),
+                status: EffectStatus::Pending.to_string(),
+                attempts: 0,
+                last_attempt_at: None,
+                last_error: None,
+                created_at: Utc::now().to_rfc3339(),
+                correlation_id: causation.correlation_id,
+                causation_id: causation.causation_id,
+            });
+        }
+        self.last_processed_sequence.set(sequence);
+        Ok(())
+    }
+
+    fn get_pending_effects(&self, max_attempts: i64) -> Result<Vec<EffectRecord>> {
+        Ok(self
+            .effects
+            .borrow()
+            .iter()
+            .filter(|record| Self::is_pending(record, max_attempts))
+            .cloned()
+            .collect())
+    }
+
+    fn mark_dispatched(&self, effect_id: &str) -> Result<()> {
+        self.update(effect_id, |record| {
+            record.status = EffectStatus::Dispatched.to_string();
+            record.attempts += 1;
+            record.last_attempt_at = Some(Utc::now().to_rfc3339());
+        })
+    }
+
+    fn mark_completed(&self, effect_id: &str) -> Result<()> {
+        self.update(effect_id, |record| {
+            record.status = EffectStatus::Completed.to_string();
+        })
+    }
+
+    fn mark_failed(&self, effect_id: &str, error: &str, exhausted: bool) -> Result<()> {
+        let status = if exhausted {
+            EffectStatus::Failed
+        } else {
+            EffectStatus::Dispatched
+        };
+        self.update(effect_id, |record| {
+            record.status = status.to_string();
+            record.last_error = Some(error.to_string());
+        })
+    }
+
+    fn has_pending_effects(&self, max_attempts: i64) -> Result<bool> {
+        Ok(self
+            .effects
+            .borrow()
+            .iter()
+            .any(|record| Self::is_pending(record, max_attempts)))
+    }
+
+    fn cancel_pending_effects(&self, event_sequence: i64) -> Result<()> {
+        let pending = EffectStatus::Pending.to_string();
+        for record in self.effects.borrow_mut().iter_mut() {
+            if record.event_sequence == event_sequence && record.status == pending {
+                record.status = EffectStatus::Cancelled.to_string();
+            }
+        }
+        Ok(())
+    }
+}
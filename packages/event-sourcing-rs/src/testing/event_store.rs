@@ -0,0 +1,245 @@
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+
+use crate::{
+    AppendResult, ConcurrencyConflict, EventStoreTrait, StoredEvent, StoredEventRow, seal_batch,
+};
+
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Event store backed by a `Vec`, with the same sequencing and
+/// expected-version semantics as the SQLite stores.
+#[derive(Default)]
+pub struct InMemoryEventStore {
+    rows: Mutex<Vec<StoredEventRow>>,
+}
+
+impl InMemoryEventStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn rows(&self) -> Vec<StoredEventRow> {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<StoredEventRow>> {
+        self.rows
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl EventStoreTrait for InMemoryEventStore {
+    fn init_schema(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn append_batch(
+        &self,
+        events: Vec<StoredEvent>,
+        user_id: String,
+        expected_version: i64,
+    ) -> Result<Vec<AppendResult>> {
+        let mut rows = self.lock();
+        let actual_version = rows.last().map_or(0, |row| row.sequence);
+        if actual_version != expected_version {
+            return Err(anyhow!(ConcurrencyConflict {
+                expected_version,
+                actual_version,
+            }));
+        }
+
+        let timestamp = Utc::now();
+        let created_at = timestamp.format(TIMESTAMP_FORMAT).to_string();
+        let previous_hash = rows.last().and_then(|row| row.hash.clone());
+        let sealed = seal_batch(
+            previous_hash,
+            events,
+            &user_id,
+            actual_version + 1,
+            &created_at,
+        );
+
+        Ok(sealed
+            .into_iter()
+            .map(|row| {
+                let sequence = row.sequence;
+                rows.push(row);
+                AppendResult {
+                    sequence,
+                    timestamp,
+                }
+            })
+            .collect())
+    }
+
+    fn load(&self) -> Result<Vec<StoredEventRow>> {
+        Ok(self.rows())
+    }
+
+    fn events_since(&self, sequence: i64, limit: i64) -> Result<Vec<StoredEventRow>> {
+        Ok(self
+            .lock()
+            .iter()
+            .filter(|row| row.sequence > sequence)
+            .take(limit.max(0) as usize)
+            .cloned()
+            .collect())
+    }
+
+    fn events_since_filtered(
+        &self,
+        sequence: i64,
+        limit: i64,
+        event_types: &[&str],
+    ) -> Result<Vec<StoredEventRow>> {
+        Ok(self
+            .lock()
+            .iter()
+            .filter(|row| row.sequence > sequence)
+            .filter(|row| event_types.contains(&row.event_type.as_str()))
+            .take(limit.max(0) as usize)
+            .cloned()
+            .collect())
+    }
+
+    fn events_until_sequence(&self, sequence: i64) -> Result<Vec<StoredEventRow>> {
+        Ok(self
+            .lock()
+            .iter()
+            .filter(|row| row.sequence <= sequence)
+            .cloned()
+            .collect())
+    }
+
+    fn events_until_time(&self, timestamp: DateTime<Utc>) -> Result<Vec<StoredEventRow>> {
+        let bound = timestamp.format(TIMESTAMP_FORMAT).to_string();
+        Ok(self
+            .lock()
+            .iter()
+            .filter(|row| row.created_at <= bound)
+            .cloned()
+            .collect())
+    }
+
+    fn events_since_time(&self, timestamp: DateTime<Utc>) -> Result<Vec<StoredEventRow>> {
+        let bound = timestamp.format(TIMESTAMP_FORMAT).to_string();
+        Ok(self
+            .lock()
+            .iter()
+            .filter(|row| row.created_at >= bound)
+            .cloned()
+            .collect())
+    }
+
+    fn max_sequence(&self) -> Result<Option<i64>> {
+        Ok(self.lock().last().map(|row| row.sequence))
+    }
+
+    fn import_rows(&self, imported: Vec<StoredEventRow>) -> Result<()> {
+        let mut rows = self.lock();
+        if !rows.is_empty() {
+            return Err(anyhow!("Event store already contains events"));
+        }
+        *rows = imported;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::is_concurrency_conflict;
+
+    fn stored_event() -> StoredEvent {
+        typed_event("Happened")
+    }
+
+    fn typed_event(event_type: &str) -> StoredEvent {
+        StoredEvent {
+            aggregate_id: "aggregate".to_string(),
+            event_type: event_type.to_string(),
+            event_data: "{}".to_string(),
+            event_version: 1,
+            correlation_id: None,
+            causation_id: None,
+        }
+    }
+
+    #[test]
+    fn appends_are_sequenced_and_version_checked() {
+        let store = InMemoryEventStore::new();
+
+        let results = store
+            .append_batch(vec![stored_event(), stored_event()], "user".into(), 0)
+            .unwrap();
+        let conflict = store
+            .append_batch(vec![stored_event()], "user".into(), 1)
+            .unwrap_err();
+
+        assert_eq!(
+            results.iter().map(|r| r.sequence).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert!(is_concurrency_conflict(&conflict));
+        assert_eq!(store.events_since(1, 10).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn appended_batches_form_a_verifiable_chain() {
+        let store = InMemoryEventStore::new();
+        store
+            .append_batch(vec![stored_event(), stored_event()], "user".into(), 0)
+            .unwrap();
+        store
+            .append_batch(vec![stored_event()], "user".into(), 2)
+            .unwrap();
+
+        let verification = store.verify_chain().unwrap();
+
+        assert!(verification.is_intact());
+        assert_eq!(verification.verified_events, 3);
+        assert_eq!(verification.head_hash, store.rows()[2].hash);
+    }
+
+    #[test]
+    fn pages_only_yield_requested_types_but_cover_the_whole_stream() {
+        let store = InMemoryEventStore::new();
+        let types = [
+            "Wanted", "Ignored", "Wanted", "Wanted", "Ignored", "Ignored",
+        ];
+        store
+            .append_batch(
+                types
+                    .iter()
+                    .map(|event_type| typed_event(event_type))
+                    .collect(),
+                "user".into(),
+                0,
+            )
+            .unwrap();
+        let store: &dyn EventStoreTrait = &store;
+
+        let pages = store
+            .pages_since(0, 2)
+            .only(&["Wanted"])
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        let sequences: Vec<Vec<i64>> = pages
+            .iter()
+            .map(|page| page.rows.iter().map(|row| row.sequence).collect())
+            .collect();
+        assert_eq!(sequences, vec![vec![1, 3], vec![4]]);
+        assert_eq!(pages.last().unwrap().scanned_to, 6);
+
+        let tail = store.pages_since(4, 2).only(&["Wanted"]).next();
+        let tail = tail.unwrap().unwrap();
+        assert!(tail.rows.is_empty());
+        assert_eq!(tail.scanned_to, 6);
+        assert!(store.pages_since(6, 2).next().is_none());
+    }
+}
@@ -0,0 +1,480 @@
+use std::{cell::Cell, rc::Rc};
+
+use anyhow::{Context, Result, anyhow};
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::{
+    Aggregate, AggregateRepository, AggregateRepositoryTrait, CausationContext, Event,
+    EventEnvelope, EventEnvelopeAdapter, EventStoreTrait, PolicyContext, PolicyRouter,
+    SyncProjector,
+    testing::{FixedClock, InMemoryEventStore},
+};
+
+const HARNESS_USER_ID: &str = "test-user";
+
+pub(crate) fn apply_all<A: Aggregate>(aggregate: Option<A>, events: &[A::Event]) -> Option<A> {
+    match aggregate {
+        Some(mut aggregate) => {
+            events.iter().for_each(|event| aggregate.apply(event));
+            Some(aggregate)
+        }
+        None => A::from_events(events),
+    }
+}
+
+/// Given/when/then harness over an [`InMemoryEventStore`].
+///
+/// `given` seeds history, `when` handles a command against the aggregate and
+/// `when_processed` runs anything built over [`Self::repository`], such as a
+/// service's command processor. Registered sync projectors see every event
+/// the harness stores, given events included.
+pub struct AggregateTestHarness<A: Aggregate> {
+    event_store: Rc<InMemoryEventStore>,
+    repository: Rc<AggregateRepository<A>>,
+    projectors: Vec<Box<dyn SyncProjector<A::Event>>>,
+    projected_sequence: Cell<i64>,
+    user_id: String,
+    clock: Rc<FixedClock>,
+}
+
+impl<A> Default for AggregateTestHarness<A>
+where
+    A: Aggregate + 'static,
+    A::Event: Event + Serialize + DeserializeOwned,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> AggregateTestHarness<A>
+where
+    A: Aggregate + 'static,
+    A::Event: Event + Serialize + DeserializeOwned,
+{
+    pub fn new() -> Self {
+        let event_store = Rc::new(InMemoryEventStore::new());
+        Self {
+            repository: Rc::new(AggregateRepository::new(event_store.clone())),
+            event_store,
+            projectors: Vec::new(),
+            projected_sequence: Cell::new(0),
+            user_id: HARNESS_USER_ID.to_string(),
+            clock: Rc::new(FixedClock::default()),
+        }
+    }
+
+    pub fn with_user(mut self, user_id: impl Into<String>) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_clock(mut self, clock: FixedClock) -> Self {
+        self.clock = Rc::new(clock);
+        self
+    }
+
+    pub fn with_sync_projector(mut self, projector: Box<dyn SyncProjector<A::Event>>) -> Self {
+        self.projectors.push(projector);
+        self
+    }
+
+    /// The clock `when` handles commands with; advance it between steps and
+    /// share it with processors driven through `when_processed`.
+    pub fn clock(&self) -> Rc<FixedClock> {
+        self.clock.clone()
+    }
+
+    pub fn event_store(&self) -> Rc<dyn EventStoreTrait> {
+        self.event_store.clone()
+    }
+
+    pub fn repository(&self) -> Rc<dyn AggregateRepositoryTrait<A>> {
+        self.repository.clone()
+    }
+
+    pub fn state(&self) -> Option<A> {
+        self.repository
+            .load()
+            .expect("Failed to load aggregate from harness event store")
+    }
+
+    pub fn given(self, events: impl IntoIterator<Item = A::Event>) -> Self {
+        let events: Vec<A::Event> = events.into_iter().collect();
+        if events.is_empty() {
+            return self;
+        }
+
+        let (aggregate, version) = self
+            .repository
+            .load_with_version()
+            .expect("Failed to load aggregate from harness event store");
+        let updated = apply_all(aggregate, &events);
+        self.repository
+            .save(
+                updated.as_ref(),
+                &events,
+                self.user_id.clone(),
+                CausationContext::default(),
+                version,
+            )
+            .expect("Failed to store given events");
+
+        self.project_pending();
+        self
+    }
+
+    pub fn when(&self, command: A::Command) -> HarnessOutcome<'_, A> {
+        self.when_processed(|| {
+            let (aggregate, version) = self.repository.load_with_version()?;
+            let events = A::handle_command(aggregate.as_ref(), command, self.clock.as_ref())
+                .map_err(|e| anyhow!("Domain error: {}", e))?;
+
+            if events.is_empty() {
+                return Ok(());
+            }
+
+            let updated = apply_all(aggregate, &events);
+            self.repository.save(
+                updated.as_ref(),
+                &events,
+                self.user_id.clone(),
+                CausationContext::new_request(),
+                version,
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Runs `process` and captures whatever it appended to the event store.
+    pub fn when_processed(&self, process: impl FnOnce() -> Result<()>) -> HarnessOutcome<'_, A> {
+        let result = self
+            .event_store
+            .stream_version()
+            .and_then(|before| process().and_then(|_| self.envelopes_since(before)));
+
+        self.project_pending();
+        HarnessOutcome {
+            harness: self,
+            result,
+        }
+    }
+
+    fn envelopes_since(&self, sequence: i64) -> Result<Vec<EventEnvelope<A::Event>>> {
+        self.event_store
+            .rows()
+            .iter()
+            .filter(|row| row.sequence > sequence)
+            .map(|row| row.to_envelope())
+            .collect::<Result<Vec<_>>>()
+            .context("Failed to decode harness events")
+    }
+
+    fn project_pending(&self) {
+        let envelopes = self
+            .envelopes_since(self.projected_sequence.get())
+            .expect("Failed to load events for projection");
+        let Some(last) = envelopes.last() else {
+            return;
+        };
+
+        for projector in &self.projectors {
+            if let Err(e) = projector.project_batch(&envelopes) {
+                panic!("Sync projector {} failed: {e:#}", projector.name());
+            }
+        }
+        self.projected_sequence.set(last.metadata.sequence);
+    }
+}
+
+pub struct HarnessOutcome<'h, A: Aggregate> {
+    harness: &'h AggregateTestHarness<A>,
+    result: Result<Vec<EventEnvelope<A::Event>>>,
+}
+
+impl<'h, A> HarnessOutcome<'h, A>
+where
+    A: Aggregate + 'static,
+    A::Event: Event + Serialize + DeserializeOwned,
+{
+    pub fn envelopes(&self) -> &[EventEnvelope<A::Event>] {
+        match &self.result {
+            Ok(envelopes) => envelopes,
+            Err(e) => panic!("Expected success, got error: {e:#}"),
+        }
+    }
+
+    pub fn events(&self) -> Vec<A::Event> {
+        self.envelopes()
+            .iter()
+            .map(|envelope| envelope.event.clone())
+            .collect()
+    }
+
+    pub fn then_expect_events(self, expected: &[A::Event]) -> Self
+    where
+        A::Event: PartialEq,
+    {
+        assert_eq!(self.events(), expected);
+        self
+    }
+
+    pub fn then_expect_no_events(self) -> Self {
+        let events = self.events();
+        assert!(events.is_empty(), "Expected no events, got {events:?}");
+        self
+    }
+
+    /// Asserts that handling failed with an error whose message chain
+    /// contains `message`, and that nothing was appended.
+    pub fn then_expect_error(self, message: &str) -> Self {
+        match &self.result {
+            Ok(envelopes) => panic!("Expected error containing {message:?}, got {envelopes:?}"),
+            Err(e) => {
+                let rendered = format!("{e:#}");
+                assert!(
+                    rendered.contains(message),
+                    "Expected error containing {message:?}, got {rendered:?}"
+                );
+            }
+        }
+        self
+    }
+
+    pub fn then_expect_state(self, check: impl FnOnce(Option<&A>)) -> Self {
+        check(self.harness.state().as_ref());
+        self
+    }
+
+    /// Applies `policies` to each event produced, the same way the process
+    /// manager does: against the latest aggregate state, with the event's
+    /// sequence.
+    pub fn then_apply_policies<R>(
+        &self,
+        policies: impl Fn(&A::Event, &PolicyContext<'_, A>) -> Vec<R>,
+    ) -> Vec<R> {
+        let state = self
+            .harness
+            .state()
+            .expect("Policies require an aggregate to exist");
+
+        self.envelopes()
+            .iter()
+            .flat_map(|envelope| {
+                let ctx = PolicyContext::new(&state, envelope.metadata.sequence);
+                policies(&envelope.event, &ctx)
+            })
+            .collect()
+    }
+
+    /// [`Self::then_apply_policies`] through the event's [`PolicyRouter`].
+    pub fn then_route_policies<R>(&self) -> Vec<R>
+    where
+        A::Event: PolicyRouter<A, R>,
+    {
+        self.then_apply_policies(|event, ctx| event.route_policies(ctx))
+    }
+
+    pub fn and(self) -> &'h AggregateTestHarness<A> {
+        self.harness
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, fmt};
+
+    use chrono::{Duration, Utc};
+    use serde::Deserialize;
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    enum CounterEvent {
+        Incremented { counter_id: Uuid, by: i64 },
+    }
+
+    impl Event for CounterEvent {
+        fn event_type(&self) -> &str {
+            "Incremented"
+        }
+
+        fn event_version(&self) -> i64 {
+            1
+        }
+
+        fn aggregate_id(&self) -> Uuid {
+            match self {
+                CounterEvent::Incremented { counter_id, .. } => *counter_id,
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Counter {
+        id: Uuid,
+        count: i64,
+    }
+
+    #[derive(Debug)]
+    struct CounterError;
+
+    impl fmt::Display for CounterError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "increment must be positive")
+        }
+    }
+
+    impl std::error::Error for CounterError {}
+
+    impl Aggregate for Counter {
+        type Event = CounterEvent;
+        type Command = (Uuid, i64);
+        type Error = CounterError;
+
+        fn aggregate_id(&self) -> String {
+            self.id.to_string()
+        }
+
+        fn apply(&mut self, event: &CounterEvent) {
+            let CounterEvent::Incremented { by, .. } = event;
+            self.count += by;
+        }
+
+        fn handle_command(
+            _: Option<&Self>,
+            (counter_id, by): (Uuid, i64),
+            _: &dyn crate::Clock,
+        ) -> Result<Vec<CounterEvent>, CounterError> {
+            if by <= 0 {
+                return Err(CounterError);
+            }
+            Ok(vec![CounterEvent::Incremented { counter_id, by }])
+        }
+
+        fn from_events(events: &[CounterEvent]) -> Option<Self> {
+            let first = events.first()?;
+            let mut counter = Counter {
+                id: first.aggregate_id(),
+                count: 0,
+            };
+            events.iter().for_each(|event| counter.apply(event));
+            Some(counter)
+        }
+    }
+
+    struct RecordingProjector {
+        sequences: Rc<RefCell<Vec<i64>>>,
+    }
+
+    impl SyncProjector<CounterEvent> for RecordingProjector {
+        fn project_batch(&self, events: &[EventEnvelope<CounterEvent>]) -> Result<()> {
+            self.sequences
+                .borrow_mut()
+                .extend(events.iter().map(|envelope| envelope.metadata.sequence));
+            Ok(())
+        }
+
+        fn name(&self) -> &str {
+            "RecordingProjector"
+        }
+    }
+
+    fn incremented(counter_id: Uuid, by: i64) -> CounterEvent {
+        CounterEvent::Incremented { counter_id, by }
+    }
+
+    #[test]
+    fn when_command_produces_expected_events_and_state() {
+        let id = Uuid::now_v7();
+
+        AggregateTestHarness::<Counter>::new()
+            .given([incremented(id, 2)])
+            .when((id, 3))
+            .then_expect_events(&[incremented(id, 3)])
+            .then_expect_state(|counter| assert_eq!(counter.unwrap().count, 5));
+    }
+
+    #[test]
+    fn domain_errors_append_nothing() {
+        let id = Uuid::now_v7();
+        let harness = AggregateTestHarness::<Counter>::new().given([incremented(id, 1)]);
+
+        harness
+            .when((id, -1))
+            .then_expect_error("increment must be positive");
+
+        assert_eq!(harness.event_store().stream_version().unwrap(), 1);
+    }
+
+    #[test]
+    fn sync_projectors_see_given_and_new_events_once() {
+        let id = Uuid::now_v7();
+        let sequences = Rc::new(RefCell::new(Vec::new()));
+
+        AggregateTestHarness::<Counter>::new()
+            .with_sync_projector(Box::new(RecordingProjector {
+                sequences: sequences.clone(),
+            }))
+            .given([incremented(id, 1), incremented(id, 1)])
+            .when((id, 1))
+            .and()
+            .when((id, 1));
+
+        assert_eq!(*sequences.borrow(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn policies_run_against_latest_state() {
+        let id = Uuid::now_v7();
+        let harness = AggregateTestHarness::<Counter>::new().given([incremented(id, 9)]);
+
+        let effects = harness.when((id, 1)).then_apply_policies(|_, ctx| {
+            if ctx.state.count >= 10 {
+                vec![ctx.sequence]
+            } else {
+                vec![]
+            }
+        });
+
+        assert_eq!(effects, vec![2]);
+    }
+
+    #[test]
+    fn repository_rebuilds_state_at_a_point_in_history() {
+        let id = Uuid::now_v7();
+        let harness = AggregateTestHarness::<Counter>::new().given([
+            incremented(id, 1),
+            incremented(id, 2),
+            incremented(id, 4),
+        ]);
+        let repository = harness.repository();
+
+        let (counter, version) = repository.load_at_sequence(2).unwrap();
+        assert_eq!(counter.unwrap().count, 3);
+        assert_eq!(version, 2);
+
+        let (counter, version) = repository.load_at_sequence(0).unwrap();
+        assert!(counter.is_none());
+        assert_eq!(version, 0);
+
+        let (counter, version) = repository.load_at_time(Utc::now()).unwrap();
+        assert_eq!(counter.unwrap().count, 7);
+        assert_eq!(version, 3);
+
+        let (counter, _) = repository
+            .load_at_time(Utc::now() - Duration::days(1))
+            .unwrap();
+        assert!(counter.is_none());
+
+        let recent = repository
+            .load_events_since_time(Utc::now() - Duration::days(1))
+            .unwrap();
+        assert_eq!(recent.len(), 3);
+        let future = repository
+            .load_events_since_time(Utc::now() + Duration::days(1))
+            .unwrap();
+        assert!(future.is_empty());
+    }
+}
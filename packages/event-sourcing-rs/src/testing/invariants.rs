@@ -0,0 +1,226 @@
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use proptest::test_runner::TestCaseError;
+
+use crate::{
+    Aggregate,
+    testing::{FixedClock, harness::apply_all},
+};
+
+type Invariant<A> = Box<dyn Fn(Option<&A>, &A) -> Result<(), String>>;
+
+/// Runs generated command sequences against an aggregate's
+/// `handle_command`, for use inside `proptest!`.
+///
+/// After every accepted command it checks that handling and applying the
+/// events did not panic, that replaying the whole stream with `from_events`
+/// gives the same state as applying it incrementally, and that every
+/// registered invariant holds. Rejected commands are skipped. Commands are
+/// handled against a [`FixedClock`] that only moves if the caller moves it.
+pub struct InvariantHarness<A: Aggregate> {
+    invariants: Vec<(&'static str, Invariant<A>)>,
+    clock: FixedClock,
+}
+
+impl<A: Aggregate> Default for InvariantHarness<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Aggregate> InvariantHarness<A> {
+    pub fn new() -> Self {
+        Self {
+            invariants: Vec::new(),
+            clock: FixedClock::default(),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: FixedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Adds a check given the state before and after each accepted command.
+    pub fn with_invariant(
+        mut self,
+        name: &'static str,
+        check: impl Fn(Option<&A>, &A) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.invariants.push((name, Box::new(check)));
+        self
+    }
+
+    /// Handles `commands` in order and returns the final state.
+    pub fn check(
+        &self,
+        commands: impl IntoIterator<Item = A::Command>,
+    ) -> Result<Option<A>, TestCaseError> {
+        let mut state: Option<A> = None;
+        let mut history: Vec<A::Event> = Vec::new();
+
+        for (index, command) in commands.into_iter().enumerate() {
+            let handled = catch_unwind(AssertUnwindSafe(|| {
+                A::handle_command(state.as_ref(), command, &self.clock)
+            }))
+            .map_err(|_| {
+                TestCaseError::fail(format!("handle_command panicked at command {index}"))
+            })?;
+            let Ok(events) = handled else {
+                continue;
+            };
+            if events.is_empty() {
+                continue;
+            }
+
+            let before = state.clone();
+            state = catch_unwind(AssertUnwindSafe(|| apply_all(state.take(), &events)))
+                .map_err(|_| TestCaseError::fail(format!("apply panicked at command {index}")))?;
+            history.extend(events);
+
+            let replayed =
+                catch_unwind(AssertUnwindSafe(|| A::from_events(&history))).map_err(|_| {
+                    TestCaseError::fail(format!("from_events panicked at command {index}"))
+                })?;
+            if to_json(&replayed)? != to_json(&state)? {
+                return Err(TestCaseError::fail(format!(
+                    "replayed state differs from incremental state at command {index}"
+                )));
+            }
+
+            if let Some(after) = &state {
+                for (name, invariant) in &self.invariants {
+                    invariant(before.as_ref(), after).map_err(|reason| {
+                        TestCaseError::fail(format!(
+                            "invariant '{name}' violated at command {index}: {reason}"
+                        ))
+                    })?;
+                }
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+fn to_json<A: Aggregate>(state: &Option<A>) -> Result<serde_json::Value, TestCaseError> {
+    serde_json::to_value(state)
+        .map_err(|err| TestCaseError::fail(format!("Failed to serialize aggregate: {err}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Counter {
+        value: i64,
+    }
+
+    #[derive(Debug, Clone)]
+    enum CounterEvent {
+        Created,
+        Changed(i64),
+    }
+
+    #[derive(Debug, Clone)]
+    enum CounterCommand {
+        Create,
+        Add(i64),
+    }
+
+    #[derive(Debug)]
+    struct Rejected;
+
+    impl std::error::Error for Rejected {}
+
+    impl std::fmt::Display for Rejected {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "rejected")
+        }
+    }
+
+    impl Aggregate for Counter {
+        type Event = CounterEvent;
+        type Command = CounterCommand;
+        type Error = Rejected;
+
+        fn aggregate_id(&self) -> String {
+            "counter".to_string()
+        }
+
+        fn apply(&mut self, event: &CounterEvent) {
+            if let CounterEvent::Changed(amount) = event {
+                self.value += amount;
+            }
+        }
+
+        fn handle_command(
+            state: Option<&Self>,
+            command: CounterCommand,
+            _: &dyn crate::Clock,
+        ) -> Result<Vec<CounterEvent>, Rejected> {
+            match (state, command) {
+                (None, CounterCommand::Create) => Ok(vec![CounterEvent::Created]),
+                (Some(counter), CounterCommand::Add(amount)) if counter.value + amount >= 0 => {
+                    Ok(vec![CounterEvent::Changed(amount)])
+                }
+                _ => Err(Rejected),
+            }
+        }
+
+        fn from_events(events: &[CounterEvent]) -> Option<Self> {
+            let mut counter = match events.first() {
+                Some(CounterEvent::Created) => Counter { value: 0 },
+                _ => return None,
+            };
+            events[1..].iter().for_each(|event| counter.apply(event));
+            Some(counter)
+        }
+    }
+
+    fn commands() -> impl Strategy<Value = Vec<CounterCommand>> {
+        prop::collection::vec(
+            prop_oneof![
+                Just(CounterCommand::Create),
+                (-5i64..5).prop_map(CounterCommand::Add),
+            ],
+            0..30,
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn accepted_commands_keep_invariants(commands in commands()) {
+            InvariantHarness::<Counter>::new()
+                .with_invariant("never negative", |_, after| {
+                    (after.value >= 0)
+                        .then_some(())
+                        .ok_or_else(|| format!("value is {}", after.value))
+                })
+                .check(commands)?;
+        }
+    }
+
+    #[test]
+    fn reports_violated_invariants() {
+        let result = InvariantHarness::<Counter>::new()
+            .with_invariant("never above three", |_, after| {
+                (after.value <= 3)
+                    .then_some(())
+                    .ok_or_else(|| format!("value is {}", after.value))
+            })
+            .check([
+                CounterCommand::Create,
+                CounterCommand::Add(2),
+                CounterCommand::Add(-9),
+                CounterCommand::Add(4),
+            ]);
+
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("'never above three' violated at command 3"));
+    }
+}
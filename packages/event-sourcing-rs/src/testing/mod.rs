@@ -0,0 +1,24 @@
+//! In-memory infrastructure and a given/when/then harness for exercising
+//! command processors, projectors and policies under `cargo test`, without a
+//! Durable Object or D1 behind them, plus a property-based harness for
+//! checking aggregate invariants. Enabled by the `testing` feature.
+
+pub mod cache;
+pub mod checkpoint_repository;
+pub mod clock;
+pub mod dead_letter_repository;
+pub mod effect_ledger;
+pub mod event_store;
+pub mod harness;
+pub mod invariants;
+pub mod processed_command_store;
+
+pub use cache::*;
+pub use checkpoint_repository::*;
+pub use clock::*;
+pub use dead_letter_repository::*;
+pub use effect_ledger::*;
+pub use event_store::*;
+pub use harness::*;
+pub use invariants::*;
+pub use processed_command_store::*;
@@ -0,0 +1,51 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use crate::{ProcessedCommand, ProcessedCommandStoreTrait};
+
+#[derive(Default)]
+pub struct InMemoryProcessedCommandStore {
+    commands: RefCell<HashMap<String, ProcessedCommand>>,
+}
+
+impl InMemoryProcessedCommandStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ProcessedCommandStoreTrait for InMemoryProcessedCommandStore {
+    fn init_schema(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn get(&self, idempotency_key: &str) -> Result<Option<ProcessedCommand>> {
+        Ok(self.commands.borrow().get(idempotency_key).cloned())
+    }
+
+    fn record(
+        &self,
+        idempotency_key: &str,
+        outcome: String,
+        processed_at: DateTime<Utc>,
+    ) -> Result<()> {
+        self.commands.borrow_mut().insert(
+            idempotency_key.to_string(),
+            ProcessedCommand {
+                idempotency_key: idempotency_key.to_string(),
+                outcome,
+                processed_at,
+            },
+        );
+        Ok(())
+    }
+
+    fn purge_before(&self, cutoff: DateTime<Utc>) -> Result<()> {
+        self.commands
+            .borrow_mut()
+            .retain(|_, command| command.processed_at >= cutoff);
+        Ok(())
+    }
+}
@@ -1,17 +1,26 @@
 use serde::{Serialize, de::DeserializeOwned};
 
+use crate::Clock;
+
 pub trait Aggregate: Sized + Clone + Serialize + DeserializeOwned {
     type Event: Clone;
     type Command;
     type Error: std::error::Error;
 
+    /// Bump whenever the serialized shape of the aggregate changes so that
+    /// snapshots written by older code are discarded instead of restored.
+    const SNAPSHOT_SCHEMA_VERSION: i64 = 1;
+
     fn aggregate_id(&self) -> String;
 
     fn apply(&mut self, event: &Self::Event);
 
+    /// Decides which events `command` produces. Anything time-dependent reads
+    /// `clock` rather than the system time, so it can be pinned in tests.
     fn handle_command(
         state: Option<&Self>,
         command: Self::Command,
+        clock: &dyn Clock,
     ) -> Result<Vec<Self::Event>, Self::Error>;
 
     fn from_events(events: &[Self::Event]) -> Option<Self>;
@@ -1,34 +1,165 @@
 use std::{marker::PhantomData, rc::Rc};
 
 use anyhow::{Context, Result};
-use serde::{Serialize, de::DeserializeOwned};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use tracing::debug;
 
 use crate::{
-    Aggregate, AppendResult, CacheExt, CacheTrait, Event, EventEnvelope, EventEnvelopeAdapter,
-    EventStoreTrait, StoredEvent,
+    Aggregate, AppendResult, CacheExt, CacheTrait, CausationContext, Event, EventEnvelope,
+    EventEnvelopeAdapter, EventStoreTrait, Snapshotter, StoredEvent, StoredEventRow,
 };
 
+const SNAPSHOT_REPLAY_BATCH_SIZE: i64 = 1000;
+
 pub trait AggregateRepositoryTrait<A: Aggregate> {
     fn load(&self) -> Result<Option<A>>;
+    fn load_with_version(&self) -> Result<(Option<A>, i64)>;
     fn load_events(&self) -> Result<Vec<EventEnvelope<A::Event>>>;
+
+    /// The events recorded at or after `timestamp`, for callers that only
+    /// look at the recent end of the stream.
+    fn load_events_since_time(
+        &self,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Vec<EventEnvelope<A::Event>>>;
+
+    /// Rebuilds the aggregate from the events up to and including `sequence`,
+    /// returning it with the sequence of the last event applied.
+    fn load_at_sequence(&self, sequence: i64) -> Result<(Option<A>, i64)>;
+
+    /// Rebuilds the aggregate from the events recorded at or before
+    /// `timestamp`, returning it with the sequence of the last event applied.
+    fn load_at_time(&self, timestamp: DateTime<Utc>) -> Result<(Option<A>, i64)>;
+
     fn save(
         &self,
         aggregate: Option<&A>,
         events: &[A::Event],
         user_id: String,
+        causation: CausationContext,
+        expected_version: i64,
     ) -> Result<Vec<AppendResult>>;
     fn save_with_envelopes(
         &self,
         aggregate: Option<&A>,
         events: &[A::Event],
         user_id: String,
+        causation: CausationContext,
+        expected_version: i64,
     ) -> Result<Vec<EventEnvelope<A::Event>>>;
 }
 
+fn rebuild_from_rows<A: Aggregate>(stored_events: Vec<StoredEventRow>) -> Result<(Option<A>, i64)>
+where
+    A::Event: DeserializeOwned,
+{
+    let Some(version) = stored_events.last().map(|row| row.sequence) else {
+        return Ok((None, 0));
+    };
+
+    let events: Vec<A::Event> = stored_events
+        .into_iter()
+        .map(|stored| {
+            serde_json::from_str(&stored.event_data).context("Failed to deserialize event")
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((A::from_events(&events), version))
+}
+
+fn load_aggregate<A: Aggregate>(
+    event_store: &Rc<dyn EventStoreTrait>,
+    snapshotter: Option<&Snapshotter>,
+) -> Result<(Option<A>, i64)>
+where
+    A::Event: DeserializeOwned,
+{
+    let Some(snapshotter) = snapshotter else {
+        let stored_events = event_store
+            .load()
+            .context("Failed to load events from store")?;
+        return rebuild_from_rows(stored_events);
+    };
+
+    if let Some((mut aggregate, snapshot_sequence)) = snapshotter.restore::<A>()? {
+        let mut version = snapshot_sequence;
+        loop {
+            let stored_events = event_store
+                .events_since(version, SNAPSHOT_REPLAY_BATCH_SIZE)
+                .context("Failed to load events since snapshot")?;
+            let batch_len = stored_events.len() as i64;
+
+            for stored in stored_events {
+                let event: A::Event = serde_json::from_str(&stored.event_data)
+                    .context("Failed to deserialize event")?;
+                aggregate.apply(&event);
+                version = stored.sequence;
+            }
+
+            if batch_len < SNAPSHOT_REPLAY_BATCH_SIZE {
+                break;
+            }
+        }
+
+        debug!(
+            snapshot_sequence,
+            version, "Restored aggregate from snapshot"
+        );
+        snapshotter.record(&aggregate, snapshot_sequence, version);
+        return Ok((Some(aggregate), version));
+    }
+
+    let stored_events = event_store
+        .load()
+        .context("Failed to load events from store")?;
+    let (aggregate, version) = rebuild_from_rows::<A>(stored_events)?;
+    if let Some(aggregate) = &aggregate {
+        snapshotter.record(aggregate, 0, version);
+    }
+    Ok((aggregate, version))
+}
+
+fn snapshot_after_save<A: Aggregate>(
+    snapshotter: Option<&Snapshotter>,
+    aggregate: Option<&A>,
+    expected_version: i64,
+    results: &[AppendResult],
+) {
+    if let (Some(snapshotter), Some(aggregate), Some(last)) =
+        (snapshotter, aggregate, results.last())
+    {
+        snapshotter.record(aggregate, expected_version, last.sequence);
+    }
+}
+
+fn append_events<E: Event>(
+    event_store: &Rc<dyn EventStoreTrait>,
+    events: &[E],
+    user_id: &str,
+    causation: CausationContext,
+    expected_version: i64,
+) -> Result<Vec<AppendResult>> {
+    if events.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let stored: Vec<StoredEvent> = events
+        .iter()
+        .map(|event| StoredEvent {
+            correlation_id: causation.correlation_id,
+            causation_id: causation.causation_id,
+            ..event.clone().into_stored_event()
+        })
+        .collect();
+
+    event_store.append_batch(stored, user_id.to_string(), expected_version)
+}
+
 #[derive(Clone)]
 pub struct AggregateRepository<A: Aggregate> {
     event_store: Rc<dyn EventStoreTrait>,
+    snapshotter: Option<Snapshotter>,
     _phantom: PhantomData<A>,
 }
 
@@ -36,9 +167,15 @@ impl<A: Aggregate> AggregateRepository<A> {
     pub fn new(event_store: Rc<dyn EventStoreTrait>) -> Self {
         Self {
             event_store,
+            snapshotter: None,
             _phantom: PhantomData,
         }
     }
+
+    pub fn with_snapshots(mut self, snapshotter: Snapshotter) -> Self {
+        self.snapshotter = Some(snapshotter);
+        self
+    }
 }
 
 impl<A: Aggregate> AggregateRepositoryTrait<A> for AggregateRepository<A>
@@ -46,30 +183,33 @@ where
     A::Event: DeserializeOwned + Serialize + Event,
 {
     fn load(&self) -> Result<Option<A>> {
+        Ok(self.load_with_version()?.0)
+    }
+
+    fn load_with_version(&self) -> Result<(Option<A>, i64)> {
+        load_aggregate(&self.event_store, self.snapshotter.as_ref())
+    }
+
+    fn load_events(&self) -> Result<Vec<EventEnvelope<A::Event>>> {
         let stored_events = self
             .event_store
             .load()
             .context("Failed to load events from store")?;
 
-        if stored_events.is_empty() {
-            return Ok(None);
-        }
-
-        let events: Vec<A::Event> = stored_events
+        stored_events
             .into_iter()
-            .map(|stored| {
-                serde_json::from_str(&stored.event_data).context("Failed to deserialize event")
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        Ok(A::from_events(&events))
+            .map(|stored| stored.to_envelope())
+            .collect()
     }
 
-    fn load_events(&self) -> Result<Vec<EventEnvelope<A::Event>>> {
+    fn load_events_since_time(
+        &self,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Vec<EventEnvelope<A::Event>>> {
         let stored_events = self
             .event_store
-            .load()
-            .context("Failed to load events from store")?;
+            .events_since_time(timestamp)
+            .context("Failed to load events since timestamp")?;
 
         stored_events
             .into_iter()
@@ -77,50 +217,91 @@ where
             .collect()
     }
 
+    fn load_at_sequence(&self, sequence: i64) -> Result<(Option<A>, i64)> {
+        let stored_events = self
+            .event_store
+            .events_until_sequence(sequence)
+            .context("Failed to load events up to sequence")?;
+        rebuild_from_rows(stored_events)
+    }
+
+    fn load_at_time(&self, timestamp: DateTime<Utc>) -> Result<(Option<A>, i64)> {
+        let stored_events = self
+            .event_store
+            .events_until_time(timestamp)
+            .context("Failed to load events up to timestamp")?;
+        rebuild_from_rows(stored_events)
+    }
+
     fn save(
         &self,
-        _aggregate: Option<&A>,
+        aggregate: Option<&A>,
         events: &[A::Event],
         user_id: String,
+        causation: CausationContext,
+        expected_version: i64,
     ) -> Result<Vec<AppendResult>> {
-        let mut results = Vec::new();
-        for event in events {
-            let stored: StoredEvent = event.clone().into_stored_event();
-            let result =
-                self.event_store
-                    .append(stored.aggregate_id.clone(), stored, user_id.clone())?;
-            results.push(result);
-        }
+        let results = append_events(
+            &self.event_store,
+            events,
+            &user_id,
+            causation,
+            expected_version,
+        )?;
+        snapshot_after_save(
+            self.snapshotter.as_ref(),
+            aggregate,
+            expected_version,
+            &results,
+        );
+
         Ok(results)
     }
 
     fn save_with_envelopes(
         &self,
-        _aggregate: Option<&A>,
+        aggregate: Option<&A>,
         events: &[A::Event],
         user_id: String,
+        causation: CausationContext,
+        expected_version: i64,
     ) -> Result<Vec<EventEnvelope<A::Event>>> {
-        let mut envelopes = Vec::new();
-        for event in events {
-            let stored: StoredEvent = event.clone().into_stored_event();
-            let result =
-                self.event_store
-                    .append(stored.aggregate_id.clone(), stored, user_id.clone())?;
-            envelopes.push(EventEnvelope::enrich(
-                event.clone(),
-                &result,
-                user_id.clone(),
-            ));
-        }
-        Ok(envelopes)
+        let results = append_events(
+            &self.event_store,
+            events,
+            &user_id,
+            causation,
+            expected_version,
+        )?;
+        snapshot_after_save(
+            self.snapshotter.as_ref(),
+            aggregate,
+            expected_version,
+            &results,
+        );
+
+        Ok(events
+            .iter()
+            .zip(results.iter())
+            .map(|(event, result)| {
+                EventEnvelope::enrich(event.clone(), result, user_id.clone(), causation)
+            })
+            .collect())
     }
 }
 
+#[derive(Serialize, Deserialize)]
+struct CachedAggregate<T> {
+    version: i64,
+    aggregate: T,
+}
+
 #[derive(Clone)]
 pub struct CachedAggregateRepository<A: Aggregate> {
     event_store: Rc<dyn EventStoreTrait>,
     cache: Rc<dyn CacheTrait>,
     cache_key: String,
+    snapshotter: Option<Snapshotter>,
     _phantom: PhantomData<A>,
 }
 
@@ -137,28 +318,33 @@ where
             event_store,
             cache,
             cache_key,
+            snapshotter: None,
             _phantom: PhantomData,
         }
     }
 
-    fn load_from_event_store(&self) -> Result<Option<A>> {
-        let stored_events = self
-            .event_store
-            .load()
-            .context("Failed to load events from store")?;
+    pub fn with_snapshots(mut self, snapshotter: Snapshotter) -> Self {
+        self.snapshotter = Some(snapshotter);
+        self
+    }
 
-        if stored_events.is_empty() {
-            return Ok(None);
-        }
+    fn load_from_event_store(&self) -> Result<(Option<A>, i64)> {
+        load_aggregate(&self.event_store, self.snapshotter.as_ref())
+    }
 
-        let events: Vec<A::Event> = stored_events
-            .into_iter()
-            .map(|stored| {
-                serde_json::from_str(&stored.event_data).context("Failed to deserialize event")
-            })
-            .collect::<Result<Vec<_>>>()?;
+    fn cache_aggregate(&self, aggregate: Option<&A>, results: &[AppendResult]) {
+        let (Some(agg), Some(last)) = (aggregate, results.last()) else {
+            return;
+        };
 
-        Ok(A::from_events(&events))
+        debug!(cache_key = %self.cache_key, "Caching updated aggregate after save");
+        let cached = CachedAggregate {
+            version: last.sequence,
+            aggregate: agg,
+        };
+        if let Err(e) = self.cache.set(self.cache_key.clone(), &cached) {
+            debug!(cache_key = %self.cache_key, error = %e, "Failed to cache aggregate - will rebuild from events on next load");
+        }
     }
 }
 
@@ -167,9 +353,13 @@ where
     A::Event: DeserializeOwned + Serialize + Event,
 {
     fn load(&self) -> Result<Option<A>> {
-        if let Ok(Some(aggregate)) = self.cache.get::<A>(self.cache_key.clone()) {
+        Ok(self.load_with_version()?.0)
+    }
+
+    fn load_with_version(&self) -> Result<(Option<A>, i64)> {
+        if let Ok(Some(cached)) = self.cache.get::<CachedAggregate<A>>(self.cache_key.clone()) {
             debug!(cache_key = %self.cache_key, "Aggregate cache HIT");
-            return Ok(Some(aggregate));
+            return Ok((Some(cached.aggregate), cached.version));
         };
 
         debug!(cache_key = %self.cache_key, "Aggregate cache MISS - loading from event store");
@@ -188,30 +378,62 @@ where
             .collect()
     }
 
+    fn load_events_since_time(
+        &self,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Vec<EventEnvelope<A::Event>>> {
+        let stored_events = self
+            .event_store
+            .events_since_time(timestamp)
+            .context("Failed to load events since timestamp")?;
+
+        stored_events
+            .into_iter()
+            .map(|stored| stored.to_envelope())
+            .collect()
+    }
+
+    fn load_at_sequence(&self, sequence: i64) -> Result<(Option<A>, i64)> {
+        let stored_events = self
+            .event_store
+            .events_until_sequence(sequence)
+            .context("Failed to load events up to sequence")?;
+        rebuild_from_rows(stored_events)
+    }
+
+    fn load_at_time(&self, timestamp: DateTime<Utc>) -> Result<(Option<A>, i64)> {
+        let stored_events = self
+            .event_store
+            .events_until_time(timestamp)
+            .context("Failed to load events up to timestamp")?;
+        rebuild_from_rows(stored_events)
+    }
+
     fn save(
         &self,
         aggregate: Option<&A>,
         events: &[A::Event],
         user_id: String,
+        causation: CausationContext,
+        expected_version: i64,
     ) -> Result<Vec<AppendResult>> {
         debug!(cache_key = %self.cache_key, "Clearing aggregate cache before save");
         let _ = self.cache.clear(self.cache_key.clone());
 
-        let mut results = Vec::new();
-        for event in events {
-            let stored: StoredEvent = event.clone().into_stored_event();
-            let result =
-                self.event_store
-                    .append(stored.aggregate_id.clone(), stored, user_id.clone())?;
-            results.push(result);
-        }
-
-        if let Some(agg) = aggregate {
-            debug!(cache_key = %self.cache_key, "Caching updated aggregate after save");
-            if let Err(e) = self.cache.set(self.cache_key.clone(), agg) {
-                debug!(cache_key = %self.cache_key, error = %e, "Failed to cache aggregate - will rebuild from events on next load");
-            }
-        }
+        let results = append_events(
+            &self.event_store,
+            events,
+            &user_id,
+            causation,
+            expected_version,
+        )?;
+        self.cache_aggregate(aggregate, &results);
+        snapshot_after_save(
+            self.snapshotter.as_ref(),
+            aggregate,
+            expected_version,
+            &results,
+        );
 
         Ok(results)
     }
@@ -221,30 +443,33 @@ where
         aggregate: Option<&A>,
         events: &[A::Event],
         user_id: String,
+        causation: CausationContext,
+        expected_version: i64,
     ) -> Result<Vec<EventEnvelope<A::Event>>> {
         debug!(cache_key = %self.cache_key, "Clearing aggregate cache before save");
         let _ = self.cache.clear(self.cache_key.clone());
 
-        let mut envelopes = Vec::new();
-        for event in events {
-            let stored: StoredEvent = event.clone().into_stored_event();
-            let result =
-                self.event_store
-                    .append(stored.aggregate_id.clone(), stored, user_id.clone())?;
-            envelopes.push(EventEnvelope::enrich(
-                event.clone(),
-                &result,
-                user_id.clone(),
-            ));
-        }
+        let results = append_events(
+            &self.event_store,
+            events,
+            &user_id,
+            causation,
+            expected_version,
+        )?;
+        self.cache_aggregate(aggregate, &results);
+        snapshot_after_save(
+            self.snapshotter.as_ref(),
+            aggregate,
+            expected_version,
+            &results,
+        );
 
-        if let Some(agg) = aggregate {
-            debug!(cache_key = %self.cache_key, "Caching updated aggregate after save");
-            if let Err(e) = self.cache.set(self.cache_key.clone(), agg) {
-                debug!(cache_key = %self.cache_key, error = %e, "Failed to cache aggregate - will rebuild from events on next load");
-            }
-        }
-
-        Ok(envelopes)
+        Ok(events
+            .iter()
+            .zip(results.iter())
+            .map(|(event, result)| {
+                EventEnvelope::enrich(event.clone(), result, user_id.clone(), causation)
+            })
+            .collect())
     }
 }
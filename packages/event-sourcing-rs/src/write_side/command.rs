@@ -2,6 +2,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::{Clock, EventMetadata};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommandMetadata {
     pub idempotency_key: Uuid,
@@ -19,7 +21,7 @@ impl CommandMetadata {
         correlation_id: Uuid,
         causation_id: Uuid,
         user_id: String,
-        timestamp: DateTime<Utc>,
+        clock: &dyn Clock,
     ) -> Self {
         Self {
             idempotency_key: Uuid::now_v7(),
@@ -28,7 +30,7 @@ impl CommandMetadata {
             correlation_id,
             causation_id,
             user_id,
-            timestamp,
+            timestamp: clock.now(),
         }
     }
 }
@@ -44,13 +46,35 @@ impl<C> CommandEnvelope<C> {
         Self { metadata, command }
     }
 
+    /// Builds an envelope that continues the trace described by `causation`,
+    /// starting a new correlation when there is nothing to continue.
+    pub fn caused_by(
+        command: C,
+        aggregate_id: Uuid,
+        causation: CausationContext,
+        user_id: String,
+        clock: &dyn Clock,
+    ) -> Self {
+        let correlation_id = causation.correlation_id.unwrap_or_else(Uuid::now_v7);
+        let causation_id = causation.causation_id.unwrap_or(correlation_id);
+
+        Self::enrich(
+            command,
+            aggregate_id,
+            correlation_id,
+            causation_id,
+            user_id,
+            clock,
+        )
+    }
+
     pub fn enrich(
         command: C,
         aggregate_id: Uuid,
         correlation_id: Uuid,
         causation_id: Uuid,
         user_id: String,
-        timestamp: DateTime<Utc>,
+        clock: &dyn Clock,
     ) -> Self {
         Self {
             metadata: CommandMetadata::new(
@@ -58,9 +82,106 @@ impl<C> CommandEnvelope<C> {
                 correlation_id,
                 causation_id,
                 user_id,
-                timestamp,
+                clock,
             ),
             command,
         }
     }
 }
+
+/// Traces an event or command back to the request it belongs to
+/// (`correlation_id`) and the command or event that directly caused it
+/// (`causation_id`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct CausationContext {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub causation_id: Option<Uuid>,
+}
+
+impl CausationContext {
+    /// Starts a new trace for a request that did not arrive in an envelope.
+    pub fn new_request() -> Self {
+        Self {
+            correlation_id: Some(Uuid::now_v7()),
+            causation_id: None,
+        }
+    }
+
+    pub fn from_command(metadata: &CommandMetadata) -> Self {
+        Self {
+            correlation_id: Some(metadata.correlation_id),
+            causation_id: Some(metadata.command_id),
+        }
+    }
+
+    pub fn from_event(metadata: &EventMetadata) -> Self {
+        let event_id = metadata.event_id();
+        Self {
+            correlation_id: Some(metadata.correlation_id.unwrap_or(event_id)),
+            causation_id: Some(event_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::FixedClock;
+
+    fn event_metadata(correlation_id: Option<Uuid>) -> EventMetadata {
+        EventMetadata {
+            aggregate_id: Uuid::now_v7(),
+            sequence: 7,
+            event_version: 1,
+            timestamp: FixedClock::default().now(),
+            user_id: "user".to_string(),
+            correlation_id,
+            causation_id: None,
+        }
+    }
+
+    #[test]
+    fn command_caused_by_event_keeps_correlation() {
+        let correlation_id = Uuid::now_v7();
+        let metadata = event_metadata(Some(correlation_id));
+
+        let envelope = CommandEnvelope::caused_by(
+            (),
+            metadata.aggregate_id,
+            CausationContext::from_event(&metadata),
+            "process-manager".to_string(),
+            &FixedClock::default(),
+        );
+
+        assert_eq!(envelope.metadata.correlation_id, correlation_id);
+        assert_eq!(envelope.metadata.causation_id, metadata.event_id());
+    }
+
+    #[test]
+    fn legacy_event_starts_correlation_at_itself() {
+        let metadata = event_metadata(None);
+
+        let causation = CausationContext::from_event(&metadata);
+
+        assert_eq!(causation.correlation_id, Some(metadata.event_id()));
+        assert_eq!(causation.causation_id, Some(metadata.event_id()));
+    }
+
+    #[test]
+    fn empty_context_starts_new_trace() {
+        let envelope = CommandEnvelope::caused_by(
+            (),
+            Uuid::now_v7(),
+            CausationContext::default(),
+            "user".to_string(),
+            &FixedClock::default(),
+        );
+
+        assert_eq!(
+            envelope.metadata.causation_id,
+            envelope.metadata.correlation_id
+        );
+    }
+}
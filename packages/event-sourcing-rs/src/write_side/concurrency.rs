@@ -0,0 +1,96 @@
+use anyhow::Result;
+use tracing::warn;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConcurrencyConflict {
+    pub expected_version: i64,
+    pub actual_version: i64,
+}
+
+impl std::fmt::Display for ConcurrencyConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Concurrency conflict: expected stream version {}, found {}",
+            self.expected_version, self.actual_version
+        )
+    }
+}
+
+impl std::error::Error for ConcurrencyConflict {}
+
+pub fn is_concurrency_conflict(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<ConcurrencyConflict>().is_some()
+}
+
+/// Runs `operation` until it succeeds, fails with an error other than a
+/// [`ConcurrencyConflict`], or `max_attempts` is reached. The operation is
+/// expected to reload the aggregate on every attempt.
+pub fn retry_on_conflict<T>(
+    max_attempts: usize,
+    mut operation: impl FnMut() -> Result<T>,
+) -> Result<T> {
+    let mut attempt = 1;
+    loop {
+        match operation() {
+            Err(err) if attempt < max_attempts && is_concurrency_conflict(&err) => {
+                warn!(attempt, max_attempts, error = %err, "Retrying command after conflict");
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    fn conflict() -> anyhow::Error {
+        ConcurrencyConflict {
+            expected_version: 1,
+            actual_version: 2,
+        }
+        .into()
+    }
+
+    #[test]
+    fn retries_until_success() {
+        let mut calls = 0;
+        let result = retry_on_conflict(3, || {
+            calls += 1;
+            if calls < 3 {
+                Err(conflict())
+            } else {
+                Ok(calls)
+            }
+        });
+
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let mut calls = 0;
+        let result: Result<()> = retry_on_conflict(3, || {
+            calls += 1;
+            Err(conflict())
+        });
+
+        assert!(is_concurrency_conflict(&result.unwrap_err()));
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn does_not_retry_other_errors() {
+        let mut calls = 0;
+        let result: Result<()> = retry_on_conflict(3, || {
+            calls += 1;
+            Err(anyhow!("Domain error"))
+        });
+
+        assert!(!is_concurrency_conflict(&result.unwrap_err()));
+        assert_eq!(calls, 1);
+    }
+}
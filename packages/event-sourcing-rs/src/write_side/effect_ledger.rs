@@ -0,0 +1,95 @@
+use anyhow::Result;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::CausationContext;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EffectStatus {
+    Pending,
+    Dispatched,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl std::fmt::Display for EffectStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EffectStatus::Pending => write!(f, "PENDING"),
+            EffectStatus::Dispatched => write!(f, "DISPATCHED"),
+            EffectStatus::Completed => write!(f, "COMPLETED"),
+            EffectStatus::Failed => write!(f, "FAILED"),
+            EffectStatus::Cancelled => write!(f, "CANCELLED"),
+        }
+    }
+}
+
+/// An effect ready to be written to the ledger.
+#[derive(Debug, Clone)]
+pub struct SerializedEffect {
+    pub effect_type: String,
+    pub effect_payload: String,
+    pub idempotency_key: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EffectRecord {
+    pub effect_id: String,
+    pub event_sequence: i64,
+    pub effect_type: String,
+    pub effect_payload: String,
+    pub idempotency_key: String,
+    pub status: String,
+    pub attempts: i64,
+    pub last_attempt_at: Option<String>,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    #[serde(default)]
+    pub correlation_id: Option<Uuid>,
+    #[serde(default)]
+    pub causation_id: Option<Uuid>,
+}
+
+impl EffectRecord {
+    pub fn causation(&self) -> CausationContext {
+        CausationContext {
+            correlation_id: self.correlation_id,
+            causation_id: self.causation_id,
+        }
+    }
+}
+
+/// Durable record of the effects a process manager has decided on, and of
+/// how far through the event stream it has got.
+///
+/// `persist_effects` must ignore effects whose idempotency key is already
+/// recorded, so that replaying an event never duplicates its effects.
+pub trait EffectLedgerTrait {
+    fn init_schema(&self) -> Result<()>;
+    fn get_last_processed_sequence(&self) -> Result<i64>;
+    fn persist_effects(
+        &self,
+        effects: &[SerializedEffect],
+        sequence: i64,
+        causation: CausationContext,
+    ) -> Result<()>;
+    /// Effects still `PENDING` or `DISPATCHED` with fewer than
+    /// `max_attempts` attempts, oldest first.
+    fn get_pending_effects(&self, max_attempts: i64) -> Result<Vec<EffectRecord>>;
+    fn mark_dispatched(&self, effect_id: &str) -> Result<()>;
+    fn mark_completed(&self, effect_id: &str) -> Result<()>;
+    /// Records a failed attempt; `exhausted` moves the effect to `FAILED`,
+    /// otherwise it stays `DISPATCHED` to be retried.
+    fn mark_failed(&self, effect_id: &str, error: &str, exhausted: bool) -> Result<()>;
+    fn has_pending_effects(&self, max_attempts: i64) -> Result<bool>;
+    /// Moves the effects of the event at `event_sequence` that have not been
+    /// dispatched yet to `CANCELLED`.
+    fn cancel_pending_effects(&self, event_sequence: i64) -> Result<()>;
+
+    /// Marks every event up to `sequence` as processed without routing any
+    /// policies, for streams whose effects were already carried out elsewhere.
+    fn acknowledge_through(&self, sequence: i64) -> Result<()> {
+        self.persist_effects(&[], sequence, CausationContext::default())
+    }
+}
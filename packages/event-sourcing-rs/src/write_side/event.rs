@@ -1,10 +1,13 @@
-use anyhow::Result;
+use anyhow::{Context, Result, bail};
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use std::fmt::Debug;
 use uuid::Uuid;
 
-use crate::event_store::{AppendResult, StoredEvent};
+use crate::{
+    CausationContext,
+    event_store::{AppendResult, StoredEvent},
+};
 
 pub trait Event: Debug + Clone + Serialize + for<'de> Deserialize<'de> + Send + Sync {
     fn event_type(&self) -> &str;
@@ -22,10 +25,64 @@ pub trait Event: Debug + Clone + Serialize + for<'de> Deserialize<'de> + Send +
             event_type: self.event_type().to_string(),
             event_data: event_str,
             event_version: self.event_version(),
+            correlation_id: None,
+            causation_id: None,
         }
     }
 }
 
+/// Static type and version of an event payload, implemented by
+/// `#[derive(DomainEvent)]` on payload structs.
+pub trait EventDescriptor {
+    const EVENT_TYPE: &'static str;
+    const EVENT_VERSION: i64;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct EventTypeInfo {
+    pub event_type: &'static str,
+    pub version: i64,
+}
+
+/// Every event type an event enum can hold, with its current version.
+/// Implemented by `#[derive(DomainEvent)]` on event enums.
+pub trait EventTypeRegistry {
+    const EVENT_TYPES: &'static [EventTypeInfo];
+
+    fn event_type_info(event_type: &str) -> Option<EventTypeInfo> {
+        Self::EVENT_TYPES
+            .iter()
+            .find(|info| info.event_type == event_type)
+            .copied()
+    }
+}
+
+/// Deserializes a stored event after checking that its type belongs to `E`
+/// and that it has been upcast to the current version.
+pub fn decode_stored_event<E>(event: StoredEvent) -> Result<E>
+where
+    E: EventTypeRegistry + DeserializeOwned,
+{
+    let Some(info) = E::event_type_info(&event.event_type) else {
+        bail!("Unknown event type {}", event.event_type);
+    };
+    if event.event_version != info.version {
+        bail!(
+            "Stored {} is v{} but the current version is v{}",
+            event.event_type,
+            event.event_version,
+            info.version
+        );
+    }
+
+    serde_json::from_str(&event.event_data).with_context(|| {
+        format!(
+            "Failed to deserialize {} v{}",
+            event.event_type, event.event_version
+        )
+    })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventMetadata {
     pub aggregate_id: Uuid,
@@ -33,6 +90,18 @@ pub struct EventMetadata {
     pub event_version: i64,
     pub timestamp: DateTime<Utc>,
     pub user_id: String,
+    #[serde(default)]
+    pub correlation_id: Option<Uuid>,
+    #[serde(default)]
+    pub causation_id: Option<Uuid>,
+}
+
+impl EventMetadata {
+    /// Stable identifier for the event, derived from its aggregate and
+    /// sequence so that it never needs to be stored.
+    pub fn event_id(&self) -> Uuid {
+        Uuid::new_v5(&self.aggregate_id, &self.sequence.to_be_bytes())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,13 +111,20 @@ pub struct EventEnvelope<E> {
 }
 
 impl<E: Event> EventEnvelope<E> {
-    pub fn enrich(event: E, append_result: &AppendResult, user_id: String) -> Self {
+    pub fn enrich(
+        event: E,
+        append_result: &AppendResult,
+        user_id: String,
+        causation: CausationContext,
+    ) -> Self {
         let event_metadata = EventMetadata {
             aggregate_id: event.aggregate_id(),
             sequence: append_result.sequence,
             event_version: event.event_version(),
             timestamp: append_result.timestamp,
             user_id,
+            correlation_id: causation.correlation_id,
+            causation_id: causation.causation_id,
         };
 
         EventEnvelope {
@@ -62,33 +138,90 @@ pub trait EventEnvelopeAdapter<E: Event> {
     fn to_envelope(&self) -> Result<EventEnvelope<E>>;
 }
 
-#[macro_export]
-macro_rules! impl_event {
-    ($name:ident, $id_field:ident) => {
-        impl Event for $name {
-            fn aggregate_id(&self) -> Uuid {
-                self.$id_field
-            }
-            fn event_type(&self) -> &str {
-                stringify!($name)
-            }
-            fn event_version(&self) -> i64 {
-                1
-            }
-        }
-    };
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use uuid::Uuid;
 
-    ($name:ident, $id_field:ident, $version:expr) => {
-        impl Event for $name {
-            fn aggregate_id(&self) -> Uuid {
-                self.$id_field
-            }
-            fn event_type(&self) -> &str {
-                stringify!($name)
-            }
-            fn event_version(&self) -> i64 {
-                $version
-            }
-        }
-    };
+    use super::*;
+    use crate::DomainEvent;
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, DomainEvent)]
+    #[event(aggregate_id = "order_id")]
+    struct OrderPlaced {
+        order_id: Uuid,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, DomainEvent)]
+    #[event(aggregate_id = "order_id", version = 3)]
+    struct ItemAdded {
+        order_id: Uuid,
+        item_id: Uuid,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, DomainEvent)]
+    #[serde(tag = "type", content = "data")]
+    enum OrderEvent {
+        OrderPlaced(OrderPlaced),
+        #[event(item_id)]
+        ItemAdded(ItemAdded),
+    }
+
+    #[test]
+    fn derived_payloads_and_enums_describe_their_events() {
+        let order_id = Uuid::now_v7();
+        let item_id = Uuid::now_v7();
+        let added = OrderEvent::ItemAdded(ItemAdded { order_id, item_id });
+        let placed = OrderEvent::OrderPlaced(OrderPlaced { order_id });
+
+        assert_eq!(added.event_type(), "ItemAdded");
+        assert_eq!(added.event_version(), 3);
+        assert_eq!(added.aggregate_id(), order_id);
+        assert_eq!(added.item_id(), Some(item_id));
+        assert_eq!(placed.item_id(), None);
+        assert_eq!(
+            OrderEvent::EVENT_TYPES,
+            &[
+                EventTypeInfo {
+                    event_type: "OrderPlaced",
+                    version: 1
+                },
+                EventTypeInfo {
+                    event_type: "ItemAdded",
+                    version: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn stored_events_round_trip_and_are_validated() {
+        let event = OrderEvent::ItemAdded(ItemAdded {
+            order_id: Uuid::now_v7(),
+            item_id: Uuid::now_v7(),
+        });
+
+        let stored = event.clone().into_stored_event();
+        assert_eq!(
+            OrderEvent::from_stored_event(stored.clone()).unwrap(),
+            event
+        );
+
+        let stale = StoredEvent {
+            event_version: 2,
+            ..stored.clone()
+        };
+        assert!(
+            OrderEvent::from_stored_event(stale)
+                .unwrap_err()
+                .to_string()
+                .contains("current version is v3")
+        );
+
+        let unknown = StoredEvent {
+            event_type: "OrderShipped".to_string(),
+            ..stored
+        };
+        assert!(OrderEvent::from_stored_event(unknown).is_err());
+    }
 }
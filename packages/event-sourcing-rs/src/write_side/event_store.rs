@@ -1,9 +1,12 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, anyhow};
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use uuid::Uuid;
 
-use crate::{Event, EventEnvelope, EventEnvelopeAdapter, EventMetadata};
+use crate::{
+    Event, EventEnvelope, EventEnvelopeAdapter, EventMetadata, StreamVerification, verify_stream,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StoredEventRow {
@@ -14,6 +17,13 @@ pub struct StoredEventRow {
     pub event_version: i64,
     pub created_at: String,
     pub user_id: String,
+    #[serde(default)]
+    pub correlation_id: Option<Uuid>,
+    #[serde(default)]
+    pub causation_id: Option<Uuid>,
+    /// Hash chaining this row to the one before it; see [`crate::chain_hash`].
+    #[serde(default)]
+    pub hash: Option<String>,
 }
 
 impl<E: Event + DeserializeOwned> EventEnvelopeAdapter<E> for StoredEventRow {
@@ -32,6 +42,8 @@ impl<E: Event + DeserializeOwned> EventEnvelopeAdapter<E> for StoredEventRow {
                 event_version: self.event_version,
                 timestamp,
                 user_id: self.user_id.clone(),
+                correlation_id: self.correlation_id,
+                causation_id: self.causation_id,
             },
             event,
         })
@@ -44,6 +56,10 @@ pub struct StoredEvent {
     pub event_type: String,
     pub event_data: String,
     pub event_version: i64,
+    #[serde(default)]
+    pub correlation_id: Option<Uuid>,
+    #[serde(default)]
+    pub causation_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone)]
@@ -53,15 +69,165 @@ pub struct AppendResult {
 }
 
 #[async_trait(?Send)]
-pub trait EventStoreTrait: Send + Sync {
+pub trait EventStoreTrait {
     fn init_schema(&self) -> Result<()>;
+
+    /// Appends every event in `events` atomically: either all of them are
+    /// stored, in order, or none are.
+    fn append_batch(
+        &self,
+        events: Vec<StoredEvent>,
+        user_id: String,
+        expected_version: i64,
+    ) -> Result<Vec<AppendResult>>;
+
     fn append(
         &self,
         aggregate_id: String,
         event: StoredEvent,
         user_id: String,
-    ) -> Result<AppendResult>;
+        expected_version: i64,
+    ) -> Result<AppendResult> {
+        let event = StoredEvent {
+            aggregate_id,
+            ..event
+        };
+        self.append_batch(vec![event], user_id, expected_version)?
+            .pop()
+            .ok_or_else(|| anyhow!("Event store returned no append result"))
+    }
+
     fn load(&self) -> Result<Vec<StoredEventRow>>;
     fn events_since(&self, sequence: i64, limit: i64) -> Result<Vec<StoredEventRow>>;
+
+    /// Up to `limit` events after `sequence` whose type is one of
+    /// `event_types`, in order. The limit applies after filtering.
+    fn events_since_filtered(
+        &self,
+        sequence: i64,
+        limit: i64,
+        event_types: &[&str],
+    ) -> Result<Vec<StoredEventRow>>;
+
+    /// Every event with a sequence at or below `sequence`, in order.
+    fn events_until_sequence(&self, sequence: i64) -> Result<Vec<StoredEventRow>>;
+
+    /// Every event recorded at or before `timestamp`, in order.
+    fn events_until_time(&self, timestamp: DateTime<Utc>) -> Result<Vec<StoredEventRow>>;
+
+    /// Every event recorded at or after `timestamp`, in order.
+    fn events_since_time(&self, timestamp: DateTime<Utc>) -> Result<Vec<StoredEventRow>>;
+
     fn max_sequence(&self) -> Result<Option<i64>>;
+
+    /// Rows exactly as stored, before any read-time transformation such as
+    /// upcasting. Hash verification and exports work on these.
+    fn load_raw(&self) -> Result<Vec<StoredEventRow>> {
+        self.load()
+    }
+
+    /// Up to `limit` rows after `sequence` exactly as stored, like
+    /// [`load_raw`](Self::load_raw) but a page at a time.
+    fn raw_events_since(&self, sequence: i64, limit: i64) -> Result<Vec<StoredEventRow>> {
+        self.events_since(sequence, limit)
+    }
+
+    /// Writes previously exported rows verbatim, keeping their sequences,
+    /// timestamps and hashes. Fails unless the store is empty.
+    fn import_rows(&self, rows: Vec<StoredEventRow>) -> Result<()>;
+
+    fn stream_version(&self) -> Result<i64> {
+        Ok(self.max_sequence()?.unwrap_or(0))
+    }
+
+    /// Recomputes the hash chain over the stored rows as written, reporting
+    /// the first event that has been edited, removed or left unhashed.
+    fn verify_chain(&self) -> Result<StreamVerification> {
+        Ok(verify_stream(&self.load_raw()?))
+    }
+}
+
+impl dyn EventStoreTrait + '_ {
+    /// Reads the stream after `sequence` a page at a time.
+    pub fn pages_since(&self, sequence: i64, page_size: i64) -> EventPages<'_> {
+        EventPages {
+            store: self,
+            after: sequence,
+            page_size,
+            event_types: None,
+            finished: false,
+        }
+    }
+}
+
+/// A page of events read by [`EventPages`].
+#[derive(Debug, Clone)]
+pub struct EventPage {
+    pub rows: Vec<StoredEventRow>,
+    /// How far through the stream this page accounts for. Filtered-out
+    /// events count as read, so a consumer can move its position to here
+    /// once it has handled `rows`, even when `rows` is empty.
+    pub scanned_to: i64,
+}
+
+/// Iterator over [`EventPage`]s, optionally limited to some event types.
+/// It stops after the first short page, so each page it yields reflects a
+/// single read of the store.
+pub struct EventPages<'a> {
+    store: &'a dyn EventStoreTrait,
+    after: i64,
+    page_size: i64,
+    event_types: Option<&'a [&'a str]>,
+    finished: bool,
+}
+
+impl<'a> EventPages<'a> {
+    /// Only yields events whose type is in `event_types`.
+    pub fn only(mut self, event_types: &'a [&'a str]) -> Self {
+        self.event_types = Some(event_types);
+        self
+    }
+
+    fn read_page(&self) -> Result<EventPage> {
+        let rows = match self.event_types {
+            Some(event_types) => {
+                self.store
+                    .events_since_filtered(self.after, self.page_size, event_types)?
+            }
+            None => self.store.events_since(self.after, self.page_size)?,
+        };
+
+        let scanned_to = if rows.len() as i64 >= self.page_size {
+            rows.last().map_or(self.after, |row| row.sequence)
+        } else {
+            self.store.max_sequence()?.unwrap_or(self.after)
+        };
+        Ok(EventPage { rows, scanned_to })
+    }
+}
+
+impl Iterator for EventPages<'_> {
+    type Item = Result<EventPage>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let page = match self.read_page() {
+            Ok(page) => page,
+            Err(err) => {
+                self.finished = true;
+                return Some(Err(err));
+            }
+        };
+        if page.rows.is_empty() && page.scanned_to <= self.after {
+            self.finished = true;
+            return None;
+        }
+
+        self.finished = (page.rows.len() as i64) < self.page_size;
+        self.after = page.scanned_to;
+        Some(Ok(page))
+    }
 }
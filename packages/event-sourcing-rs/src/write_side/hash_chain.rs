@@ -0,0 +1,278 @@
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::{StoredEvent, StoredEventRow};
+
+/// The fields of a stored event covered by its hash, in a fixed order so the
+/// serialized form is stable.
+#[derive(Serialize)]
+struct HashedFields<'a> {
+    previous_hash: Option<&'a str>,
+    sequence: i64,
+    aggregate_id: &'a str,
+    event_type: &'a str,
+    event_version: i64,
+    event_data: &'a str,
+    created_at: &'a str,
+    user_id: &'a str,
+    correlation_id: Option<Uuid>,
+    causation_id: Option<Uuid>,
+}
+
+/// Hex-encoded SHA-256 of `row`'s payload and metadata, chained to the hash
+/// of the event before it. The row's own `hash` field is not part of the
+/// input.
+pub fn chain_hash(previous_hash: Option<&str>, row: &StoredEventRow) -> String {
+    let fields = HashedFields {
+        previous_hash,
+        sequence: row.sequence,
+        aggregate_id: &row.aggregate_id,
+        event_type: &row.event_type,
+        event_version: row.event_version,
+        event_data: &row.event_data,
+        created_at: &row.created_at,
+        user_id: &row.user_id,
+        correlation_id: row.correlation_id,
+        causation_id: row.causation_id,
+    };
+    let canonical =
+        serde_json::to_vec(&fields).expect("hashed event fields always serialize to JSON");
+    hex::encode(Sha256::digest(canonical))
+}
+
+/// Builds the rows for a batch about to be appended after `previous_hash`,
+/// assigning consecutive sequences from `first_sequence` and chaining each
+/// row's hash to the one before it.
+pub fn seal_batch(
+    previous_hash: Option<String>,
+    events: Vec<StoredEvent>,
+    user_id: &str,
+    first_sequence: i64,
+    created_at: &str,
+) -> Vec<StoredEventRow> {
+    let mut previous_hash = previous_hash;
+    events
+        .into_iter()
+        .zip(first_sequence..)
+        .map(|(event, sequence)| {
+            let mut row = StoredEventRow {
+                sequence,
+                aggregate_id: event.aggregate_id,
+                event_type: event.event_type,
+                event_data: event.event_data,
+                event_version: event.event_version,
+                created_at: created_at.to_string(),
+                user_id: user_id.to_string(),
+                correlation_id: event.correlation_id,
+                causation_id: event.causation_id,
+                hash: None,
+            };
+            let hash = chain_hash(previous_hash.as_deref(), &row);
+            row.hash = Some(hash.clone());
+            previous_hash = Some(hash);
+            row
+        })
+        .collect()
+}
+
+/// Computes hashes for rows stored before the chain existed, continuing from
+/// `previous_hash`. Returns `(sequence, hash)` pairs for the caller to persist.
+pub fn seal_existing(previous_hash: Option<&str>, rows: &[StoredEventRow]) -> Vec<(i64, String)> {
+    let mut previous_hash = previous_hash.map(str::to_string);
+    rows.iter()
+        .map(|row| {
+            let hash = chain_hash(previous_hash.as_deref(), row);
+            previous_hash = Some(hash.clone());
+            (row.sequence, hash)
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum BrokenLinkReason {
+    /// The row was stored without a hash.
+    MissingHash,
+    /// The row does not follow directly on from the previous sequence, so
+    /// at least one event was removed.
+    SequenceGap { expected_sequence: i64 },
+    /// The stored hash does not match the row's contents and the hash of the
+    /// event before it.
+    HashMismatch { expected: String, actual: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BrokenLink {
+    pub sequence: i64,
+    pub reason: BrokenLinkReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StreamVerification {
+    /// Number of events verified before the first broken link, or the whole
+    /// stream when it is intact.
+    pub verified_events: i64,
+    pub head_hash: Option<String>,
+    pub first_broken_link: Option<BrokenLink>,
+}
+
+impl StreamVerification {
+    pub fn is_intact(&self) -> bool {
+        self.first_broken_link.is_none()
+    }
+}
+
+/// Walks `rows` in sequence order, recomputing every hash, and stops at the
+/// first event whose link to the chain is broken.
+pub fn verify_stream(rows: &[StoredEventRow]) -> StreamVerification {
+    let mut previous: Option<(i64, &str)> = None;
+    let mut verified_events = 0;
+
+    for row in rows {
+        let expected_sequence = previous.map_or(1, |(sequence, _)| sequence + 1);
+        let previous_hash = previous.map(|(_, hash)| hash);
+
+        let reason = if row.sequence != expected_sequence {
+            Some(BrokenLinkReason::SequenceGap { expected_sequence })
+        } else {
+            match row.hash.as_deref() {
+                None => Some(BrokenLinkReason::MissingHash),
+                Some(actual) => {
+                    let expected = chain_hash(previous_hash, row);
+                    (expected != actual).then(|| BrokenLinkReason::HashMismatch {
+                        expected,
+                        actual: actual.to_string(),
+                    })
+                }
+            }
+        };
+
+        if let Some(reason) = reason {
+            return StreamVerification {
+                verified_events,
+                head_hash: previous_hash.map(str::to_string),
+                first_broken_link: Some(BrokenLink {
+                    sequence: row.sequence,
+                    reason,
+                }),
+            };
+        }
+
+        previous = row.hash.as_deref().map(|hash| (row.sequence, hash));
+        verified_events += 1;
+    }
+
+    StreamVerification {
+        verified_events,
+        head_hash: previous.map(|(_, hash)| hash.to_string()),
+        first_broken_link: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stored_event(payload: &str) -> StoredEvent {
+        StoredEvent {
+            aggregate_id: Uuid::nil().to_string(),
+            event_type: "Noted".to_string(),
+            event_data: payload.to_string(),
+            event_version: 1,
+            correlation_id: None,
+            causation_id: None,
+        }
+    }
+
+    fn stream() -> Vec<StoredEventRow> {
+        let first = seal_batch(
+            None,
+            vec![stored_event("{\"n\":1}"), stored_event("{\"n\":2}")],
+            "user",
+            1,
+            "2026-01-01 10:00:00",
+        );
+        let second = seal_batch(
+            first.last().and_then(|row| row.hash.clone()),
+            vec![stored_event("{\"n\":3}")],
+            "user",
+            3,
+            "2026-01-01 10:05:00",
+        );
+        first.into_iter().chain(second).collect()
+    }
+
+    #[test]
+    fn sealed_batches_verify_as_one_chain() {
+        let rows = stream();
+        let verification = verify_stream(&rows);
+
+        assert!(verification.is_intact());
+        assert_eq!(verification.verified_events, 3);
+        assert_eq!(verification.head_hash, rows[2].hash);
+    }
+
+    #[test]
+    fn edited_payload_breaks_the_chain_at_that_event() {
+        let mut rows = stream();
+        rows[1].event_data = "{\"n\":20}".to_string();
+
+        let verification = verify_stream(&rows);
+
+        assert_eq!(verification.verified_events, 1);
+        assert_eq!(verification.head_hash, rows[0].hash);
+        let link = verification.first_broken_link.unwrap();
+        assert_eq!(link.sequence, 2);
+        assert!(matches!(link.reason, BrokenLinkReason::HashMismatch { .. }));
+    }
+
+    #[test]
+    fn deleted_event_is_reported_as_a_gap() {
+        let mut rows = stream();
+        rows.remove(1);
+
+        let link = verify_stream(&rows).first_broken_link.unwrap();
+
+        assert_eq!(link.sequence, 3);
+        assert_eq!(
+            link.reason,
+            BrokenLinkReason::SequenceGap {
+                expected_sequence: 2
+            }
+        );
+    }
+
+    #[test]
+    fn sealing_existing_rows_matches_sealing_on_append() {
+        let sealed = stream();
+        let legacy: Vec<StoredEventRow> = sealed
+            .iter()
+            .map(|row| StoredEventRow {
+                hash: None,
+                ..row.clone()
+            })
+            .collect();
+
+        let hashes = seal_existing(None, &legacy);
+
+        assert_eq!(
+            hashes,
+            sealed
+                .iter()
+                .map(|row| (row.sequence, row.hash.clone().unwrap()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn unhashed_rows_are_reported() {
+        let mut rows = stream();
+        rows[0].hash = None;
+
+        let link = verify_stream(&rows).first_broken_link.unwrap();
+
+        assert_eq!(link.sequence, 1);
+        assert_eq!(link.reason, BrokenLinkReason::MissingHash);
+    }
+}
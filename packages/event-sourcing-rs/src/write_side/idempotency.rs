@@ -0,0 +1,196 @@
+use std::rc::Rc;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Serialize, de::DeserializeOwned};
+use tracing::{info, warn};
+
+use crate::{Clock, SystemClock};
+
+#[derive(Debug, Clone)]
+pub struct ProcessedCommand {
+    pub idempotency_key: String,
+    pub outcome: String,
+    pub processed_at: DateTime<Utc>,
+}
+
+pub trait ProcessedCommandStoreTrait {
+    fn init_schema(&self) -> Result<()>;
+    fn get(&self, idempotency_key: &str) -> Result<Option<ProcessedCommand>>;
+    fn record(
+        &self,
+        idempotency_key: &str,
+        outcome: String,
+        processed_at: DateTime<Utc>,
+    ) -> Result<()>;
+    fn purge_before(&self, cutoff: DateTime<Utc>) -> Result<()>;
+}
+
+/// Executes each idempotency key at most once within the retention window.
+/// Only successful outcomes are recorded: a failed command appended nothing,
+/// so a retry is free to run it again.
+#[derive(Clone)]
+pub struct IdempotencyGuard {
+    store: Rc<dyn ProcessedCommandStoreTrait>,
+    retention: Duration,
+    clock: Rc<dyn Clock>,
+}
+
+impl IdempotencyGuard {
+    pub fn new(store: Rc<dyn ProcessedCommandStoreTrait>, retention: Duration) -> Self {
+        Self {
+            store,
+            retention,
+            clock: Rc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn execute<T: Serialize + DeserializeOwned>(
+        &self,
+        idempotency_key: &str,
+        operation: impl FnOnce() -> Result<T>,
+    ) -> Result<T> {
+        let now = self.clock.now();
+
+        if let Some(processed) = self
+            .store
+            .get(idempotency_key)
+            .context("Failed to look up processed command")?
+            && processed.processed_at >= now - self.retention
+        {
+            info!(
+                idempotency_key,
+                "Command already processed, returning cached outcome"
+            );
+            return serde_json::from_str(&processed.outcome)
+                .context("Failed to deserialize cached command outcome");
+        }
+
+        let outcome = operation()?;
+
+        let recorded = serde_json::to_string(&outcome)
+            .context("Failed to serialize command outcome")
+            .and_then(|serialized| self.store.record(idempotency_key, serialized, now))
+            .and_then(|_| self.store.purge_before(now - self.retention));
+        if let Err(e) = recorded {
+            warn!(idempotency_key, error = %e, "Failed to record processed command");
+        }
+
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::HashMap};
+
+    use anyhow::anyhow;
+
+    use super::*;
+    use crate::testing::FixedClock;
+
+    #[derive(Default)]
+    struct InMemoryProcessedCommandStore {
+        commands: RefCell<HashMap<String, ProcessedCommand>>,
+    }
+
+    impl ProcessedCommandStoreTrait for InMemoryProcessedCommandStore {
+        fn init_schema(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn get(&self, idempotency_key: &str) -> Result<Option<ProcessedCommand>> {
+            Ok(self.commands.borrow().get(idempotency_key).cloned())
+        }
+
+        fn record(
+            &self,
+            idempotency_key: &str,
+            outcome: String,
+            processed_at: DateTime<Utc>,
+        ) -> Result<()> {
+            self.commands.borrow_mut().insert(
+                idempotency_key.to_string(),
+                ProcessedCommand {
+                    idempotency_key: idempotency_key.to_string(),
+                    outcome,
+                    processed_at,
+                },
+            );
+            Ok(())
+        }
+
+        fn purge_before(&self, cutoff: DateTime<Utc>) -> Result<()> {
+            self.commands
+                .borrow_mut()
+                .retain(|_, command| command.processed_at >= cutoff);
+            Ok(())
+        }
+    }
+
+    fn guard() -> (
+        Rc<InMemoryProcessedCommandStore>,
+        Rc<FixedClock>,
+        IdempotencyGuard,
+    ) {
+        let store = Rc::new(InMemoryProcessedCommandStore::default());
+        let clock = Rc::new(FixedClock::default());
+        (
+            store.clone(),
+            clock.clone(),
+            IdempotencyGuard::new(store, Duration::hours(1)).with_clock(clock),
+        )
+    }
+
+    #[test]
+    fn repeated_key_returns_cached_outcome() {
+        let (_, _, guard) = guard();
+        let mut calls = 0;
+
+        let first = guard.execute("key", || {
+            calls += 1;
+            Ok(calls)
+        });
+        let second = guard.execute("key", || {
+            calls += 1;
+            Ok(calls)
+        });
+
+        assert_eq!(first.unwrap(), 1);
+        assert_eq!(second.unwrap(), 1);
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn failed_commands_are_not_recorded() {
+        let (store, _, guard) = guard();
+
+        let result: Result<()> = guard.execute("key", || Err(anyhow!("Domain error")));
+
+        assert!(result.is_err());
+        assert!(store.get("key").unwrap().is_none());
+        assert!(guard.execute("key", || Ok(())).is_ok());
+    }
+
+    #[test]
+    fn expired_keys_execute_again() {
+        let (store, clock, guard) = guard();
+        store.commands.borrow_mut().insert(
+            "key".to_string(),
+            ProcessedCommand {
+                idempotency_key: "key".to_string(),
+                outcome: "1".to_string(),
+                processed_at: clock.now() - Duration::hours(2),
+            },
+        );
+
+        let result = guard.execute("key", || Ok(2));
+
+        assert_eq!(result.unwrap(), 2);
+    }
+}
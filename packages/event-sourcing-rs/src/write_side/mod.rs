@@ -1,19 +1,37 @@
 pub mod aggregate;
 pub mod aggregate_repository;
 pub mod cache;
+pub mod clock;
 pub mod command;
 pub mod command_handler;
+pub mod concurrency;
+pub mod effect_ledger;
 pub mod event;
 pub mod event_reactor;
 pub mod event_store;
+pub mod hash_chain;
+pub mod idempotency;
 pub mod policy;
+pub mod process_manager;
+pub mod snapshot;
+pub mod stream_transfer;
+pub mod upcaster;
 
 pub use aggregate::*;
 pub use aggregate_repository::*;
 pub use cache::*;
+pub use clock::*;
 pub use command::*;
 pub use command_handler::*;
+pub use concurrency::*;
+pub use effect_ledger::*;
 pub use event::*;
 pub use event_reactor::*;
 pub use event_store::*;
+pub use hash_chain::*;
+pub use idempotency::*;
 pub use policy::*;
+pub use process_manager::*;
+pub use snapshot::*;
+pub use stream_transfer::*;
+pub use upcaster::*;
@@ -18,3 +18,19 @@ pub trait HasPolicies<A: 'static, R: 'static>: Sized + 'static {
         Self::policies().iter().flat_map(|f| f(self, ctx)).collect()
     }
 }
+
+/// Implemented on an aggregate's event enum to route each variant to the
+/// policies registered for it through [`HasPolicies`].
+pub trait PolicyRouter<A, R> {
+    /// Types of the events that have policies, so that callers can skip
+    /// reading the rest of the stream.
+    const ROUTED_EVENT_TYPES: &'static [&'static str];
+
+    fn route_policies(&self, ctx: &PolicyContext<'_, A>) -> Vec<R>;
+
+    /// Sequence of an earlier event that this event compensates for, whose
+    /// undispatched effects should be cancelled.
+    fn compensated_sequence(&self) -> Option<i64> {
+        None
+    }
+}
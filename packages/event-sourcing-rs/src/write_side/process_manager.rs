@@ -0,0 +1,580 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use crate::{
+    Aggregate, AggregateRepositoryTrait, CausationContext, EffectLedgerTrait, Event, EventEnvelope,
+    EventEnvelopeAdapter, EventStoreTrait, PolicyContext, PolicyRouter, SerializedEffect,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IdempotencyKey(pub String);
+
+impl IdempotencyKey {
+    pub fn for_notification(
+        aggregate_id: Uuid,
+        event_sequence: i64,
+        recipient_id: &str,
+        notification_type: &str,
+    ) -> Self {
+        Self(format!(
+            "{}:{}:notify:{}:{}",
+            aggregate_id, event_sequence, recipient_id, notification_type
+        ))
+    }
+
+    pub fn for_command(aggregate_id: Uuid, event_sequence: i64, command_type: &str) -> Self {
+        Self(format!(
+            "{}:{}:cmd:{}",
+            aggregate_id, event_sequence, command_type
+        ))
+    }
+}
+
+/// An effect that policies decide on and an [`EffectExecutor`] carries out.
+pub trait ProcessEffect: Serialize + DeserializeOwned {
+    fn idempotency_key(&self) -> &IdempotencyKey;
+    fn effect_type(&self) -> &'static str;
+}
+
+#[async_trait(?Send)]
+pub trait EffectExecutor<E> {
+    async fn execute(&self, effect: &E, causation: CausationContext) -> Result<()>;
+}
+
+/// Turns new events into effects through the event's [`PolicyRouter`],
+/// records them in the ledger, and dispatches them with at-least-once
+/// semantics: an effect is retried until it succeeds or has been attempted
+/// `max_retry_attempts` times.
+pub struct ProcessManager<A: Aggregate, E, X> {
+    ledger: Rc<dyn EffectLedgerTrait>,
+    executor: X,
+    event_store: Rc<dyn EventStoreTrait>,
+    aggregate_repository: Rc<dyn AggregateRepositoryTrait<A>>,
+    default_batch_size: i64,
+    max_retry_attempts: i64,
+    _phantom: PhantomData<E>,
+}
+
+impl<A, E, X> ProcessManager<A, E, X>
+where
+    A: Aggregate,
+    A::Event: Event + DeserializeOwned + PolicyRouter<A, E>,
+    E: ProcessEffect,
+    X: EffectExecutor<E>,
+{
+    pub fn new(
+        ledger: Rc<dyn EffectLedgerTrait>,
+        executor: X,
+        event_store: Rc<dyn EventStoreTrait>,
+        aggregate_repository: Rc<dyn AggregateRepositoryTrait<A>>,
+        default_batch_size: i64,
+        max_retry_attempts: i64,
+    ) -> Self {
+        Self {
+            ledger,
+            executor,
+            event_store,
+            aggregate_repository,
+            default_batch_size,
+            max_retry_attempts,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn process_new_events(&self) -> Result<()> {
+        let last_sequence = self.ledger.get_last_processed_sequence()?;
+        let Some(page) = self
+            .event_store
+            .pages_since(last_sequence, self.default_batch_size)
+            .only(<A::Event as PolicyRouter<A, E>>::ROUTED_EVENT_TYPES)
+            .next()
+            .transpose()
+            .context("Failed to load events since last processed sequence")?
+        else {
+            return Ok(());
+        };
+
+        if page.rows.is_empty() {
+            return self
+                .ledger
+                .persist_effects(&[], page.scanned_to, CausationContext::default())
+                .context("Failed to record skipped events");
+        }
+
+        let Ok(Some(aggregate_state)) = self.aggregate_repository.load() else {
+            warn!("Failed to load aggregate state");
+            return Ok(());
+        };
+
+        // TODO: think about what could happen if we are processing an event against an
+        // aggregate that is more up-to-date.
+        for event_row in page.rows {
+            let sequence = event_row.sequence;
+            let envelope: EventEnvelope<A::Event> = event_row
+                .to_envelope()
+                .with_context(|| format!("Failed to decode event at sequence {sequence}"))?;
+            let causation = CausationContext::from_event(&envelope.metadata);
+
+            let ctx = PolicyContext::new(&aggregate_state, sequence);
+            let effects = envelope.event.route_policies(&ctx);
+
+            if !effects.is_empty() {
+                info!(
+                    "Process manager determined {} effect(s) for event sequence {}",
+                    effects.len(),
+                    sequence
+                );
+            }
+
+            let serialized = effects
+                .iter()
+                .map(|effect| {
+                    Ok(SerializedEffect {
+                        effect_type: effect.effect_type().to_string(),
+                        effect_payload: serde_json::to_string(effect)
+                            .context("Failed to serialize effect to JSON")?,
+                        idempotency_key: effect.idempotency_key().0.clone(),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            self.ledger
+                .persist_effects(&serialized, sequence, causation)
+                .context("Failed to persist effects")?;
+
+            if let Some(compensated) = envelope.event.compensated_sequence() {
+                info!(
+                    "Event sequence {} compensates for sequence {}, cancelling its pending effects",
+                    sequence, compensated
+                );
+                self.ledger
+                    .cancel_pending_effects(compensated)
+                    .context("Failed to cancel pending effects")?;
+            }
+        }
+
+        if self.ledger.get_last_processed_sequence()? < page.scanned_to {
+            self.ledger
+                .persist_effects(&[], page.scanned_to, CausationContext::default())
+                .context("Failed to record skipped events")?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn dispatch_pending_effects(&self) -> Result<()> {
+        let pending = self
+            .ledger
+            .get_pending_effects(self.max_retry_attempts)
+            .context("Failed to get pending effects")?;
+
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        info!(
+            "Process manager dispatching {} pending effect(s)",
+            pending.len()
+        );
+
+        let mut had_failure = false;
+
+        for record in pending {
+            self.ledger
+                .mark_dispatched(&record.effect_id)
+                .context("Failed to mark effect as dispatched")?;
+
+            let effect: E = serde_json::from_str(&record.effect_payload)
+                .context("Failed to deserialize effect")?;
+
+            match self.executor.execute(&effect, record.causation()).await {
+                Ok(()) => {
+                    self.ledger
+                        .mark_completed(&record.effect_id)
+                        .context("Failed to mark effect as completed")?;
+                    info!("Effect {} completed successfully", record.effect_id);
+                }
+                Err(e) => {
+                    had_failure = true;
+
+                    let exhausted = record.attempts + 1 >= self.max_retry_attempts;
+                    self.ledger
+                        .mark_failed(&record.effect_id, &e.to_string(), exhausted)
+                        .context("Failed to mark effect as failed")?;
+
+                    if exhausted {
+                        error!(
+                            "Effect {} failed after {} attempts: {}",
+                            record.effect_id, self.max_retry_attempts, e
+                        );
+                    } else {
+                        info!(
+                            "Effect {} failed (attempt {}): {}. Will retry.",
+                            record.effect_id,
+                            record.attempts + 1,
+                            e
+                        );
+                    }
+                }
+            }
+        }
+
+        if had_failure {
+            bail!("One or more effects failed during dispatch");
+        }
+
+        Ok(())
+    }
+
+    /// Processes one batch of new events and dispatches pending effects.
+    pub async fn on_alarm(&self) -> Result<()> {
+        info!("Process manager alarm triggered");
+        self.process_new_events()?;
+        self.dispatch_pending_effects().await
+    }
+
+    /// Repeats [`Self::on_alarm`] until effects stop producing new events,
+    /// for callers that must not return before the stream has settled.
+    pub async fn run_until_settled(&self) -> Result<()> {
+        info!("Process manager alarm triggered");
+        loop {
+            if let Ok(false) = self.has_pending_events() {
+                debug!("No events to process");
+                break;
+            }
+            self.process_new_events()?;
+            self.dispatch_pending_effects().await?;
+        }
+        Ok(())
+    }
+
+    pub fn has_pending_events(&self) -> Result<bool> {
+        let last_processed = self.ledger.get_last_processed_sequence()?;
+        let pending_events = self
+            .event_store
+            .events_since_filtered(
+                last_processed,
+                1,
+                <A::Event as PolicyRouter<A, E>>::ROUTED_EVENT_TYPES,
+            )
+            .map(|events| !events.is_empty())
+            .unwrap_or(false);
+        Ok(pending_events)
+    }
+
+    pub fn has_pending_effects(&self) -> Result<bool> {
+        self.ledger.has_pending_effects(self.max_retry_attempts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, fmt};
+
+    use anyhow::anyhow;
+    use futures::executor::block_on;
+
+    use super::*;
+    use crate::testing::{AggregateTestHarness, InMemoryEffectLedger};
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct Pinged {
+        id: Uuid,
+        compensates: Option<i64>,
+    }
+
+    impl Event for Pinged {
+        fn event_type(&self) -> &str {
+            "Pinged"
+        }
+
+        fn event_version(&self) -> i64 {
+            1
+        }
+
+        fn aggregate_id(&self) -> Uuid {
+            self.id
+        }
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Pinger {
+        id: Uuid,
+        pings: i64,
+    }
+
+    #[derive(Debug)]
+    struct PingerError;
+
+    impl fmt::Display for PingerError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "pinger error")
+        }
+    }
+
+    impl std::error::Error for PingerError {}
+
+    impl Aggregate for Pinger {
+        type Event = Pinged;
+        type Command = Uuid;
+        type Error = PingerError;
+
+        fn aggregate_id(&self) -> String {
+            self.id.to_string()
+        }
+
+        fn apply(&mut self, _: &Pinged) {
+            self.pings += 1;
+        }
+
+        fn handle_command(
+            _: Option<&Self>,
+            id: Uuid,
+            _: &dyn crate::Clock,
+        ) -> Result<Vec<Pinged>, PingerError> {
+            Ok(vec![Pinged {
+                id,
+                compensates: None,
+            }])
+        }
+
+        fn from_events(events: &[Pinged]) -> Option<Self> {
+            Some(Pinger {
+                id: events.first()?.id,
+                pings: events.len() as i64,
+            })
+        }
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct Pong {
+        sequence: i64,
+        idempotency_key: IdempotencyKey,
+    }
+
+    impl ProcessEffect for Pong {
+        fn idempotency_key(&self) -> &IdempotencyKey {
+            &self.idempotency_key
+        }
+
+        fn effect_type(&self) -> &'static str {
+            "PONG"
+        }
+    }
+
+    impl PolicyRouter<Pinger, Pong> for Pinged {
+        const ROUTED_EVENT_TYPES: &'static [&'static str] = &["Pinged"];
+
+        fn route_policies(&self, ctx: &PolicyContext<'_, Pinger>) -> Vec<Pong> {
+            vec![Pong {
+                sequence: ctx.sequence,
+                idempotency_key: IdempotencyKey::for_command(self.id, ctx.sequence, "pong"),
+            }]
+        }
+
+        fn compensated_sequence(&self) -> Option<i64> {
+            self.compensates
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingExecutor {
+        failures_remaining: RefCell<usize>,
+        executed: RefCell<Vec<(Pong, CausationContext)>>,
+    }
+
+    #[async_trait(?Send)]
+    impl EffectExecutor<Pong> for Rc<RecordingExecutor> {
+        async fn execute(&self, effect: &Pong, causation: CausationContext) -> Result<()> {
+            let mut failures_remaining = self.failures_remaining.borrow_mut();
+            if *failures_remaining > 0 {
+                *failures_remaining -= 1;
+                return Err(anyhow!("executor unavailable"));
+            }
+            self.executed.borrow_mut().push((effect.clone(), causation));
+            Ok(())
+        }
+    }
+
+    struct Fixture {
+        harness: AggregateTestHarness<Pinger>,
+        ledger: Rc<InMemoryEffectLedger>,
+        executor: Rc<RecordingExecutor>,
+        manager: ProcessManager<Pinger, Pong, Rc<RecordingExecutor>>,
+    }
+
+    fn fixture(failures: usize, max_retry_attempts: i64) -> Fixture {
+        let id = Uuid::now_v7();
+        let harness = AggregateTestHarness::<Pinger>::new().given([
+            Pinged {
+                id,
+                compensates: None,
+            },
+            Pinged {
+                id,
+                compensates: None,
+            },
+        ]);
+        let ledger = Rc::new(InMemoryEffectLedger::new());
+        let executor = Rc::new(RecordingExecutor {
+            failures_remaining: RefCell::new(failures),
+            ..Default::default()
+        });
+        let manager = ProcessManager::new(
+            ledger.clone(),
+            executor.clone(),
+            harness.event_store(),
+            harness.repository(),
+            100,
+            max_retry_attempts,
+        );
+        Fixture {
+            harness,
+            ledger,
+            executor,
+            manager,
+        }
+    }
+
+    #[test]
+    fn routes_each_event_to_effects_exactly_once() {
+        let fixture = fixture(0, 3);
+
+        fixture.manager.process_new_events().unwrap();
+        fixture.manager.process_new_events().unwrap();
+
+        let sequences: Vec<i64> = fixture
+            .ledger
+            .effects()
+            .iter()
+            .map(|record| record.event_sequence)
+            .collect();
+        assert_eq!(sequences, vec![1, 2]);
+        assert!(!fixture.manager.has_pending_events().unwrap());
+    }
+
+    #[test]
+    fn unrouted_events_are_skipped_but_counted_as_processed() {
+        let fixture = fixture(0, 3);
+        let event_store = fixture.harness.event_store();
+        let id = Uuid::now_v7();
+        event_store
+            .append_batch(
+                vec![crate::StoredEvent {
+                    aggregate_id: id.to_string(),
+                    event_type: "Ignored".to_string(),
+                    event_data: serde_json::to_string(&Pinged {
+                        id,
+                        compensates: None,
+                    })
+                    .unwrap(),
+                    event_version: 1,
+                    correlation_id: None,
+                    causation_id: None,
+                }],
+                "user".to_string(),
+                2,
+            )
+            .unwrap();
+
+        fixture.manager.process_new_events().unwrap();
+
+        assert_eq!(fixture.ledger.effects().len(), 2);
+        assert_eq!(fixture.ledger.get_last_processed_sequence().unwrap(), 3);
+        assert!(!fixture.manager.has_pending_events().unwrap());
+    }
+
+    #[test]
+    fn compensating_events_cancel_undispatched_effects() {
+        let fixture = fixture(0, 3);
+        let id = fixture.harness.state().unwrap().id;
+        fixture
+            .harness
+            .event_store()
+            .append_batch(
+                vec![crate::StoredEvent {
+                    aggregate_id: id.to_string(),
+                    event_type: "Pinged".to_string(),
+                    event_data: serde_json::to_string(&Pinged {
+                        id,
+                        compensates: Some(2),
+                    })
+                    .unwrap(),
+                    event_version: 1,
+                    correlation_id: None,
+                    causation_id: None,
+                }],
+                "user".to_string(),
+                2,
+            )
+            .unwrap();
+
+        block_on(fixture.manager.on_alarm()).unwrap();
+
+        let statuses: Vec<(i64, String)> = fixture
+            .ledger
+            .effects()
+            .into_iter()
+            .map(|record| (record.event_sequence, record.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (1, "COMPLETED".to_string()),
+                (2, "CANCELLED".to_string()),
+                (3, "COMPLETED".to_string()),
+            ]
+        );
+        assert_eq!(fixture.executor.executed.borrow().len(), 2);
+    }
+
+    #[test]
+    fn dispatched_effects_carry_the_causing_event() {
+        let fixture = fixture(0, 3);
+
+        block_on(fixture.manager.on_alarm()).unwrap();
+
+        let executed = fixture.executor.executed.borrow();
+        let envelopes = fixture.harness.repository().load_events().unwrap();
+        assert_eq!(executed.len(), 2);
+        for ((pong, causation), envelope) in executed.iter().zip(&envelopes) {
+            assert_eq!(pong.sequence, envelope.metadata.sequence);
+            assert_eq!(causation.causation_id, Some(envelope.metadata.event_id()));
+        }
+        assert!(!fixture.manager.has_pending_effects().unwrap());
+    }
+
+    #[test]
+    fn failed_effects_are_retried_until_exhausted() {
+        let fixture = fixture(usize::MAX, 2);
+
+        assert!(block_on(fixture.manager.on_alarm()).is_err());
+        assert!(fixture.manager.has_pending_effects().unwrap());
+        assert!(block_on(fixture.manager.dispatch_pending_effects()).is_err());
+
+        let statuses: Vec<String> = fixture
+            .ledger
+            .effects()
+            .into_iter()
+            .map(|record| record.status)
+            .collect();
+        assert_eq!(statuses, vec!["FAILED", "FAILED"]);
+        assert!(!fixture.manager.has_pending_effects().unwrap());
+    }
+
+    #[test]
+    fn transient_failures_succeed_on_retry() {
+        let fixture = fixture(1, 3);
+
+        assert!(block_on(fixture.manager.on_alarm()).is_err());
+        block_on(fixture.manager.dispatch_pending_effects()).unwrap();
+
+        assert_eq!(fixture.executor.executed.borrow().len(), 2);
+        assert!(!fixture.manager.has_pending_effects().unwrap());
+    }
+}
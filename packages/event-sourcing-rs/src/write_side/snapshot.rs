@@ -0,0 +1,216 @@
+use std::rc::Rc;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::Aggregate;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregateSnapshot {
+    pub sequence: i64,
+    pub aggregate_data: String,
+    pub schema_version: i64,
+}
+
+pub trait SnapshotStoreTrait {
+    fn init_schema(&self) -> Result<()>;
+    fn latest(&self) -> Result<Option<AggregateSnapshot>>;
+    fn save(&self, snapshot: AggregateSnapshot) -> Result<()>;
+    fn clear(&self) -> Result<()>;
+}
+
+/// Restores aggregates from the latest snapshot and writes a new one every
+/// `interval` events.
+#[derive(Clone)]
+pub struct Snapshotter {
+    store: Rc<dyn SnapshotStoreTrait>,
+    interval: i64,
+}
+
+impl Snapshotter {
+    pub fn new(store: Rc<dyn SnapshotStoreTrait>, interval: i64) -> Self {
+        Self {
+            store,
+            interval: interval.max(1),
+        }
+    }
+
+    /// Returns the snapshotted aggregate and the sequence it was taken at.
+    /// Snapshots written under a different schema version, or that no longer
+    /// deserialize, are discarded so the caller falls back to a full replay.
+    pub fn restore<A: Aggregate>(&self) -> Result<Option<(A, i64)>> {
+        let Some(snapshot) = self.store.latest().context("Failed to load snapshot")? else {
+            return Ok(None);
+        };
+
+        if snapshot.schema_version != A::SNAPSHOT_SCHEMA_VERSION {
+            info!(
+                snapshot_version = snapshot.schema_version,
+                current_version = A::SNAPSHOT_SCHEMA_VERSION,
+                "Discarding snapshot with outdated schema version"
+            );
+            self.store.clear().context("Failed to clear snapshots")?;
+            return Ok(None);
+        }
+
+        match serde_json::from_str::<A>(&snapshot.aggregate_data) {
+            Ok(aggregate) => Ok(Some((aggregate, snapshot.sequence))),
+            Err(e) => {
+                warn!(sequence = snapshot.sequence, error = %e, "Discarding unreadable snapshot");
+                self.store.clear().context("Failed to clear snapshots")?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Snapshots `aggregate` if the stream crossed an interval boundary while
+    /// moving from `previous_version` to `version`. Failures are logged rather
+    /// than returned, the event stream remains the source of truth.
+    pub fn record<A: Aggregate>(&self, aggregate: &A, previous_version: i64, version: i64) {
+        if version / self.interval <= previous_version / self.interval {
+            return;
+        }
+
+        let aggregate_data = match serde_json::to_string(aggregate) {
+            Ok(data) => data,
+            Err(e) => {
+                warn!(sequence = version, error = %e, "Failed to serialize aggregate snapshot");
+                return;
+            }
+        };
+
+        debug!(sequence = version, "Saving aggregate snapshot");
+        let snapshot = AggregateSnapshot {
+            sequence: version,
+            aggregate_data,
+            schema_version: A::SNAPSHOT_SCHEMA_VERSION,
+        };
+        if let Err(e) = self.store.save(snapshot) {
+            warn!(sequence = version, error = %e, "Failed to save aggregate snapshot");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, fmt};
+
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct Counter {
+        count: i64,
+    }
+
+    #[derive(Debug)]
+    struct CounterError;
+
+    impl fmt::Display for CounterError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "counter error")
+        }
+    }
+
+    impl std::error::Error for CounterError {}
+
+    impl Aggregate for Counter {
+        type Event = i64;
+        type Command = ();
+        type Error = CounterError;
+
+        const SNAPSHOT_SCHEMA_VERSION: i64 = 2;
+
+        fn aggregate_id(&self) -> String {
+            "counter".to_string()
+        }
+
+        fn apply(&mut self, event: &i64) {
+            self.count += event;
+        }
+
+        fn handle_command(
+            _: Option<&Self>,
+            _: (),
+            _: &dyn crate::Clock,
+        ) -> Result<Vec<i64>, CounterError> {
+            Ok(vec![])
+        }
+
+        fn from_events(events: &[i64]) -> Option<Self> {
+            Some(Counter {
+                count: events.iter().sum(),
+            })
+        }
+    }
+
+    #[derive(Default)]
+    struct InMemorySnapshotStore {
+        snapshot: RefCell<Option<AggregateSnapshot>>,
+    }
+
+    impl SnapshotStoreTrait for InMemorySnapshotStore {
+        fn init_schema(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn latest(&self) -> Result<Option<AggregateSnapshot>> {
+            Ok(self.snapshot.borrow().clone())
+        }
+
+        fn save(&self, snapshot: AggregateSnapshot) -> Result<()> {
+            *self.snapshot.borrow_mut() = Some(snapshot);
+            Ok(())
+        }
+
+        fn clear(&self) -> Result<()> {
+            *self.snapshot.borrow_mut() = None;
+            Ok(())
+        }
+    }
+
+    fn snapshotter() -> (Rc<InMemorySnapshotStore>, Snapshotter) {
+        let store = Rc::new(InMemorySnapshotStore::default());
+        (store.clone(), Snapshotter::new(store, 10))
+    }
+
+    #[test]
+    fn records_only_when_crossing_interval() {
+        let (store, snapshotter) = snapshotter();
+
+        snapshotter.record(&Counter { count: 1 }, 0, 9);
+        assert!(store.latest().unwrap().is_none());
+
+        snapshotter.record(&Counter { count: 2 }, 9, 12);
+        let snapshot = store.latest().unwrap().unwrap();
+        assert_eq!(snapshot.sequence, 12);
+        assert_eq!(snapshot.schema_version, 2);
+    }
+
+    #[test]
+    fn restores_latest_snapshot() {
+        let (_, snapshotter) = snapshotter();
+        snapshotter.record(&Counter { count: 5 }, 0, 10);
+
+        let restored = snapshotter.restore::<Counter>().unwrap();
+
+        assert_eq!(restored, Some((Counter { count: 5 }, 10)));
+    }
+
+    #[test]
+    fn discards_snapshot_with_outdated_schema_version() {
+        let (store, snapshotter) = snapshotter();
+        store
+            .save(AggregateSnapshot {
+                sequence: 10,
+                aggregate_data: r#"{"count":5}"#.to_string(),
+                schema_version: 1,
+            })
+            .unwrap();
+
+        let restored = snapshotter.restore::<Counter>().unwrap();
+
+        assert!(restored.is_none());
+        assert!(store.latest().unwrap().is_none());
+    }
+}
@@ -0,0 +1,398 @@
+use std::rc::Rc;
+
+use anyhow::{Context, Result, anyhow, bail};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::{
+    CheckpointRepository, Clock, EffectLedgerTrait, EventStoreTrait, StoredEventRow, SystemClock,
+    verify_stream,
+};
+
+pub const STREAM_EXPORT_FORMAT_VERSION: i64 = 1;
+
+/// First line of an export, describing the rows that follow.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StreamExportHeader {
+    pub format_version: i64,
+    pub exported_at: DateTime<Utc>,
+    pub event_count: i64,
+    pub last_sequence: i64,
+    /// Hash of the last exported row; the rows' own hashes chain back from it.
+    pub head_hash: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum ExportLine {
+    Header(StreamExportHeader),
+    Event(StoredEventRow),
+}
+
+/// Rows read from the store for each chunk of an export.
+const EXPORT_PAGE_SIZE: i64 = 500;
+
+fn export_line(line: ExportLine) -> Result<String> {
+    serde_json::to_string(&line)
+        .map(|json| json + "\n")
+        .context("Failed to serialize export line")
+}
+
+/// Newline-delimited JSON export of a stream, read from the store a page at
+/// a time: the header line first, then one chunk of event lines per page,
+/// in sequence order. Sequences run from 1 without gaps, so the stream
+/// version doubles as the event count.
+pub struct StreamExport {
+    event_store: Rc<dyn EventStoreTrait>,
+    header: Option<StreamExportHeader>,
+    after: i64,
+    last_sequence: i64,
+    page_size: i64,
+}
+
+impl StreamExport {
+    /// Fixes the export's head at the current end of the stream, so events
+    /// appended while the export is being read are left out of it.
+    pub fn new(event_store: Rc<dyn EventStoreTrait>, exported_at: DateTime<Utc>) -> Result<Self> {
+        let last_sequence = event_store.stream_version()?;
+        let head_hash = event_store
+            .raw_events_since(last_sequence - 1, 1)
+            .context("Failed to load export head")?
+            .pop()
+            .and_then(|row| row.hash);
+
+        Ok(Self {
+            event_store,
+            header: Some(StreamExportHeader {
+                format_version: STREAM_EXPORT_FORMAT_VERSION,
+                exported_at,
+                event_count: last_sequence,
+                last_sequence,
+                head_hash,
+            }),
+            after: 0,
+            last_sequence,
+            page_size: EXPORT_PAGE_SIZE,
+        })
+    }
+
+    fn next_page(&mut self) -> Result<String> {
+        let limit = self.page_size.min(self.last_sequence - self.after);
+        let rows = self
+            .event_store
+            .raw_events_since(self.after, limit)
+            .context("Failed to load events for export")?;
+        let Some(last) = rows.last() else {
+            bail!(
+                "Event stream ended at sequence {} before the export head {}",
+                self.after,
+                self.last_sequence
+            );
+        };
+        self.after = last.sequence;
+
+        rows.into_iter()
+            .map(|row| export_line(ExportLine::Event(row)))
+            .collect()
+    }
+}
+
+impl Iterator for StreamExport {
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(header) = self.header.take() {
+            return Some(export_line(ExportLine::Header(header)));
+        }
+        if self.after >= self.last_sequence {
+            return None;
+        }
+
+        let page = self.next_page();
+        if page.is_err() {
+            self.after = self.last_sequence;
+        }
+        Some(page)
+    }
+}
+
+/// Parses an export produced by [`StreamExport`] and checks it is complete
+/// and untampered: the header matches the rows, sequences run from 1 without
+/// gaps, and the hash chain verifies up to the header's head hash.
+pub fn parse_stream_export(ndjson: &str) -> Result<(StreamExportHeader, Vec<StoredEventRow>)> {
+    let mut lines = ndjson
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str::<ExportLine>(line)
+                .with_context(|| format!("Invalid export line {}", index + 1))
+        });
+
+    let header = match lines.next().transpose()? {
+        Some(ExportLine::Header(header)) => header,
+        _ => bail!("Export must start with a header line"),
+    };
+    if header.format_version != STREAM_EXPORT_FORMAT_VERSION {
+        bail!(
+            "Unsupported export format version {}",
+            header.format_version
+        );
+    }
+
+    let mut rows = Vec::new();
+    for line in lines {
+        match line? {
+            ExportLine::Event(row) => {
+                let expected_sequence = rows.len() as i64 + 1;
+                if row.sequence != expected_sequence {
+                    bail!(
+                        "Sequence gap in export: expected {expected_sequence}, found {}",
+                        row.sequence
+                    );
+                }
+                rows.push(row);
+            }
+            ExportLine::Header(_) => bail!("Export contains more than one header"),
+        }
+    }
+
+    if rows.len() as i64 != header.event_count {
+        bail!(
+            "Export header lists {} events but {} were found",
+            header.event_count,
+            rows.len()
+        );
+    }
+
+    let verification = verify_stream(&rows);
+    if let Some(link) = verification.first_broken_link {
+        bail!(
+            "Export hash chain is broken at sequence {}: {:?}",
+            link.sequence,
+            link.reason
+        );
+    }
+    if verification.head_hash != header.head_hash {
+        bail!("Export head hash does not match its header");
+    }
+
+    Ok((header, rows))
+}
+
+/// Moves a whole event stream in and out of a store as NDJSON, for backups
+/// and for rebuilding an aggregate in a fresh Durable Object.
+pub struct EventStreamTransfer {
+    event_store: Rc<dyn EventStoreTrait>,
+    effect_ledger: Rc<dyn EffectLedgerTrait>,
+    checkpoint_repository: Option<Box<dyn CheckpointRepository>>,
+    clock: Rc<dyn Clock>,
+}
+
+impl EventStreamTransfer {
+    pub fn new(
+        event_store: Rc<dyn EventStoreTrait>,
+        effect_ledger: Rc<dyn EffectLedgerTrait>,
+    ) -> Self {
+        Self {
+            event_store,
+            effect_ledger,
+            checkpoint_repository: None,
+            clock: Rc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Resets every projection checkpoint after an import so the projectors
+    /// replay the imported stream.
+    pub fn with_checkpoints(
+        mut self,
+        checkpoint_repository: Box<dyn CheckpointRepository>,
+    ) -> Self {
+        self.checkpoint_repository = Some(checkpoint_repository);
+        self
+    }
+
+    pub fn export(&self) -> Result<StreamExport> {
+        StreamExport::new(self.event_store.clone(), self.clock.now())
+    }
+
+    /// Validates `ndjson` and writes its rows into the (empty) event store.
+    /// The effect ledger is moved past the imported events, since their
+    /// effects already ran where the stream was exported from.
+    pub fn import(&self, ndjson: &str) -> Result<StreamExportHeader> {
+        let (header, rows) = parse_stream_export(ndjson)?;
+
+        self.event_store
+            .import_rows(rows)
+            .context("Failed to import events")?;
+        self.effect_ledger
+            .acknowledge_through(header.last_sequence)
+            .context("Failed to acknowledge imported events in effect ledger")?;
+
+        if let Some(checkpoint_repository) = &self.checkpoint_repository {
+            for checkpoint in checkpoint_repository.get_all_checkpoints()? {
+                checkpoint_repository
+                    .reset_checkpoint(&checkpoint.projector_name)
+                    .map_err(|err| {
+                        anyhow!(
+                            "Failed to reset checkpoint {}: {err}",
+                            checkpoint.projector_name
+                        )
+                    })?;
+            }
+        }
+
+        info!(
+            event_count = header.event_count,
+            last_sequence = header.last_sequence,
+            "Imported event stream"
+        );
+        Ok(header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        StoredEvent,
+        testing::{InMemoryEffectLedger, InMemoryEventStore},
+    };
+
+    fn stored_event(payload: &str) -> StoredEvent {
+        StoredEvent {
+            aggregate_id: "aggregate".to_string(),
+            event_type: "Noted".to_string(),
+            event_data: payload.to_string(),
+            event_version: 1,
+            correlation_id: None,
+            causation_id: None,
+        }
+    }
+
+    fn transfer() -> (
+        Rc<InMemoryEventStore>,
+        Rc<InMemoryEffectLedger>,
+        EventStreamTransfer,
+    ) {
+        let event_store = Rc::new(InMemoryEventStore::new());
+        let ledger = Rc::new(InMemoryEffectLedger::new());
+        let transfer = EventStreamTransfer::new(event_store.clone(), ledger.clone());
+        (event_store, ledger, transfer)
+    }
+
+    fn exported_stream() -> String {
+        let (source, _, transfer) = transfer();
+        source
+            .append_batch(
+                vec![stored_event("{\"n\":1}"), stored_event("{\"n\":2}")],
+                "user".into(),
+                0,
+            )
+            .unwrap();
+        transfer
+            .export()
+            .unwrap()
+            .collect::<Result<String>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn round_trips_into_an_empty_store() {
+        let ndjson = exported_stream();
+        let (target, ledger, transfer) = transfer();
+
+        let header = transfer.import(&ndjson).unwrap();
+
+        assert_eq!(header.event_count, 2);
+        assert_eq!(ndjson.lines().count(), 3);
+        assert!(target.verify_chain().unwrap().is_intact());
+        assert_eq!(target.rows()[1].event_data, "{\"n\":2}");
+        assert_eq!(ledger.get_last_processed_sequence().unwrap(), 2);
+        let reexported = transfer
+            .export()
+            .unwrap()
+            .collect::<Result<String>>()
+            .unwrap();
+        let event_lines = |export: &str| {
+            export
+                .lines()
+                .skip(1)
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(event_lines(&reexported), event_lines(&ndjson));
+    }
+
+    #[test]
+    fn exports_a_page_per_chunk_up_to_the_head_at_export_time() {
+        let (source, _, transfer) = transfer();
+        source
+            .append_batch(
+                (1..=3)
+                    .map(|n| stored_event(&format!("{{\"n\":{n}}}")))
+                    .collect(),
+                "user".into(),
+                0,
+            )
+            .unwrap();
+
+        let mut export = transfer.export().unwrap();
+        export.page_size = 2;
+        source
+            .append_batch(vec![stored_event("{\"n\":4}")], "user".into(), 3)
+            .unwrap();
+        let chunks = export.collect::<Result<Vec<String>>>().unwrap();
+
+        assert_eq!(
+            chunks
+                .iter()
+                .map(|chunk| chunk.lines().count())
+                .collect::<Vec<_>>(),
+            vec![1, 2, 1]
+        );
+        let (header, rows) = parse_stream_export(&chunks.concat()).unwrap();
+        assert_eq!(header.event_count, 3);
+        assert_eq!(rows.last().unwrap().event_data, "{\"n\":3}");
+    }
+
+    #[test]
+    fn rejects_missing_and_tampered_events() {
+        let ndjson = exported_stream();
+        let lines: Vec<&str> = ndjson.lines().collect();
+
+        let missing = [lines[0], lines[2]].join("\n");
+        assert!(
+            parse_stream_export(&missing)
+                .unwrap_err()
+                .to_string()
+                .contains("Sequence gap")
+        );
+
+        let tampered = ndjson.replace("{\\\"n\\\":2}", "{\\\"n\\\":3}");
+        assert_ne!(tampered, ndjson);
+        assert!(
+            parse_stream_export(&tampered)
+                .unwrap_err()
+                .to_string()
+                .contains("hash chain is broken")
+        );
+    }
+
+    #[test]
+    fn refuses_to_import_into_a_populated_store() {
+        let ndjson = exported_stream();
+        let (target, _, transfer) = transfer();
+        target
+            .append_batch(vec![stored_event("{}")], "user".into(), 0)
+            .unwrap();
+
+        assert!(transfer.import(&ndjson).is_err());
+    }
+}
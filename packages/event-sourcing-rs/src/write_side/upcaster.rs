@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result, anyhow, bail};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::{AppendResult, EventStoreTrait, EventTypeRegistry, StoredEvent, StoredEventRow};
+
+/// Migrates the serialized payload of an event from one version to the next.
+pub type Upcaster = fn(Value) -> Value;
+
+/// Upcasters keyed on `(event_type, from_version)`. Each upcaster moves an
+/// event forward by exactly one version, so a stored event is migrated by
+/// chaining them until it reaches the latest registered version.
+#[derive(Clone, Default)]
+pub struct UpcasterRegistry {
+    upcasters: HashMap<(String, i64), Upcaster>,
+    latest_versions: HashMap<String, i64>,
+}
+
+impl UpcasterRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(mut self, event_type: &str, from_version: i64, upcaster: Upcaster) -> Self {
+        self.upcasters
+            .insert((event_type.to_string(), from_version), upcaster);
+
+        let latest = self
+            .latest_versions
+            .entry(event_type.to_string())
+            .or_insert(from_version + 1);
+        *latest = (*latest).max(from_version + 1);
+        self
+    }
+
+    pub fn latest_version(&self, event_type: &str) -> Option<i64> {
+        self.latest_versions.get(event_type).copied()
+    }
+
+    pub fn upcast(&self, row: StoredEventRow) -> Result<StoredEventRow> {
+        let Some(target_version) = self.latest_version(&row.event_type) else {
+            return Ok(row);
+        };
+        if row.event_version >= target_version {
+            return Ok(row);
+        }
+
+        let mut value: Value = serde_json::from_str(&row.event_data).with_context(|| {
+            format!(
+                "Failed to parse {} v{} at sequence {} for upcasting",
+                row.event_type, row.event_version, row.sequence
+            )
+        })?;
+
+        let mut version = row.event_version;
+        while version < target_version {
+            let upcaster = self
+                .upcasters
+                .get(&(row.event_type.clone(), version))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "No upcaster registered for {} v{} at sequence {}",
+                        row.event_type,
+                        version,
+                        row.sequence
+                    )
+                })?;
+            value = upcaster(value);
+            version += 1;
+        }
+
+        Ok(StoredEventRow {
+            event_data: serde_json::to_string(&value)
+                .context("Failed to serialize upcasted event")?,
+            event_version: version,
+            ..row
+        })
+    }
+
+    pub fn upcast_all(&self, rows: Vec<StoredEventRow>) -> Result<Vec<StoredEventRow>> {
+        rows.into_iter().map(|row| self.upcast(row)).collect()
+    }
+
+    /// Checks the registry against the event types of `E`: every upcaster
+    /// must target a known event type, and every event type above v1 must
+    /// have an unbroken chain of upcasters from v1 to its current version.
+    pub fn validate<E: EventTypeRegistry>(&self) -> Result<()> {
+        for (event_type, from_version) in self.upcasters.keys() {
+            let info = E::event_type_info(event_type).ok_or_else(|| {
+                anyhow!("Upcaster registered for unknown event type {event_type}")
+            })?;
+            if *from_version >= info.version {
+                bail!(
+                    "Upcaster for {event_type} v{from_version} is at or beyond the current version v{}",
+                    info.version
+                );
+            }
+        }
+
+        for info in E::EVENT_TYPES {
+            if let Some(missing) = (1..info.version).find(|version| {
+                !self
+                    .upcasters
+                    .contains_key(&(info.event_type.to_string(), *version))
+            }) {
+                bail!(
+                    "{} is v{} but has no upcaster from v{missing}",
+                    info.event_type,
+                    info.version
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Event store decorator that upcasts every row it reads, so repositories,
+/// projectors, process managers and broadcasters only ever see the latest
+/// version of each event.
+pub struct UpcastingEventStore<S: EventStoreTrait> {
+    inner: S,
+    registry: UpcasterRegistry,
+}
+
+impl<S: EventStoreTrait> UpcastingEventStore<S> {
+    pub fn new(inner: S, registry: UpcasterRegistry) -> Self {
+        Self { inner, registry }
+    }
+}
+
+#[async_trait(?Send)]
+impl<S: EventStoreTrait> EventStoreTrait for UpcastingEventStore<S> {
+    fn init_schema(&self) -> Result<()> {
+        self.inner.init_schema()
+    }
+
+    fn append_batch(
+        &self,
+        events: Vec<StoredEvent>,
+        user_id: String,
+        expected_version: i64,
+    ) -> Result<Vec<AppendResult>> {
+        self.inner.append_batch(events, user_id, expected_version)
+    }
+
+    fn load(&self) -> Result<Vec<StoredEventRow>> {
+        self.registry.upcast_all(self.inner.load()?)
+    }
+
+    fn events_since(&self, sequence: i64, limit: i64) -> Result<Vec<StoredEventRow>> {
+        self.registry
+            .upcast_all(self.inner.events_since(sequence, limit)?)
+    }
+
+    fn events_since_filtered(
+        &self,
+        sequence: i64,
+        limit: i64,
+        event_types: &[&str],
+    ) -> Result<Vec<StoredEventRow>> {
+        self.registry.upcast_all(
+            self.inner
+                .events_since_filtered(sequence, limit, event_types)?,
+        )
+    }
+
+    fn events_until_sequence(&self, sequence: i64) -> Result<Vec<StoredEventRow>> {
+        self.registry
+            .upcast_all(self.inner.events_until_sequence(sequence)?)
+    }
+
+    fn events_until_time(&self, timestamp: DateTime<Utc>) -> Result<Vec<StoredEventRow>> {
+        self.registry
+            .upcast_all(self.inner.events_until_time(timestamp)?)
+    }
+
+    fn events_since_time(&self, timestamp: DateTime<Utc>) -> Result<Vec<StoredEventRow>> {
+        self.registry
+            .upcast_all(self.inner.events_since_time(timestamp)?)
+    }
+
+    fn max_sequence(&self) -> Result<Option<i64>> {
+        self.inner.max_sequence()
+    }
+
+    fn stream_version(&self) -> Result<i64> {
+        self.inner.stream_version()
+    }
+
+    fn load_raw(&self) -> Result<Vec<StoredEventRow>> {
+        self.inner.load_raw()
+    }
+
+    fn raw_events_since(&self, sequence: i64, limit: i64) -> Result<Vec<StoredEventRow>> {
+        self.inner.raw_events_since(sequence, limit)
+    }
+
+    fn import_rows(&self, rows: Vec<StoredEventRow>) -> Result<()> {
+        self.inner.import_rows(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn row(event_version: i64, event_data: Value) -> StoredEventRow {
+        StoredEventRow {
+            sequence: 1,
+            aggregate_id: "aggregate".to_string(),
+            event_type: "LabourPlanned".to_string(),
+            event_data: event_data.to_string(),
+            event_version,
+            created_at: "2026-01-01 00:00:00".to_string(),
+            user_id: "user".to_string(),
+            correlation_id: None,
+            causation_id: None,
+            hash: None,
+        }
+    }
+
+    fn add_field(mut value: Value) -> Value {
+        value["data"]["mother_name"] = json!("Unknown");
+        value
+    }
+
+    fn rename_field(mut value: Value) -> Value {
+        let name = value["data"]["mother_name"].take();
+        value["data"]["display_name"] = name;
+        value
+    }
+
+    #[test]
+    fn chains_upcasters_to_latest_version() {
+        let registry = UpcasterRegistry::new()
+            .register("LabourPlanned", 1, add_field)
+            .register("LabourPlanned", 2, rename_field);
+
+        let upcasted = registry
+            .upcast(row(1, json!({"type": "LabourPlanned", "data": {}})))
+            .unwrap();
+
+        assert_eq!(upcasted.event_version, 3);
+        let value: Value = serde_json::from_str(&upcasted.event_data).unwrap();
+        assert_eq!(value["data"]["display_name"], json!("Unknown"));
+    }
+
+    #[test]
+    fn leaves_current_and_unregistered_events_untouched() {
+        let registry = UpcasterRegistry::new().register("LabourPlanned", 1, add_field);
+        let current = row(2, json!({"data": {}}));
+
+        let upcasted = registry.upcast(current.clone()).unwrap();
+        assert_eq!(upcasted.event_data, current.event_data);
+
+        let other = StoredEventRow {
+            event_type: "LabourBegun".to_string(),
+            ..row(1, json!({"data": {}}))
+        };
+        assert_eq!(registry.upcast(other).unwrap().event_version, 1);
+    }
+
+    #[test]
+    fn missing_upcaster_returns_error() {
+        let registry = UpcasterRegistry::new().register("LabourPlanned", 2, rename_field);
+
+        let result = registry.upcast(row(1, json!({"data": {}})));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn invalid_payload_returns_error() {
+        let registry = UpcasterRegistry::new().register("LabourPlanned", 1, add_field);
+        let invalid = StoredEventRow {
+            event_data: "not json".to_string(),
+            ..row(1, json!({}))
+        };
+
+        assert!(registry.upcast(invalid).is_err());
+    }
+
+    #[test]
+    fn validates_upcaster_chains_against_event_versions() {
+        use crate::EventTypeInfo;
+
+        struct Events;
+        impl EventTypeRegistry for Events {
+            const EVENT_TYPES: &'static [EventTypeInfo] = &[EventTypeInfo {
+                event_type: "LabourPlanned",
+                version: 3,
+            }];
+        }
+
+        assert!(UpcasterRegistry::new().validate::<Events>().is_err());
+        assert!(
+            UpcasterRegistry::new()
+                .register("LabourPlanned", 1, add_field)
+                .validate::<Events>()
+                .is_err()
+        );
+        assert!(
+            UpcasterRegistry::new()
+                .register("LabourPlanned", 1, add_field)
+                .register("LabourPlanned", 2, rename_field)
+                .validate::<Events>()
+                .is_ok()
+        );
+        assert!(
+            UpcasterRegistry::new()
+                .register("LabourPlanned", 1, add_field)
+                .register("LabourPlanned", 2, rename_field)
+                .register("LabourBegun", 1, add_field)
+                .validate::<Events>()
+                .is_err()
+        );
+    }
+}
@@ -4,19 +4,27 @@ use uuid::Uuid;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", content = "payload")]
 pub enum AdminCommand {
-    RebuildReadModels { aggregate_id: Uuid },
+    RebuildReadModels {
+        aggregate_id: Uuid,
+    },
+    RebuildProjections {
+        aggregate_id: Uuid,
+        projectors: Vec<String>,
+    },
 }
 
 impl AdminCommand {
     pub fn labour_id(&self) -> Uuid {
         match self {
             AdminCommand::RebuildReadModels { aggregate_id } => *aggregate_id,
+            AdminCommand::RebuildProjections { aggregate_id, .. } => *aggregate_id,
         }
     }
 
     pub fn command_name(&self) -> &'static str {
         match self {
             AdminCommand::RebuildReadModels { .. } => "AdminCommand::RebuildReadModels",
+            AdminCommand::RebuildProjections { .. } => "AdminCommand::RebuildProjections",
         }
     }
 }
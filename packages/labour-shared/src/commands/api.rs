@@ -2,8 +2,8 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
-    AdminCommand, ContractionCommand, LabourCommand, LabourUpdateCommand, SubscriberCommand,
-    SubscriptionCommand,
+    AdminCommand, ContractionCommand, LabourCommand, LabourUpdateCommand, MilestoneCommand,
+    SubscriberCommand, SubscriptionCommand,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +13,7 @@ pub enum ApiCommand {
     Contraction(ContractionCommand),
     Labour(LabourCommand),
     LabourUpdate(LabourUpdateCommand),
+    Milestone(MilestoneCommand),
     Subscriber(SubscriberCommand),
     Subscription(SubscriptionCommand),
 }
@@ -24,6 +25,7 @@ impl ApiCommand {
             Self::Contraction(cmd) => cmd.labour_id(),
             Self::Labour(cmd) => cmd.labour_id(),
             Self::LabourUpdate(cmd) => cmd.labour_id(),
+            Self::Milestone(cmd) => cmd.labour_id(),
             Self::Subscriber(cmd) => cmd.labour_id(),
             Self::Subscription(cmd) => cmd.labour_id(),
         }
@@ -0,0 +1,206 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum::{EnumString, VariantNames};
+use uuid::Uuid;
+
+use crate::{ApiCommand, ContractionCommand, MilestoneCommand};
+
+pub const MAX_BATCH_COMMANDS: usize = 100;
+
+/// Commands captured on a device, often while offline, submitted together
+/// once it reconnects. They are applied in order and each gets its own
+/// result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandBatch {
+    pub labour_id: Uuid,
+    pub commands: Vec<BatchedCommand>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchedCommand {
+    /// Chosen by the client and stable across resubmissions, so a batch can
+    /// safely be sent again if the response is lost.
+    pub client_id: String,
+    /// When the user acted, by the device's clock.
+    pub device_timestamp: DateTime<Utc>,
+    pub command: ApiCommand,
+}
+
+impl BatchedCommand {
+    /// The command with any time it left to the server filled in from the
+    /// device timestamp, so offline contractions keep the time they were
+    /// tapped rather than the time they were synced. Device clocks running
+    /// ahead of `now` are clamped to it.
+    pub fn into_command_at(self, now: DateTime<Utc>) -> ApiCommand {
+        let device_time = self.device_timestamp.min(now);
+
+        match self.command {
+            ApiCommand::Contraction(ContractionCommand::StartContraction {
+                labour_id,
+                contraction_id,
+                start_time,
+            }) => ApiCommand::Contraction(ContractionCommand::StartContraction {
+                labour_id,
+                contraction_id,
+                start_time: Some(start_time.unwrap_or(device_time)),
+            }),
+            ApiCommand::Contraction(ContractionCommand::EndContraction {
+                labour_id,
+                contraction_id,
+                end_time,
+                intensity,
+            }) => ApiCommand::Contraction(ContractionCommand::EndContraction {
+                labour_id,
+                contraction_id,
+                end_time: Some(end_time.unwrap_or(device_time)),
+                intensity,
+            }),
+            ApiCommand::Milestone(MilestoneCommand::RecordMilestone {
+                labour_id,
+                milestone_type,
+                occurred_at,
+                notes,
+                notify_subscribers,
+            }) => ApiCommand::Milestone(MilestoneCommand::RecordMilestone {
+                labour_id,
+                milestone_type,
+                occurred_at: Some(occurred_at.unwrap_or(device_time)),
+                notes,
+                notify_subscribers,
+            }),
+            command => command,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, EnumString, VariantNames, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum BatchCommandStatus {
+    #[strum(serialize = "APPLIED", serialize = "applied")]
+    APPLIED,
+    /// The command was valid but lost to something already recorded, such
+    /// as a contraction a birth partner logged over the same period.
+    #[strum(serialize = "CONFLICT", serialize = "conflict")]
+    CONFLICT,
+    #[strum(serialize = "REJECTED", serialize = "rejected")]
+    REJECTED,
+    /// Not attempted because an earlier command in the batch it depends on
+    /// did not apply.
+    #[strum(serialize = "SKIPPED", serialize = "skipped")]
+    SKIPPED,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchCommandResult {
+    pub client_id: String,
+    pub status: BatchCommandStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The recorded contraction a conflicting one overlapped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflicting_contraction_id: Option<Uuid>,
+}
+
+impl BatchCommandResult {
+    pub fn applied(client_id: String) -> Self {
+        Self {
+            client_id,
+            status: BatchCommandStatus::APPLIED,
+            error: None,
+            conflicting_contraction_id: None,
+        }
+    }
+
+    pub fn conflict(client_id: String, conflicting_contraction_id: Uuid) -> Self {
+        Self {
+            client_id,
+            status: BatchCommandStatus::CONFLICT,
+            error: Some("Overlaps a contraction that has already been recorded".to_string()),
+            conflicting_contraction_id: Some(conflicting_contraction_id),
+        }
+    }
+
+    pub fn rejected(client_id: String, error: String) -> Self {
+        Self {
+            client_id,
+            status: BatchCommandStatus::REJECTED,
+            error: Some(error),
+            conflicting_contraction_id: None,
+        }
+    }
+
+    pub fn skipped(client_id: String) -> Self {
+        Self {
+            client_id,
+            status: BatchCommandStatus::SKIPPED,
+            error: Some("An earlier command in the batch did not apply".to_string()),
+            conflicting_contraction_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandBatchResult {
+    pub results: Vec<BatchCommandResult>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn start_contraction(start_time: Option<DateTime<Utc>>) -> ApiCommand {
+        ApiCommand::Contraction(ContractionCommand::StartContraction {
+            labour_id: Uuid::nil(),
+            contraction_id: Uuid::nil(),
+            start_time,
+        })
+    }
+
+    fn start_time_of(command: ApiCommand) -> Option<DateTime<Utc>> {
+        match command {
+            ApiCommand::Contraction(ContractionCommand::StartContraction {
+                start_time, ..
+            }) => start_time,
+            _ => panic!("expected StartContraction"),
+        }
+    }
+
+    #[test]
+    fn missing_times_are_taken_from_the_device() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let tapped = now - Duration::minutes(20);
+        let batched = BatchedCommand {
+            client_id: "1".to_string(),
+            device_timestamp: tapped,
+            command: start_contraction(None),
+        };
+
+        assert_eq!(start_time_of(batched.into_command_at(now)), Some(tapped));
+    }
+
+    #[test]
+    fn device_clocks_ahead_of_the_server_are_clamped() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let batched = BatchedCommand {
+            client_id: "1".to_string(),
+            device_timestamp: now + Duration::minutes(5),
+            command: start_contraction(None),
+        };
+
+        assert_eq!(start_time_of(batched.into_command_at(now)), Some(now));
+    }
+
+    #[test]
+    fn explicit_times_are_kept() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let explicit = now - Duration::hours(1);
+        let batched = BatchedCommand {
+            client_id: "1".to_string(),
+            device_timestamp: now - Duration::minutes(1),
+            command: start_contraction(Some(explicit)),
+        };
+
+        assert_eq!(start_time_of(batched.into_command_at(now)), Some(explicit));
+    }
+}
@@ -2,6 +2,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::value_objects::{Baby, PhaseProgressionPolicy};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", content = "payload")]
 pub enum LabourCommand {
@@ -38,6 +40,37 @@ pub enum LabourCommand {
     DeleteLabour {
         labour_id: Uuid,
     },
+
+    /// Replaces the labour's phase progression policy. `None` restores the
+    /// preset for a first or subsequent labour.
+    UpdatePhaseProgressionPolicy {
+        labour_id: Uuid,
+        policy: Option<PhaseProgressionPolicy>,
+    },
+
+    /// Records the birth details. They stay private to the mother until
+    /// `PublishBirthAnnouncement` is sent.
+    RecordBirth {
+        labour_id: Uuid,
+        time_of_birth: DateTime<Utc>,
+        babies: Vec<Baby>,
+    },
+
+    AmendBirthRecord {
+        labour_id: Uuid,
+        time_of_birth: Option<DateTime<Utc>>,
+        babies: Option<Vec<Baby>>,
+    },
+
+    PublishBirthAnnouncement {
+        labour_id: Uuid,
+    },
+
+    /// Takes back the sender's most recent contraction end, update or
+    /// subscriber approval, if it was made in the last few minutes.
+    UndoLastAction {
+        labour_id: Uuid,
+    },
 }
 
 impl LabourCommand {
@@ -49,6 +82,11 @@ impl LabourCommand {
             LabourCommand::CompleteLabour { labour_id, .. } => *labour_id,
             LabourCommand::SendLabourInvite { labour_id, .. } => *labour_id,
             LabourCommand::DeleteLabour { labour_id, .. } => *labour_id,
+            LabourCommand::UpdatePhaseProgressionPolicy { labour_id, .. } => *labour_id,
+            LabourCommand::RecordBirth { labour_id, .. } => *labour_id,
+            LabourCommand::AmendBirthRecord { labour_id, .. } => *labour_id,
+            LabourCommand::PublishBirthAnnouncement { labour_id, .. } => *labour_id,
+            LabourCommand::UndoLastAction { labour_id, .. } => *labour_id,
         }
     }
 }
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::value_objects::LabourUpdateType;
+use crate::value_objects::{LabourUpdateTrigger, LabourUpdateType};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", content = "payload")]
@@ -28,6 +28,19 @@ pub enum LabourUpdateCommand {
         labour_id: Uuid,
         labour_update_id: Uuid,
     },
+
+    /// Prepares an update that is posted when `trigger` fires.
+    ScheduleLabourUpdate {
+        labour_id: Uuid,
+        labour_update_type: LabourUpdateType,
+        message: String,
+        trigger: LabourUpdateTrigger,
+    },
+
+    CancelScheduledLabourUpdate {
+        labour_id: Uuid,
+        scheduled_update_id: Uuid,
+    },
 }
 
 impl LabourUpdateCommand {
@@ -37,6 +50,8 @@ impl LabourUpdateCommand {
             LabourUpdateCommand::UpdateLabourUpdateMessage { labour_id, .. } => *labour_id,
             LabourUpdateCommand::UpdateLabourUpdateType { labour_id, .. } => *labour_id,
             LabourUpdateCommand::DeleteLabourUpdate { labour_id, .. } => *labour_id,
+            LabourUpdateCommand::ScheduleLabourUpdate { labour_id, .. } => *labour_id,
+            LabourUpdateCommand::CancelScheduledLabourUpdate { labour_id, .. } => *labour_id,
         }
     }
 }
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::value_objects::LabourMilestoneType;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "payload")]
+pub enum MilestoneCommand {
+    /// Subscribers are only told about the milestone when
+    /// `notify_subscribers` is set.
+    RecordMilestone {
+        labour_id: Uuid,
+        milestone_type: LabourMilestoneType,
+        occurred_at: Option<DateTime<Utc>>,
+        notes: Option<String>,
+        notify_subscribers: bool,
+    },
+
+    UpdateMilestone {
+        labour_id: Uuid,
+        milestone_id: Uuid,
+        occurred_at: Option<DateTime<Utc>>,
+        notes: Option<String>,
+    },
+
+    DeleteMilestone {
+        labour_id: Uuid,
+        milestone_id: Uuid,
+    },
+}
+
+impl MilestoneCommand {
+    pub fn labour_id(&self) -> Uuid {
+        match self {
+            MilestoneCommand::RecordMilestone { labour_id, .. } => *labour_id,
+            MilestoneCommand::UpdateMilestone { labour_id, .. } => *labour_id,
+            MilestoneCommand::DeleteMilestone { labour_id, .. } => *labour_id,
+        }
+    }
+}
@@ -1,8 +1,10 @@
 pub mod admin;
 pub mod api;
+pub mod batch;
 pub mod checkout;
 pub mod contraction;
 pub mod labour;
 pub mod labour_update;
+pub mod milestone;
 pub mod subscriber;
 pub mod subscription;
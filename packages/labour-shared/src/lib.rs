@@ -3,12 +3,12 @@ pub mod queries;
 pub mod value_objects;
 
 pub use commands::{
-    admin::AdminCommand, api::ApiCommand, contraction::ContractionCommand, labour::LabourCommand,
-    labour_update::LabourUpdateCommand, subscriber::SubscriberCommand,
-    subscription::SubscriptionCommand,
+    admin::AdminCommand, api::ApiCommand, batch::CommandBatch, contraction::ContractionCommand,
+    labour::LabourCommand, labour_update::LabourUpdateCommand, milestone::MilestoneCommand,
+    subscriber::SubscriberCommand, subscription::SubscriptionCommand,
 };
 
 pub use queries::{
-    api::ApiQuery, contraction::ContractionQuery, cursor::Cursor, labour::LabourQuery,
-    labour_update::LabourUpdateQuery,
+    admin::AdminQuery, api::ApiQuery, contraction::ContractionQuery, cursor::Cursor,
+    labour::LabourQuery, labour_update::LabourUpdateQuery, milestone::MilestoneQuery,
 };
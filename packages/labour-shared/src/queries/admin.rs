@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "payload")]
+pub enum AdminQuery {
+    #[serde(rename = "GetLabourAtSequence")]
+    GetLabourAtSequence { labour_id: Uuid, sequence: i64 },
+
+    #[serde(rename = "GetLabourAtTime")]
+    GetLabourAtTime {
+        labour_id: Uuid,
+        timestamp: DateTime<Utc>,
+    },
+}
+
+impl AdminQuery {
+    pub fn labour_id(&self) -> Uuid {
+        match self {
+            AdminQuery::GetLabourAtSequence { labour_id, .. } => *labour_id,
+            AdminQuery::GetLabourAtTime { labour_id, .. } => *labour_id,
+        }
+    }
+}
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
-    ContractionQuery, LabourQuery, LabourUpdateQuery,
+    ContractionQuery, LabourQuery, LabourUpdateQuery, MilestoneQuery,
     queries::{subscription::SubscriptionQuery, user::UserQuery},
 };
 
@@ -18,6 +18,9 @@ pub enum ApiQuery {
     #[serde(rename = "LabourUpdate")]
     LabourUpdate(LabourUpdateQuery),
 
+    #[serde(rename = "Milestone")]
+    Milestone(MilestoneQuery),
+
     #[serde(rename = "Subscription")]
     Subscription(SubscriptionQuery),
 
@@ -31,6 +34,7 @@ impl ApiQuery {
             Self::Labour(query) => query.labour_id(),
             Self::Contraction(query) => query.labour_id(),
             Self::LabourUpdate(query) => query.labour_id(),
+            Self::Milestone(query) => query.labour_id(),
             Self::Subscription(query) => query.labour_id(),
             Self::User(query) => query.labour_id(),
         }
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::queries::cursor::Cursor;
+use crate::{queries::cursor::Cursor, value_objects::contraction::ContractionPatternRule};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", content = "payload")]
@@ -18,6 +18,13 @@ pub enum ContractionQuery {
         labour_id: Uuid,
         contraction_id: Uuid,
     },
+
+    #[serde(rename = "GetContractionStatistics")]
+    GetContractionStatistics {
+        labour_id: Uuid,
+        window_minutes: Option<i64>,
+        rule: Option<ContractionPatternRule>,
+    },
 }
 
 impl ContractionQuery {
@@ -25,6 +32,7 @@ impl ContractionQuery {
         match self {
             ContractionQuery::GetContractions { labour_id, .. } => *labour_id,
             ContractionQuery::GetContractionById { labour_id, .. } => *labour_id,
+            ContractionQuery::GetContractionStatistics { labour_id, .. } => *labour_id,
         }
     }
 }
@@ -6,12 +6,20 @@ use uuid::Uuid;
 pub enum LabourQuery {
     #[serde(rename = "GetLabour")]
     GetLabour { labour_id: Uuid },
+    /// The policy automatic phase changes currently follow.
+    #[serde(rename = "GetPhaseProgressionPolicy")]
+    GetPhaseProgressionPolicy { labour_id: Uuid },
+    /// Subscribers only see the birth record once it has been published.
+    #[serde(rename = "GetBirthRecord")]
+    GetBirthRecord { labour_id: Uuid },
 }
 
 impl LabourQuery {
     pub fn labour_id(&self) -> Uuid {
         match self {
             LabourQuery::GetLabour { labour_id } => *labour_id,
+            LabourQuery::GetPhaseProgressionPolicy { labour_id } => *labour_id,
+            LabourQuery::GetBirthRecord { labour_id } => *labour_id,
         }
     }
 }
@@ -18,6 +18,13 @@ pub enum LabourUpdateQuery {
         labour_id: Uuid,
         labour_update_id: Uuid,
     },
+
+    #[serde(rename = "GetScheduledLabourUpdates")]
+    GetScheduledLabourUpdates {
+        labour_id: Uuid,
+        limit: usize,
+        cursor: Option<Cursor>,
+    },
 }
 
 impl LabourUpdateQuery {
@@ -25,6 +32,7 @@ impl LabourUpdateQuery {
         match self {
             LabourUpdateQuery::GetLabourUpdates { labour_id, .. } => *labour_id,
             LabourUpdateQuery::GetLabourUpdateById { labour_id, .. } => *labour_id,
+            LabourUpdateQuery::GetScheduledLabourUpdates { labour_id, .. } => *labour_id,
         }
     }
 }
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::queries::cursor::Cursor;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "payload")]
+pub enum MilestoneQuery {
+    #[serde(rename = "GetMilestones")]
+    GetMilestones {
+        labour_id: Uuid,
+        limit: usize,
+        cursor: Option<Cursor>,
+    },
+
+    #[serde(rename = "GetMilestoneById")]
+    GetMilestoneById { labour_id: Uuid, milestone_id: Uuid },
+}
+
+impl MilestoneQuery {
+    pub fn labour_id(&self) -> Uuid {
+        match self {
+            MilestoneQuery::GetMilestones { labour_id, .. } => *labour_id,
+            MilestoneQuery::GetMilestoneById { labour_id, .. } => *labour_id,
+        }
+    }
+}
@@ -1,7 +1,9 @@
+pub mod admin;
 pub mod api;
 pub mod contraction;
 pub mod cursor;
 pub mod labour;
 pub mod labour_update;
+pub mod milestone;
 pub mod subscription;
 pub mod user;
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use crate::value_objects::birth::BabySex;
+
+/// One baby in a birth record. Everything is optional so families can share
+/// as much or as little as they like.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Baby {
+    pub name: Option<String>,
+    pub weight_grams: Option<u32>,
+    pub sex: Option<BabySex>,
+}
+
+impl Baby {
+    /// A short description for announcements, e.g. "Ada (girl, 3.40 kg)".
+    pub fn describe(&self) -> String {
+        let name = self.name.as_deref().unwrap_or("A baby");
+
+        let mut details = vec![];
+        match self.sex {
+            Some(BabySex::FEMALE) => details.push("girl".to_string()),
+            Some(BabySex::MALE) => details.push("boy".to_string()),
+            None => {}
+        }
+        if let Some(weight_grams) = self.weight_grams {
+            details.push(format!("{:.2} kg", weight_grams as f64 / 1000.0));
+        }
+
+        if details.is_empty() {
+            name.to_string()
+        } else {
+            format!("{name} ({})", details.join(", "))
+        }
+    }
+
+    /// Describes every baby in one phrase, e.g. "Ada (girl) and Max (boy)".
+    pub fn describe_all(babies: &[Baby]) -> String {
+        let descriptions: Vec<String> = babies.iter().map(Baby::describe).collect();
+        match descriptions.as_slice() {
+            [] => String::new(),
+            [only] => only.clone(),
+            [rest @ .., last] => format!("{} and {last}", rest.join(", ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baby(name: Option<&str>, weight_grams: Option<u32>, sex: Option<BabySex>) -> Baby {
+        Baby {
+            name: name.map(str::to_string),
+            weight_grams,
+            sex,
+        }
+    }
+
+    #[test]
+    fn describe_includes_known_details() {
+        assert_eq!(
+            baby(Some("Ada"), Some(3400), Some(BabySex::FEMALE)).describe(),
+            "Ada (girl, 3.40 kg)"
+        );
+        assert_eq!(baby(Some("Ada"), None, None).describe(), "Ada");
+        assert_eq!(baby(None, Some(2950), None).describe(), "A baby (2.95 kg)");
+    }
+
+    #[test]
+    fn describe_all_joins_multiples() {
+        let babies = [
+            baby(Some("Ada"), None, None),
+            baby(Some("Max"), None, None),
+            baby(Some("Eve"), None, None),
+        ];
+
+        assert_eq!(Baby::describe_all(&babies[..1]), "Ada");
+        assert_eq!(Baby::describe_all(&babies[..2]), "Ada and Max");
+        assert_eq!(Baby::describe_all(&babies), "Ada, Max and Eve");
+    }
+}
@@ -0,0 +1,5 @@
+pub mod baby;
+pub mod sex;
+
+pub use baby::Baby;
+pub use sex::BabySex;
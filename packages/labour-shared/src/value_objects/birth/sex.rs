@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Eq;
+use strum::{EnumString, VariantNames};
+
+#[derive(Debug, Clone, Deserialize, Serialize, EnumString, VariantNames, PartialEq, Hash, Eq)]
+pub enum BabySex {
+    #[strum(serialize = "FEMALE", serialize = "female")]
+    FEMALE,
+    #[strum(serialize = "MALE", serialize = "male")]
+    MALE,
+}
+
+impl std::fmt::Display for BabySex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BabySex::FEMALE => write!(f, "FEMALE"),
+            BabySex::MALE => write!(f, "MALE"),
+        }
+    }
+}
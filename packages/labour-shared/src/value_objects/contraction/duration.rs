@@ -40,6 +40,15 @@ impl Duration {
         })
     }
 
+    /// A zero-length duration, as recorded for a contraction still in
+    /// progress. It cannot fail, as it starts and ends at the same time.
+    pub fn instant(at: DateTime<Utc>) -> Self {
+        Self {
+            start_time: at,
+            end_time: at,
+        }
+    }
+
     pub fn start_time(&self) -> &DateTime<Utc> {
         &self.start_time
     }
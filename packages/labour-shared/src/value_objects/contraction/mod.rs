@@ -1 +1,4 @@
 pub mod duration;
+pub mod pattern_rule;
+
+pub use pattern_rule::ContractionPatternRule;
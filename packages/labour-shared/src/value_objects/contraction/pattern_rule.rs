@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A hospital "when to come in" rule such as 5-1-1: contractions every
+/// `interval_minutes` or less, each lasting at least `duration_minutes`,
+/// kept up for `sustained_minutes`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ContractionPatternRule {
+    pub interval_minutes: u32,
+    pub duration_minutes: u32,
+    pub sustained_minutes: u32,
+}
+
+impl ContractionPatternRule {
+    pub const FIVE_ONE_ONE: Self = Self {
+        interval_minutes: 5,
+        duration_minutes: 1,
+        sustained_minutes: 60,
+    };
+
+    pub const FOUR_ONE_ONE: Self = Self {
+        interval_minutes: 4,
+        duration_minutes: 1,
+        sustained_minutes: 60,
+    };
+
+    pub const PRESETS: [Self; 2] = [Self::FIVE_ONE_ONE, Self::FOUR_ONE_ONE];
+}
+
+impl Default for ContractionPatternRule {
+    fn default() -> Self {
+        Self::FIVE_ONE_ONE
+    }
+}
+
+impl fmt::Display for ContractionPatternRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.sustained_minutes.is_multiple_of(60) {
+            write!(
+                f,
+                "{}-{}-{}",
+                self.interval_minutes,
+                self.duration_minutes,
+                self.sustained_minutes / 60
+            )
+        } else {
+            write!(
+                f,
+                "{}-{}-{}m",
+                self.interval_minutes, self.duration_minutes, self.sustained_minutes
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_display_as_their_common_names() {
+        assert_eq!(ContractionPatternRule::FIVE_ONE_ONE.to_string(), "5-1-1");
+        assert_eq!(ContractionPatternRule::FOUR_ONE_ONE.to_string(), "4-1-1");
+    }
+
+    #[test]
+    fn partial_hours_display_in_minutes() {
+        let rule = ContractionPatternRule {
+            interval_minutes: 3,
+            duration_minutes: 1,
+            sustained_minutes: 90,
+        };
+        assert_eq!(rule.to_string(), "3-1-90m");
+    }
+}
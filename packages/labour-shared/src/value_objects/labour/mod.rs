@@ -1,3 +1,5 @@
 pub mod phase;
+pub mod phase_progression_policy;
 
 pub use phase::LabourPhase;
+pub use phase_progression_policy::{PhaseProgressionPolicy, PhaseThresholds};
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+/// What the recent contractions must average for labour to reach a phase.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseThresholds {
+    pub min_intensity: f64,
+    pub min_duration_minutes: f64,
+    /// Longest average gap between contraction starts, or `None` to ignore
+    /// how often they come.
+    pub max_interval_minutes: Option<f64>,
+}
+
+/// How a labour moves between phases automatically as contractions are
+/// recorded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseProgressionPolicy {
+    pub automatic: bool,
+    pub recent_contraction_count: usize,
+    pub active: PhaseThresholds,
+    pub transition: PhaseThresholds,
+    /// Looser thresholds for active labour once the waters have broken, or
+    /// `None` to treat waters breaking like any other milestone.
+    #[serde(default)]
+    pub waters_broken_active: Option<PhaseThresholds>,
+}
+
+impl PhaseProgressionPolicy {
+    /// The thresholds every labour used before policies could be changed,
+    /// plus looser active thresholds once the waters have broken.
+    pub fn first_labour() -> Self {
+        Self {
+            automatic: true,
+            recent_contraction_count: 3,
+            active: PhaseThresholds {
+                min_intensity: 6.0,
+                min_duration_minutes: 1.0,
+                max_interval_minutes: None,
+            },
+            transition: PhaseThresholds {
+                min_intensity: 8.0,
+                min_duration_minutes: 1.5,
+                max_interval_minutes: None,
+            },
+            waters_broken_active: Some(PhaseThresholds {
+                min_intensity: 5.0,
+                min_duration_minutes: 0.75,
+                max_interval_minutes: Some(10.0),
+            }),
+        }
+    }
+
+    /// Later labours tend to progress faster, so they move on at lower
+    /// intensities once contractions are coming regularly.
+    pub fn subsequent_labour() -> Self {
+        Self {
+            automatic: true,
+            recent_contraction_count: 3,
+            active: PhaseThresholds {
+                min_intensity: 5.0,
+                min_duration_minutes: 0.75,
+                max_interval_minutes: Some(10.0),
+            },
+            transition: PhaseThresholds {
+                min_intensity: 7.0,
+                min_duration_minutes: 1.25,
+                max_interval_minutes: Some(5.0),
+            },
+            waters_broken_active: Some(PhaseThresholds {
+                min_intensity: 4.0,
+                min_duration_minutes: 0.75,
+                max_interval_minutes: Some(10.0),
+            }),
+        }
+    }
+
+    pub fn for_labour(first_labour: bool) -> Self {
+        if first_labour {
+            Self::first_labour()
+        } else {
+            Self::subsequent_labour()
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.recent_contraction_count == 0 {
+            return Err("recent_contraction_count must be at least 1".to_string());
+        }
+        let thresholds = [
+            Some(("active", &self.active)),
+            Some(("transition", &self.transition)),
+            self.waters_broken_active
+                .as_ref()
+                .map(|thresholds| ("waters_broken_active", thresholds)),
+        ];
+        for (phase, thresholds) in thresholds.into_iter().flatten() {
+            if !(0.0..=10.0).contains(&thresholds.min_intensity) {
+                return Err(format!("{phase} min_intensity must be between 0 and 10"));
+            }
+            if thresholds.min_duration_minutes < 0.0 {
+                return Err(format!("{phase} min_duration_minutes cannot be negative"));
+            }
+            if let Some(interval) = thresholds.max_interval_minutes {
+                if interval <= 0.0 {
+                    return Err(format!("{phase} max_interval_minutes must be positive"));
+                }
+                if self.recent_contraction_count < 2 {
+                    return Err(format!(
+                        "{phase} max_interval_minutes needs recent_contraction_count of at least 2"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for PhaseProgressionPolicy {
+    fn default() -> Self {
+        Self::first_labour()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_are_valid() {
+        assert!(PhaseProgressionPolicy::first_labour().validate().is_ok());
+        assert!(
+            PhaseProgressionPolicy::subsequent_labour()
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn interval_criteria_need_more_than_one_contraction() {
+        let policy = PhaseProgressionPolicy {
+            recent_contraction_count: 1,
+            ..PhaseProgressionPolicy::subsequent_labour()
+        };
+        assert!(policy.validate().is_err());
+    }
+
+    #[test]
+    fn intensity_must_be_on_the_recorded_scale() {
+        let mut policy = PhaseProgressionPolicy::first_labour();
+        policy.transition.min_intensity = 11.0;
+        assert!(policy.validate().is_err());
+    }
+}
@@ -1,3 +1,5 @@
+pub mod trigger;
 pub mod update_type;
 
+pub use trigger::LabourUpdateTrigger;
 pub use update_type::LabourUpdateType;
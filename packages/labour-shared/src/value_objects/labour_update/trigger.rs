@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::value_objects::LabourPhase;
+
+/// When a scheduled labour update is posted.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum LabourUpdateTrigger {
+    /// Posted at `send_at`.
+    AtTime { send_at: DateTime<Utc> },
+    /// Posted once labour reaches `phase`, or any later phase.
+    OnPhase { phase: LabourPhase },
+}
+
+impl LabourUpdateTrigger {
+    pub fn send_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            LabourUpdateTrigger::AtTime { send_at } => Some(*send_at),
+            LabourUpdateTrigger::OnPhase { .. } => None,
+        }
+    }
+
+    pub fn phase(&self) -> Option<&LabourPhase> {
+        match self {
+            LabourUpdateTrigger::AtTime { .. } => None,
+            LabourUpdateTrigger::OnPhase { phase } => Some(phase),
+        }
+    }
+
+    /// Whether an update with this trigger is due at `now` in `current_phase`.
+    pub fn is_due(&self, now: DateTime<Utc>, current_phase: &LabourPhase) -> bool {
+        match self {
+            LabourUpdateTrigger::AtTime { send_at } => *send_at <= now,
+            LabourUpdateTrigger::OnPhase { phase } => current_phase >= phase,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phase_triggers_fire_on_the_phase_or_any_later_one() {
+        let trigger = LabourUpdateTrigger::OnPhase {
+            phase: LabourPhase::ACTIVE,
+        };
+        let now = Utc::now();
+
+        assert!(!trigger.is_due(now, &LabourPhase::EARLY));
+        assert!(trigger.is_due(now, &LabourPhase::ACTIVE));
+        assert!(trigger.is_due(now, &LabourPhase::TRANSITION));
+    }
+
+    #[test]
+    fn serializes_with_a_type_tag() {
+        let trigger = LabourUpdateTrigger::OnPhase {
+            phase: LabourPhase::ACTIVE,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&trigger).unwrap(),
+            serde_json::json!({"type": "OnPhase", "phase": "ACTIVE"})
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Eq;
+use strum::{EnumString, VariantNames};
+
+#[derive(Debug, Clone, Deserialize, Serialize, EnumString, VariantNames, PartialEq, Hash, Eq)]
+#[allow(non_camel_case_types)]
+pub enum LabourMilestoneType {
+    #[strum(serialize = "WATERS_BROKEN", serialize = "waters_broken")]
+    WATERS_BROKEN,
+    #[strum(
+        serialize = "LEFT_FOR_BIRTH_LOCATION",
+        serialize = "left_for_birth_location"
+    )]
+    LEFT_FOR_BIRTH_LOCATION,
+    #[strum(
+        serialize = "ARRIVED_AT_BIRTH_LOCATION",
+        serialize = "arrived_at_birth_location"
+    )]
+    ARRIVED_AT_BIRTH_LOCATION,
+    #[strum(serialize = "EPIDURAL_GIVEN", serialize = "epidural_given")]
+    EPIDURAL_GIVEN,
+    #[strum(serialize = "INDUCTION_STARTED", serialize = "induction_started")]
+    INDUCTION_STARTED,
+}
+
+impl LabourMilestoneType {
+    /// Milestones that can only happen once in a labour. Travel and pain
+    /// relief can repeat, e.g. after a transfer.
+    pub fn occurs_once(&self) -> bool {
+        matches!(
+            self,
+            LabourMilestoneType::WATERS_BROKEN | LabourMilestoneType::INDUCTION_STARTED
+        )
+    }
+
+    /// How the milestone reads in notifications.
+    pub fn description(&self) -> &'static str {
+        match self {
+            LabourMilestoneType::WATERS_BROKEN => "their waters have broken",
+            LabourMilestoneType::LEFT_FOR_BIRTH_LOCATION => {
+                "they have left for the hospital or birth centre"
+            }
+            LabourMilestoneType::ARRIVED_AT_BIRTH_LOCATION => {
+                "they have arrived at the hospital or birth centre"
+            }
+            LabourMilestoneType::EPIDURAL_GIVEN => "they have had an epidural",
+            LabourMilestoneType::INDUCTION_STARTED => "their induction has started",
+        }
+    }
+}
+
+impl std::fmt::Display for LabourMilestoneType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LabourMilestoneType::WATERS_BROKEN => write!(f, "WATERS_BROKEN"),
+            LabourMilestoneType::LEFT_FOR_BIRTH_LOCATION => write!(f, "LEFT_FOR_BIRTH_LOCATION"),
+            LabourMilestoneType::ARRIVED_AT_BIRTH_LOCATION => {
+                write!(f, "ARRIVED_AT_BIRTH_LOCATION")
+            }
+            LabourMilestoneType::EPIDURAL_GIVEN => write!(f, "EPIDURAL_GIVEN"),
+            LabourMilestoneType::INDUCTION_STARTED => write!(f, "INDUCTION_STARTED"),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod milestone_type;
+
+pub use milestone_type::LabourMilestoneType;
@@ -1,8 +1,12 @@
+pub mod birth;
 pub mod contraction;
 pub mod labour;
 pub mod labour_update;
+pub mod milestone;
 pub mod subscriber;
 
-pub use labour::LabourPhase;
-pub use labour_update::LabourUpdateType;
+pub use birth::{Baby, BabySex};
+pub use labour::{LabourPhase, PhaseProgressionPolicy};
+pub use labour_update::{LabourUpdateTrigger, LabourUpdateType};
+pub use milestone::LabourMilestoneType;
 pub use subscriber::{SubscriberAccessLevel, SubscriberContactMethod, SubscriberRole};
@@ -1,3 +1,4 @@
+use fern_labour_event_sourcing_rs::CausationContext;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -10,4 +11,6 @@ pub struct DispatchRequest {
     pub destination: NotificationDestination,
     pub rendered_content: RenderedContent,
     pub idempotency_key: String,
+    #[serde(flatten)]
+    pub causation: CausationContext,
 }
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use fern_labour_event_sourcing_rs::CausationContext;
 use uuid::Uuid;
 use worker::Response;
 
@@ -21,5 +22,6 @@ pub trait GenerationClient {
         notification_id: Uuid,
         channel: NotificationChannel,
         template_data: NotificationTemplateData,
+        causation: CausationContext,
     ) -> Result<Response, GenerationClientError>;
 }
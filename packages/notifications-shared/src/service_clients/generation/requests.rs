@@ -1,3 +1,4 @@
+use fern_labour_event_sourcing_rs::CausationContext;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -8,4 +9,6 @@ pub struct RenderRequest {
     pub notification_id: Uuid,
     pub channel: NotificationChannel,
     pub template_data: NotificationTemplateData,
+    #[serde(flatten)]
+    pub causation: CausationContext,
 }
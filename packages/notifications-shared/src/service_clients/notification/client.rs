@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
+use fern_labour_event_sourcing_rs::CausationContext;
 
 use crate::{
     service_clients::notification::exceptions::NotificationClientError,
@@ -16,5 +17,6 @@ pub trait NotificationClient {
         template_data: NotificationTemplateData,
         metadata: Option<HashMap<String, String>>,
         priority: NotificationPriority,
+        causation: CausationContext,
     ) -> Result<(), NotificationClientError>;
 }
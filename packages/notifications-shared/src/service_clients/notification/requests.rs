@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use fern_labour_event_sourcing_rs::CausationContext;
 use serde::{Deserialize, Serialize};
 
 use crate::value_objects::{NotificationPriority, NotificationTemplateData};
@@ -13,4 +14,6 @@ pub struct NotificationRequest {
     pub metadata: Option<HashMap<String, String>>,
     #[serde(default)]
     pub priority: NotificationPriority,
+    #[serde(flatten)]
+    pub causation: CausationContext,
 }
@@ -58,6 +58,38 @@ pub enum NotificationTemplateData {
         birthing_person_name: String,
         link: String,
     },
+    ContractionPatternDetectedData {
+        birthing_person_first_name: String,
+        rule: String,
+        link: String,
+    },
+    ContractionAutoClosedData {
+        birthing_person_first_name: String,
+        started_at: String,
+        link: String,
+    },
+    SubscriberContractionPatternDetectedData {
+        birthing_person_first_name: String,
+        subscriber_first_name: String,
+        rule: String,
+        link: String,
+    },
+    BirthAnnouncementData {
+        birthing_person_name: String,
+        birthing_person_first_name: String,
+        subscriber_first_name: String,
+        babies: String,
+        time_of_birth: String,
+        link: String,
+    },
+    MilestoneRecordedData {
+        birthing_person_name: String,
+        birthing_person_first_name: String,
+        subscriber_first_name: String,
+        milestone: String,
+        occurred_at: String,
+        link: String,
+    },
 }
 
 impl NotificationTemplateData {
@@ -75,6 +107,17 @@ impl NotificationTemplateData {
             NotificationTemplateData::SubscriberInviteData { .. } => "SubscriberInviteData",
             NotificationTemplateData::SubscriberRequestedData { .. } => "SubscriberRequestedData",
             NotificationTemplateData::SubscriberApprovedData { .. } => "SubscriberApprovedData",
+            NotificationTemplateData::ContractionPatternDetectedData { .. } => {
+                "ContractionPatternDetectedData"
+            }
+            NotificationTemplateData::ContractionAutoClosedData { .. } => {
+                "ContractionAutoClosedData"
+            }
+            NotificationTemplateData::SubscriberContractionPatternDetectedData { .. } => {
+                "SubscriberContractionPatternDetectedData"
+            }
+            NotificationTemplateData::BirthAnnouncementData { .. } => "BirthAnnouncementData",
+            NotificationTemplateData::MilestoneRecordedData { .. } => "MilestoneRecordedData",
         }
     }
 }
@@ -1,5 +1,6 @@
-use chrono::Utc;
-use fern_labour_event_sourcing_rs::{CommandEnvelope, CommandMetadata};
+use std::rc::Rc;
+
+use fern_labour_event_sourcing_rs::{Clock, CommandEnvelope, CommandMetadata, SystemClock};
 use serde::Serialize;
 use tracing::error;
 use uuid::Uuid;
@@ -34,11 +35,20 @@ impl std::error::Error for Error {}
 
 pub struct DurableObjectCQRSClient {
     namespace: ObjectNamespace,
+    clock: Rc<dyn Clock>,
 }
 
 impl DurableObjectCQRSClient {
     pub fn create(namespace: ObjectNamespace) -> Self {
-        Self { namespace }
+        Self {
+            namespace,
+            clock: Rc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
     }
 
     pub async fn command<C: Serialize>(
@@ -134,7 +144,7 @@ impl DurableObjectCQRSClient {
             correlation_id,
             correlation_id,
             user.user_id.clone(),
-            Utc::now(),
+            self.clock.as_ref(),
         );
         CommandEnvelope::new(metadata, command)
     }
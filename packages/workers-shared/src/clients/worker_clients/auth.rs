@@ -58,6 +58,20 @@ impl User {
             name: None,
         }
     }
+
+    /// Acts as `user_id` when carrying out something they asked for earlier,
+    /// such as a scheduled labour update, so it is attributed to them.
+    pub fn acting_for(user_id: &str) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            issuer: "internal".to_string(),
+            email: None,
+            phone_number: None,
+            first_name: None,
+            last_name: None,
+            name: None,
+        }
+    }
 }
 
 #[derive(Deserialize)]
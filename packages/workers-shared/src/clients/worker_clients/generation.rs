@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use fern_labour_event_sourcing_rs::CausationContext;
 use fern_labour_notifications_shared::service_clients::{
     GenerationClient, GenerationClientError, RenderRequest, RenderResponse,
 };
@@ -31,12 +32,14 @@ impl FetcherGenerationClient {
         notification_id: Uuid,
         channel: NotificationChannel,
         template_data: NotificationTemplateData,
+        causation: CausationContext,
         url: &str,
     ) -> Result<Response, GenerationClientError> {
         let request = RenderRequest {
             notification_id,
             channel,
             template_data,
+            causation,
         };
 
         let (init, _) = build_json_post_request(
@@ -59,11 +62,13 @@ impl GenerationClient for FetcherGenerationClient {
         notification_id: Uuid,
         channel: NotificationChannel,
         template_data: NotificationTemplateData,
+        causation: CausationContext,
     ) -> Result<Response, GenerationClientError> {
         self.do_render(
             notification_id,
             channel,
             template_data,
+            causation,
             "https://fernlabour.com/api/v1/render-async",
         )
         .await
@@ -80,6 +85,7 @@ impl GenerationClient for FetcherGenerationClient {
                 notification_id,
                 channel,
                 template_data,
+                CausationContext::default(),
                 "https://fernlabour.com/api/v1/render",
             )
             .await?;
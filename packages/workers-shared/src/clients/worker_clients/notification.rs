@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
+use fern_labour_event_sourcing_rs::CausationContext;
 use fern_labour_notifications_shared::service_clients::notification::{
     NotificationClient, NotificationClientError, NotificationRequest,
 };
@@ -36,6 +37,7 @@ impl NotificationClient for FetcherNotificationClient {
         template_data: NotificationTemplateData,
         metadata: Option<HashMap<String, String>>,
         priority: NotificationPriority,
+        causation: CausationContext,
     ) -> Result<(), NotificationClientError> {
         let request = NotificationRequest {
             channel: channel.to_string(),
@@ -43,6 +45,7 @@ impl NotificationClient for FetcherNotificationClient {
             template_data,
             metadata,
             priority,
+            causation,
         };
 
         let (init, _) = build_json_post_request(
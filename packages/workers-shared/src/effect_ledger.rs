@@ -3,32 +3,24 @@ use serde::Deserialize;
 use uuid::Uuid;
 use worker::SqlStorage;
 
-use super::types::Effect;
-
-#[derive(Debug, Clone, Deserialize)]
-pub struct EffectRecord {
-    pub effect_id: String,
-    pub event_sequence: i64,
-    pub effect_type: String,
-    pub effect_payload: String,
-    pub idempotency_key: String,
-    pub status: String,
-    pub attempts: i64,
-    pub last_attempt_at: Option<String>,
-    pub last_error: Option<String>,
-    pub created_at: String,
-}
+use fern_labour_event_sourcing_rs::{
+    CausationContext, EffectLedgerTrait, EffectRecord, SerializedEffect,
+};
+
+use crate::sql::add_column_if_missing;
 
-pub struct EffectLedger {
+pub struct SqlEffectLedger {
     sql: SqlStorage,
 }
 
-impl EffectLedger {
+impl SqlEffectLedger {
     pub fn create(sql: SqlStorage) -> Self {
         Self { sql }
     }
+}
 
-    pub fn init_schema(&self) -> Result<()> {
+impl EffectLedgerTrait for SqlEffectLedger {
+    fn init_schema(&self) -> Result<()> {
         self.sql
             .exec(
                 "CREATE TABLE IF NOT EXISTS process_manager_state (
@@ -52,12 +44,17 @@ impl EffectLedger {
                     attempts INTEGER NOT NULL DEFAULT 0,
                     last_attempt_at DATETIME,
                     last_error TEXT,
-                    created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+                    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                    correlation_id TEXT,
+                    causation_id TEXT
                 )",
                 None,
             )
             .context("Failed to create pending_effects table")?;
 
+        add_column_if_missing(&self.sql, "pending_effects", "correlation_id", "TEXT")?;
+        add_column_if_missing(&self.sql, "pending_effects", "causation_id", "TEXT")?;
+
         self.sql
             .exec(
                 "CREATE INDEX IF NOT EXISTS idx_pending_effects_status
@@ -69,7 +66,7 @@ impl EffectLedger {
         Ok(())
     }
 
-    pub fn get_last_processed_sequence(&self) -> Result<i64> {
+    fn get_last_processed_sequence(&self) -> Result<i64> {
         #[derive(Deserialize)]
         struct Row {
             last_processed_sequence: Option<i64>,
@@ -91,23 +88,29 @@ impl EffectLedger {
             .unwrap_or(0))
     }
 
-    pub fn persist_effects(&self, effects: &[Effect], sequence: i64) -> Result<()> {
+    fn persist_effects(
+        &self,
+        effects: &[SerializedEffect],
+        sequence: i64,
+        causation: CausationContext,
+    ) -> Result<()> {
         for effect in effects {
             let effect_id = Uuid::now_v7().to_string();
-            let effect_payload =
-                serde_json::to_string(effect).context("Failed to serialize effect to JSON")?;
 
             self.sql
                 .exec(
                     "INSERT OR IGNORE INTO pending_effects
-                     (effect_id, event_sequence, effect_type, effect_payload, idempotency_key)
-                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                     (effect_id, event_sequence, effect_type, effect_payload, idempotency_key,
+                      correlation_id, causation_id)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
                     Some(vec![
                         effect_id.into(),
                         sequence.into(),
-                        effect.effect_type().into(),
-                        effect_payload.into(),
-                        effect.idempotency_key().0.clone().into(),
+                        effect.effect_type.as_str().into(),
+                        effect.effect_payload.as_str().into(),
+                        effect.idempotency_key.as_str().into(),
+                        causation.correlation_id.map(|id| id.to_string()).into(),
+                        causation.causation_id.map(|id| id.to_string()).into(),
                     ]),
                 )
                 .context("Failed to insert effect into pending_effects")?;
@@ -127,7 +130,7 @@ impl EffectLedger {
         Ok(())
     }
 
-    pub fn get_pending_effects(&self, max_attempts: i64) -> Result<Vec<EffectRecord>> {
+    fn get_pending_effects(&self, max_attempts: i64) -> Result<Vec<EffectRecord>> {
         self.sql
             .exec(
                 "SELECT * FROM pending_effects
@@ -141,7 +144,7 @@ impl EffectLedger {
             .context("Failed to deserialize effect records")
     }
 
-    pub fn mark_dispatched(&self, effect_id: &str) -> Result<()> {
+    fn mark_dispatched(&self, effect_id: &str) -> Result<()> {
         self.sql
             .exec(
                 "UPDATE pending_effects
@@ -155,7 +158,7 @@ impl EffectLedger {
         Ok(())
     }
 
-    pub fn mark_completed(&self, effect_id: &str) -> Result<()> {
+    fn mark_completed(&self, effect_id: &str) -> Result<()> {
         self.sql
             .exec(
                 "UPDATE pending_effects SET status = 'COMPLETED' WHERE effect_id = ?1",
@@ -165,7 +168,7 @@ impl EffectLedger {
         Ok(())
     }
 
-    pub fn mark_failed(&self, effect_id: &str, error: &str, exhausted: bool) -> Result<()> {
+    fn mark_failed(&self, effect_id: &str, error: &str, exhausted: bool) -> Result<()> {
         let status = if exhausted { "FAILED" } else { "DISPATCHED" };
         self.sql
             .exec(
@@ -178,7 +181,7 @@ impl EffectLedger {
         Ok(())
     }
 
-    pub fn has_pending_effects(&self, max_attempts: i64) -> Result<bool> {
+    fn has_pending_effects(&self, max_attempts: i64) -> Result<bool> {
         #[derive(Deserialize)]
         struct CountResult {
             count: i64,
@@ -201,4 +204,15 @@ impl EffectLedger {
             _ => Err(anyhow!("No max sequence results found")),
         }
     }
+
+    fn cancel_pending_effects(&self, event_sequence: i64) -> Result<()> {
+        self.sql
+            .exec(
+                "UPDATE pending_effects SET status = 'CANCELLED'
+                 WHERE event_sequence = ?1 AND status = 'PENDING'",
+                Some(vec![event_sequence.into()]),
+            )
+            .context("Failed to cancel pending effects")?;
+        Ok(())
+    }
 }
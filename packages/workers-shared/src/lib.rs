@@ -1,11 +1,16 @@
 pub mod cache;
 pub mod clients;
 pub mod cors;
+pub mod effect_ledger;
+pub mod processed_command_store;
 pub mod queue_producer;
 pub mod setup;
+pub mod sql;
 
 pub use cache::{CacheError, CacheTrait, KVCache};
 pub use clients::worker_clients::auth::User;
 pub use cors::CorsContext;
+pub use effect_ledger::SqlEffectLedger;
+pub use processed_command_store::SqlProcessedCommandStore;
 pub use queue_producer::NotificationQueueProducer;
 pub use setup::{config::ConfigTrait, exceptions::SetupError};
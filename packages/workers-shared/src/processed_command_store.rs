@@ -0,0 +1,107 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::Deserialize;
+use worker::SqlStorage;
+
+use fern_labour_event_sourcing_rs::{ProcessedCommand, ProcessedCommandStoreTrait};
+
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+#[derive(Deserialize)]
+struct ProcessedCommandRow {
+    idempotency_key: String,
+    outcome: String,
+    processed_at: String,
+}
+
+pub struct SqlProcessedCommandStore {
+    sql: SqlStorage,
+}
+
+impl SqlProcessedCommandStore {
+    pub fn create(sql: SqlStorage) -> SqlProcessedCommandStore {
+        Self { sql }
+    }
+}
+
+impl ProcessedCommandStoreTrait for SqlProcessedCommandStore {
+    fn init_schema(&self) -> Result<()> {
+        self.sql
+            .exec(
+                "CREATE TABLE IF NOT EXISTS processed_commands (
+                    idempotency_key TEXT PRIMARY KEY,
+                    outcome TEXT NOT NULL,
+                    processed_at DATETIME DEFAULT CURRENT_TIMESTAMP NOT NULL
+                )",
+                None,
+            )
+            .context("Failed to create processed_commands table")?;
+
+        self.sql
+            .exec(
+                "CREATE INDEX IF NOT EXISTS idx_processed_commands_processed_at
+                 ON processed_commands(processed_at)",
+                None,
+            )
+            .context("Failed to create processed_commands index")?;
+        Ok(())
+    }
+
+    fn get(&self, idempotency_key: &str) -> Result<Option<ProcessedCommand>> {
+        let rows: Vec<ProcessedCommandRow> = self
+            .sql
+            .exec(
+                "SELECT idempotency_key, outcome, processed_at
+                 FROM processed_commands WHERE idempotency_key = ?1",
+                Some(vec![idempotency_key.into()]),
+            )
+            .context("Failed to query processed command")?
+            .to_array()
+            .context("Failed to deserialize processed command row")?;
+
+        rows.into_iter()
+            .next()
+            .map(|row| {
+                let processed_at =
+                    NaiveDateTime::parse_from_str(&row.processed_at, TIMESTAMP_FORMAT)
+                        .context("Failed to parse processed_at timestamp")?
+                        .and_utc();
+                Ok(ProcessedCommand {
+                    idempotency_key: row.idempotency_key,
+                    outcome: row.outcome,
+                    processed_at,
+                })
+            })
+            .transpose()
+    }
+
+    fn record(
+        &self,
+        idempotency_key: &str,
+        outcome: String,
+        processed_at: DateTime<Utc>,
+    ) -> Result<()> {
+        self.sql
+            .exec(
+                "INSERT OR REPLACE INTO processed_commands (idempotency_key, outcome, processed_at)
+                 VALUES (?1, ?2, ?3)",
+                Some(vec![
+                    idempotency_key.into(),
+                    outcome.into(),
+                    processed_at.format(TIMESTAMP_FORMAT).to_string().into(),
+                ]),
+            )
+            .context("Failed to record processed command")?;
+        Ok(())
+    }
+
+    fn purge_before(&self, cutoff: DateTime<Utc>) -> Result<()> {
+        self.sql
+            .exec(
+                "DELETE FROM processed_commands WHERE processed_at < ?1",
+                Some(vec![cutoff.format(TIMESTAMP_FORMAT).to_string().into()]),
+            )
+            .context("Failed to purge processed commands")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use worker::SqlStorage;
+
+#[derive(Deserialize)]
+struct ColumnInfo {
+    name: String,
+}
+
+fn column_names(sql: &SqlStorage, table: &str) -> Result<Vec<String>> {
+    let columns: Vec<ColumnInfo> = sql
+        .exec(&format!("PRAGMA table_info({table})"), None)
+        .with_context(|| format!("Failed to read columns of {table}"))?
+        .to_array()
+        .with_context(|| format!("Failed to deserialize columns of {table}"))?;
+    Ok(columns.into_iter().map(|info| info.name).collect())
+}
+
+/// Adds `column` to an existing Durable Object SQLite table. Tables created
+/// before the column existed have no migration runner, so schemas call this
+/// from `init_schema` after their `CREATE TABLE IF NOT EXISTS`.
+pub fn add_column_if_missing(
+    sql: &SqlStorage,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<()> {
+    if column_names(sql, table)?.iter().any(|name| name == column) {
+        return Ok(());
+    }
+
+    sql.exec(
+        &format!("ALTER TABLE {table} ADD COLUMN {column} {definition}"),
+        None,
+    )
+    .with_context(|| format!("Failed to add column {column} to {table}"))?;
+    Ok(())
+}
+
+/// Name of the table a read model is rebuilt into before being swapped in
+/// for `table`.
+pub fn shadow_table_name(table: &str) -> String {
+    format!("{table}_rebuild")
+}
+
+/// Replaces the rows of `table` with those of its shadow table and drops the
+/// shadow. The statements run back to back with no `await` between them, so
+/// the Durable Object commits them as a single transaction and readers never
+/// see an empty table. Columns are copied by name, since columns added later
+/// by [`add_column_if_missing`] sit in a different position in older tables.
+pub fn promote_shadow_table(sql: &SqlStorage, table: &str) -> Result<()> {
+    let shadow = shadow_table_name(table);
+    let columns = column_names(sql, &shadow)?.join(", ");
+
+    sql.exec(&format!("DELETE FROM {table}"), None)
+        .with_context(|| format!("Failed to clear {table}"))?;
+    sql.exec(
+        &format!("INSERT INTO {table} ({columns}) SELECT {columns} FROM {shadow}"),
+        None,
+    )
+    .with_context(|| format!("Failed to copy {shadow} into {table}"))?;
+    drop_shadow_table(sql, table)
+}
+
+pub fn drop_shadow_table(sql: &SqlStorage, table: &str) -> Result<()> {
+    let shadow = shadow_table_name(table);
+    sql.exec(&format!("DROP TABLE IF EXISTS {shadow}"), None)
+        .with_context(|| format!("Failed to drop {shadow}"))?;
+    Ok(())
+}
@@ -1,11 +1,9 @@
-use chrono::Utc;
 use fern_labour_event_sourcing_rs::CommandEnvelope;
 use fern_labour_notifications_shared::{
     InternalCommand, QueueMessage,
     service_clients::{DispatchRequest, DispatchResponse},
 };
 use tracing::{error, info};
-use uuid::Uuid;
 use worker::{Request, Response, RouteContext};
 
 use crate::{application::dispatch::DispatchContext, setup::app_state::AppState};
@@ -23,6 +21,7 @@ pub async fn dispatch_async(
         }
     };
     let notification_id = dispatch_request.notification_id;
+    let causation = dispatch_request.causation;
 
     let context = DispatchContext::from(dispatch_request);
 
@@ -40,13 +39,12 @@ pub async fn dispatch_async(
                 notification_id,
                 external_id,
             };
-            let envelope = CommandEnvelope::enrich(
+            let envelope = CommandEnvelope::caused_by(
                 QueueMessage::Internal(command),
                 notification_id,
-                Uuid::now_v7(),
-                Uuid::now_v7(),
+                causation,
                 "dispatch".to_string(),
-                Utc::now(),
+                ctx.data.clock.as_ref(),
             );
 
             match ctx.data.command_producer.publish(envelope).await {
@@ -1,5 +1,4 @@
-use chrono::Utc;
-use fern_labour_event_sourcing_rs::CommandEnvelope;
+use fern_labour_event_sourcing_rs::{Clock, CommandEnvelope};
 use fern_labour_notifications_shared::{
     InternalCommand, QueueMessage, value_objects::NotificationStatus,
 };
@@ -17,6 +16,7 @@ use crate::{
 
 fn process_webhook_interpretation(
     interpretation: WebhookInterpretation,
+    clock: &dyn Clock,
 ) -> Option<CommandEnvelope<QueueMessage>> {
     let command = match interpretation.status {
         NotificationStatus::DELIVERED => Some(InternalCommand::MarkAsDelivered {
@@ -36,7 +36,7 @@ fn process_webhook_interpretation(
         correlation_id,
         correlation_id,
         "dispatch".to_string(),
-        Utc::now(),
+        clock,
     ))
 }
 
@@ -85,7 +85,9 @@ pub async fn handle_sendgrid_webhook(
                     "Interpreted webhook: notification_id={}, status={:?}",
                     interpretation.notification_id, interpretation.status
                 );
-                if let Some(command_envelope) = process_webhook_interpretation(interpretation) {
+                if let Some(command_envelope) =
+                    process_webhook_interpretation(interpretation, ctx.data.clock.as_ref())
+                {
                     commands.push(command_envelope);
                 }
             }
@@ -138,7 +140,9 @@ pub async fn handle_resend_webhook(
                 "Interpreted webhook: notification_id={}, status={:?}",
                 interpretation.notification_id, interpretation.status
             );
-            if let Some(command_envelope) = process_webhook_interpretation(interpretation) {
+            if let Some(command_envelope) =
+                process_webhook_interpretation(interpretation, ctx.data.clock.as_ref())
+            {
                 commands.push(command_envelope);
             }
         }
@@ -203,7 +207,9 @@ pub async fn handle_twilio_webhook(
                 "Interpreted webhook: notification_id={}, status={:?}",
                 interpretation.notification_id, interpretation.status
             );
-            if let Some(command_envelope) = process_webhook_interpretation(interpretation) {
+            if let Some(command_envelope) =
+                process_webhook_interpretation(interpretation, ctx.data.clock.as_ref())
+            {
                 commands.push(command_envelope);
             }
         }
@@ -1,5 +1,5 @@
 use anyhow::{Context, Result};
-use fern_labour_event_sourcing_rs::CommandEnvelope;
+use fern_labour_event_sourcing_rs::{Clock, CommandEnvelope, SystemClock};
 use fern_labour_notifications_shared::{QueueMessage, QueueProducerTrait};
 use fern_labour_workers_shared::{ConfigTrait, NotificationQueueProducer};
 use std::rc::Rc;
@@ -29,6 +29,7 @@ pub struct AppState {
     pub webhook_verification: WebhookVerificationService,
     pub command_producer: Box<dyn QueueProducerTrait<Envelope = CommandEnvelope<QueueMessage>>>,
     pub internal_service_token: String,
+    pub clock: Rc<dyn Clock>,
 }
 
 impl AppState {
@@ -102,6 +103,7 @@ impl AppState {
             webhook_verification,
             command_producer,
             internal_service_token: config.internal_service_token,
+            clock: Rc::new(SystemClock),
         })
     }
 }
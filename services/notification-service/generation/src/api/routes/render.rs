@@ -1,11 +1,9 @@
-use chrono::Utc;
 use fern_labour_event_sourcing_rs::CommandEnvelope;
 use fern_labour_notifications_shared::{
     InternalCommand, QueueMessage,
     service_clients::{RenderRequest, RenderResponse},
 };
 use tracing::{error, info};
-use uuid::Uuid;
 use worker::{Request, Response, RouteContext};
 
 use crate::setup::app_state::AppState;
@@ -40,13 +38,12 @@ pub async fn render_async(
                 notification_id: request.notification_id,
                 rendered_content,
             };
-            let envelope = CommandEnvelope::enrich(
+            let envelope = CommandEnvelope::caused_by(
                 QueueMessage::Internal(command),
                 request.notification_id,
-                Uuid::now_v7(),
-                Uuid::now_v7(),
+                request.causation,
                 "generation".to_string(),
-                Utc::now(),
+                ctx.data.clock.as_ref(),
             );
             match ctx.data.command_producer.publish(envelope).await {
                 Ok(_) => info!("PUBLISHED"),
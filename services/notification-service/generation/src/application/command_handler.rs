@@ -1,6 +1,7 @@
+use std::rc::Rc;
+
 use anyhow::{Context, Result};
-use chrono::Utc;
-use fern_labour_event_sourcing_rs::CommandEnvelope;
+use fern_labour_event_sourcing_rs::{Clock, CommandEnvelope, SystemClock};
 use fern_labour_notifications_shared::{InternalCommand, ServiceCommand};
 use tracing::{debug, error, info};
 use uuid::Uuid;
@@ -11,6 +12,7 @@ use crate::application::{exceptions::AppError, template_engine::TemplateEngineTr
 pub struct NotificationCommandHandler {
     pub template_engine: Box<dyn TemplateEngineTrait>,
     pub command_bus: Queue,
+    clock: Rc<dyn Clock>,
 }
 
 impl NotificationCommandHandler {
@@ -18,9 +20,15 @@ impl NotificationCommandHandler {
         Self {
             template_engine,
             command_bus,
+            clock: Rc::new(SystemClock),
         }
     }
 
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     pub async fn consume_event(&self, command: &ServiceCommand, user_id: &str) -> Result<()> {
         match command {
             ServiceCommand::RenderNotification {
@@ -50,7 +58,7 @@ impl NotificationCommandHandler {
                     Uuid::now_v7(),
                     Uuid::now_v7(),
                     user_id.to_string(),
-                    Utc::now(),
+                    self.clock.as_ref(),
                 );
 
                 info!(
@@ -246,6 +246,99 @@ impl TemplateEngineTrait for TinyTemplateEngine {
                     "Template not found for channel {channel}"
                 ))),
             },
+            data @ NotificationTemplateData::BirthAnnouncementData { .. } => match channel {
+                NotificationChannel::EMAIL => Ok(RenderedContent::Email {
+                    subject: self.render_subject::<templates::BirthAnnouncementSubjectTemplate>(
+                        data.template(),
+                        &data,
+                    )?,
+                    html_body: self.render_body::<templates::BirthAnnouncementBodyTemplate>(
+                        data.template(),
+                        &data,
+                    )?,
+                }),
+                NotificationChannel::SMS => Ok(RenderedContent::Sms {
+                    body: self.render_body::<templates::sms_templates::birth_announcement::BirthAnnouncementTemplate>(data.template(), &data)?,
+                }),
+                NotificationChannel::WHATSAPP => Ok(RenderedContent::WhatsApp {
+                    template_sid: self.render_subject::<templates::whatsapp_templates::birth_announcement::BirthAnnouncementTemplateSid>(data.template(), &data)?,
+                    content_variables: self.render_body::<templates::whatsapp_templates::birth_announcement::BirthAnnouncementContentVariablesTemplate>(data.template(), &data)?,
+                }),
+            },
+            data @ NotificationTemplateData::MilestoneRecordedData { .. } => match channel {
+                NotificationChannel::EMAIL => Ok(RenderedContent::Email {
+                    subject: self.render_subject::<templates::MilestoneRecordedSubjectTemplate>(
+                        data.template(),
+                        &data,
+                    )?,
+                    html_body: self.render_body::<templates::MilestoneRecordedBodyTemplate>(
+                        data.template(),
+                        &data,
+                    )?,
+                }),
+                NotificationChannel::SMS => Ok(RenderedContent::Sms {
+                    body: self.render_body::<templates::sms_templates::milestone_recorded::MilestoneRecordedTemplate>(data.template(), &data)?,
+                }),
+                NotificationChannel::WHATSAPP => Ok(RenderedContent::WhatsApp {
+                    template_sid: self.render_subject::<templates::whatsapp_templates::milestone_recorded::MilestoneRecordedTemplateSid>(data.template(), &data)?,
+                    content_variables: self.render_body::<templates::whatsapp_templates::milestone_recorded::MilestoneRecordedContentVariablesTemplate>(data.template(), &data)?,
+                }),
+            },
+            data @ NotificationTemplateData::ContractionAutoClosedData { .. } => match channel {
+                NotificationChannel::EMAIL => Ok(RenderedContent::Email {
+                    subject: self.render_subject::<templates::ContractionAutoClosedSubjectTemplate>(
+                        data.template(),
+                        &data,
+                    )?,
+                    html_body: self.render_body::<templates::ContractionAutoClosedBodyTemplate>(
+                        data.template(),
+                        &data,
+                    )?,
+                }),
+                NotificationChannel::SMS => Ok(RenderedContent::Sms {
+                    body: self.render_body::<templates::sms_templates::contraction_auto_closed::ContractionAutoClosedTemplate>(data.template(), &data)?,
+                }),
+                NotificationChannel::WHATSAPP => Err(AppError::ValidationError(format!(
+                    "Template not found for channel {channel}"
+                ))),
+            },
+            data @ NotificationTemplateData::ContractionPatternDetectedData { .. } => match channel {
+                NotificationChannel::EMAIL => Ok(RenderedContent::Email {
+                    subject: self
+                        .render_subject::<templates::ContractionPatternDetectedSubjectTemplate>(
+                            data.template(),
+                            &data,
+                        )?,
+                    html_body: self.render_body::<templates::ContractionPatternDetectedBodyTemplate>(
+                        data.template(),
+                        &data,
+                    )?,
+                }),
+                NotificationChannel::SMS => Ok(RenderedContent::Sms {
+                    body: self.render_body::<templates::sms_templates::contraction_pattern_detected::ContractionPatternDetectedTemplate>(data.template(), &data)?,
+                }),
+                NotificationChannel::WHATSAPP => Err(AppError::ValidationError(format!(
+                    "Template not found for channel {channel}"
+                ))),
+            },
+            data @ NotificationTemplateData::SubscriberContractionPatternDetectedData { .. } => match channel {
+                NotificationChannel::EMAIL => Ok(RenderedContent::Email {
+                    subject: self.render_subject::<templates::SubscriberContractionPatternDetectedSubjectTemplate>(
+                        data.template(),
+                        &data,
+                    )?,
+                    html_body: self.render_body::<templates::SubscriberContractionPatternDetectedBodyTemplate>(
+                        data.template(),
+                        &data,
+                    )?,
+                }),
+                NotificationChannel::SMS => Ok(RenderedContent::Sms {
+                    body: self.render_body::<templates::sms_templates::subscriber_contraction_pattern_detected::SubscriberContractionPatternDetectedTemplate>(data.template(), &data)?,
+                }),
+                NotificationChannel::WHATSAPP => Err(AppError::ValidationError(format!(
+                    "Template not found for channel {channel}"
+                ))),
+            },
         }
     }
 }
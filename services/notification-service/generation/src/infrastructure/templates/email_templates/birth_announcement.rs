@@ -0,0 +1,35 @@
+use crate::infrastructure::templates::template::TemplateTrait;
+
+pub struct BirthAnnouncementSubjectTemplate;
+pub struct BirthAnnouncementBodyTemplate;
+
+impl TemplateTrait for BirthAnnouncementSubjectTemplate {
+    fn template_string() -> &'static str {
+        r#"{birthing_person_first_name} has a birth announcement 🎉"#
+    }
+}
+
+impl TemplateTrait for BirthAnnouncementBodyTemplate {
+    fn template_string() -> &'static str {
+        include_str!("../email/birth_announcement.html")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_announcement_subject_contains_placeholder() {
+        let template = BirthAnnouncementSubjectTemplate::template_string();
+        assert!(template.contains("{birthing_person_first_name}"));
+    }
+
+    #[test]
+    fn test_birth_announcement_body_contains_birth_details() {
+        let template = BirthAnnouncementBodyTemplate::template_string();
+        assert!(template.contains("<!DOCTYPE html>"));
+        assert!(template.contains("{babies}"));
+        assert!(template.contains("{time_of_birth}"));
+    }
+}
@@ -0,0 +1,28 @@
+use crate::infrastructure::templates::template::TemplateTrait;
+
+pub struct ContractionAutoClosedSubjectTemplate;
+pub struct ContractionAutoClosedBodyTemplate;
+
+impl TemplateTrait for ContractionAutoClosedSubjectTemplate {
+    fn template_string() -> &'static str {
+        r#"We stopped your contraction timer ⏱️"#
+    }
+}
+
+impl TemplateTrait for ContractionAutoClosedBodyTemplate {
+    fn template_string() -> &'static str {
+        include_str!("../email/contraction_auto_closed.html")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contraction_auto_closed_body_contains_placeholders() {
+        let template = ContractionAutoClosedBodyTemplate::template_string();
+        assert!(template.contains("<!DOCTYPE html>"));
+        assert!(template.contains("{started_at}"));
+    }
+}
@@ -0,0 +1,33 @@
+use crate::infrastructure::templates::template::TemplateTrait;
+
+pub struct ContractionPatternDetectedSubjectTemplate;
+pub struct ContractionPatternDetectedBodyTemplate;
+
+impl TemplateTrait for ContractionPatternDetectedSubjectTemplate {
+    fn template_string() -> &'static str {
+        r#"Your contractions match the {rule} pattern ⏱️"#
+    }
+}
+
+impl TemplateTrait for ContractionPatternDetectedBodyTemplate {
+    fn template_string() -> &'static str {
+        include_str!("../email/contraction_pattern_detected.html")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contraction_pattern_detected_subject_contains_rule_placeholder() {
+        let template = ContractionPatternDetectedSubjectTemplate::template_string();
+        assert!(template.contains("{rule}"));
+    }
+
+    #[test]
+    fn test_contraction_pattern_detected_body_contains_html() {
+        let template = ContractionPatternDetectedBodyTemplate::template_string();
+        assert!(template.contains("<!DOCTYPE html>"));
+    }
+}
@@ -0,0 +1,35 @@
+use crate::infrastructure::templates::template::TemplateTrait;
+
+pub struct MilestoneRecordedSubjectTemplate;
+pub struct MilestoneRecordedBodyTemplate;
+
+impl TemplateTrait for MilestoneRecordedSubjectTemplate {
+    fn template_string() -> &'static str {
+        r#"An update from {birthing_person_first_name}"#
+    }
+}
+
+impl TemplateTrait for MilestoneRecordedBodyTemplate {
+    fn template_string() -> &'static str {
+        include_str!("../email/milestone_recorded.html")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_milestone_recorded_subject_contains_placeholder() {
+        let template = MilestoneRecordedSubjectTemplate::template_string();
+        assert!(template.contains("{birthing_person_first_name}"));
+    }
+
+    #[test]
+    fn test_milestone_recorded_body_contains_milestone() {
+        let template = MilestoneRecordedBodyTemplate::template_string();
+        assert!(template.contains("<!DOCTYPE html>"));
+        assert!(template.contains("{milestone}"));
+        assert!(template.contains("{occurred_at}"));
+    }
+}
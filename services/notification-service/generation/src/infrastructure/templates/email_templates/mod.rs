@@ -1,10 +1,15 @@
+pub mod birth_announcement;
 pub mod contact_us;
+pub mod contraction_auto_closed;
+pub mod contraction_pattern_detected;
 pub mod labour_announcement;
 pub mod labour_begun;
 pub mod labour_completed;
 pub mod labour_completed_with_note;
 pub mod labour_invite;
 pub mod labour_update;
+pub mod milestone_recorded;
 pub mod subscriber_approved;
+pub mod subscriber_contraction_pattern_detected;
 pub mod subscriber_invite;
 pub mod subscriber_requested;
@@ -0,0 +1,34 @@
+use crate::infrastructure::templates::template::TemplateTrait;
+
+pub struct SubscriberContractionPatternDetectedSubjectTemplate;
+pub struct SubscriberContractionPatternDetectedBodyTemplate;
+
+impl TemplateTrait for SubscriberContractionPatternDetectedSubjectTemplate {
+    fn template_string() -> &'static str {
+        r#"{birthing_person_first_name}'s contractions match the {rule} pattern ⏱️"#
+    }
+}
+
+impl TemplateTrait for SubscriberContractionPatternDetectedBodyTemplate {
+    fn template_string() -> &'static str {
+        include_str!("../email/subscriber_contraction_pattern_detected.html")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscriber_contraction_pattern_detected_subject_contains_placeholders() {
+        let template = SubscriberContractionPatternDetectedSubjectTemplate::template_string();
+        assert!(template.contains("{birthing_person_first_name}"));
+        assert!(template.contains("{rule}"));
+    }
+
+    #[test]
+    fn test_subscriber_contraction_pattern_detected_body_contains_html() {
+        let template = SubscriberContractionPatternDetectedBodyTemplate::template_string();
+        assert!(template.contains("<!DOCTYPE html>"));
+    }
+}
@@ -3,7 +3,16 @@ pub mod sms_templates;
 pub mod template;
 pub mod whatsapp_templates;
 
+pub use email_templates::birth_announcement::{
+    BirthAnnouncementBodyTemplate, BirthAnnouncementSubjectTemplate,
+};
 pub use email_templates::contact_us::{ContactUsBodyTemplate, ContactUsSubjectTemplate};
+pub use email_templates::contraction_auto_closed::{
+    ContractionAutoClosedBodyTemplate, ContractionAutoClosedSubjectTemplate,
+};
+pub use email_templates::contraction_pattern_detected::{
+    ContractionPatternDetectedBodyTemplate, ContractionPatternDetectedSubjectTemplate,
+};
 pub use email_templates::labour_announcement::{
     LabourAnnouncementBodyTemplate, LabourAnnouncementSubjectTemplate,
 };
@@ -16,9 +25,16 @@ pub use email_templates::labour_completed_with_note::{
 };
 pub use email_templates::labour_invite::{LabourInviteBodyTemplate, LabourInviteSubjectTemplate};
 pub use email_templates::labour_update::{LabourUpdateBodyTemplate, LabourUpdateSubjectTemplate};
+pub use email_templates::milestone_recorded::{
+    MilestoneRecordedBodyTemplate, MilestoneRecordedSubjectTemplate,
+};
 pub use email_templates::subscriber_approved::{
     SubscriberApprovedBodyTemplate, SubscriberApprovedSubjectTemplate,
 };
+pub use email_templates::subscriber_contraction_pattern_detected::{
+    SubscriberContractionPatternDetectedBodyTemplate,
+    SubscriberContractionPatternDetectedSubjectTemplate,
+};
 pub use email_templates::subscriber_invite::{
     SubscriberInviteBodyTemplate, SubscriberInviteSubjectTemplate,
 };
@@ -26,8 +42,13 @@ pub use email_templates::subscriber_requested::{
     SubscriberRequestedBodyTemplate, SubscriberRequestedSubjectTemplate,
 };
 
+pub use sms_templates::birth_announcement::BirthAnnouncementTemplate;
+pub use sms_templates::contraction_auto_closed::ContractionAutoClosedTemplate;
+pub use sms_templates::contraction_pattern_detected::ContractionPatternDetectedTemplate;
 pub use sms_templates::labour_announcement::LabourAnnouncementTemplate;
 pub use sms_templates::labour_begun::LabourBegunTemplate;
 pub use sms_templates::labour_completed::LabourCompletedTemplate;
 pub use sms_templates::labour_completed_with_note::LabourCompletedWithNoteTemplate;
 pub use sms_templates::labour_update::LabourUpdateTemplate;
+pub use sms_templates::milestone_recorded::MilestoneRecordedTemplate;
+pub use sms_templates::subscriber_contraction_pattern_detected::SubscriberContractionPatternDetectedTemplate;
@@ -0,0 +1,25 @@
+use crate::infrastructure::templates::template::TemplateTrait;
+
+pub struct BirthAnnouncementTemplate;
+
+impl TemplateTrait for BirthAnnouncementTemplate {
+    fn template_string() -> &'static str {
+        "Hey {subscriber_first_name},\n\
+         Wonderful news from {birthing_person_first_name}!\n\
+         Welcome to the world, {babies}, born {time_of_birth}."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_announcement_contains_placeholders() {
+        let template = BirthAnnouncementTemplate::template_string();
+        assert!(template.contains("{subscriber_first_name}"));
+        assert!(template.contains("{birthing_person_first_name}"));
+        assert!(template.contains("{babies}"));
+        assert!(template.contains("{time_of_birth}"));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::infrastructure::templates::template::TemplateTrait;
+
+pub struct ContractionAutoClosedTemplate;
+
+impl TemplateTrait for ContractionAutoClosedTemplate {
+    fn template_string() -> &'static str {
+        "Hey {birthing_person_first_name},\n\
+         The contraction you started at {started_at} was still being timed, so we stopped it.\n\
+         You can correct its end time in FernLabour."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contraction_auto_closed_contains_placeholders() {
+        let template = ContractionAutoClosedTemplate::template_string();
+        assert!(template.contains("{birthing_person_first_name}"));
+        assert!(template.contains("{started_at}"));
+    }
+}
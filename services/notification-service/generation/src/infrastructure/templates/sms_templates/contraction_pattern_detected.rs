@@ -0,0 +1,23 @@
+use crate::infrastructure::templates::template::TemplateTrait;
+
+pub struct ContractionPatternDetectedTemplate;
+
+impl TemplateTrait for ContractionPatternDetectedTemplate {
+    fn template_string() -> &'static str {
+        "Hey {birthing_person_first_name},\n\
+         Your recent contractions have met the {rule} rule.\n\
+         This is often the point to call your midwife or maternity unit."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contraction_pattern_detected_contains_placeholders() {
+        let template = ContractionPatternDetectedTemplate::template_string();
+        assert!(template.contains("{birthing_person_first_name}"));
+        assert!(template.contains("{rule}"));
+    }
+}
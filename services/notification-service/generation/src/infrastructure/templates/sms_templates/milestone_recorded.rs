@@ -0,0 +1,24 @@
+use crate::infrastructure::templates::template::TemplateTrait;
+
+pub struct MilestoneRecordedTemplate;
+
+impl TemplateTrait for MilestoneRecordedTemplate {
+    fn template_string() -> &'static str {
+        "Hey {subscriber_first_name},\n\
+         {birthing_person_first_name} has let you know that {milestone}.\n\
+         Keep an eye on FernLabour for their next update."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_milestone_recorded_contains_placeholders() {
+        let template = MilestoneRecordedTemplate::template_string();
+        assert!(template.contains("{subscriber_first_name}"));
+        assert!(template.contains("{birthing_person_first_name}"));
+        assert!(template.contains("{milestone}"));
+    }
+}
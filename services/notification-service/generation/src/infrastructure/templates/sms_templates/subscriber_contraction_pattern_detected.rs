@@ -0,0 +1,24 @@
+use crate::infrastructure::templates::template::TemplateTrait;
+
+pub struct SubscriberContractionPatternDetectedTemplate;
+
+impl TemplateTrait for SubscriberContractionPatternDetectedTemplate {
+    fn template_string() -> &'static str {
+        "Hey {subscriber_first_name},\n\
+         {birthing_person_first_name}'s contractions have met the {rule} rule.\n\
+         Check FernLabour for updates and be ready to go."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscriber_contraction_pattern_detected_contains_placeholders() {
+        let template = SubscriberContractionPatternDetectedTemplate::template_string();
+        assert!(template.contains("{subscriber_first_name}"));
+        assert!(template.contains("{birthing_person_first_name}"));
+        assert!(template.contains("{rule}"));
+    }
+}
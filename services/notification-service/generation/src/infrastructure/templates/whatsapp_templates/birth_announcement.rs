@@ -0,0 +1,49 @@
+use crate::infrastructure::templates::template::TemplateTrait;
+
+/// Birth announcements go out through the approved labour announcement
+/// template, with the birth details as the announcement text.
+pub use super::labour_announcement::LabourAnnouncementTemplateSid as BirthAnnouncementTemplateSid;
+
+pub struct BirthAnnouncementContentVariablesTemplate;
+
+impl TemplateTrait for BirthAnnouncementContentVariablesTemplate {
+    fn template_string() -> &'static str {
+        "\\{\"1\":\"{subscriber_first_name}\",\"2\":\"{birthing_person_first_name}\",\"3\":\"Welcome to the world, {babies}, born {time_of_birth}.\"}"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_announcement_renders_to_valid_json() {
+        use serde_json::Value;
+        use tinytemplate::TinyTemplate;
+
+        let mut tt = TinyTemplate::new();
+        tt.add_template(
+            "test",
+            BirthAnnouncementContentVariablesTemplate::template_string(),
+        )
+        .unwrap();
+
+        let mut context = std::collections::HashMap::new();
+        context.insert("subscriber_first_name", "John");
+        context.insert("birthing_person_first_name", "Sarah");
+        context.insert("babies", "Ada (girl, 3.40 kg)");
+        context.insert("time_of_birth", "1 January 2024 at 12:00 UTC");
+
+        let rendered = tt.render("test", &context).unwrap();
+
+        let parsed: Value =
+            serde_json::from_str(&rendered).expect("Rendered template should be valid JSON");
+
+        assert_eq!(parsed["1"], "John");
+        assert_eq!(parsed["2"], "Sarah");
+        assert_eq!(
+            parsed["3"],
+            "Welcome to the world, Ada (girl, 3.40 kg), born 1 January 2024 at 12:00 UTC."
+        );
+    }
+}
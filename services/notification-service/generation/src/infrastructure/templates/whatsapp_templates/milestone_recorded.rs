@@ -0,0 +1,48 @@
+use crate::infrastructure::templates::template::TemplateTrait;
+
+/// Milestones go out through the approved labour announcement template,
+/// with the milestone as the announcement text.
+pub use super::labour_announcement::LabourAnnouncementTemplateSid as MilestoneRecordedTemplateSid;
+
+pub struct MilestoneRecordedContentVariablesTemplate;
+
+impl TemplateTrait for MilestoneRecordedContentVariablesTemplate {
+    fn template_string() -> &'static str {
+        "\\{\"1\":\"{subscriber_first_name}\",\"2\":\"{birthing_person_first_name}\",\"3\":\"{birthing_person_first_name} has let you know that {milestone}.\"}"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_milestone_recorded_renders_to_valid_json() {
+        use serde_json::Value;
+        use tinytemplate::TinyTemplate;
+
+        let mut tt = TinyTemplate::new();
+        tt.add_template(
+            "test",
+            MilestoneRecordedContentVariablesTemplate::template_string(),
+        )
+        .unwrap();
+
+        let mut context = std::collections::HashMap::new();
+        context.insert("subscriber_first_name", "John");
+        context.insert("birthing_person_first_name", "Sarah");
+        context.insert("milestone", "their waters have broken");
+
+        let rendered = tt.render("test", &context).unwrap();
+
+        let parsed: Value =
+            serde_json::from_str(&rendered).expect("Rendered template should be valid JSON");
+
+        assert_eq!(parsed["1"], "John");
+        assert_eq!(parsed["2"], "Sarah");
+        assert_eq!(
+            parsed["3"],
+            "Sarah has let you know that their waters have broken."
+        );
+    }
+}
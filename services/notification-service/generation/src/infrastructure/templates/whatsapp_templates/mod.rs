@@ -1,4 +1,6 @@
+pub mod birth_announcement;
 pub mod labour_announcement;
 pub mod labour_begun;
 pub mod labour_completed;
 pub mod labour_completed_with_note;
+pub mod milestone_recorded;
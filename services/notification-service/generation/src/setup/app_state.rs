@@ -1,5 +1,7 @@
+use std::rc::Rc;
+
 use anyhow::{Context, Result};
-use fern_labour_event_sourcing_rs::CommandEnvelope;
+use fern_labour_event_sourcing_rs::{Clock, CommandEnvelope, SystemClock};
 use fern_labour_notifications_shared::{QueueMessage, QueueProducerTrait};
 use fern_labour_workers_shared::NotificationQueueProducer;
 use worker::Env;
@@ -12,6 +14,7 @@ use crate::{
 pub struct AppState {
     pub template_engine: Box<dyn TemplateEngineTrait>,
     pub command_producer: Box<dyn QueueProducerTrait<Envelope = CommandEnvelope<QueueMessage>>>,
+    pub clock: Rc<dyn Clock>,
 }
 
 impl AppState {
@@ -32,6 +35,7 @@ impl AppState {
         Ok(Self {
             template_engine,
             command_producer,
+            clock: Rc::new(SystemClock),
         })
     }
 }
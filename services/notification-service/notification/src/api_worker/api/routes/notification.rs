@@ -1,3 +1,4 @@
+use fern_labour_event_sourcing_rs::CommandEnvelope;
 use fern_labour_notifications_shared::service_clients::notification::NotificationRequest;
 use fern_labour_workers_shared::{CorsContext, clients::worker_clients::auth::User};
 use tracing::{error, info};
@@ -27,6 +28,7 @@ pub async fn handle_create_notification(
     };
 
     let notification_id = Uuid::now_v7();
+    let causation = request_dto.causation;
 
     let domain_command = match request_dto.try_into_domain(notification_id) {
         Ok(cmd) => cmd,
@@ -48,15 +50,18 @@ pub async fn handle_create_notification(
         "Creating notification via public API"
     );
 
+    let envelope = CommandEnvelope::caused_by(
+        domain_command,
+        notification_id,
+        causation,
+        user.user_id.clone(),
+        ctx.data.clock.as_ref(),
+    );
+
     let res = ctx
         .data
         .do_client
-        .command(
-            notification_id,
-            domain_command,
-            &user,
-            "/notification/domain",
-        )
+        .send_envelope(notification_id, "/notification/domain", envelope, &user)
         .await
         .map_err(|e| format!("Failed to send command to notification aggregate: {e}"))?;
 
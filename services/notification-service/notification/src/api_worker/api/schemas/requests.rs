@@ -72,6 +72,7 @@ mod tests {
             },
             metadata: None,
             priority: NotificationPriority::Normal,
+            causation: Default::default(),
         };
 
         let notification_id = Uuid::now_v7();
@@ -103,6 +104,7 @@ mod tests {
             },
             metadata: None,
             priority: NotificationPriority::Normal,
+            causation: Default::default(),
         };
 
         let result = dto.try_into_domain(Uuid::now_v7());
@@ -122,6 +124,7 @@ mod tests {
             },
             metadata: None,
             priority: NotificationPriority::Normal,
+            causation: Default::default(),
         };
 
         let result = dto.try_into_domain(Uuid::now_v7());
@@ -144,6 +147,7 @@ mod tests {
             },
             metadata: Some(metadata.clone()),
             priority: NotificationPriority::High,
+            causation: Default::default(),
         };
 
         let result = dto.try_into_domain(Uuid::now_v7()).unwrap();
@@ -1,4 +1,7 @@
+use std::rc::Rc;
+
 use anyhow::{Context, Result};
+use fern_labour_event_sourcing_rs::{Clock, SystemClock};
 use fern_labour_notifications_shared::service_clients::{DispatchClient, GenerationClient};
 use fern_labour_workers_shared::{
     ConfigTrait,
@@ -38,6 +41,7 @@ pub struct AppState {
     pub do_client: DurableObjectCQRSClient,
     pub generation_client: Box<dyn GenerationClient>,
     pub dispatch_client: Box<dyn DispatchClient>,
+    pub clock: Rc<dyn Clock>,
 }
 
 impl AppState {
@@ -83,12 +87,12 @@ impl AppState {
         )))
     }
 
-    fn create_do_client(env: &Env) -> Result<DurableObjectCQRSClient> {
+    fn create_do_client(env: &Env, clock: Rc<dyn Clock>) -> Result<DurableObjectCQRSClient> {
         let aggregate_namespace = env
             .durable_object("NOTIFICATION_AGGREGATE")
             .context("Missing binding NOTIFICATION_AGGREGATE")?;
 
-        Ok(DurableObjectCQRSClient::create(aggregate_namespace))
+        Ok(DurableObjectCQRSClient::create(aggregate_namespace).with_clock(clock))
     }
 
     fn create_auth_service(env: &Env) -> Result<Box<dyn AuthServiceClient>> {
@@ -138,7 +142,8 @@ impl AppState {
             notification_activity_db,
         ));
 
-        let do_client = Self::create_do_client(env)?;
+        let clock: Rc<dyn Clock> = Rc::new(SystemClock);
+        let do_client = Self::create_do_client(env, clock.clone())?;
         let generation_client = Self::create_generation(env, &config.internal_auth_token)?;
         let dispatch_client = Self::create_dispatch(env, &config.internal_auth_token)?;
 
@@ -152,6 +157,7 @@ impl AppState {
             do_client,
             generation_client,
             dispatch_client,
+            clock,
         })
     }
 }
@@ -16,6 +16,11 @@ pub enum RequestDto {
         envelope: CommandEnvelope<AdminCommand>,
     },
     EventsQuery,
+    VerifyEventStream,
+    ExportEventStream,
+    ImportEventStream {
+        ndjson: String,
+    },
 }
 
 impl RequestDto {
@@ -64,6 +69,15 @@ impl RequestDto {
                 Ok(Self::AdminCommand { envelope })
             }
             (worker::Method::Get, "/notification/events") => Ok(Self::EventsQuery),
+            (worker::Method::Get, "/admin/verify") => Ok(Self::VerifyEventStream),
+            (worker::Method::Get, "/admin/export") => Ok(Self::ExportEventStream),
+            (worker::Method::Post, "/admin/import") => {
+                let ndjson = req.text().await?;
+
+                info!(bytes = ndjson.len(), "Received event stream import");
+
+                Ok(Self::ImportEventStream { ndjson })
+            }
             _ => Response::error("Not Found", 404).map(|_| unreachable!()),
         }
     }
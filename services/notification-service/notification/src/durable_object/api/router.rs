@@ -1,3 +1,6 @@
+use anyhow::anyhow;
+use fern_labour_event_sourcing_rs::CausationContext;
+use futures::stream;
 use tracing::{error, info};
 use worker::Response;
 
@@ -52,7 +55,12 @@ pub fn route_and_handle(aggregate: &NotificationAggregate, request: RequestDto)
                 .services
                 .write_model()
                 .notification_command_processor
-                .handle_command(envelope.command, envelope.metadata.user_id.clone());
+                .handle_idempotent_command(
+                    envelope.command,
+                    envelope.metadata.user_id.clone(),
+                    CausationContext::from_command(&envelope.metadata),
+                    &envelope.metadata.idempotency_key.to_string(),
+                );
 
             if let Err(ref err) = result {
                 error!("Command execution failed: {}", err);
@@ -77,7 +85,12 @@ pub fn route_and_handle(aggregate: &NotificationAggregate, request: RequestDto)
                 .services
                 .write_model()
                 .notification_command_processor
-                .handle_command(domain_command, envelope.metadata.user_id.clone());
+                .handle_idempotent_command(
+                    domain_command,
+                    envelope.metadata.user_id.clone(),
+                    CausationContext::from_command(&envelope.metadata),
+                    &envelope.metadata.idempotency_key.to_string(),
+                );
 
             if let Err(ref err) = result {
                 error!("Command execution failed: {}", err);
@@ -116,5 +129,80 @@ pub fn route_and_handle(aggregate: &NotificationAggregate, request: RequestDto)
                 .query_service
                 .get_event_stream(),
         ),
+        RequestDto::VerifyEventStream => {
+            info!("Verifying event stream hash chain");
+
+            let result = aggregate
+                .services
+                .read_model()
+                .query_service
+                .verify_event_stream();
+
+            match &result {
+                Ok(verification) if !verification.is_intact() => {
+                    error!(broken_link = ?verification.first_broken_link, "Event stream hash chain is broken");
+                }
+                Ok(_) => info!("Event stream hash chain verified"),
+                Err(err) => error!("Event stream verification failed: {}", err),
+            }
+
+            CommandResult::from_json_result(result)
+        }
+        RequestDto::ExportEventStream => {
+            info!("Exporting event stream");
+
+            let result = aggregate
+                .services
+                .write_model()
+                .event_stream_transfer
+                .export();
+
+            match result {
+                Ok(export) => {
+                    let chunks = export.map(|chunk| {
+                        chunk.map_err(|err| {
+                            error!("Event stream export failed: {}", err);
+                            worker::Error::RustError(err.to_string())
+                        })
+                    });
+                    let response =
+                        Response::from_stream(stream::iter(chunks)).and_then(|mut response| {
+                            response
+                                .headers_mut()
+                                .set("Content-Type", "application/x-ndjson")?;
+                            Ok(response)
+                        });
+                    match response {
+                        Ok(response) => CommandResult::Success(response),
+                        Err(err) => {
+                            error!("Event stream export failed: {}", err);
+                            CommandResult::from_unit_result(Err(anyhow!(err.to_string())))
+                        }
+                    }
+                }
+                Err(err) => {
+                    error!("Event stream export failed: {}", err);
+                    CommandResult::from_unit_result(Err(err))
+                }
+            }
+        }
+        RequestDto::ImportEventStream { ndjson } => {
+            info!("Importing event stream");
+
+            let result = aggregate
+                .services
+                .write_model()
+                .event_stream_transfer
+                .import(&ndjson)
+                .map(|_| ());
+
+            if let Err(ref err) = result {
+                error!("Event stream import failed: {}", err);
+            } else {
+                info!("Event stream imported successfully");
+            }
+
+            CommandResult::from_unit_result(result)
+        }
     }
 }
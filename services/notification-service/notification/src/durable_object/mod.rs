@@ -107,7 +107,7 @@ impl DurableObject for NotificationAggregate {
         // These futures could be processed concurrently. However, if the notification is high priority
         // we want to wait until all event processing is completed before projecting.
         // This ensures that the read models show the most up-to-date changes after processing is over.
-        let process_manager_result = services.process_manager.on_alarm().await;
+        let process_manager_result = services.process_manager.run_until_settled().await;
         if let Err(ref e) = process_manager_result {
             error!(error = %e, "Error in process manager alarm handling");
         }
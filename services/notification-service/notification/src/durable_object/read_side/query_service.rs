@@ -2,7 +2,9 @@ use std::rc::Rc;
 
 use anyhow::{Context, Result};
 use chrono::NaiveDateTime;
-use fern_labour_event_sourcing_rs::{Event, EventEnvelope, EventMetadata, EventStoreTrait};
+use fern_labour_event_sourcing_rs::{
+    Event, EventEnvelope, EventMetadata, EventStoreTrait, StreamVerification,
+};
 
 use crate::durable_object::write_side::domain::NotificationEvent;
 
@@ -42,6 +44,8 @@ impl QueryService {
                     event_version: stored.event_version,
                     timestamp,
                     user_id: stored.user_id,
+                    correlation_id: stored.correlation_id,
+                    causation_id: stored.causation_id,
                 };
 
                 Ok(EventEnvelope { metadata, event })
@@ -50,4 +54,10 @@ impl QueryService {
 
         Ok(envelopes)
     }
+
+    pub fn verify_event_stream(&self) -> Result<StreamVerification> {
+        self.event_store
+            .verify_chain()
+            .context("Failed to verify event stream")
+    }
 }
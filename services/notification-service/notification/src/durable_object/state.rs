@@ -1,17 +1,22 @@
 use std::{cell::RefCell, rc::Rc};
 
 use anyhow::{Context, Result};
+use chrono::Duration;
 use fern_labour_notifications_shared::{
     QueueMessage, QueueProducerTrait,
     service_clients::{DispatchClient, GenerationClient},
 };
 use fern_labour_workers_shared::{
-    NotificationQueueProducer,
+    NotificationQueueProducer, SqlEffectLedger, SqlProcessedCommandStore,
     clients::{FetcherDispatchClient, FetcherGenerationClient},
 };
-use worker::{Env, State};
+use worker::{Env, SqlStorage, State};
 
-use fern_labour_event_sourcing_rs::{AggregateRepository, AsyncProjector, CommandEnvelope};
+use fern_labour_event_sourcing_rs::{
+    AggregateRepository, AsyncProjector, Clock, CommandEnvelope, EffectLedgerTrait,
+    EventStoreTrait, EventStreamTransfer, IdempotencyGuard, ProcessManager,
+    ProcessedCommandStoreTrait, SystemClock, UpcastingEventStore,
+};
 
 use crate::{
     durable_object::{
@@ -28,9 +33,9 @@ use crate::{
                 AdminCommandProcessor,
                 command_processors::{NotificationCommandProcessor, ServiceCommandProcessor},
             },
-            domain::NotificationEvent,
+            domain::{Notification, NotificationEvent, events::upcasters::upcaster_registry},
             infrastructure::SqlEventStore,
-            process_manager::{EffectLedger, NotificationEffectExecutor, ProcessManager},
+            process_manager::{Effect, NotificationEffectExecutor},
         },
     },
     read_models::{
@@ -39,9 +44,12 @@ use crate::{
     },
 };
 
+const IDEMPOTENCY_RETENTION_HOURS: i64 = 24;
+
 pub struct WriteModel {
     pub notification_command_processor: NotificationCommandProcessor,
     pub admin_command_processor: AdminCommandProcessor,
+    pub event_stream_transfer: EventStreamTransfer,
 }
 
 pub struct ReadModel {
@@ -49,7 +57,7 @@ pub struct ReadModel {
 }
 
 pub struct AsyncProcessors {
-    pub process_manager: ProcessManager<NotificationEffectExecutor>,
+    pub process_manager: ProcessManager<Notification, Effect, NotificationEffectExecutor>,
     pub projection_processor: ProjectionProcessor,
 }
 
@@ -57,6 +65,7 @@ pub struct AggregateServices {
     write_model: WriteModel,
     read_model: ReadModel,
     async_processors: RefCell<Option<Rc<AsyncProcessors>>>,
+    clock: Rc<dyn Clock>,
 }
 
 impl AggregateServices {
@@ -109,33 +118,68 @@ impl AggregateServices {
         )))
     }
 
-    fn build_write_model(state: &State) -> Result<WriteModel> {
+    fn build_idempotency_guard(sql: &SqlStorage, clock: Rc<dyn Clock>) -> Result<IdempotencyGuard> {
+        let store = SqlProcessedCommandStore::create(sql.clone());
+        store
+            .init_schema()
+            .context("Processed command store initialization failed")?;
+
+        Ok(
+            IdempotencyGuard::new(Rc::new(store), Duration::hours(IDEMPOTENCY_RETENTION_HOURS))
+                .with_clock(clock),
+        )
+    }
+
+    fn build_event_store(sql: &SqlStorage, clock: Rc<dyn Clock>) -> Rc<dyn EventStoreTrait> {
+        Rc::new(UpcastingEventStore::new(
+            SqlEventStore::create(sql.clone()).with_clock(clock),
+            upcaster_registry(),
+        ))
+    }
+
+    fn build_write_model(state: &State, clock: Rc<dyn Clock>) -> Result<WriteModel> {
         let sql = state.storage().sql();
-        let event_store = SqlEventStore::create(sql.clone());
+        let event_store = Self::build_event_store(&sql, clock.clone());
         event_store
             .init_schema()
             .context("Event store initialization failed")?;
 
         let repository = Box::new(AggregateRepository::new(event_store.clone()));
-        let notification_command_processor = NotificationCommandProcessor::new(repository);
+        let notification_command_processor = NotificationCommandProcessor::new(
+            repository,
+            Self::build_idempotency_guard(&sql, clock.clone())?,
+        )
+        .with_clock(clock.clone());
 
         let admin_command_processor = AdminCommandProcessor::create();
 
+        let ledger = Rc::new(SqlEffectLedger::create(sql));
+        ledger
+            .init_schema()
+            .context("Effect ledger initialization failed")?;
+        let event_stream_transfer = EventStreamTransfer::new(event_store, ledger).with_clock(clock);
+
         Ok(WriteModel {
             notification_command_processor,
             admin_command_processor,
+            event_stream_transfer,
         })
     }
 
-    fn build_read_model(state: &State) -> Result<ReadModel> {
-        let query_service = QueryService::new(SqlEventStore::create(state.storage().sql()));
+    fn build_read_model(state: &State, clock: Rc<dyn Clock>) -> Result<ReadModel> {
+        let query_service =
+            QueryService::new(Self::build_event_store(&state.storage().sql(), clock));
 
         Ok(ReadModel { query_service })
     }
 
-    fn build_async_processors(state: &State, env: &Env) -> Result<AsyncProcessors> {
+    fn build_async_processors(
+        state: &State,
+        env: &Env,
+        clock: Rc<dyn Clock>,
+    ) -> Result<AsyncProcessors> {
         let sql = state.storage().sql();
-        let event_store = SqlEventStore::create(sql.clone());
+        let event_store = Self::build_event_store(&sql, clock.clone());
 
         let command_bus = Self::create_command_bus(env)?;
 
@@ -150,19 +194,22 @@ impl AggregateServices {
         let generation_client = Self::create_generation_client(env, &internal_auth_token)?;
         let dispatch_client = Self::create_dispatch_client(env, &internal_auth_token)?;
         let service_command_processor =
-            ServiceCommandProcessor::create(command_bus, generation_client, dispatch_client);
+            ServiceCommandProcessor::create(command_bus, generation_client, dispatch_client)
+                .with_clock(clock.clone());
 
         let aggregate_repository = Rc::new(AggregateRepository::new(event_store.clone()));
-        let notification_command_processor = NotificationCommandProcessor::new(Box::new(
-            AggregateRepository::new(event_store.clone()),
-        ));
+        let notification_command_processor = NotificationCommandProcessor::new(
+            Box::new(AggregateRepository::new(event_store.clone())),
+            Self::build_idempotency_guard(&sql, clock.clone())?,
+        )
+        .with_clock(clock);
 
         let executor = NotificationEffectExecutor::new(
             service_command_processor,
             notification_command_processor,
         );
 
-        let ledger = EffectLedger::create(sql.clone());
+        let ledger = Rc::new(SqlEffectLedger::create(sql.clone()));
         ledger
             .init_schema()
             .context("Effect ledger initialization failed")?;
@@ -177,13 +224,15 @@ impl AggregateServices {
     }
 
     pub fn from_worker_state(state: &State) -> Result<Self> {
-        let write_model = Self::build_write_model(state)?;
-        let read_model = Self::build_read_model(state)?;
+        let clock: Rc<dyn Clock> = Rc::new(SystemClock);
+        let write_model = Self::build_write_model(state, clock.clone())?;
+        let read_model = Self::build_read_model(state, clock.clone())?;
 
         Ok(Self {
             write_model,
             read_model,
             async_processors: RefCell::new(None),
+            clock,
         })
     }
 
@@ -197,7 +246,7 @@ impl AggregateServices {
 
     pub fn async_processors(&self, state: &State, env: &Env) -> Result<()> {
         if self.async_processors.borrow().is_none() {
-            let services = Self::build_async_processors(state, env)?;
+            let services = Self::build_async_processors(state, env, self.clock.clone())?;
             *self.async_processors.borrow_mut() = Some(Rc::new(services));
         }
         Ok(())
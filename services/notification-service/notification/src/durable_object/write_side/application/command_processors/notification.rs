@@ -1,28 +1,166 @@
+use std::rc::Rc;
+
 use anyhow::{Result, anyhow};
-use fern_labour_event_sourcing_rs::{Aggregate, AggregateRepositoryTrait};
+use fern_labour_event_sourcing_rs::{
+    Aggregate, AggregateRepositoryTrait, CausationContext, Clock, IdempotencyGuard, SystemClock,
+    retry_on_conflict,
+};
 
 use crate::durable_object::write_side::domain::{Notification, NotificationCommand};
 
+const MAX_CONCURRENCY_ATTEMPTS: usize = 3;
+
 pub struct NotificationCommandProcessor {
     repository: Box<dyn AggregateRepositoryTrait<Notification>>,
+    idempotency: IdempotencyGuard,
+    clock: Rc<dyn Clock>,
 }
 
 impl NotificationCommandProcessor {
-    pub fn new(repository: Box<dyn AggregateRepositoryTrait<Notification>>) -> Self {
-        Self { repository }
+    pub fn new(
+        repository: Box<dyn AggregateRepositoryTrait<Notification>>,
+        idempotency: IdempotencyGuard,
+    ) -> Self {
+        Self {
+            repository,
+            idempotency,
+            clock: Rc::new(SystemClock),
+        }
     }
 
-    pub fn handle_command(&self, command: NotificationCommand, user_id: String) -> Result<()> {
-        let aggregate = self.repository.load()?;
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
 
-        let events = Notification::handle_command(aggregate.as_ref(), command)
+    /// Handles `command` at most once per `idempotency_key`; repeats within the
+    /// retention window return the original outcome without executing again.
+    pub fn handle_idempotent_command(
+        &self,
+        command: NotificationCommand,
+        user_id: String,
+        causation: CausationContext,
+        idempotency_key: &str,
+    ) -> Result<()> {
+        self.idempotency.execute(idempotency_key, || {
+            self.handle_command(command, user_id, causation)
+        })
+    }
+
+    pub fn handle_command(
+        &self,
+        command: NotificationCommand,
+        user_id: String,
+        causation: CausationContext,
+    ) -> Result<()> {
+        retry_on_conflict(MAX_CONCURRENCY_ATTEMPTS, || {
+            self.try_handle_command(command.clone(), user_id.clone(), causation)
+        })
+    }
+
+    fn try_handle_command(
+        &self,
+        command: NotificationCommand,
+        user_id: String,
+        causation: CausationContext,
+    ) -> Result<()> {
+        let (aggregate, version) = self.repository.load_with_version()?;
+
+        let events = Notification::handle_command(aggregate.as_ref(), command, self.clock.as_ref())
             .map_err(|e| anyhow!("Domain error: {}", e))?;
 
         if events.is_empty() {
             return Ok(());
         }
 
-        self.repository.save(aggregate.as_ref(), &events, user_id)?;
+        self.repository
+            .save(aggregate.as_ref(), &events, user_id, causation, version)?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use chrono::Duration;
+    use fern_labour_event_sourcing_rs::{
+        AggregateRepository,
+        testing::{AggregateTestHarness, InMemoryProcessedCommandStore},
+    };
+    use fern_labour_notifications_shared::{
+        ServiceCommand,
+        value_objects::{
+            EmailAddress, NotificationChannel, NotificationDestination, NotificationPriority,
+            NotificationTemplateData,
+        },
+    };
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::durable_object::write_side::process_manager::Effect;
+
+    fn processor(harness: &AggregateTestHarness<Notification>) -> NotificationCommandProcessor {
+        NotificationCommandProcessor::new(
+            Box::new(AggregateRepository::new(harness.event_store())),
+            IdempotencyGuard::new(
+                Rc::new(InMemoryProcessedCommandStore::new()),
+                Duration::hours(1),
+            ),
+        )
+    }
+
+    fn request_notification(
+        notification_id: Uuid,
+        priority: NotificationPriority,
+    ) -> NotificationCommand {
+        NotificationCommand::RequestNotification {
+            notification_id,
+            channel: NotificationChannel::EMAIL,
+            destination: NotificationDestination::Email(
+                EmailAddress::new("test@example.com").unwrap(),
+            ),
+            template_data: NotificationTemplateData::ContactUs {
+                name: "John Doe".to_string(),
+            },
+            metadata: None,
+            priority,
+        }
+    }
+
+    fn render_priorities(priority: NotificationPriority) -> Vec<bool> {
+        let harness = AggregateTestHarness::<Notification>::new();
+        let processor = processor(&harness);
+        let notification_id = Uuid::now_v7();
+
+        harness
+            .when_processed(|| {
+                processor.handle_command(
+                    request_notification(notification_id, priority),
+                    "user".to_string(),
+                    CausationContext::new_request(),
+                )
+            })
+            .then_route_policies::<Effect>()
+            .into_iter()
+            .map(|effect| match effect {
+                Effect::ServiceCommand {
+                    command: ServiceCommand::RenderNotification { .. },
+                    priority,
+                    ..
+                } => priority,
+                Effect::ServiceCommand { .. } => panic!("Expected a render command"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn requested_notifications_are_rendered() {
+        assert_eq!(render_priorities(NotificationPriority::Normal), vec![false]);
+    }
+
+    #[test]
+    fn high_priority_notifications_render_synchronously() {
+        assert_eq!(render_priorities(NotificationPriority::High), vec![true]);
+    }
+}
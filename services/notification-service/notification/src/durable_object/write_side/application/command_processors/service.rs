@@ -1,12 +1,12 @@
+use std::rc::Rc;
+
 use anyhow::{Context, Result, anyhow};
-use chrono::Utc;
-use fern_labour_event_sourcing_rs::CommandEnvelope;
+use fern_labour_event_sourcing_rs::{CausationContext, Clock, CommandEnvelope, SystemClock};
 use fern_labour_notifications_shared::{
     QueueMessage, QueueProducerTrait, ServiceCommand,
     service_clients::{DispatchClient, DispatchRequest, GenerationClient},
 };
 use tracing::info;
-use uuid::Uuid;
 
 use crate::durable_object::write_side::domain::NotificationCommand;
 
@@ -14,6 +14,7 @@ pub struct ServiceCommandProcessor {
     command_queue: Box<dyn QueueProducerTrait<Envelope = CommandEnvelope<QueueMessage>>>,
     generation_client: Box<dyn GenerationClient>,
     dispatch_client: Box<dyn DispatchClient>,
+    clock: Rc<dyn Clock>,
 }
 
 impl ServiceCommandProcessor {
@@ -26,21 +27,25 @@ impl ServiceCommandProcessor {
             command_queue,
             generation_client,
             dispatch_client,
+            clock: Rc::new(SystemClock),
         }
     }
 
-    pub async fn handle(&self, command: ServiceCommand) -> Result<()> {
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn handle(&self, command: ServiceCommand, causation: CausationContext) -> Result<()> {
         let aggregate_id = command.notification_id();
-        let correlation_id = Uuid::now_v7();
         let message = QueueMessage::Service(command);
 
-        let envelope = CommandEnvelope::enrich(
+        let envelope = CommandEnvelope::caused_by(
             message,
             aggregate_id,
-            correlation_id,
-            correlation_id,
+            causation,
             "notification".to_string(),
-            Utc::now(),
+            self.clock.as_ref(),
         );
 
         self.command_queue
@@ -49,7 +54,11 @@ impl ServiceCommandProcessor {
             .context("Failed to send service command to queue")
     }
 
-    pub async fn handle_priority(&self, command: ServiceCommand) -> Result<NotificationCommand> {
+    pub async fn handle_priority(
+        &self,
+        command: ServiceCommand,
+        causation: CausationContext,
+    ) -> Result<NotificationCommand> {
         match command {
             ServiceCommand::RenderNotification {
                 notification_id,
@@ -92,6 +101,7 @@ impl ServiceCommandProcessor {
                     destination,
                     rendered_content,
                     idempotency_key: format!("notification-{}", notification_id),
+                    causation,
                 };
 
                 let external_id = self
@@ -7,7 +7,7 @@ use fern_labour_notifications_shared::value_objects::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use fern_labour_event_sourcing_rs::Aggregate;
+use fern_labour_event_sourcing_rs::{Aggregate, Clock};
 
 use crate::durable_object::write_side::domain::{
     NotificationCommand, NotificationError, NotificationEvent,
@@ -118,6 +118,7 @@ impl Aggregate for Notification {
     fn handle_command(
         state: Option<&Self>,
         command: Self::Command,
+        _clock: &dyn Clock,
     ) -> std::result::Result<Vec<Self::Event>, Self::Error> {
         let events = match command {
             NotificationCommand::RequestNotification {
@@ -284,6 +285,7 @@ impl Aggregate for Notification {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use fern_labour_event_sourcing_rs::testing::FixedClock;
     use fern_labour_notifications_shared::value_objects::EmailAddress;
 
     fn create_test_notification_id() -> Uuid {
@@ -314,7 +316,7 @@ mod tests {
             priority: NotificationPriority::default(),
         };
 
-        let events = Notification::handle_command(None, command).unwrap();
+        let events = Notification::handle_command(None, command, &FixedClock::default()).unwrap();
 
         assert!(matches!(
             events[0],
@@ -349,7 +351,7 @@ mod tests {
             priority: NotificationPriority::default(),
         };
 
-        let result = Notification::handle_command(Some(&existing), command);
+        let result = Notification::handle_command(Some(&existing), command, &FixedClock::default());
 
         assert!(matches!(result, Err(NotificationError::AlreadyExists)));
     }
@@ -379,7 +381,9 @@ mod tests {
             },
         };
 
-        let events = Notification::handle_command(Some(&notification), command).unwrap();
+        let events =
+            Notification::handle_command(Some(&notification), command, &FixedClock::default())
+                .unwrap();
 
         assert!(matches!(
             events[0],
@@ -412,7 +416,8 @@ mod tests {
             },
         };
 
-        let result = Notification::handle_command(Some(&notification), command);
+        let result =
+            Notification::handle_command(Some(&notification), command, &FixedClock::default());
 
         assert!(matches!(
             result,
@@ -445,7 +450,9 @@ mod tests {
             external_id: Some("ext-123".to_string()),
         };
 
-        let events = Notification::handle_command(Some(&notification), command).unwrap();
+        let events =
+            Notification::handle_command(Some(&notification), command, &FixedClock::default())
+                .unwrap();
 
         assert_eq!(events.len(), 1);
         assert!(matches!(
@@ -482,7 +489,8 @@ mod tests {
             external_id: Some("ext-456".to_string()),
         };
 
-        let result = Notification::handle_command(Some(&notification), command);
+        let result =
+            Notification::handle_command(Some(&notification), command, &FixedClock::default());
 
         assert!(matches!(
             result,
@@ -512,7 +520,9 @@ mod tests {
 
         let command = NotificationCommand::MarkAsDelivered { notification_id };
 
-        let events = Notification::handle_command(Some(&notification), command).unwrap();
+        let events =
+            Notification::handle_command(Some(&notification), command, &FixedClock::default())
+                .unwrap();
 
         assert_eq!(events.len(), 1);
         assert!(matches!(
@@ -540,7 +550,8 @@ mod tests {
 
         let command = NotificationCommand::MarkAsDelivered { notification_id };
 
-        let result = Notification::handle_command(Some(&notification), command);
+        let result =
+            Notification::handle_command(Some(&notification), command, &FixedClock::default());
 
         assert!(matches!(
             result,
@@ -570,7 +581,8 @@ mod tests {
 
         let command = NotificationCommand::MarkAsDelivered { notification_id };
 
-        let result = Notification::handle_command(Some(&notification), command);
+        let result =
+            Notification::handle_command(Some(&notification), command, &FixedClock::default());
 
         assert!(matches!(
             result,
@@ -603,7 +615,9 @@ mod tests {
             reason: Some("Something went wrong".to_string()),
         };
 
-        let events = Notification::handle_command(Some(&notification), command).unwrap();
+        let events =
+            Notification::handle_command(Some(&notification), command, &FixedClock::default())
+                .unwrap();
 
         assert_eq!(events.len(), 1);
         assert!(matches!(
@@ -634,7 +648,8 @@ mod tests {
             reason: Some("Something went wrong".to_string()),
         };
 
-        let result = Notification::handle_command(Some(&notification), command);
+        let result =
+            Notification::handle_command(Some(&notification), command, &FixedClock::default());
 
         assert!(matches!(
             result,
@@ -738,3 +753,119 @@ mod tests {
         assert_eq!(notification.status(), &NotificationStatus::DELIVERED);
     }
 }
+
+#[cfg(test)]
+mod properties {
+    use fern_labour_event_sourcing_rs::testing::InvariantHarness;
+    use fern_labour_notifications_shared::value_objects::EmailAddress;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn notification_id() -> Uuid {
+        Uuid::from_u128(0xfe)
+    }
+
+    fn request() -> NotificationCommand {
+        NotificationCommand::RequestNotification {
+            notification_id: notification_id(),
+            channel: NotificationChannel::EMAIL,
+            destination: NotificationDestination::Email(
+                EmailAddress::new("test@example.com").unwrap(),
+            ),
+            template_data: NotificationTemplateData::ContactUs {
+                name: "John Doe".to_string(),
+            },
+            metadata: None,
+            priority: NotificationPriority::default(),
+        }
+    }
+
+    fn rendered_content() -> impl Strategy<Value = RenderedContent> {
+        prop_oneof![
+            3 => Just(RenderedContent::Email {
+                subject: "Subject".to_string(),
+                html_body: "<p>Body</p>".to_string(),
+            }),
+            1 => Just(RenderedContent::Sms {
+                body: "Body".to_string(),
+            }),
+        ]
+    }
+
+    fn command() -> impl Strategy<Value = NotificationCommand> {
+        prop_oneof![
+            Just(request()),
+            rendered_content().prop_map(|rendered_content| {
+                NotificationCommand::StoreRenderedContent {
+                    notification_id: notification_id(),
+                    rendered_content,
+                }
+            }),
+            prop::option::of("[a-z]{4}").prop_map(|external_id| {
+                NotificationCommand::MarkAsDispatched {
+                    notification_id: notification_id(),
+                    external_id,
+                }
+            }),
+            Just(NotificationCommand::MarkAsDelivered {
+                notification_id: notification_id(),
+            }),
+            prop::option::of("[a-z]{4}").prop_map(|reason| NotificationCommand::MarkAsFailed {
+                notification_id: notification_id(),
+                reason,
+            }),
+        ]
+    }
+
+    fn commands() -> impl Strategy<Value = Vec<NotificationCommand>> {
+        prop::collection::vec(command(), 0..20)
+            .prop_map(|commands| std::iter::once(request()).chain(commands).collect())
+    }
+
+    fn allowed_transition(from: &NotificationStatus, to: &NotificationStatus) -> bool {
+        use NotificationStatus::*;
+        matches!(
+            (from, to),
+            (REQUESTED, RENDERED)
+                | (RENDERED, SENT)
+                | (FAILED, SENT)
+                | (SENT, DELIVERED)
+                | (SENT, FAILED)
+        )
+    }
+
+    fn harness() -> InvariantHarness<Notification> {
+        InvariantHarness::new()
+            .with_invariant(
+                "status follows the delivery state machine",
+                |before, after: &Notification| match before {
+                    None if after.status != NotificationStatus::REQUESTED => {
+                        Err(format!("created in status {:?}", after.status))
+                    }
+                    Some(before) if !allowed_transition(&before.status, &after.status) => Err(
+                        format!("moved from {:?} to {:?}", before.status, after.status),
+                    ),
+                    _ => Ok(()),
+                },
+            )
+            .with_invariant(
+                "only rendered notifications leave REQUESTED",
+                |_, after: &Notification| {
+                    if after.status != NotificationStatus::REQUESTED
+                        && after.rendered_content.is_none()
+                    {
+                        return Err(format!("{:?} without rendered content", after.status));
+                    }
+                    Ok(())
+                },
+            )
+    }
+
+    proptest! {
+        #[test]
+        fn notification_invariants_hold_for_any_command_sequence(commands in commands()) {
+            harness().check(commands)?;
+        }
+    }
+}
@@ -6,9 +6,10 @@ use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, fmt::Debug};
 use uuid::Uuid;
 
-use fern_labour_event_sourcing_rs::{Event, impl_event};
+use fern_labour_event_sourcing_rs::DomainEvent;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, DomainEvent)]
+#[event(aggregate_id = "notification_id")]
 pub struct NotificationRequested {
     pub notification_id: Uuid,
     pub channel: NotificationChannel,
@@ -18,33 +19,31 @@ pub struct NotificationRequested {
     pub priority: NotificationPriority,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, DomainEvent)]
+#[event(aggregate_id = "notification_id")]
 pub struct RenderedContentStored {
     pub notification_id: Uuid,
     pub rendered_content: RenderedContent,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, DomainEvent)]
+#[event(aggregate_id = "notification_id")]
 pub struct NotificationDispatched {
     pub notification_id: Uuid,
     pub external_id: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, DomainEvent)]
+#[event(aggregate_id = "notification_id")]
 pub struct NotificationDelivered {
     pub notification_id: Uuid,
     pub external_id: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, DomainEvent)]
+#[event(aggregate_id = "notification_id")]
 pub struct NotificationDeliveryFailed {
     pub notification_id: Uuid,
     pub external_id: String,
     pub reason: Option<String>,
 }
-
-impl_event!(NotificationRequested, notification_id);
-impl_event!(RenderedContentStored, notification_id);
-impl_event!(NotificationDispatched, notification_id);
-impl_event!(NotificationDelivered, notification_id);
-impl_event!(NotificationDeliveryFailed, notification_id);
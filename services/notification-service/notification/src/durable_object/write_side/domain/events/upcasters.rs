@@ -0,0 +1,20 @@
+use fern_labour_event_sourcing_rs::UpcasterRegistry;
+
+/// Upcasters for stored NotificationEvent payloads. When a payload's
+/// `#[event(version = N)]` is bumped, register a `(event_type, from_version)`
+/// upcaster here that migrates the previous payload, e.g.
+/// `.register("NotificationRequested", 1, upcast_notification_requested_v1)`.
+pub fn upcaster_registry() -> UpcasterRegistry {
+    UpcasterRegistry::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::durable_object::write_side::domain::events::NotificationEvent;
+
+    #[test]
+    fn upcasters_cover_every_event_version() {
+        upcaster_registry().validate::<NotificationEvent>().unwrap();
+    }
+}
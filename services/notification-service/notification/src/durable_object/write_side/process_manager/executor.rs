@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use async_trait::async_trait;
+use fern_labour_event_sourcing_rs::{CausationContext, EffectExecutor};
 use fern_labour_notifications_shared::ServiceCommand;
 
 use crate::durable_object::write_side::{
@@ -7,11 +8,6 @@ use crate::durable_object::write_side::{
     process_manager::types::Effect,
 };
 
-#[async_trait(?Send)]
-pub trait EffectExecutor {
-    async fn execute(&self, effect: &Effect) -> Result<()>;
-}
-
 pub struct NotificationEffectExecutor {
     service_command_processor: ServiceCommandProcessor,
     notification_command_processor: NotificationCommandProcessor,
@@ -28,20 +24,29 @@ impl NotificationEffectExecutor {
         }
     }
 
-    async fn handle_service_command(&self, command: ServiceCommand, priority: bool) -> Result<()> {
+    async fn handle_service_command(
+        &self,
+        command: ServiceCommand,
+        priority: bool,
+        causation: CausationContext,
+    ) -> Result<()> {
         if priority {
             let notification_command = self
                 .service_command_processor
-                .handle_priority(command)
+                .handle_priority(command, causation)
                 .await
                 .context("Failed to execute service command")?;
 
             self.notification_command_processor
-                .handle_command(notification_command, "process-manager".to_string())
+                .handle_command(
+                    notification_command,
+                    "process-manager".to_string(),
+                    causation,
+                )
                 .context("Failed to handle resulting notification command")?;
         } else {
             self.service_command_processor
-                .handle(command)
+                .handle(command, causation)
                 .await
                 .context("Failed to queue service command")?;
         }
@@ -51,13 +56,13 @@ impl NotificationEffectExecutor {
 }
 
 #[async_trait(?Send)]
-impl EffectExecutor for NotificationEffectExecutor {
-    async fn execute(&self, effect: &Effect) -> Result<()> {
+impl EffectExecutor<Effect> for NotificationEffectExecutor {
+    async fn execute(&self, effect: &Effect, causation: CausationContext) -> Result<()> {
         match effect {
             Effect::ServiceCommand {
                 command, priority, ..
             } => {
-                self.handle_service_command(command.clone(), *priority)
+                self.handle_service_command(command.clone(), *priority, causation)
                     .await
             }
         }
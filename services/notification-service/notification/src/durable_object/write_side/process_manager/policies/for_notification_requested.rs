@@ -1,9 +1,9 @@
-use fern_labour_event_sourcing_rs::{HasPolicies, PolicyContext, PolicyFn};
+use fern_labour_event_sourcing_rs::{HasPolicies, IdempotencyKey, PolicyContext, PolicyFn};
 use fern_labour_notifications_shared::{ServiceCommand, value_objects::NotificationPriority};
 
 use crate::durable_object::write_side::{
     domain::{Notification, events::notification::NotificationRequested},
-    process_manager::types::{Effect, IdempotencyKey},
+    process_manager::types::Effect,
 };
 
 impl HasPolicies<Notification, Effect> for NotificationRequested {
@@ -1,2 +1,27 @@
 pub mod for_notification_requested;
 pub mod for_rendered_content_stored;
+
+use fern_labour_event_sourcing_rs::{EventDescriptor, HasPolicies, PolicyContext, PolicyRouter};
+
+use crate::durable_object::write_side::{
+    domain::{
+        Notification, NotificationEvent,
+        events::notification::{NotificationRequested, RenderedContentStored},
+    },
+    process_manager::types::Effect,
+};
+
+impl PolicyRouter<Notification, Effect> for NotificationEvent {
+    const ROUTED_EVENT_TYPES: &'static [&'static str] = &[
+        NotificationRequested::EVENT_TYPE,
+        RenderedContentStored::EVENT_TYPE,
+    ];
+
+    fn route_policies(&self, ctx: &PolicyContext<'_, Notification>) -> Vec<Effect> {
+        match self {
+            NotificationEvent::NotificationRequested(e) => e.apply_policies(ctx),
+            NotificationEvent::RenderedContentStored(e) => e.apply_policies(ctx),
+            _ => vec![],
+        }
+    }
+}
@@ -1,18 +1,6 @@
+use fern_labour_event_sourcing_rs::{IdempotencyKey, ProcessEffect};
 use fern_labour_notifications_shared::ServiceCommand;
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct IdempotencyKey(pub String);
-
-impl IdempotencyKey {
-    pub fn for_command(aggregate_id: Uuid, event_sequence: i64, command_type: &str) -> Self {
-        Self(format!(
-            "{}:{}:cmd:{}",
-            aggregate_id, event_sequence, command_type
-        ))
-    }
-}
 
 #[derive(Clone, Serialize, Deserialize)]
 pub enum Effect {
@@ -23,8 +11,8 @@ pub enum Effect {
     },
 }
 
-impl Effect {
-    pub fn idempotency_key(&self) -> &IdempotencyKey {
+impl ProcessEffect for Effect {
+    fn idempotency_key(&self) -> &IdempotencyKey {
         match self {
             Effect::ServiceCommand {
                 idempotency_key, ..
@@ -32,28 +20,9 @@ impl Effect {
         }
     }
 
-    pub fn effect_type(&self) -> &'static str {
+    fn effect_type(&self) -> &'static str {
         match self {
             Effect::ServiceCommand { .. } => "SERVICE_COMMAND",
         }
     }
 }
-
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub enum EffectStatus {
-    Pending,
-    Dispatched,
-    Completed,
-    Failed,
-}
-
-impl std::fmt::Display for EffectStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            EffectStatus::Pending => write!(f, "PENDING"),
-            EffectStatus::Dispatched => write!(f, "DISPATCHED"),
-            EffectStatus::Completed => write!(f, "COMPLETED"),
-            EffectStatus::Failed => write!(f, "FAILED"),
-        }
-    }
-}
@@ -16,7 +16,7 @@ use crate::{
     durable_object::write_side::domain::NotificationCommand,
 };
 
-use fern_labour_event_sourcing_rs::CommandEnvelope;
+use fern_labour_event_sourcing_rs::{CausationContext, CommandEnvelope};
 use fern_labour_notifications_shared::{
     QueueMessage, ServiceCommand, service_clients::DispatchRequest,
 };
@@ -87,7 +87,7 @@ pub async fn main(message_batch: MessageBatch<String>, env: Env, _ctx: Context)
                         QueueMessage::Service(cmd) => {
                             match cmd {
                                 ServiceCommand::RenderNotification { notification_id, channel, template_data } => {
-                                    app_state.generation_client.render_async(notification_id, channel, template_data).await.map_err(|e| anyhow!("Generation client error: {e}"))
+                                    app_state.generation_client.render_async(notification_id, channel, template_data, CausationContext::from_command(&envelope.metadata)).await.map_err(|e| anyhow!("Generation client error: {e}"))
                                 },
                                 ServiceCommand::DispatchNotification { notification_id, channel, destination, rendered_content } => {
                             let request = DispatchRequest {
@@ -96,6 +96,7 @@ pub async fn main(message_batch: MessageBatch<String>, env: Env, _ctx: Context)
                                 destination: destination.clone(),
                                 rendered_content: rendered_content.clone(),
                                 idempotency_key: envelope.metadata.idempotency_key.to_string(),
+                                causation: CausationContext::from_command(&envelope.metadata),
                             };
                             app_state.dispatch_client.dispatch_async(request).await.map_err(|e| anyhow!("Dispatch client error: {e}"))
                                 }